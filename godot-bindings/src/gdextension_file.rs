@@ -0,0 +1,447 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::{get_godot_version, GodotVersion};
+
+/// Entry symbol generated by `#[gdextension]` when no `entry_point` is specified.
+const DEFAULT_ENTRY_SYMBOL: &str = "gdext_rust_init";
+
+/// Environment variable declaring the oldest supported Godot version; kept in sync with godot-codegen.
+pub(crate) const MIN_GODOT_API_ENV: &str = "GDEXT_MIN_GODOT_API";
+
+/// Creates or updates the `.gdextension` file of a Godot project, from a build script.
+///
+/// The `[configuration]` section receives the entry symbol, `compatibility_minimum` and (if set) the `reloadable` flag. In `[libraries]`,
+/// the debug and release entries for the target currently being compiled point to the library in Cargo's target directory. Entries for
+/// other platforms, as well as other sections and comments, are kept. The file is only written if its contents change.
+///
+/// Library paths are relative to the Godot project, i.e. the closest directory containing `project.godot` (or the directory of the
+/// `.gdextension` file if there is none).
+///
+/// # Example
+/// In `build.rs`, with `godot-bindings` as a build dependency:
+/// ```no_run
+/// godot_bindings::GdextensionFile::new("../godot/rust.gdextension")
+///     .reloadable(true)
+///     .write();
+/// ```
+#[derive(Clone, Debug)]
+pub struct GdextensionFile {
+    path: PathBuf,
+    entry_symbol: String,
+    library_name: Option<String>,
+    compatibility_minimum: Option<(u8, u8)>,
+    reloadable: Option<bool>,
+}
+
+impl GdextensionFile {
+    /// Targets the `.gdextension` file at `path`, relative to the crate's manifest directory.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            entry_symbol: DEFAULT_ENTRY_SYMBOL.to_string(),
+            library_name: None,
+            compatibility_minimum: None,
+            reloadable: None,
+        }
+    }
+
+    /// Entry symbol, if changed with `#[gdextension(entry_symbol = "...")]`. Default is `gdext_rust_init`.
+    pub fn entry_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.entry_symbol = symbol.into();
+        self
+    }
+
+    /// Name of the library, if changed in the `[lib]` section of `Cargo.toml`. Default is the package name, with `-` replaced by `_`.
+    pub fn library_name(mut self, name: impl Into<String>) -> Self {
+        self.library_name = Some(name.into());
+        self
+    }
+
+    /// Oldest Godot version that may load the library.
+    ///
+    /// Default is the version set through `GDEXT_MIN_GODOT_API`, or otherwise the version against which gdext is compiled.
+    pub fn compatibility_minimum(mut self, major: u8, minor: u8) -> Self {
+        self.compatibility_minimum = Some((major, minor));
+        self
+    }
+
+    /// Whether Godot reloads the library when it changes (Godot 4.2+). Not written unless set.
+    pub fn reloadable(mut self, reloadable: bool) -> Self {
+        self.reloadable = Some(reloadable);
+        self
+    }
+
+    /// Writes the file, if its contents change.
+    ///
+    /// # Panics
+    /// If not invoked from a build script, if the target platform is not supported by Godot, or on I/O errors.
+    pub fn write(self) {
+        let manifest_dir = env_var("CARGO_MANIFEST_DIR");
+        let path = Path::new(&manifest_dir).join(&self.path);
+        let file_dir = path
+            .parent()
+            .and_then(|dir| dir.canonicalize().ok())
+            .unwrap_or_else(|| panic!("directory of {} does not exist", path.display()));
+        let project_dir = find_project_dir(&file_dir);
+
+        let (major, minor) = self
+            .compatibility_minimum
+            .unwrap_or_else(default_compatibility_minimum);
+
+        let mut configuration = vec![
+            ("entry_symbol", quoted(&self.entry_symbol)),
+            ("compatibility_minimum", format!("{major}.{minor}")),
+        ];
+        if let Some(reloadable) = self.reloadable {
+            configuration.push(("reloadable", reloadable.to_string()));
+        }
+
+        let libraries = self.library_entries(&project_dir);
+        let libraries = libraries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect();
+
+        let existing = std::fs::read_to_string(&path).unwrap_or_default();
+        let contents = update_contents(
+            &existing,
+            &[("configuration", configuration), ("libraries", libraries)],
+        );
+
+        if contents != existing {
+            std::fs::write(&path, contents)
+                .unwrap_or_else(|e| panic!("failed to write {}: {e}", path.display()));
+        }
+    }
+
+    /// Returns `(key, "res://...")` for the debug and release builds of the current target.
+    fn library_entries(&self, project_dir: &Path) -> Vec<(String, String)> {
+        let os = env_var("CARGO_CFG_TARGET_OS");
+        let arch = env_var("CARGO_CFG_TARGET_ARCH");
+        let (platform, arch_tag) = platform_tags(&os, &arch).unwrap_or_else(|| {
+            panic!("target `{os}` on `{arch}` has no corresponding Godot platform")
+        });
+
+        let library_name = self
+            .library_name
+            .clone()
+            .unwrap_or_else(|| env_var("CARGO_PKG_NAME").replace('-', "_"));
+        let file_name = library_file_name(&os, &library_name);
+
+        // OUT_DIR is <target>/[<triple>/]<profile>/build/<package>-<hash>/out.
+        let out_dir = PathBuf::from(env_var("OUT_DIR"));
+        let target_dir = out_dir
+            .ancestors()
+            .nth(4)
+            .and_then(|dir| dir.canonicalize().ok())
+            .expect("OUT_DIR is not inside Cargo's target directory");
+
+        ["debug", "release"]
+            .into_iter()
+            .map(|profile| {
+                let key = match arch_tag {
+                    Some(arch_tag) => format!("{platform}.{profile}.{arch_tag}"),
+                    None => format!("{platform}.{profile}"),
+                };
+                let library = target_dir.join(profile).join(&file_name);
+                let value = format!("res://{}", relative_path(project_dir, &library));
+
+                (key, quoted(&value))
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn env_var(name: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| {
+        panic!("{name} not set; GdextensionFile must be used in a build script")
+    })
+}
+
+fn quoted(value: &str) -> String {
+    format!("\"{value}\"")
+}
+
+fn default_compatibility_minimum() -> (u8, u8) {
+    let GodotVersion { major, minor, .. } = get_godot_version();
+
+    // gdext compiled against 4.2+ relies on the 4.2 GDExtension interface, so it does not load in older versions.
+    let floor = minor.min(2);
+
+    match declared_min_api() {
+        Some((declared_major, declared_minor)) if declared_major == major => {
+            (major, declared_minor.clamp(floor, minor))
+        }
+        _ => (major, minor),
+    }
+}
+
+/// Oldest Godot version `(major, minor)` declared through `GDEXT_MIN_GODOT_API`, if set and valid.
+pub(crate) fn declared_min_api() -> Option<(u8, u8)> {
+    let value = std::env::var(MIN_GODOT_API_ENV).ok()?;
+    let (declared_major, declared_minor) = value.trim().split_once('.')?;
+
+    Some((
+        declared_major.parse::<u8>().ok()?,
+        declared_minor.parse::<u8>().ok()?,
+    ))
+}
+
+/// Maps Rust's `target_os` and `target_arch` to Godot's platform and architecture feature tags.
+///
+/// The architecture is omitted for x86_64 macOS, for which Godot's convention is the tag-less (universal) entry.
+fn platform_tags(os: &str, arch: &str) -> Option<(&'static str, Option<&'static str>)> {
+    let platform = match os {
+        "linux" => "linux",
+        "windows" => "windows",
+        "macos" => "macos",
+        "ios" => "ios",
+        "android" => "android",
+        "emscripten" => "web",
+        _ => return None,
+    };
+
+    let arch_tag = match arch {
+        "x86_64" if platform == "macos" => return Some((platform, None)),
+        "x86_64" => "x86_64",
+        "x86" => "x86_32",
+        "aarch64" => "arm64",
+        "arm" => "arm32",
+        "riscv64" => "rv64",
+        "wasm32" => "wasm32",
+        _ => return None,
+    };
+
+    Some((platform, Some(arch_tag)))
+}
+
+fn library_file_name(os: &str, library_name: &str) -> String {
+    match os {
+        "windows" => format!("{library_name}.dll"),
+        "macos" | "ios" => format!("lib{library_name}.dylib"),
+        "emscripten" => format!("{library_name}.wasm"),
+        _ => format!("lib{library_name}.so"),
+    }
+}
+
+fn find_project_dir(file_dir: &Path) -> PathBuf {
+    file_dir
+        .ancestors()
+        .find(|dir| dir.join("project.godot").is_file())
+        .unwrap_or(file_dir)
+        .to_path_buf()
+}
+
+/// Path of `to` relative to `from`, with `/` separators. Both paths must be absolute and canonical.
+fn relative_path(from: &Path, to: &Path) -> String {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let ups = std::iter::repeat("..".to_string()).take(from.len() - common);
+    let downs = to[common..]
+        .iter()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned());
+
+    ups.chain(downs).collect::<Vec<_>>().join("/")
+}
+
+/// Sets `key = value` pairs in the given sections of an INI-style file, keeping all other lines.
+///
+/// Existing keys are replaced in place, new keys are appended to their section, and missing sections are added at the end.
+fn update_contents(existing: &str, updates: &[(&str, Vec<(&str, String)>)]) -> String {
+    // Lines before the first section header are stored with an empty name.
+    let mut sections: Vec<(String, Vec<String>)> = vec![(String::new(), vec![])];
+    for line in existing.lines() {
+        match line
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+        {
+            Some(name) => sections.push((name.to_string(), vec![line.to_string()])),
+            None => sections.last_mut().unwrap().1.push(line.to_string()),
+        }
+    }
+
+    for (section_name, entries) in updates {
+        let lines = match sections.iter().position(|(name, _)| name == section_name) {
+            Some(index) => &mut sections[index].1,
+            None => {
+                let previous = &mut sections.last_mut().unwrap().1;
+                if previous
+                    .last()
+                    .map_or(false, |line| !line.trim().is_empty())
+                {
+                    previous.push(String::new());
+                }
+
+                sections.push((section_name.to_string(), vec![format!("[{section_name}]")]));
+                &mut sections.last_mut().unwrap().1
+            }
+        };
+
+        for (key, value) in entries {
+            let line = format!("{key} = {value}");
+            let existing_line = lines.iter().position(|line| {
+                line.split_once('=')
+                    .map_or(false, |(line_key, _)| line_key.trim() == *key)
+            });
+
+            match existing_line {
+                Some(index) => lines[index] = line,
+                None => {
+                    // Insert after the last non-empty line, so blank lines keep separating sections.
+                    let end = lines
+                        .iter()
+                        .rposition(|line| !line.trim().is_empty())
+                        .map_or(0, |index| index + 1);
+                    lines.insert(end, line);
+                }
+            }
+        }
+    }
+
+    let mut contents = String::new();
+    for line in sections.iter().flat_map(|(_, lines)| lines) {
+        contents.push_str(line);
+        contents.push('\n');
+    }
+    contents
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn update_contents_replaces_and_appends() {
+        let existing = "\
+; Maintained by build.rs
+[configuration]
+entry_symbol = \"old_init\"
+
+[libraries]
+windows.debug.x86_64 = \"res://old.dll\"
+linux.debug.x86_64 = \"res://old.so\"
+
+[icons]
+MyNode = \"res://icon.svg\"
+";
+
+        let updated = update_contents(
+            existing,
+            &[
+                (
+                    "configuration",
+                    vec![
+                        ("entry_symbol", quoted("gdext_rust_init")),
+                        ("compatibility_minimum", "4.2".to_string()),
+                    ],
+                ),
+                (
+                    "libraries",
+                    vec![
+                        ("linux.debug.x86_64", quoted("res://new.so")),
+                        ("linux.release.x86_64", quoted("res://new_release.so")),
+                    ],
+                ),
+            ],
+        );
+
+        let expected = "\
+; Maintained by build.rs
+[configuration]
+entry_symbol = \"gdext_rust_init\"
+compatibility_minimum = 4.2
+
+[libraries]
+windows.debug.x86_64 = \"res://old.dll\"
+linux.debug.x86_64 = \"res://new.so\"
+linux.release.x86_64 = \"res://new_release.so\"
+
+[icons]
+MyNode = \"res://icon.svg\"
+";
+        assert_eq!(updated, expected);
+
+        // Applying the same update again is a no-op.
+        let unchanged = update_contents(
+            &updated,
+            &[(
+                "libraries",
+                vec![("linux.debug.x86_64", quoted("res://new.so"))],
+            )],
+        );
+        assert_eq!(unchanged, updated);
+    }
+
+    #[test]
+    fn update_contents_creates_sections() {
+        let updated = update_contents(
+            "",
+            &[
+                ("configuration", vec![("entry_symbol", quoted("init"))]),
+                (
+                    "libraries",
+                    vec![("web.debug.wasm32", quoted("res://a.wasm"))],
+                ),
+            ],
+        );
+
+        let expected = "\
+[configuration]
+entry_symbol = \"init\"
+
+[libraries]
+web.debug.wasm32 = \"res://a.wasm\"
+";
+        assert_eq!(updated, expected);
+    }
+
+    #[test]
+    fn relative_paths() {
+        let project = Path::new("/work/game/godot");
+
+        assert_eq!(
+            relative_path(project, Path::new("/work/game/target/debug/libgame.so")),
+            "../target/debug/libgame.so"
+        );
+        assert_eq!(
+            relative_path(project, Path::new("/work/game/godot/bin/libgame.so")),
+            "bin/libgame.so"
+        );
+    }
+
+    #[test]
+    fn platforms() {
+        assert_eq!(
+            platform_tags("linux", "x86_64"),
+            Some(("linux", Some("x86_64")))
+        );
+        assert_eq!(platform_tags("macos", "x86_64"), Some(("macos", None)));
+        assert_eq!(
+            platform_tags("macos", "aarch64"),
+            Some(("macos", Some("arm64")))
+        );
+        assert_eq!(
+            platform_tags("emscripten", "wasm32"),
+            Some(("web", Some("wasm32")))
+        );
+        assert_eq!(platform_tags("freebsd", "x86_64"), None);
+
+        assert_eq!(library_file_name("windows", "game"), "game.dll");
+        assert_eq!(library_file_name("linux", "game"), "libgame.so");
+    }
+}
@@ -5,10 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub(crate) mod gdextension_file;
 pub(crate) mod watch;
 
 use std::path::Path;
 
+pub use gdextension_file::GdextensionFile;
 pub use watch::StopWatch;
 
 // Note: we cannot prevent both `custom-godot` and `prebuilt-godot` from being specified; see Cargo.toml for more information.
@@ -126,6 +128,25 @@ pub use prebuilt::*;
 
 const NEXT_MINOR_VERSION: u8 = 3;
 
+/// Returns the prebuilt `extension_api.json` of Godot `major.minor`, regardless of the Godot version used for codegen.
+///
+/// Godot 4.2 is available with the `prebuilt-godot` feature, 4.0 and 4.1 with the `min-godot-api` feature. Used to determine which APIs
+/// are newer than a given version.
+pub fn load_gdextension_json_of(major: u8, minor: u8) -> Option<&'static str> {
+    match (major, minor) {
+        #[cfg(feature = "min-godot-api")]
+        (4, 0) => return Some(godot4_prebuilt_4_0::load_gdextension_json()),
+        #[cfg(feature = "min-godot-api")]
+        (4, 1) => return Some(godot4_prebuilt_4_1::load_gdextension_json()),
+        #[cfg(feature = "prebuilt-godot")]
+        (4, 2) => return Some(godot4_prebuilt::load_gdextension_json()),
+        _ => {}
+    }
+
+    let _ = (major, minor);
+    None
+}
+
 pub fn clear_dir(dir: &Path, watch: &mut StopWatch) {
     if dir.exists() {
         std::fs::remove_dir_all(dir).unwrap_or_else(|e| panic!("failed to delete dir: {e}"));
@@ -151,6 +172,23 @@ pub fn emit_godot_version_cfg() {
         println!(r#"cargo:rustc-cfg=before_api="{major}.{m}""#);
     }
 
+    // Like since_api/before_api, but for the oldest supported version (GDEXT_MIN_GODOT_API, if lower). Code using engine APIs must be
+    // gated with these, as codegen omits engine APIs newer than the minimum version.
+    println!(
+        "cargo:rerun-if-env-changed={}",
+        gdextension_file::MIN_GODOT_API_ENV
+    );
+    let min_minor = match gdextension_file::declared_min_api() {
+        Some((min_major, min_minor)) if min_major == major => min_minor.min(minor),
+        _ => minor,
+    };
+    for m in 1..=min_minor {
+        println!(r#"cargo:rustc-cfg=since_min_api="{major}.{m}""#);
+    }
+    for m in min_minor + 1..=max {
+        println!(r#"cargo:rustc-cfg=before_min_api="{major}.{m}""#);
+    }
+
     // The below configuration keys are very rarely needed and should generally not be used.
     println!(r#"cargo:rustc-cfg=gdextension_minor_api="{major}.{minor}""#);
 
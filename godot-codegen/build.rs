@@ -5,6 +5,47 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::fs;
+use std::path::{Path, PathBuf};
+
 fn main() {
     godot_bindings::emit_godot_version_cfg();
+    emit_source_hash();
+}
+
+/// Hashes the codegen sources, so that cached bindings (see `cache.rs`) are invalidated by any change to the generator itself --
+/// also between git revisions that share the same crate version.
+fn emit_source_hash() {
+    let mut files = vec![PathBuf::from("Cargo.toml")];
+    collect_files(Path::new("src"), &mut files);
+    files.sort();
+
+    // FNV-1a, like the cache key itself: stable across Rust versions and machines.
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for file in &files {
+        let path = file.to_string_lossy().replace('\\', "/");
+        let contents = fs::read(file).unwrap_or_else(|e| panic!("read {}: {e}", file.display()));
+
+        for byte in path.bytes().chain([0xff]).chain(contents).chain([0xff]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    println!("cargo:rustc-env=GDEXT_CODEGEN_SOURCE_HASH={hash:016x}");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|e| panic!("read {}: {e}", dir.display()));
+
+    for entry in entries {
+        let path = entry.expect("directory entry").path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
 }
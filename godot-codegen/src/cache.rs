@@ -0,0 +1,302 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reuse of generated bindings across clean builds.
+//!
+//! If the environment variable [`CACHE_DIR_ENV`] is set, build scripts store the generated files in a subdirectory of that directory,
+//! named after the crate and a hash of everything that influences codegen: the codegen sources, the Godot API, codegen-relevant Cargo
+//! features, the target and the build-time environment variables read by codegen. A later build with the same inputs copies the files instead of running
+//! codegen again. The directory can be persisted by CI caches, or checked into version control.
+
+use crate::util::{CLASS_ALLOWLIST_ENV, CLASS_ALLOWLIST_FILE_ENV, MIN_GODOT_API_ENV};
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the environment variable that holds the directory for cached bindings.
+pub const CACHE_DIR_ENV: &str = "GDEXT_BINDINGS_CACHE";
+
+/// Codegen-relevant state of the environment, besides the Godot API itself.
+const KEY_ENV_VARS: &[&str] = &[
+    "TARGET",
+    MIN_GODOT_API_ENV,
+    CLASS_ALLOWLIST_ENV,
+    CLASS_ALLOWLIST_FILE_ENV,
+];
+
+/// Runs `generate` to write bindings to `gen_path`, unless they can be restored from the cache. Stores newly generated bindings.
+pub fn generate_cached(
+    crate_name: &str,
+    gen_path: &Path,
+    watch: &mut godot_bindings::StopWatch,
+    generate: impl FnOnce(&mut godot_bindings::StopWatch),
+) {
+    let Some(cache) = BindingsCache::from_env(crate_name, watch) else {
+        generate(watch);
+        return;
+    };
+
+    if cache.restore(gen_path) {
+        watch.record("restore_cached_bindings");
+    } else {
+        generate(watch);
+        cache.store(gen_path);
+        watch.record("store_cached_bindings");
+    }
+}
+
+/// Location of generated files for one crate and one set of codegen inputs.
+struct BindingsCache {
+    entry_dir: PathBuf,
+}
+
+impl BindingsCache {
+    /// Determines the cache entry for `crate_name`, based on the current Godot API and codegen configuration.
+    ///
+    /// Returns `None` if caching is disabled, i.e. [`CACHE_DIR_ENV`] is not set.
+    fn from_env(crate_name: &str, watch: &mut godot_bindings::StopWatch) -> Option<Self> {
+        println!("cargo:rerun-if-env-changed={CACHE_DIR_ENV}");
+        let cache_dir = std::env::var_os(CACHE_DIR_ENV)?;
+
+        let json = godot_bindings::load_gdextension_json(watch);
+        #[allow(clippy::useless_asref)]
+        let key = compute_key(json.as_ref());
+        watch.record("compute_cache_key");
+
+        let entry_dir = Path::new(&cache_dir).join(format!("{crate_name}-{key:016x}"));
+
+        Some(Self { entry_dir })
+    }
+
+    /// Copies cached files to `gen_path`. Returns `false` if there is no cache entry yet.
+    fn restore(&self, gen_path: &Path) -> bool {
+        if !self.entry_dir.is_dir() {
+            return false;
+        }
+
+        // Clear everything the entry does not contain first, so that no leftovers are mixed into the restored bindings (and stored
+        // along with them later). Files with identical content are kept as-is, to not trigger recompilation.
+        clear_files_not_in(&self.entry_dir, gen_path)
+            .and_then(|()| copy_dir(&self.entry_dir, gen_path))
+            .unwrap_or_else(|e| panic!("failed to restore cached bindings: {e}"));
+        true
+    }
+
+    /// Stores the files in `gen_path` as cache entry.
+    fn store(&self, gen_path: &Path) {
+        // Write to a temporary directory first, so that concurrent builds never observe a partial entry.
+        let tmp_dir = self
+            .entry_dir
+            .with_extension(format!("tmp{}", std::process::id()));
+
+        let result =
+            copy_dir(gen_path, &tmp_dir).and_then(|()| fs::rename(&tmp_dir, &self.entry_dir));
+
+        // A failing cache must not fail the build; another build may also have stored the entry in the meantime.
+        if let Err(e) = result {
+            let _ = fs::remove_dir_all(&tmp_dir);
+            println!(
+                "cargo:warning=could not store bindings in {}: {e}",
+                self.entry_dir.display()
+            );
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn compute_key(json_api: &str) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+    // The version alone does not change between git revisions; see build.rs.
+    hasher.write(env!("GDEXT_CODEGEN_SOURCE_HASH").as_bytes());
+    hasher.write(json_api.as_bytes());
+
+    for feature in enabled_features() {
+        hasher.write(feature.as_bytes());
+    }
+
+    for var in KEY_ENV_VARS {
+        println!("cargo:rerun-if-env-changed={var}");
+        hasher.write(var.as_bytes());
+
+        if let Some(value) = std::env::var_os(var) {
+            hasher.write(value.to_string_lossy().as_bytes());
+        }
+    }
+
+    // The class allowlist file is part of the input, not only its path.
+    if let Some(path) = std::env::var_os(CLASS_ALLOWLIST_FILE_ENV) {
+        if let Ok(contents) = fs::read(path) {
+            hasher.write(&contents);
+        }
+    }
+
+    hasher.finish()
+}
+
+fn enabled_features() -> Vec<&'static str> {
+    let features = [
+        ("codegen-fmt", cfg!(feature = "codegen-fmt")),
+        ("codegen-full", cfg!(feature = "codegen-full")),
+        ("codegen-lazy-fptrs", cfg!(feature = "codegen-lazy-fptrs")),
+        ("codegen-try-methods", cfg!(feature = "codegen-try-methods")),
+        ("codegen-nodes-2d", cfg!(feature = "codegen-nodes-2d")),
+        ("codegen-nodes-3d", cfg!(feature = "codegen-nodes-3d")),
+        ("codegen-editor", cfg!(feature = "codegen-editor")),
+        ("codegen-servers", cfg!(feature = "codegen-servers")),
+        ("double-precision", cfg!(feature = "double-precision")),
+        ("custom-godot", cfg!(feature = "custom-godot")),
+        (
+            "experimental-godot-api",
+            cfg!(feature = "experimental-godot-api"),
+        ),
+    ];
+
+    features
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect()
+}
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if !is_same_content(&entry.path(), &target) {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes files and directories in `dir` that have no counterpart in `reference`, e.g. classes generated for another configuration.
+fn clear_files_not_in(reference: &Path, dir: &Path) -> std::io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let counterpart = reference.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            if counterpart.is_dir() {
+                clear_files_not_in(&counterpart, &entry.path())?;
+            } else {
+                fs::remove_dir_all(entry.path())?;
+            }
+        } else if !counterpart.is_file() {
+            fs::remove_file(entry.path())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `target` exists with the same content as `source`. Identical files are not copied, to keep their modification time.
+fn is_same_content(source: &Path, target: &Path) -> bool {
+    match (fs::read(source), fs::read(target)) {
+        (Ok(source), Ok(target)) => source == target,
+        _ => false,
+    }
+}
+
+/// FNV-1a hash. Unlike `DefaultHasher`, its output is stable across Rust versions, so cache entries can be shared between machines.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+
+        // Separator, so that ("ab", "c") and ("a", "bc") hash differently.
+        self.0 ^= 0xff;
+        self.0 = self.0.wrapping_mul(0x100000001b3);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fnv_separates_writes() {
+        let mut hasher = Fnv1a::new();
+        hasher.write(b"");
+        let empty = hasher.finish();
+
+        let mut hasher = Fnv1a::new();
+        hasher.write(b"ab");
+        hasher.write(b"c");
+        let split = hasher.finish();
+
+        let mut hasher = Fnv1a::new();
+        hasher.write(b"a");
+        hasher.write(b"bc");
+
+        assert_ne!(split, hasher.finish());
+        assert_ne!(empty, Fnv1a::new().finish());
+    }
+
+    #[test]
+    fn restore_keeps_unchanged_and_removes_stale() {
+        let root = std::env::temp_dir().join(format!("gdext-cache-test-{}", std::process::id()));
+        let entry_dir = root.join("entry");
+        let gen_dir = root.join("gen");
+        let _ = fs::remove_dir_all(&root);
+
+        let write = |path: PathBuf, contents: &str| {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+        let modified = |path: PathBuf| fs::metadata(path).unwrap().modified().unwrap();
+
+        write(entry_dir.join("classes/node.rs"), "node");
+        write(entry_dir.join("central.rs"), "central");
+        write(gen_dir.join("classes/node.rs"), "node");
+        write(gen_dir.join("classes/stale.rs"), "stale");
+        write(gen_dir.join("old/file.rs"), "old");
+        write(gen_dir.join("central.rs"), "outdated");
+        let node_modified = modified(gen_dir.join("classes/node.rs"));
+
+        let cache = BindingsCache { entry_dir };
+        assert!(cache.restore(&gen_dir));
+
+        let central = fs::read_to_string(gen_dir.join("central.rs")).unwrap();
+        assert_eq!(central, "central");
+        assert_eq!(modified(gen_dir.join("classes/node.rs")), node_modified);
+        assert!(!gen_dir.join("classes/stale.rs").exists());
+        assert!(!gen_dir.join("old").exists());
+
+        // Restoring into a fresh directory, e.g. after `cargo clean`.
+        let fresh_dir = root.join("fresh");
+        assert!(cache.restore(&fresh_dir));
+        assert!(fresh_dir.join("classes/node.rs").is_file());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
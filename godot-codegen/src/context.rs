@@ -34,6 +34,10 @@ impl<'a> Context<'a> {
     pub fn build_from_api(api: &'a JsonExtensionApi) -> Self {
         let mut ctx = Self::default();
 
+        // Class exclusions depend on the enabled codegen modules and the minimum Godot version, so determine those first.
+        special_cases::load_min_api_history(api);
+        special_cases::select_module_classes(api);
+
         for class in api.singletons.iter() {
             ctx.singletons.insert(class.name.as_str());
         }
@@ -8,7 +8,7 @@
 use crate::context::Context;
 use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions};
 use crate::generator::method_tables::MethodTableKey;
-use crate::generator::{enums, functions_common};
+use crate::generator::{self, enums, functions_common};
 use crate::models::domain::{
     BuiltinClass, BuiltinMethod, ClassLike, ExtensionApi, FnDirection, Function, ModName, RustTy,
     TyName,
@@ -16,6 +16,7 @@ use crate::models::domain::{
 use crate::{conv, util, SubmitFn};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
+use std::collections::HashSet;
 use std::path::Path;
 
 // Shared with native_structures.rs.
@@ -34,7 +35,6 @@ pub fn generate_builtin_class_files(
     gen_path: &Path,
     submit_fn: &mut SubmitFn,
 ) {
-    let _ = std::fs::remove_dir_all(gen_path);
     std::fs::create_dir_all(gen_path).expect("create classes directory");
 
     let mut modules = vec![];
@@ -59,12 +59,23 @@ pub fn generate_builtin_class_files(
         });
     }
 
+    generator::remove_stale_files(gen_path, &module_files(&modules));
+
     let out_path = gen_path.join("mod.rs");
     let mod_contents = make_builtin_module_file(modules);
 
     submit_fn(out_path, mod_contents);
 }
 
+/// File names of the given modules, plus `mod.rs`.
+pub fn module_files(modules: &[GeneratedBuiltinModule]) -> HashSet<String> {
+    modules
+        .iter()
+        .map(|m| format!("{}.rs", m.module_name.rust_mod))
+        .chain(["mod.rs".to_string()])
+        .collect()
+}
+
 pub fn make_builtin_module_file(classes_and_modules: Vec<GeneratedBuiltinModule>) -> TokenStream {
     let decls = classes_and_modules.iter().map(|m| {
         let GeneratedBuiltinModule {
@@ -179,25 +190,31 @@ fn make_builtin_method_definition(
         let variant_type = quote! { sys::VariantType::#builtin_name };
         let variant_type_str = &builtin_name.godot_ty;
 
+        // Per-method cache, like for class methods.
         quote! {
-            fptr_by_key(sys::lazy_keys::BuiltinMethodKey {
-                variant_type: #variant_type,
-                variant_type_str: #variant_type_str,
-                method_name: #method_name_str,
-                hash: #hash,
-            })
+            {
+                static BIND: sys::LazyMethodBind<sys::BuiltinMethodBind> = sys::LazyMethodBind::new();
+                BIND.get_or_load(|| {
+                    sys::builtin_method_table().fptr_by_key(sys::lazy_keys::BuiltinMethodKey {
+                        variant_type: #variant_type,
+                        variant_type_str: #variant_type_str,
+                        method_name: #method_name_str,
+                        hash: #hash,
+                    })
+                })
+            }
         }
     } else {
         let table_index = ctx.get_table_index(&MethodTableKey::from_builtin(builtin_class, method));
 
-        quote! { fptr_by_index(#table_index) }
+        quote! { sys::builtin_method_table().fptr_by_index(#table_index) }
     };
 
     let receiver = functions_common::make_receiver(method.qualifier(), quote! { self.sys_ptr });
     let object_ptr = &receiver.ffi_arg;
 
     let ptrcall_invocation = quote! {
-        let method_bind = sys::builtin_method_table().#fptr_access;
+        let method_bind = #fptr_access;
 
         <CallSig as PtrcallSignatureTuple>::out_builtin_ptrcall::<RetMarshal>(
             method_bind,
@@ -224,6 +241,7 @@ fn make_builtin_method_definition(
             receiver,
             varcall_invocation,
             ptrcall_invocation,
+            try_code: None,
         },
     )
 }
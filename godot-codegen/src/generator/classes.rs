@@ -6,31 +6,71 @@
  */
 
 use crate::context::{Context, NotificationEnum};
-use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions};
+use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions, TryFnCode};
 use crate::generator::method_tables::MethodTableKey;
-use crate::generator::{constants, docs, enums, functions_common, notifications, virtual_traits};
+use crate::generator::{
+    self, constants, docs, enums, functions_common, notifications, virtual_traits,
+};
 use crate::models::domain::{
     ApiView, Class, ClassLike, ClassMethod, ExtensionApi, FnDirection, FnQualifier, Function,
-    ModName, TyName,
+    TyName,
 };
+use crate::models::json::JsonExtensionApi;
+use crate::parallel::{self, Shard};
 use crate::util::{ident, make_string_name};
-use crate::{conv, util, SubmitFn};
+use crate::{conv, util, SyncSubmitFn};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::path::Path;
 
+/// Generates one file per class, spread across threads, and the `mod.rs` file declaring them.
 pub fn generate_class_files(
+    json_api: &JsonExtensionApi,
     api: &ExtensionApi,
-    ctx: &mut Context,
-    view: &ApiView,
     gen_path: &Path,
-    submit_fn: &mut SubmitFn,
+    submit_fn: SyncSubmitFn,
 ) {
-    let _ = std::fs::remove_dir_all(gen_path);
     std::fs::create_dir_all(gen_path).expect("create classes directory");
 
+    let mut modules: Vec<GeneratedClassModule> =
+        parallel::for_each_shard(json_api, api.classes.len(), |api, ctx, shard| {
+            let view = ApiView::new(api);
+            generate_class_shard(api, ctx, &view, gen_path, shard, submit_fn)
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+
+    // Keep declarations in API order, independent of the number of threads.
+    modules.sort_by_key(|m| m.class_index);
+
+    let generated_files = modules
+        .iter()
+        .map(|m| format!("{}.rs", api.classes[m.class_index].mod_name().rust_mod))
+        .chain(["mod.rs".to_string()])
+        .collect();
+    generator::remove_stale_files(gen_path, &generated_files);
+
+    let out_path = gen_path.join("mod.rs");
+    let mod_contents = make_class_module_file(api, modules);
+
+    submit_fn(out_path, mod_contents);
+}
+
+fn generate_class_shard(
+    api: &ExtensionApi,
+    ctx: &mut Context,
+    view: &ApiView,
+    gen_path: &Path,
+    shard: Shard,
+    submit_fn: SyncSubmitFn,
+) -> Vec<GeneratedClassModule> {
     let mut modules = vec![];
-    for class in api.classes.iter() {
+    for (class_index, class) in api.classes.iter().enumerate() {
+        if !shard.contains(class_index) {
+            continue;
+        }
+
         let generated_class = make_class(class, ctx, view);
         let file_contents = generated_class.code;
 
@@ -39,18 +79,16 @@ pub fn generate_class_files(
         submit_fn(out_path, file_contents);
 
         modules.push(GeneratedClassModule {
-            class_name: class.name().clone(),
-            module_name: class.mod_name().clone(),
-            own_notification_enum_name: generated_class.notification_enum.try_to_own_name(),
-            inherits_macro_ident: generated_class.inherits_macro_ident,
+            class_index,
+            own_notification_enum_name: generated_class
+                .notification_enum
+                .try_to_own_name()
+                .map(|name| name.to_string()),
             is_pub_sidecar: generated_class.has_sidecar_module,
         });
     }
 
-    let out_path = gen_path.join("mod.rs");
-    let mod_contents = make_class_module_file(modules);
-
-    submit_fn(out_path, mod_contents);
+    modules
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -59,16 +97,15 @@ pub fn generate_class_files(
 struct GeneratedClass {
     code: TokenStream,
     notification_enum: NotificationEnum,
-    inherits_macro_ident: Ident,
     /// Sidecars are the associated modules with related enum/flag types, such as `node_3d` for `Node3D` class.
     has_sidecar_module: bool,
 }
 
+/// Information about a generated class file, needed for `mod.rs`. Holds no tokens, so it can be passed between codegen threads.
 struct GeneratedClassModule {
-    class_name: TyName,
-    module_name: ModName,
-    own_notification_enum_name: Option<Ident>,
-    inherits_macro_ident: Ident,
+    /// Index in `ExtensionApi::classes`.
+    class_index: usize,
+    own_notification_enum_name: Option<String>,
     is_pub_sidecar: bool,
 }
 
@@ -100,7 +137,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
     let enums = enums::make_enums(&class.enums);
     let constants = constants::make_constants(&class.constants);
-    let inherits_macro = format_ident!("inherits_transitive_{}", class_name.rust_ty);
+    let inherits_macro = make_inherits_macro_ident(class_name);
     let (exportable_impl, exportable_macro_impl) = make_exportable_impl(class_name, ctx);
     let deref_impl = make_deref_impl(class_name, &base_ty);
 
@@ -132,6 +169,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
     let notify_methods = notifications::make_notify_methods(class_name, ctx);
 
     let (assoc_memory, assoc_dyn_memory) = make_bounds(class);
+    let preload_methods = make_preload_methods(class);
 
     let internal_methods = quote! {
         fn __checked_id(&self) -> Option<crate::obj::InstanceId> {
@@ -194,6 +232,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                 fn as_type_ptr(&self) -> sys::GDExtensionTypePtr {
                    std::ptr::addr_of!(self.object_ptr) as sys::GDExtensionTypePtr
                 }
+                #preload_methods
             }
             #(
                 impl crate::obj::Inherits<crate::engine::#all_bases> for #class_name {}
@@ -227,23 +266,30 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
             name: notification_enum_name,
             declared_by_own_class: notification_enum.is_some(),
         },
-        inherits_macro_ident: inherits_macro,
         has_sidecar_module,
     }
 }
 
-fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> TokenStream {
+fn make_inherits_macro_ident(class_name: &TyName) -> Ident {
+    format_ident!("inherits_transitive_{}", class_name.rust_ty)
+}
+
+fn make_class_module_file(
+    api: &ExtensionApi,
+    classes_and_modules: Vec<GeneratedClassModule>,
+) -> TokenStream {
     let mut class_decls = Vec::new();
     let mut notify_decls = Vec::new();
 
     for m in classes_and_modules.iter() {
         let GeneratedClassModule {
-            module_name,
-            class_name,
+            class_index,
             own_notification_enum_name,
             is_pub_sidecar: is_pub,
-            ..
         } = m;
+        let class = &api.classes[*class_index];
+        let class_name = class.name();
+        let module_name = class.mod_name();
         let virtual_trait_name = ident(&class_name.virtual_trait_name());
 
         let vis = is_pub.then_some(quote! { pub });
@@ -256,6 +302,7 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
         class_decls.push(class_decl);
 
         if let Some(enum_name) = own_notification_enum_name {
+            let enum_name = ident(enum_name);
             let notify_decl = quote! {
                 pub use super::#module_name::re_export::#enum_name;
             };
@@ -265,10 +312,7 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
     }
 
     let macros = classes_and_modules.iter().map(|m| {
-        let GeneratedClassModule {
-            inherits_macro_ident,
-            ..
-        } = m;
+        let inherits_macro_ident = make_inherits_macro_ident(api.classes[m.class_index].name());
 
         // We cannot re-export the following, because macro is in the crate root
         // pub use #module_ident::re_export::#inherits_macro_ident;
@@ -411,6 +455,41 @@ fn make_bounds(class: &Class) -> (Ident, Ident) {
     (assoc_memory, assoc_dyn_memory)
 }
 
+/// With lazy function tables, loads all method binds of the class. Lives in the class itself rather than in the method table, so
+/// that the method names of classes which are never preloaded can be stripped by the linker.
+fn make_preload_methods(class: &Class) -> TokenStream {
+    if !cfg!(feature = "codegen-lazy-fptrs") {
+        return TokenStream::new();
+    }
+
+    let get_method_table = class.api_level.table_global_getter();
+    let class_name_str = &class.name().godot_ty;
+
+    let fptr_loads = class.methods.iter().filter_map(|method| {
+        let FnDirection::Outbound { hash } = method.direction() else {
+            return None;
+        };
+        let godot_method_name = method.godot_name();
+
+        // Same key as in make_class_method_definition(), so that calls find the preloaded bind.
+        Some(quote! {
+            table.fptr_by_key(sys::lazy_keys::ClassMethodKey {
+                class_name: #class_name_str,
+                method_name: #godot_method_name,
+                hash: #hash,
+            });
+        })
+    });
+
+    quote! {
+        fn __preload_methods() {
+            // SAFETY: preloading is only allowed once the class' init level has been loaded, see init::preload_methods().
+            let table = unsafe { sys::#get_method_table() };
+            #( #fptr_loads )*
+        }
+    }
+}
+
 fn make_class_methods(class: &Class, methods: &[ClassMethod], ctx: &mut Context) -> FnDefinitions {
     let get_method_table = class.api_level.table_global_getter();
 
@@ -444,22 +523,28 @@ fn make_class_method_definition(
         quote! { self.__checked_id() }
     };
 
+    // With lazy function tables, each method caches its own bind, so the metadata of uncalled methods can be stripped by the linker.
     let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
         let class_name_str = &class.name().godot_ty;
         quote! {
-            fptr_by_key(sys::lazy_keys::ClassMethodKey {
-                class_name: #class_name_str,
-                method_name: #godot_method_name,
-                hash: #hash,
-            })
+            {
+                static BIND: sys::LazyMethodBind<sys::ClassMethodBind> = sys::LazyMethodBind::new();
+                BIND.get_or_load(|| {
+                    sys::#get_method_table().fptr_by_key(sys::lazy_keys::ClassMethodKey {
+                        class_name: #class_name_str,
+                        method_name: #godot_method_name,
+                        hash: #hash,
+                    })
+                })
+            }
         }
     } else {
-        quote! { fptr_by_index(#table_index) }
+        quote! { sys::#get_method_table().fptr_by_index(#table_index) }
     };
 
     let object_ptr = &receiver.ffi_arg;
     let ptrcall_invocation = quote! {
-        let method_bind = sys::#get_method_table().#fptr_access;
+        let method_bind = #fptr_access;
 
         <CallSig as PtrcallSignatureTuple>::out_class_ptrcall::<RetMarshal>(
             method_bind,
@@ -471,7 +556,7 @@ fn make_class_method_definition(
     };
 
     let varcall_invocation = quote! {
-        let method_bind = sys::#get_method_table().#fptr_access;
+        let method_bind = #fptr_access;
 
         <CallSig as VarcallSignatureTuple>::out_class_varcall(
             method_bind,
@@ -483,12 +568,53 @@ fn make_class_method_definition(
         )
     };
 
+    let generates_try_method =
+        cfg!(feature = "codegen-try-methods") && !has_try_method_conflict(class, method);
+
+    let try_code = if generates_try_method {
+        Some(TryFnCode {
+            ptrcall_invocation: quote! {
+                let method_bind = #fptr_access;
+
+                <CallSig as PtrcallSignatureTuple>::try_out_class_ptrcall::<RetMarshal>(
+                    method_bind,
+                    #rust_method_name,
+                    #object_ptr,
+                    #maybe_instance_id,
+                    args,
+                )
+            },
+            varcall_invocation: quote! {
+                let method_bind = #fptr_access;
+
+                <CallSig as VarcallSignatureTuple>::try_out_class_varcall(
+                    method_bind,
+                    #rust_method_name,
+                    #object_ptr,
+                    #maybe_instance_id,
+                    args,
+                    varargs
+                )
+            },
+        })
+    } else {
+        None
+    };
+
     functions_common::make_function_definition(
         method,
         &FnCode {
             receiver,
             varcall_invocation,
             ptrcall_invocation,
+            try_code,
         },
     )
 }
+
+/// Some classes already have a Godot method named `try_*`, e.g. `Mutex::try_lock()` next to `Mutex::lock()`.
+fn has_try_method_conflict(class: &Class, method: &ClassMethod) -> bool {
+    let try_name = format!("try_{}", method.name());
+
+    class.methods.iter().any(|other| other.name() == try_name)
+}
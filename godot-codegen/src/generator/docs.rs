@@ -11,6 +11,7 @@
 
 use crate::models::domain::ModName;
 use crate::models::domain::TyName;
+use crate::{special_cases, util};
 use proc_macro2::Ident;
 
 pub fn make_class_doc(
@@ -48,6 +49,7 @@ pub fn make_class_doc(
     );
 
     let trait_name = class_name.virtual_trait_name();
+    let min_api_section = make_min_api_section(class_name);
 
     format!(
         "Godot class `{godot_ty}.`\n\n\
@@ -59,10 +61,35 @@ pub fn make_class_doc(
         * [`{trait_name}`][crate::engine::{trait_name}]: virtual methods\n\
         {notify_line}\
         \n\n\
+        {min_api_section}\
         See also [Godot docs for `{godot_ty}`]({online_link}).\n\n",
     )
 }
 
+/// Lists methods that are excluded because they are newer than the minimum Godot version.
+fn make_min_api_section(class_name: &TyName) -> String {
+    let Some((min_major, min_minor)) = util::get_min_godot_api() else {
+        return String::new();
+    };
+
+    let methods = special_cases::get_class_methods_above_min_api(class_name);
+    if methods.is_empty() {
+        return String::new();
+    }
+
+    let lines: String = methods
+        .iter()
+        .map(|(method, (major, minor))| format!("* `{method}()`: requires Godot {major}.{minor}\n"))
+        .collect();
+
+    format!(
+        "# Unavailable methods\n\n\
+        The following methods are not available, because `{env}` is set to {min_major}.{min_minor}:\n\n\
+        {lines}\n\n",
+        env = util::MIN_GODOT_API_ENV,
+    )
+}
+
 pub fn make_virtual_trait_doc(class_name: &TyName) -> String {
     let TyName { rust_ty, godot_ty } = class_name;
 
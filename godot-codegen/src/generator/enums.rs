@@ -88,6 +88,7 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
     let self_as_trait;
     let engine_impl;
     let enum_ord_type;
+    let validate_fn;
 
     if enum_.is_bitfield {
         bitfield_ops = quote! {
@@ -104,6 +105,7 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
         };
         enum_ord_type = quote! { u64 };
         self_as_trait = quote! { <Self as crate::obj::EngineBitfield> };
+        validate_fn = TokenStream::new(); // Any combination of bits is accepted.
         engine_impl = quote! {
             impl crate::obj::EngineBitfield for #rust_enum_name {
                 fn try_from_ord(ord: u64) -> Option<Self> {
@@ -123,6 +125,16 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
         bitfield_ops = TokenStream::new();
         enum_ord_type = quote! { i32 };
         self_as_trait = quote! { <Self as crate::obj::EngineEnum> };
+
+        let error_fmt = format!("ordinal {{}} is not a valid enumerator of {rust_enum_name}");
+        validate_fn = quote! {
+            fn validate_engine_arg(&self) -> std::result::Result<(), String> {
+                match #self_as_trait::try_from_ord(self.ord) {
+                    Some(_) => Ok(()),
+                    None => Err(format!(#error_fmt, self.ord)),
+                }
+            }
+        };
         engine_impl = quote! {
             impl crate::obj::EngineEnum for #rust_enum_name {
                 fn try_from_ord(ord: i32) -> Option<Self> {
@@ -168,6 +180,8 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
             fn to_godot(&self) -> Self::Via {
                 #self_as_trait::ord(*self)
             }
+
+            #validate_fn
         }
 
         impl crate::builtin::meta::FromGodot for #rust_enum_name {
@@ -37,6 +37,15 @@ pub struct FnCode {
     pub receiver: FnReceiver,
     pub varcall_invocation: TokenStream,
     pub ptrcall_invocation: TokenStream,
+
+    /// If present, an additional `try_*` function returning `Result` is generated (feature `codegen-try-methods`).
+    pub try_code: Option<TryFnCode>,
+}
+
+/// Invocations for the fallible `try_*` variant of a function. They evaluate to `Result<R, CallError>`.
+pub struct TryFnCode {
+    pub varcall_invocation: TokenStream,
+    pub ptrcall_invocation: TokenStream,
 }
 
 pub struct FnDefinition {
@@ -128,6 +137,7 @@ pub fn make_function_definition(sig: &dyn Function, code: &FnCode) -> FnDefiniti
     let return_decl = &sig.return_value().decl;
 
     let receiver_param = &code.receiver.param;
+    let track_caller = make_track_caller_attr();
     let primary_function = if sig.is_virtual() {
         // Virtual functions
 
@@ -149,6 +159,7 @@ pub fn make_function_definition(sig: &dyn Function, code: &FnCode) -> FnDefiniti
         // TODO use Result instead of panic on error
         quote! {
             #safety_doc
+            #track_caller
             #vis #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
                 #( #params, )*
@@ -167,18 +178,11 @@ pub fn make_function_definition(sig: &dyn Function, code: &FnCode) -> FnDefiniti
         // Always ptrcall, no varargs
 
         let ptrcall_invocation = &code.ptrcall_invocation;
-        let maybe_return_ty = &sig.return_value().type_;
-
-        // This differentiation is needed because we need to differentiate between Option<Gd<T>>, T and () as return types.
-        // Rust traits don't provide specialization and thus would encounter overlapping blanket impls, so we cannot use the type system here.
-        let ret_marshal = match maybe_return_ty {
-            Some(RustTy::EngineClass { tokens, .. }) => quote! { PtrcallReturnOptionGdT<#tokens> },
-            Some(return_ty) => quote! { PtrcallReturnT<#return_ty> },
-            None => quote! { PtrcallReturnUnit },
-        };
+        let ret_marshal = make_ptrcall_ret_marshal(sig);
 
         quote! {
             #safety_doc
+            #track_caller
             #vis #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
                 #( #params, )*
@@ -195,15 +199,99 @@ pub fn make_function_definition(sig: &dyn Function, code: &FnCode) -> FnDefiniti
         }
     };
 
+    let try_function = match &code.try_code {
+        Some(try_code) if !sig.is_virtual() => {
+            make_try_function_definition(sig, code, try_code, &call_sig, &maybe_unsafe, &safety_doc)
+        }
+        _ => TokenStream::new(),
+    };
+
     FnDefinition {
         functions: quote! {
             #primary_function
+            #try_function
             #default_fn_code
         },
         builders: default_structs_code,
     }
 }
 
+/// Generates `try_<name>`, which takes all parameters explicitly (no default-parameter builder) and returns `Result` instead of panicking.
+fn make_try_function_definition(
+    sig: &dyn Function,
+    code: &FnCode,
+    try_code: &TryFnCode,
+    call_sig: &TokenStream,
+    maybe_unsafe: &TokenStream,
+    safety_doc: &TokenStream,
+) -> TokenStream {
+    let vis = make_vis(sig.is_private());
+    let [params, _, arg_names] = make_params_exprs(sig.params());
+
+    let rust_function_name_str = sig.name();
+    let try_fn_name = format_ident!("try_{}", rust_function_name_str);
+    let doc = format!(
+        "Like [`{rust_function_name_str}()`][Self::{rust_function_name_str}], but returns an error instead of panicking if the call fails."
+    );
+
+    let return_ty = sig.return_value().type_tokens();
+    let return_decl = quote! { -> Result<#return_ty, crate::engine::dynamic_call::CallError> };
+    let receiver_param = &code.receiver.param;
+    let track_caller = make_track_caller_attr();
+
+    if sig.is_vararg() {
+        let varcall_invocation = &try_code.varcall_invocation;
+
+        quote! {
+            #[doc = #doc]
+            #safety_doc
+            #track_caller
+            #vis #maybe_unsafe fn #try_fn_name(
+                #receiver_param
+                #( #params, )*
+                varargs: &[Variant]
+            ) #return_decl {
+                type CallSig = #call_sig;
+
+                let args = (#( #arg_names, )*);
+
+                unsafe {
+                    #varcall_invocation
+                }
+            }
+        }
+    } else {
+        let ptrcall_invocation = &try_code.ptrcall_invocation;
+        let ret_marshal = make_ptrcall_ret_marshal(sig);
+
+        quote! {
+            #[doc = #doc]
+            #safety_doc
+            #track_caller
+            #vis #maybe_unsafe fn #try_fn_name(
+                #receiver_param
+                #( #params, )*
+            ) #return_decl {
+                type RetMarshal = #ret_marshal;
+                type CallSig = #call_sig;
+
+                let args = (#( #arg_names, )*);
+
+                unsafe {
+                    #ptrcall_invocation
+                }
+            }
+        }
+    }
+}
+
+/// With `debug-validation` in debug builds, argument validation reports the caller of the generated method instead of gdext internals.
+fn make_track_caller_attr() -> TokenStream {
+    quote! {
+        #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
+    }
+}
+
 pub fn make_receiver(qualifier: FnQualifier, ffi_arg_in: TokenStream) -> FnReceiver {
     assert_ne!(qualifier, FnQualifier::Global, "expected class");
 
@@ -270,6 +358,16 @@ fn make_params_exprs(method_args: &[FnParam]) -> [Vec<TokenStream>; 3] {
     [params, param_types, arg_names]
 }
 
+fn make_ptrcall_ret_marshal(sig: &dyn Function) -> TokenStream {
+    // This differentiation is needed because we need to differentiate between Option<Gd<T>>, T and () as return types.
+    // Rust traits don't provide specialization and thus would encounter overlapping blanket impls, so we cannot use the type system here.
+    match &sig.return_value().type_ {
+        Some(RustTy::EngineClass { tokens, .. }) => quote! { PtrcallReturnOptionGdT<#tokens> },
+        Some(return_ty) => quote! { PtrcallReturnT<#return_ty> },
+        None => quote! { PtrcallReturnUnit },
+    }
+}
+
 fn function_uses_pointers(sig: &dyn Function) -> bool {
     let has_pointer_params = sig
         .params()
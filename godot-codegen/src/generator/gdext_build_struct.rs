@@ -6,6 +6,7 @@
  */
 
 use crate::models::domain::GodotApiVersion;
+use crate::util;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -17,6 +18,11 @@ pub fn make_gdext_build_struct(header: &GodotApiVersion) -> TokenStream {
         version_string,
     } = header;
 
+    let min_api = match util::get_min_godot_api() {
+        Some((min_major, min_minor)) => quote! { Some((#min_major, #min_minor)) },
+        None => quote! { None },
+    };
+
     // Should this be mod?
     quote! {
         /// Provides meta-information about the library and the Godot version in use.
@@ -35,6 +41,32 @@ pub fn make_gdext_build_struct(header: &GodotApiVersion) -> TokenStream {
                 (#major, #minor, #patch)
             }
 
+            /// Oldest Godot version `(major, minor)` declared as supported through `GDEXT_MIN_GODOT_API` at build time, if any.
+            ///
+            /// Allows the library to be loaded by Godot versions older than the one it was compiled against, see
+            /// [`Self::godot_min_runtime_version()`].
+            pub const fn godot_min_api() -> Option<(u8, u8)> {
+                #min_api
+            }
+
+            /// Oldest Godot version `(major, minor, patch)` that can load this library.
+            ///
+            /// Without `GDEXT_MIN_GODOT_API`, this is the version against which gdext was compiled. Otherwise, it is the declared
+            /// minimum, but no older than the GDExtension interface used by gdext itself (4.2 if compiled against 4.2 or later).
+            pub const fn godot_min_runtime_version() -> (u8, u8, u8) {
+                let Some((_, min_minor)) = Self::godot_min_api() else {
+                    return Self::godot_static_version_triple();
+                };
+
+                let binding_minor = if cfg!(since_api = "4.2") { 2 } else { #minor };
+                let minor = if min_minor > binding_minor { min_minor } else { binding_minor };
+                if minor >= #minor {
+                    Self::godot_static_version_triple()
+                } else {
+                    (#major, minor, 0)
+                }
+            }
+
             /// Version of the Godot engine which loaded gdext via GDExtension binding.
             pub fn godot_runtime_version_string() -> String {
                 unsafe {
@@ -180,6 +180,10 @@ pub fn make_utility_function_table(api: &ExtensionApi) -> TokenStream {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
+/// Number of per-class load functions in one generated module, with eager function tables.
+#[cfg(not(feature = "codegen-lazy-fptrs"))]
+const LOAD_FUNCTIONS_PER_MODULE: usize = 64;
+
 struct NamedMethodTable {
     table_name: Ident,
     imports: TokenStream,
@@ -341,29 +345,48 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
         assert_eq!(method_count, 0, "empty method table should have count 0");
     }
 
-    let method_load_inits = method_init_groups.iter().map(|group| {
-        let func = group.function_name();
-        quote! {
-            #func(&mut function_pointers, string_names, fetch_fptr);
-        }
+    // Load functions are split across several modules. rustc partitions code by module, so this allows more parallelism in codegen
+    // and smaller units to redo in incremental builds.
+    let chunks = method_init_groups
+        .chunks(LOAD_FUNCTIONS_PER_MODULE)
+        .enumerate()
+        .map(|(i, groups)| (format_ident!("load_{i}"), groups));
+
+    let method_load_inits = chunks.clone().flat_map(|(module, groups)| {
+        groups.iter().map(move |group| {
+            let func = group.function_name();
+            quote! {
+                #module::#func(&mut function_pointers, string_names, fetch_fptr);
+            }
+        })
     });
 
-    let method_load_decls = method_init_groups.iter().map(|group| {
-        let func = group.function_name();
-        let method_inits = &group.method_inits;
-        let class_var_init = &group.class_var_init;
+    let method_load_decls = chunks.map(|(module, groups)| {
+        let funcs = groups.iter().map(|group| {
+            let func = group.function_name();
+            let method_inits = &group.method_inits;
+            let class_var_init = &group.class_var_init;
+
+            quote! {
+                pub(super) fn #func(
+                    function_pointers: &mut Vec<#fptr_type>,
+                    string_names: &mut crate::StringCache,
+                    fetch_fptr: FetchFn,
+                ) {
+                    #class_var_init
+
+                    #(
+                        function_pointers.push(#method_inits);
+                    )*
+                }
+            }
+        });
 
         quote! {
-            fn #func(
-                function_pointers: &mut Vec<#fptr_type>,
-                string_names: &mut crate::StringCache,
-                fetch_fptr: FetchFn,
-            ) {
-                #class_var_init
-
-                #(
-                    function_pointers.push(#method_inits);
-                )*
+            mod #module {
+                use super::*;
+
+                #( #funcs )*
             }
         }
     });
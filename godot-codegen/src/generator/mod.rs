@@ -7,8 +7,10 @@
 
 use crate::context::Context;
 use crate::models::domain::{ClassCodegenLevel, ExtensionApi};
-use crate::SubmitFn;
+use crate::models::json::JsonExtensionApi;
+use crate::{parallel, SubmitFn, SyncSubmitFn};
 use quote::quote;
+use std::collections::HashSet;
 use std::path::Path;
 
 pub mod builtins;
@@ -36,6 +38,22 @@ pub mod virtual_traits;
 // - utility_functions
 // - native_structures
 
+/// Removes files in `gen_path` that were not generated in this run, e.g. classes that are no longer part of the API.
+///
+/// Used instead of clearing directories upfront, so that files with unchanged content keep their modification time.
+pub fn remove_stale_files(gen_path: &Path, generated_files: &HashSet<String>) {
+    let Ok(entries) = std::fs::read_dir(gen_path) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if !generated_files.contains(&file_name) {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
 pub fn generate_sys_central_file(
     api: &ExtensionApi,
     ctx: &mut Context,
@@ -47,20 +65,26 @@ pub fn generate_sys_central_file(
     submit_fn(sys_gen_path.join("central.rs"), sys_code);
 }
 
-pub fn generate_sys_classes_file(
-    api: &ExtensionApi,
+/// Generates the class method tables of all API levels, in parallel.
+pub fn generate_sys_classes_files(
+    json_api: &JsonExtensionApi,
     sys_gen_path: &Path,
-    watch: &mut godot_bindings::StopWatch,
-    ctx: &mut Context,
-    submit_fn: &mut SubmitFn,
+    submit_fn: SyncSubmitFn,
 ) {
-    for api_level in ClassCodegenLevel::with_tables() {
-        let code = method_tables::make_class_method_table(api, api_level, ctx);
-        let filename = api_level.table_file();
+    let api_levels = ClassCodegenLevel::with_tables();
 
-        submit_fn(sys_gen_path.join(filename), code);
-        watch.record(format!("generate_classes_{}_file", api_level.lower()));
-    }
+    parallel::for_each_shard(json_api, api_levels.len(), |api, ctx, shard| {
+        for (i, api_level) in api_levels.into_iter().enumerate() {
+            if !shard.contains(i) {
+                continue;
+            }
+
+            let code = method_tables::make_class_method_table(api, api_level, ctx);
+            let filename = api_level.table_file();
+
+            submit_fn(sys_gen_path.join(filename), code);
+        }
+    });
 }
 
 pub fn generate_sys_utilities_file(
@@ -6,7 +6,7 @@
  */
 
 use crate::context::Context;
-use crate::generator::builtins;
+use crate::generator::{self, builtins};
 use crate::models::domain::{ExtensionApi, ModName, NativeStructure, TyName};
 use crate::util::ident;
 use crate::{conv, util, SubmitFn};
@@ -20,7 +20,6 @@ pub fn generate_native_structures_files(
     gen_path: &Path,
     submit_fn: &mut SubmitFn,
 ) {
-    let _ = std::fs::remove_dir_all(gen_path);
     std::fs::create_dir_all(gen_path).expect("create native directory");
 
     let mut modules = vec![];
@@ -41,6 +40,8 @@ pub fn generate_native_structures_files(
         });
     }
 
+    generator::remove_stale_files(gen_path, &builtins::module_files(&modules));
+
     let out_path = gen_path.join("mod.rs");
     let mod_contents = builtins::make_builtin_module_file(modules);
 
@@ -77,6 +77,7 @@ pub(crate) fn make_utility_function_definition(function: &UtilityFunction) -> To
             receiver: FnReceiver::global_function(),
             varcall_invocation,
             ptrcall_invocation,
+            try_code: None,
         },
     );
 
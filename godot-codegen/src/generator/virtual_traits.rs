@@ -104,6 +104,22 @@ fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
             unimplemented!()
         }
 
+        /// Captures state that should survive a hot reload of the Rust library in the editor (Godot 4.2+).
+        ///
+        /// Called before the instance is dropped for the reload. The returned value is kept in the Godot object and passed to
+        /// [`restore_reload_state()`][Self::restore_reload_state] once the reloaded library has recreated the instance. Exported
+        /// properties are already preserved by Godot and need not be included. Must be implemented together with `restore_reload_state()`.
+        fn save_reload_state(&self) -> Variant {
+            unimplemented!()
+        }
+
+        /// Restores the state captured by [`save_reload_state()`][Self::save_reload_state] after a hot reload (Godot 4.2+).
+        ///
+        /// Called right after `init()` recreated the instance; Godot restores exported properties afterwards.
+        fn restore_reload_state(&mut self, state: Variant) {
+            unimplemented!()
+        }
+
     }
 }
 
@@ -123,6 +139,7 @@ fn make_virtual_method(method: &ClassMethod) -> Option<TokenStream> {
             // make_return() requests following args, but they are not used for virtual methods. We can provide empty streams.
             varcall_invocation: TokenStream::new(),
             ptrcall_invocation: TokenStream::new(),
+            try_code: None,
         },
     );
 
@@ -8,10 +8,12 @@
 // Codegen has no FFI and thus no reason to use unsafe code.
 #![forbid(unsafe_code)]
 
+mod cache;
 mod context;
 mod conv;
 mod generator;
 mod models;
+mod parallel;
 mod special_cases;
 mod util;
 
@@ -26,18 +28,28 @@ use crate::generator::native_structures::generate_native_structures_files;
 use crate::generator::utility_functions::generate_utilities_file;
 use crate::generator::{
     generate_core_central_file, generate_core_mod_file, generate_sys_builtin_lifecycle_file,
-    generate_sys_builtin_methods_file, generate_sys_central_file, generate_sys_classes_file,
+    generate_sys_builtin_methods_file, generate_sys_central_file, generate_sys_classes_files,
     generate_sys_utilities_file,
 };
-use crate::models::domain::{ApiView, ExtensionApi};
+use crate::models::domain::ExtensionApi;
 use crate::models::json::{load_extension_api, JsonExtensionApi};
 
+pub use crate::cache::{generate_cached, CACHE_DIR_ENV};
+
 use proc_macro2::TokenStream;
 use std::path::{Path, PathBuf};
 
 pub type SubmitFn = dyn FnMut(PathBuf, TokenStream);
 
+/// Like [`SubmitFn`], but can be called from several codegen threads.
+pub type SyncSubmitFn = fn(PathBuf, TokenStream);
+
 fn write_file(path: &Path, contents: String) {
+    // Keep unchanged files untouched, including their modification time. Only modules whose code changed are then seen as modified.
+    if std::fs::read(path).map_or(false, |existing| existing == contents.as_bytes()) {
+        return;
+    }
+
     let dir = path.parent().unwrap();
     let _ = std::fs::create_dir_all(dir);
 
@@ -60,6 +72,9 @@ pub fn generate_sys_files(
     h_path: &Path,
     watch: &mut godot_bindings::StopWatch,
 ) {
+    // Method tables only contain APIs up to the minimum Godot version, see special_cases::load_min_api_history().
+    println!("cargo:rerun-if-env-changed={}", util::MIN_GODOT_API_ENV);
+
     let json_api = load_extension_api(watch);
 
     let mut ctx = Context::build_from_api(&json_api);
@@ -81,8 +96,8 @@ pub fn generate_sys_files(
     generate_sys_builtin_lifecycle_file(&api, sys_gen_path, &mut submit_fn);
     watch.record("generate_builtin_lifecycle_file");
 
-    generate_sys_classes_file(&api, sys_gen_path, watch, &mut ctx, &mut submit_fn);
-    // watch records inside the function.
+    generate_sys_classes_files(&json_api, sys_gen_path, submit_fn);
+    watch.record("generate_classes_files");
 
     generate_sys_utilities_file(&api, sys_gen_path, &mut submit_fn);
     watch.record("generate_utilities_file");
@@ -95,6 +110,9 @@ pub fn generate_sys_files(
 pub fn generate_core_files(core_gen_path: &Path) {
     let mut watch = godot_bindings::StopWatch::start();
 
+    // APIs newer than the minimum Godot version are excluded, see special_cases::load_min_api_history().
+    println!("cargo:rerun-if-env-changed={}", util::MIN_GODOT_API_ENV);
+
     generate_core_mod_file(core_gen_path, &mut submit_fn);
 
     let json_api = load_extension_api(&mut watch);
@@ -102,7 +120,6 @@ pub fn generate_core_files(core_gen_path: &Path) {
     watch.record("build_context");
 
     let api = ExtensionApi::from_json(&json_api, &mut ctx);
-    let view = ApiView::new(&api);
     watch.record("map_domain_models");
 
     // TODO if ctx is no longer needed for below functions:
@@ -117,13 +134,7 @@ pub fn generate_core_files(core_gen_path: &Path) {
 
     // Class files -- currently output in godot-core; could maybe be separated cleaner
     // Note: deletes entire generated directory!
-    generate_class_files(
-        &api,
-        &mut ctx,
-        &view,
-        &core_gen_path.join("classes"),
-        &mut submit_fn,
-    );
+    generate_class_files(&json_api, &api, &core_gen_path.join("classes"), submit_fn);
     watch.record("generate_class_files");
 
     generate_builtin_class_files(
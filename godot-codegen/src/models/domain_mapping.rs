@@ -27,6 +27,8 @@ use std::collections::HashMap;
 
 impl ExtensionApi {
     pub fn from_json(json: &JsonExtensionApi, ctx: &mut Context) -> Self {
+        validate_precision(&json.header);
+
         Self {
             builtins: BuiltinVariant::all_from_json(&json.global_enums, &json.builtin_classes, ctx),
             classes: json
@@ -307,6 +309,28 @@ impl BuildConfiguration {
     }
 }
 
+/// Panics if the JSON was dumped from a Godot build whose float precision differs from the `double-precision` feature.
+fn validate_precision(json: &JsonHeader) {
+    // Older JSON files do not state the precision; the mismatch is then only detected at startup.
+    let Some(precision) = json.precision.as_deref() else {
+        return;
+    };
+
+    let godot_is_double = match precision {
+        "single" => false,
+        "double" => true,
+        _ => panic!("invalid precision in extension_api.json: {precision}"),
+    };
+
+    let gdext_is_double = cfg!(feature = "double-precision");
+    assert_eq!(
+        godot_is_double, gdext_is_double,
+        "extension_api.json was generated by a Godot build with precision={precision}, but the Cargo feature `double-precision` is {}.\n\
+        The feature must be enabled if and only if Godot is compiled with `precision=double`.",
+        if gdext_is_double { "enabled" } else { "disabled" }
+    );
+}
+
 impl GodotApiVersion {
     pub fn from_json(json: &JsonHeader) -> Self {
         let version_string = json
@@ -35,6 +35,8 @@ pub struct JsonHeader {
     pub version_status: String,
     pub version_build: String,
     pub version_full_name: String,
+    /// `"single"` or `"double"`; only present since Godot 4.2.
+    pub precision: Option<String>,
 }
 
 #[derive(DeJson)]
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Distribution of codegen work across threads.
+//!
+//! `proc-macro2` tokens are not `Send`, and neither are the domain models and the [`Context`] that contain them. Every thread thus maps
+//! the shared JSON models on its own, generates its share of the files and passes only plain data back to the calling thread.
+
+use crate::context::Context;
+use crate::models::domain::ExtensionApi;
+use crate::models::json::JsonExtensionApi;
+use crate::util::CODEGEN_THREADS_ENV;
+
+/// Upper bound for the default thread count. Each thread maps the domain models separately, so more threads have diminishing returns.
+const MAX_DEFAULT_THREADS: usize = 8;
+
+/// Subset of work items handled by one thread.
+#[derive(Copy, Clone, Debug)]
+pub struct Shard {
+    index: usize,
+    count: usize,
+}
+
+impl Shard {
+    /// Whether the item at `item_index` belongs to this shard.
+    ///
+    /// Items are distributed round-robin, so that large neighboring items (e.g. classes of one family) end up on different threads.
+    pub fn contains(&self, item_index: usize) -> bool {
+        item_index % self.count == self.index
+    }
+}
+
+/// Runs `generate` on up to `item_count` threads, each with its own [`Context`] and [`ExtensionApi`]. Results are in shard order.
+pub fn for_each_shard<R, F>(json_api: &JsonExtensionApi, item_count: usize, generate: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(&ExtensionApi, &mut Context, Shard) -> R + Sync,
+{
+    let count = thread_count().min(item_count).max(1);
+    let generate = &generate;
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..count)
+            .map(|index| {
+                scope.spawn(move || {
+                    let mut ctx = Context::build_from_api(json_api);
+                    let api = ExtensionApi::from_json(json_api, &mut ctx);
+
+                    generate(&api, &mut ctx, Shard { index, count })
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                // Propagate panics of codegen threads with their original message.
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+fn thread_count() -> usize {
+    if let Ok(value) = std::env::var(CODEGEN_THREADS_ENV) {
+        return value.trim().parse().unwrap_or_else(|_| {
+            panic!("{CODEGEN_THREADS_ENV} must be a number of threads; got `{value}`")
+        });
+    }
+
+    std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(MAX_DEFAULT_THREADS)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shards_cover_all_items_once() {
+        let count = 3;
+        for item_index in 0..10 {
+            let owners = (0..count)
+                .filter(|&index| Shard { index, count }.contains(item_index))
+                .count();
+
+            assert_eq!(owners, 1, "item {item_index}");
+        }
+    }
+}
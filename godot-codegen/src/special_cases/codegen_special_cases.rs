@@ -10,9 +10,14 @@
 // TODO make this file private and only accessed by special_cases.rs.
 
 use crate::context::Context;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{
+    JsonBuiltinMethod, JsonClassMethod, JsonExtensionApi, JsonUtilityFunction,
+};
 use crate::special_cases;
 
+#[cfg(not(feature = "codegen-full"))]
+use std::sync::OnceLock;
+
 pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
     // TODO Fall back to varcall (recent addition in GDExtension API).
     // See https://github.com/godot-rust/gdext/issues/382.
@@ -21,7 +26,11 @@ pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
 
 #[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_class_excluded(godot_class_name: &str) -> bool {
-    !SELECTED_CLASSES.contains(&godot_class_name)
+    let in_module = MODULE_CLASSES
+        .get()
+        .map_or(false, |classes| classes.contains(godot_class_name));
+
+    !in_module && !SELECTED_CLASSES.contains(&godot_class_name)
 }
 
 #[cfg(feature = "codegen-full")]
@@ -114,6 +123,186 @@ pub(crate) fn is_utility_function_excluded(
         })
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Codegen modules
+
+/// Classes enabled through `codegen-*` module features or the class allowlist, in addition to [`SELECTED_CLASSES`].
+#[cfg(not(feature = "codegen-full"))]
+static MODULE_CLASSES: OnceLock<std::collections::HashSet<String>> = OnceLock::new();
+
+/// Determines which classes belong to the enabled codegen modules. Must run before any class exclusion is checked.
+#[cfg(not(feature = "codegen-full"))]
+pub(crate) fn select_module_classes(api: &JsonExtensionApi) {
+    use crate::models::domain::ClassCodegenLevel;
+    use crate::util;
+    use std::collections::{HashMap, HashSet};
+
+    let bases: HashMap<&str, &str> = api
+        .classes
+        .iter()
+        .filter_map(|class| Some((class.name.as_str(), class.inherits.as_deref()?)))
+        .collect();
+
+    let inherits = |class_name: &str, base_name: &str| {
+        let mut current = class_name;
+        while let Some(base) = bases.get(current) {
+            if *base == base_name {
+                return true;
+            }
+            current = base;
+        }
+        false
+    };
+
+    let mut classes: HashSet<String> = api
+        .classes
+        .iter()
+        .filter(|class| {
+            let name = class.name.as_str();
+            let level = util::get_api_level(class);
+
+            // 2D and 3D modules only contain scene classes, not servers (PhysicsServer2D) or editor ones.
+            let is_scene = level == ClassCodegenLevel::Scene;
+
+            (cfg!(feature = "codegen-servers") && name.contains("Server"))
+                || (cfg!(feature = "codegen-editor") && level == ClassCodegenLevel::Editor)
+                || (cfg!(feature = "codegen-nodes-2d")
+                    && is_scene
+                    && (inherits(name, "Node2D") || name.ends_with("2D")))
+                || (cfg!(feature = "codegen-nodes-3d")
+                    && is_scene
+                    && (inherits(name, "Node3D") || name.ends_with("3D")))
+        })
+        .map(|class| class.name.clone())
+        .collect();
+
+    // Allowlisted classes must exist, to catch typos.
+    let allowlist = read_class_allowlist();
+    for class_name in allowlist.iter() {
+        assert!(
+            api.classes.iter().any(|class| &class.name == class_name),
+            "class `{class_name}` in codegen allowlist does not exist in the Godot API"
+        );
+        classes.insert(class_name.clone());
+    }
+
+    // Generated classes deref to their base, so bases must be generated as well (e.g. ScrollContainer for EditorInspector).
+    let module_bases: Vec<String> = classes
+        .iter()
+        .flat_map(|class_name| {
+            std::iter::successors(bases.get(class_name.as_str()), |base| bases.get(*base))
+        })
+        .map(|base| base.to_string())
+        .collect();
+    classes.extend(module_bases);
+
+    report_omitted_methods(api, &allowlist, &classes);
+
+    // Both godot-ffi and godot-core generate code; if they share a process, the selection is identical.
+    let _ = MODULE_CLASSES.set(classes);
+}
+
+/// Warns about methods of allowlisted classes that are omitted, because they use a class which is not generated.
+///
+/// Dependencies are not added automatically: following argument and return types transitively would pull in most of the API.
+#[cfg(not(feature = "codegen-full"))]
+fn report_omitted_methods(
+    api: &JsonExtensionApi,
+    allowlist: &[String],
+    classes: &std::collections::HashSet<String>,
+) {
+    use crate::util;
+    use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+    let all_classes: HashSet<&str> = api.classes.iter().map(|c| c.name.as_str()).collect();
+    let is_missing = |ty: &str| {
+        let class_name = referenced_class(ty);
+        let is_generated = classes.contains(class_name) || SELECTED_CLASSES.contains(&class_name);
+
+        // Explicitly deleted types are omitted regardless of the allowlist.
+        all_classes.contains(class_name)
+            && !is_generated
+            && !special_cases::is_godot_type_deleted(ty)
+    };
+
+    // Missing class -> methods that use it.
+    let mut omitted: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    for class in api.classes.iter().filter(|c| allowlist.contains(&c.name)) {
+        for method in util::option_as_slice(&class.methods) {
+            let return_ty = method.return_value.as_ref().map(|ret| ret.type_.as_str());
+            let arg_tys = util::option_as_slice(&method.arguments)
+                .iter()
+                .map(|arg| arg.type_.as_str());
+
+            for ty in return_ty
+                .into_iter()
+                .chain(arg_tys)
+                .filter(|ty| is_missing(ty))
+            {
+                omitted
+                    .entry(referenced_class(ty))
+                    .or_default()
+                    .insert(format!("{}::{}", class.name, method.name));
+            }
+        }
+    }
+
+    for (class_name, methods) in omitted {
+        let methods = methods.into_iter().collect::<Vec<_>>().join(", ");
+        println!(
+            "cargo:warning=codegen allowlist: `{class_name}` is not generated, so these methods are omitted: {methods}"
+        );
+    }
+}
+
+/// Class that a type from the JSON refers to, e.g. `Node` for `typedarray::Node` or `enum::Node.ProcessMode`.
+///
+/// Returns the type itself if it is not related to a class (e.g. `int` or `enum::Error`).
+#[cfg(not(feature = "codegen-full"))]
+fn referenced_class(ty: &str) -> &str {
+    let ty = ty.trim_start_matches("const ").trim_end_matches('*');
+    let ty = ty.split_once("::").map_or(ty, |(_, inner)| inner);
+    ty.split_once('.').map_or(ty, |(class, _)| class)
+}
+
+#[cfg(feature = "codegen-full")]
+pub(crate) fn select_module_classes(_api: &JsonExtensionApi) {}
+
+/// Reads the class names from [`CLASS_ALLOWLIST_ENV`] and the file in [`CLASS_ALLOWLIST_FILE_ENV`]. In the file, `#` starts a comment.
+#[cfg(not(feature = "codegen-full"))]
+fn read_class_allowlist() -> Vec<String> {
+    use crate::util::{CLASS_ALLOWLIST_ENV, CLASS_ALLOWLIST_FILE_ENV};
+
+    println!("cargo:rerun-if-env-changed={CLASS_ALLOWLIST_ENV}");
+    println!("cargo:rerun-if-env-changed={CLASS_ALLOWLIST_FILE_ENV}");
+
+    let mut names = String::new();
+    if let Ok(list) = std::env::var(CLASS_ALLOWLIST_ENV) {
+        names.push_str(&list);
+    }
+
+    if let Ok(path) = std::env::var(CLASS_ALLOWLIST_FILE_ENV) {
+        println!("cargo:rerun-if-changed={path}");
+
+        let file = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("cannot read {CLASS_ALLOWLIST_FILE_ENV} `{path}`: {e}"));
+
+        for line in file.lines() {
+            let line = line
+                .split_once('#')
+                .map_or(line, |(content, _comment)| content);
+            names.push('\n');
+            names.push_str(line);
+        }
+    }
+
+    names
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
@@ -132,18 +321,35 @@ const SELECTED_CLASSES: &[&str] = &[
     "CanvasItem",
     "CanvasLayer",
     "ClassDB",
+    "CodeHighlighter",
     "CollisionObject2D",
     "CollisionShape2D",
+    "ConfigFile",
     "Control",
+    "DirAccess",
+    "EditorFileSystem",
+    "EditorFileSystemDirectory",
+    "EditorInterface",
     "EditorPlugin",
+    "EditorResourcePreviewGenerator",
+    "EditorSelection",
+    "EditorSyntaxHighlighter",
     "Engine",
+    "EngineDebugger",
+    "EngineProfiler",
     "FileAccess",
     "HTTPRequest",
     "Image",
+    "ImageTexture",
     "ImageTextureLayered",
     "Input",
     "InputEvent",
     "InputEventAction",
+    "InputEventFromWindow",
+    "InputEventMouse",
+    "InputEventMouseButton",
+    "InputEventMouseMotion",
+    "InputEventWithModifiers",
     "Label",
     "MainLoop",
     "Marker2D",
@@ -156,9 +362,12 @@ const SELECTED_CLASSES: &[&str] = &[
     "OS",
     "PackedScene",
     "PathFollow2D",
+    "Performance",
     "PhysicsBody2D",
     "PrimitiveMesh",
     "RefCounted",
+    "RegEx",
+    "RegExMatch",
     "RenderingServer",
     "Resource",
     "ResourceFormatLoader",
@@ -172,9 +381,12 @@ const SELECTED_CLASSES: &[&str] = &[
     "ScriptLanguage",
     "Sprite2D",
     "SpriteFrames",
+    "SubViewport",
+    "SyntaxHighlighter",
     "TextServer",
     "TextServerExtension",
     "Texture",
+    "Texture2D",
     "Texture2DArray",
     "TextureLayered",
     "Time",
@@ -0,0 +1,274 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! APIs newer than the minimum Godot version set through `GDEXT_MIN_GODOT_API`.
+//!
+//! Determined by comparing the API used for codegen against the prebuilt extension APIs of the minimum and all later Godot versions.
+
+#![allow(clippy::question_mark)] // in #[derive(DeJson)]
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use nanoserde::DeJson;
+
+use crate::models::json::JsonExtensionApi;
+use crate::util;
+
+type Version = (u8, u8);
+
+/// Names of the symbols in an extension API that can be excluded. Other parts of the JSON are skipped during deserialization.
+#[derive(DeJson)]
+struct JsonApiSymbols {
+    classes: Vec<JsonClassSymbols>,
+    utility_functions: Vec<JsonNamed>,
+}
+
+#[derive(DeJson)]
+struct JsonClassSymbols {
+    name: String,
+    methods: Option<Vec<JsonNamed>>,
+}
+
+#[derive(DeJson)]
+struct JsonNamed {
+    name: String,
+}
+
+/// Symbols of one Godot version.
+struct ApiSymbols {
+    /// Class name -> method names.
+    classes: HashMap<String, Vec<String>>,
+    utility_functions: HashSet<String>,
+}
+
+impl ApiSymbols {
+    fn from_json(json: &str, version: Version) -> Self {
+        let symbols: JsonApiSymbols = DeJson::deserialize_json(json).unwrap_or_else(|e| {
+            panic!(
+                "failed to deserialize extension API of Godot {}.{}: {e}",
+                version.0, version.1
+            )
+        });
+
+        Self::new(
+            symbols.classes.into_iter().map(|class| {
+                let methods = class.methods.unwrap_or_default();
+                (class.name, methods.into_iter().map(|m| m.name).collect())
+            }),
+            symbols.utility_functions.into_iter().map(|f| f.name),
+        )
+    }
+
+    fn from_api(api: &JsonExtensionApi) -> Self {
+        Self::new(
+            api.classes.iter().map(|class| {
+                let methods = util::option_as_slice(&class.methods);
+                (
+                    class.name.clone(),
+                    methods.iter().map(|m| m.name.clone()).collect(),
+                )
+            }),
+            api.utility_functions.iter().map(|f| f.name.clone()),
+        )
+    }
+
+    fn new(
+        classes: impl Iterator<Item = (String, Vec<String>)>,
+        utility_functions: impl Iterator<Item = String>,
+    ) -> Self {
+        Self {
+            classes: classes.collect(),
+            utility_functions: utility_functions.collect(),
+        }
+    }
+
+    fn has_class(&self, class_name: &str) -> bool {
+        self.classes.contains_key(class_name)
+    }
+
+    fn has_class_method(&self, class_name: &str, method_name: &str) -> bool {
+        self.classes
+            .get(class_name)
+            .map_or(false, |methods| methods.iter().any(|m| m == method_name))
+    }
+}
+
+struct ApiHistory {
+    /// Minimum version and each later version with a prebuilt API, oldest first.
+    versions: Vec<(Version, ApiSymbols)>,
+
+    /// Version used for codegen, newer than all in `versions`.
+    current_version: Version,
+    current: ApiSymbols,
+}
+
+impl ApiHistory {
+    fn load(api: &JsonExtensionApi) -> Option<Self> {
+        let min_version = util::get_min_godot_api()?;
+        let current_version = (api.header.version_major, api.header.version_minor);
+        if min_version >= current_version {
+            return None;
+        }
+
+        let (major, min_minor) = min_version;
+        let mut versions = Vec::new();
+
+        for minor in min_minor..current_version.1 {
+            let Some(json) = godot_bindings::load_gdextension_json_of(major, minor) else {
+                break;
+            };
+
+            let version = (major, minor);
+            versions.push((version, ApiSymbols::from_json(json, version)));
+        }
+
+        if versions.is_empty() {
+            println!(
+                "cargo:warning={env}={}.{}: no extension API of this Godot version is available (is the `min-godot-api` \
+                feature enabled?), so APIs introduced up to {}.{} are not excluded",
+                min_version.0,
+                min_version.1,
+                current_version.0,
+                current_version.1,
+                env = util::MIN_GODOT_API_ENV,
+            );
+            return None;
+        }
+
+        Some(Self {
+            versions,
+            current_version,
+            current: ApiSymbols::from_api(api),
+        })
+    }
+
+    /// Version that introduced a symbol, if it is newer than the minimum version.
+    ///
+    /// Symbols missing in all prebuilt versions are attributed to the version used for codegen, even if a version in between (without
+    /// prebuilt API) introduced them.
+    fn since(&self, exists_in: impl Fn(&ApiSymbols) -> bool) -> Option<Version> {
+        let ((_, min), later) = self.versions.split_first().unwrap();
+        if exists_in(min) {
+            return None;
+        }
+
+        let since = later
+            .iter()
+            .find(|(_, symbols)| exists_in(symbols))
+            .map_or(self.current_version, |(version, _)| *version);
+
+        Some(since)
+    }
+}
+
+/// Loads the API history. Must be called before any of the other functions in this module; until then, nothing is excluded.
+pub(crate) fn init(api: &JsonExtensionApi) {
+    HISTORY.get_or_init(|| ApiHistory::load(api));
+}
+
+/// Godot version that introduced a class or a type nested in it, if newer than the minimum version.
+///
+/// `godot_ty` can be a class name, but also a type like `enum::AESContext.Mode` or `typedarray::Node`.
+pub(crate) fn get_class_since_api(godot_ty: &str) -> Option<Version> {
+    let ty = godot_ty
+        .split_once("::")
+        .map_or(godot_ty, |(_, inner)| inner);
+    let class_name = ty.split_once('.').map_or(ty, |(class, _)| class);
+
+    // Builtin types, global enums, native structures etc.
+    let history = history()?;
+    if !history.current.has_class(class_name) {
+        return None;
+    }
+
+    history.since(|symbols| symbols.has_class(class_name))
+}
+
+/// Godot version that introduced a class method, if newer than the minimum version.
+pub(crate) fn get_class_method_since_api(class_name: &str, method_name: &str) -> Option<Version> {
+    history()?.since(|symbols| symbols.has_class_method(class_name, method_name))
+}
+
+/// All methods of a class that are newer than the minimum version, in API order.
+pub(crate) fn get_class_methods_since_api(class_name: &str) -> Vec<(String, Version)> {
+    let Some(history) = history() else {
+        return Vec::new();
+    };
+
+    let Some(methods) = history.current.classes.get(class_name) else {
+        return Vec::new();
+    };
+
+    methods
+        .iter()
+        .filter_map(|method| {
+            let since = history.since(|symbols| symbols.has_class_method(class_name, method))?;
+            Some((method.clone(), since))
+        })
+        .collect()
+}
+
+/// Godot version that introduced a utility function, if newer than the minimum version.
+pub(crate) fn get_utility_function_since_api(function_name: &str) -> Option<Version> {
+    history()?.since(|symbols| symbols.utility_functions.contains(function_name))
+}
+
+static HISTORY: OnceLock<Option<ApiHistory>> = OnceLock::new();
+
+fn history() -> Option<&'static ApiHistory> {
+    HISTORY.get()?.as_ref()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn symbols(classes: &[(&str, &[&str])]) -> ApiSymbols {
+        ApiSymbols::new(
+            classes.iter().map(|(class, methods)| {
+                let methods = methods.iter().map(|m| m.to_string()).collect();
+                (class.to_string(), methods)
+            }),
+            std::iter::empty(),
+        )
+    }
+
+    #[test]
+    fn since_finds_introducing_version() {
+        let history = ApiHistory {
+            versions: vec![
+                ((4, 0), symbols(&[("Node", &["add_child"])])),
+                (
+                    (4, 1),
+                    symbols(&[("Node", &["add_child", "call_thread_safe"])]),
+                ),
+            ],
+            current_version: (4, 2),
+            current: symbols(&[
+                (
+                    "Node",
+                    &["add_child", "call_thread_safe", "get_process_thread_group"],
+                ),
+                ("EditorContextMenuPlugin", &[]),
+            ]),
+        };
+
+        let method_since = |method: &str| history.since(|s| s.has_class_method("Node", method));
+        assert_eq!(method_since("add_child"), None);
+        assert_eq!(method_since("call_thread_safe"), Some((4, 1)));
+        assert_eq!(method_since("get_process_thread_group"), Some((4, 2)));
+
+        assert_eq!(history.since(|s| s.has_class("Node")), None);
+        assert_eq!(
+            history.since(|s| s.has_class("EditorContextMenuPlugin")),
+            Some((4, 2))
+        );
+    }
+}
@@ -7,6 +7,7 @@
 
 // Deliberately private -- all checks must go through `special_cases`.
 mod codegen_special_cases;
+mod min_api;
 #[allow(clippy::module_inception)]
 mod special_cases;
 
@@ -24,8 +24,10 @@
 #![allow(clippy::match_like_matches_macro)] // if there is only one rule
 
 use crate::models::domain::TyName;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
-use crate::special_cases::codegen_special_cases;
+use crate::models::json::{
+    JsonBuiltinMethod, JsonClassMethod, JsonExtensionApi, JsonUtilityFunction,
+};
+use crate::special_cases::{codegen_special_cases, min_api};
 use crate::Context;
 
 // Deliberately private -- all checks must go through `special_cases`.
@@ -35,6 +37,10 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     if codegen_special_cases::is_class_method_excluded(method, ctx){
         return true;
     }
+
+    if is_class_method_above_min_api(class_name, &method.name) {
+        return true;
+    }
     
     match (class_name.godot_ty.as_str(), method.name.as_str()) {
         // Already covered by manual APIs
@@ -51,6 +57,17 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     }
 }
 
+/// Selects classes of the `codegen-*` module features. Must be called before [`is_class_deleted()`].
+pub fn select_module_classes(api: &JsonExtensionApi) {
+    codegen_special_cases::select_module_classes(api);
+}
+
+/// Determines the APIs newer than the minimum Godot version, by comparing `api` with older Godot versions. Must be called before
+/// [`is_class_deleted()`] and the other `*_min_api` checks.
+pub fn load_min_api_history(api: &JsonExtensionApi) {
+    min_api::init(api);
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
@@ -59,6 +76,11 @@ pub fn is_class_deleted(class_name: &TyName) -> bool {
 pub fn is_godot_type_deleted(godot_ty: &str) -> bool {
     // Note: parameter can be a class or builtin name, but also something like "enum::AESContext.Mode".
 
+    // Exclude classes newer than the minimum Godot version, and any types nested in them.
+    if min_api::get_class_since_api(godot_ty).is_some() {
+        return true;
+    }
+
     // Exclude experimental APIs unless opted-in.
     if !cfg!(feature = "experimental-godot-api") && is_class_experimental(godot_ty) {
         return true;
@@ -156,6 +178,26 @@ fn is_class_experimental(godot_class_name: &str) -> bool {
     }
 }
 
+/// Whether a class method is newer than the minimum Godot version set through `GDEXT_MIN_GODOT_API`.
+///
+/// Such methods are not generated; instead, the class docs name the required version.
+pub fn is_class_method_above_min_api(class_name: &TyName, godot_method_name: &str) -> bool {
+    get_class_method_since_api(class_name, godot_method_name).is_some()
+}
+
+/// Godot version that introduced a class method, if it is newer than the minimum Godot version.
+pub fn get_class_method_since_api(
+    class_name: &TyName,
+    godot_method_name: &str,
+) -> Option<(u8, u8)> {
+    min_api::get_class_method_since_api(&class_name.godot_ty, godot_method_name)
+}
+
+/// All methods of a class that are excluded due to the minimum Godot version, with their required version.
+pub fn get_class_methods_above_min_api(class_name: &TyName) -> Vec<(String, (u8, u8))> {
+    min_api::get_class_methods_since_api(&class_name.godot_ty)
+}
+
 /// Whether a method is available in the method table as a named accessor.
 #[rustfmt::skip]
 pub fn is_named_accessor_in_table(class_or_builtin_ty: &TyName, godot_method_name: &str) -> bool {
@@ -261,6 +303,7 @@ pub fn is_builtin_type_scalar(name: &str) -> bool {
 
 pub fn is_utility_function_deleted(function: &JsonUtilityFunction, ctx: &mut Context) -> bool {
     codegen_special_cases::is_utility_function_excluded(function, ctx)
+        || min_api::get_utility_function_since_api(&function.name).is_some()
 }
 
 pub fn maybe_rename_class_method<'m>(class_name: &TyName, godot_method_name: &'m str) -> &'m str {
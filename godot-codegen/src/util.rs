@@ -10,6 +10,7 @@ use crate::models::json::JsonClass;
 use crate::models::domain::ClassCodegenLevel;
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote};
+use std::sync::OnceLock;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
@@ -18,6 +19,40 @@ pub fn option_as_slice<T>(option: &Option<Vec<T>>) -> &[T] {
     option.as_ref().map_or(&[], Vec::as_slice)
 }
 
+/// Name of the environment variable that sets the oldest Godot version a project supports, e.g. `4.1`.
+pub const MIN_GODOT_API_ENV: &str = "GDEXT_MIN_GODOT_API";
+
+/// Name of the environment variable listing additional classes to generate, separated by commas or whitespace.
+pub const CLASS_ALLOWLIST_ENV: &str = "GDEXT_CODEGEN_CLASSES";
+
+/// Name of the environment variable holding the path to a file with additional classes to generate, one per line.
+pub const CLASS_ALLOWLIST_FILE_ENV: &str = "GDEXT_CODEGEN_CLASSES_FILE";
+
+/// Name of the environment variable limiting the number of threads used by codegen. Does not affect the generated code.
+pub const CODEGEN_THREADS_ENV: &str = "GDEXT_CODEGEN_THREADS";
+
+/// Oldest Godot version `(major, minor)` the user supports, as set through [`MIN_GODOT_API_ENV`].
+///
+/// `None` if the variable is not set, in which case all APIs of the Godot version used for codegen are available.
+pub fn get_min_godot_api() -> Option<(u8, u8)> {
+    static MIN_API: OnceLock<Option<(u8, u8)>> = OnceLock::new();
+
+    *MIN_API.get_or_init(|| {
+        let value = std::env::var(MIN_GODOT_API_ENV).ok()?;
+        let parsed = value
+            .trim()
+            .split_once('.')
+            .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)));
+
+        match parsed {
+            Some(version) => Some(version),
+            None => panic!(
+                "{MIN_GODOT_API_ENV} must have the form `major.minor`, e.g. `4.1`; got `{value}`"
+            ),
+        }
+    })
+}
+
 pub fn make_imports() -> TokenStream {
     quote! {
         use godot_ffi as sys;
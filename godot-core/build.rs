@@ -10,13 +10,14 @@ use std::path::Path;
 fn main() {
     // It would be better to generate this in /.generated or /target/godot-gen, however IDEs currently
     // struggle with static analysis when symbols are outside the crate directory (April 2023).
+    // Not cleared upfront: codegen only rewrites files whose content changed, and removes stale class files itself.
     let gen_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen"));
 
-    if gen_path.exists() {
-        std::fs::remove_dir_all(gen_path).unwrap_or_else(|e| panic!("failed to delete dir: {e}"));
-    }
+    let mut watch = godot_bindings::StopWatch::start();
+    godot_codegen::generate_cached("godot-core", gen_path, &mut watch, |_watch| {
+        godot_codegen::generate_core_files(gen_path);
+    });
 
-    godot_codegen::generate_core_files(gen_path);
     println!("cargo:rerun-if-changed=build.rs");
 
     godot_bindings::emit_godot_version_cfg();
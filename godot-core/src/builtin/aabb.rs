@@ -55,6 +55,7 @@ impl Aabb {
     }
 
     /// Whether `self` covers at least the entire area of `b` (and possibly more).
+    #[doc(alias = "contains_aabb")]
     #[inline]
     pub fn encloses(&self, b: Aabb) -> bool {
         let end = self.end();
@@ -72,6 +73,7 @@ impl Aabb {
     ///
     /// # Panics
     /// If `self.size` is negative.
+    #[doc(alias = "expand_to_point")]
     #[inline]
     pub fn expand(&self, to: Vector3) -> Self {
         self.merge(&Aabb::new(to, Vector3::ZERO))
@@ -189,6 +191,49 @@ impl Aabb {
         self.size = end - self.position
     }
 
+    /// Returns the eight corners.
+    ///
+    /// Bits 2, 1 and 0 of the index select the X, Y and Z coordinate respectively: `0` for `position`, `1` for `end()`.
+    /// This matches the order of `AABB.get_endpoint()`.
+    ///
+    /// _Godot equivalent: `AABB.get_endpoint()`_
+    #[doc(alias = "get_endpoint")]
+    #[inline]
+    pub fn corners(&self) -> [Vector3; 8] {
+        let start = self.position;
+        let end = self.end();
+
+        std::array::from_fn(|i| {
+            let pick = |bit: usize, min: real, max: real| if i & bit == 0 { min } else { max };
+            Vector3::new(
+                pick(4, start.x, end.x),
+                pick(2, start.y, end.y),
+                pick(1, start.z, end.z),
+            )
+        })
+    }
+
+    /// Returns the twelve edges as `(from, to)` pairs of [corners][Self::corners].
+    ///
+    /// The first four edges run along the X axis, the next four along Y and the last four along Z. Each edge runs from the
+    /// smaller to the larger coordinate.
+    #[inline]
+    pub fn edges(&self) -> [(Vector3, Vector3); 12] {
+        let corners = self.corners();
+
+        // For each axis bit, pair every corner without that bit with the corner that has it.
+        let mut edges = [(Vector3::ZERO, Vector3::ZERO); 12];
+        let mut n = 0;
+        for axis_bit in [4, 2, 1] {
+            for i in (0..8).filter(|i| i & axis_bit == 0) {
+                edges[n] = (corners[i], corners[i | axis_bit]);
+                n += 1;
+            }
+        }
+
+        edges
+    }
+
     /// Returns the normalized longest axis of the AABB.
     #[inline]
     pub fn longest_axis(&self) -> Vector3 {
@@ -431,6 +476,29 @@ mod test {
         crate::builtin::test_utils::roundtrip(&aabb, expected_json);
     }
 
+    #[test]
+    fn corners_match_endpoint_order() {
+        let aabb = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+        let corners = aabb.corners();
+
+        assert_eq!(corners[0], aabb.position);
+        assert_eq!(corners[1], Vector3::new(1.0, 2.0, 9.0));
+        assert_eq!(corners[2], Vector3::new(1.0, 7.0, 3.0));
+        assert_eq!(corners[4], Vector3::new(5.0, 2.0, 3.0));
+        assert_eq!(corners[7], aabb.end());
+    }
+
+    #[test]
+    fn edges_are_axis_aligned() {
+        let aabb = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+        let edges = aabb.edges();
+
+        for (i, (from, to)) in edges.into_iter().enumerate() {
+            let expected = [Vector3::RIGHT * 4.0, Vector3::UP * 5.0, Vector3::BACK * 6.0][i / 4];
+            assert_eq!(to - from, expected, "edge {i}");
+        }
+    }
+
     #[test]
     fn test_axes_functions() {
         let aabb = Aabb {
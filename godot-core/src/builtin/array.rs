@@ -9,8 +9,10 @@ use godot_ffi as sys;
 
 use crate::builtin::*;
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
+use std::cmp::Ordering;
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 use super::meta::{
@@ -125,6 +127,77 @@ impl<T: GodotType> Array<T> {
         self.as_inner().resize(to_i64(size));
     }
 
+    /// Shortens the array to `len` elements, dropping the rest. Has no effect if the array is not longer than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.resize(len);
+        }
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run. Elements are compared as variants.
+    ///
+    /// If the array is sorted, this removes all duplicates.
+    pub fn dedup(&mut self) {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+
+        // Compact kept elements to the front, then truncate once.
+        let mut write = 1;
+        for read in 1..len {
+            // SAFETY: `read < len` and `write <= read`, so both indices are in bounds.
+            let is_duplicate = unsafe { *self.ptr(read) == *self.ptr(write - 1) };
+            if !is_duplicate {
+                self.swap_elements(read, write);
+                write += 1;
+            }
+        }
+
+        self.truncate(write);
+    }
+
+    /// Swaps the elements at the two indices, without converting them.
+    ///
+    /// # Panics
+    ///
+    /// If either index is out of bounds.
+    fn swap_elements(&mut self, a: usize, b: usize) {
+        if a != b {
+            let (ptr_a, ptr_b) = (self.ptr_mut(a), self.ptr_mut(b));
+            // SAFETY: `ptr_mut` checked both indices, and the pointers are distinct because the indices are.
+            unsafe { std::ptr::swap(ptr_a, ptr_b) };
+        }
+    }
+
+    /// Appends the variants of `iter`, which must all be of type `T`.
+    fn extend_variants(&mut self, mut iter: impl Iterator<Item = Variant>) {
+        // The GDExtension API does not offer the equivalent of `Vec::reserve`. Instead, resize once for the elements that
+        // `size_hint()` guarantees and write them in place, rather than making one engine call per element.
+        //
+        // The elements are collected before resizing: the iterator may modify this array through a shared clone, so no pointer into
+        // the array must be held while it runs.
+        let (guaranteed, _) = iter.size_hint();
+        let first: Vec<Variant> = iter.by_ref().take(guaranteed).collect();
+
+        if !first.is_empty() {
+            let start = self.len();
+            self.resize(start + first.len());
+
+            for (i, variant) in first.into_iter().enumerate() {
+                // SAFETY: The array holds `start + first.len()` elements and `i < first.len()`. The pointer is obtained after the
+                // resize and no other code runs between obtaining and writing through it.
+                unsafe {
+                    *self.ptr_mut(start + i) = variant;
+                }
+            }
+        }
+
+        for variant in iter {
+            self.as_inner().push_back(variant);
+        }
+    }
+
     /// Reverses the order of the elements in the array.
     pub fn reverse(&mut self) {
         self.as_inner().reverse();
@@ -354,11 +427,16 @@ impl<T: GodotType> Array<T> {
 
     /// Checks that the inner array has the correct type set on it for storing elements of type `T`.
     fn with_checked_type(self) -> Result<Self, ConvertError> {
+        self.check_type().map(|()| self)
+    }
+
+    /// Like [`with_checked_type()`](Self::with_checked_type), but without taking ownership.
+    pub(crate) fn check_type(&self) -> Result<(), ConvertError> {
         let self_ty = self.type_info();
         let target_ty = TypeInfo::of::<T>();
 
         if self_ty == target_ty {
-            Ok(self)
+            Ok(())
         } else {
             Err(FromGodotError::BadArrayType {
                 expected: target_ty,
@@ -502,6 +580,156 @@ impl<T: GodotType + FromGodot> Array<T> {
         let variant = self.as_inner().pop_at(to_i64(index));
         T::from_variant(&variant)
     }
+
+    /// Sorts the array with a Rust comparator.
+    ///
+    /// Unlike [`sort_unstable_custom()`](Array::sort_unstable_custom), this does not need a `Callable`: elements are converted once, sorted
+    /// in Rust, and written back in their new order, without converting them back.
+    ///
+    /// The sorting algorithm is not [stable](https://en.wikipedia.org/wiki/Sorting_algorithm#Stability), see
+    /// [`slice::sort_unstable_by()`].
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut elements: Vec<(T, Variant)> = (0..self.len())
+            .map(|i| {
+                // SAFETY: `ptr` checks the bounds; the reference does not outlive this closure.
+                let variant = unsafe { (*self.ptr(i)).clone() };
+                (T::from_variant(&variant), variant)
+            })
+            .collect();
+
+        elements.sort_unstable_by(|(a, _), (b, _)| compare(a, b));
+
+        for (i, (_, variant)) in elements.into_iter().enumerate() {
+            let ptr_mut = self.ptr_mut(i);
+            // SAFETY: `ptr_mut` checked that the index is not out of bounds.
+            unsafe {
+                *ptr_mut = variant;
+            }
+        }
+    }
+
+    /// Sorts the array by a key extracted from each element, see [`sort_unstable_by()`](Self::sort_unstable_by).
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_unstable_by(|a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Binary searches a sorted array with a Rust comparator, like [`slice::binary_search_by()`].
+    ///
+    /// `compare` returns the ordering of an element relative to the target. If a matching element is found, returns `Ok` with its index;
+    /// otherwise `Err` with the index where the target could be inserted to keep the order. Only the visited elements are converted.
+    ///
+    /// Calling this on an array not sorted according to `compare` returns an unspecified result.
+    pub fn binary_search_by<F>(&self, mut compare: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match compare(&self.get(mid)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last element.
+    ///
+    /// Unlike [`remove()`](Self::remove), this does not move the following elements and is thus O(1), but changes the order of elements.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.check_bounds(index);
+        let last = self.len() - 1;
+        self.swap_elements(index, last);
+
+        let variant = self.as_inner().pop_back();
+        T::from_variant(&variant)
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`, preserving their order.
+    ///
+    /// All elements are visited once, in order. Removal happens in a single resize at the end, instead of shifting the array for each
+    /// removed element.
+    ///
+    /// # Panics
+    ///
+    /// If `predicate` changes the length of the array, e.g. through another reference to it.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+
+        let mut write = 0;
+        for read in 0..len {
+            // SAFETY: `ptr` checks the bounds; the reference does not outlive this statement.
+            let element = T::from_variant(unsafe { &*self.ptr(read) });
+            if predicate(&element) {
+                self.swap_elements(read, write);
+                write += 1;
+            }
+        }
+
+        assert_eq!(self.len(), len, "Array length changed during retain()");
+        self.truncate(write);
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// The elements are removed right away, even if the iterator is not consumed. Elements after the range are moved to the front in a
+    /// single pass.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds, or its start is greater than its end.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<T> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "Array drain range {start}..{end} is out of bounds: length is {len}",
+        );
+
+        let removed = (start..end)
+            // SAFETY: the range was checked to be in bounds.
+            .map(|i| unsafe { (*self.ptr(i)).clone() })
+            .collect::<Vec<_>>();
+
+        // Move the tail into the gap; the removed elements end up after it and are truncated.
+        for read in end..len {
+            self.swap_elements(read, read - (end - start));
+        }
+        self.truncate(len - (end - start));
+
+        Drain {
+            removed: removed.into_iter(),
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl<T: GodotType + ToGodot> Array<T> {
@@ -917,14 +1145,14 @@ impl<T: GodotType + ToGodot> FromIterator<T> for Array<T> {
 /// Extends a `Array` with the contents of an iterator.
 impl<T: GodotType + ToGodot> Extend<T> for Array<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`.
-        // Otherwise we could use it to pre-allocate based on `iter.size_hint()`.
-        //
-        // A faster implementation using `resize()` and direct pointer writes might still be
-        // possible.
-        for item in iter.into_iter() {
-            self.push(item);
-        }
+        self.extend_variants(iter.into_iter().map(|item| item.to_variant()));
+    }
+}
+
+/// Extends a `Array` with the contents of an iterator over references.
+impl<'a, T: GodotType + ToGodot> Extend<&'a T> for Array<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_variants(iter.into_iter().map(|item| item.to_variant()));
     }
 }
 
@@ -977,6 +1205,36 @@ impl<'a, T: GodotType + FromGodot> Iterator for Iter<'a, T> {
     }
 }
 
+/// An iterator over the elements removed from an `Array`.
+///
+/// See [`Array::drain()`].
+pub struct Drain<T: GodotType> {
+    removed: std::vec::IntoIter<Variant>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: GodotType + FromGodot> Iterator for Drain<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.removed.next().map(|variant| T::from_variant(&variant))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.removed.size_hint()
+    }
+}
+
+impl<T: GodotType + FromGodot> DoubleEndedIterator for Drain<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.removed
+            .next_back()
+            .map(|variant| T::from_variant(&variant))
+    }
+}
+
+impl<T: GodotType + FromGodot> ExactSizeIterator for Drain<T> {}
+
 // TODO There's a macro for this, but it doesn't support generics yet; add support and use it
 impl<T: GodotType> PartialEq for Array<T> {
     #[inline]
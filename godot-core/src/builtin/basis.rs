@@ -10,7 +10,7 @@ use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{ApproxEq, FloatExt, GlamConv, GlamType};
 use crate::builtin::real_consts::FRAC_PI_2;
-use crate::builtin::{real, Quaternion, RMat3, RQuat, RVec2, RVec3, Vector3};
+use crate::builtin::{real, Quaternion, RMat3, RMat3Ops, RQuat, RVec2, RVec3, RVec3Ops, Vector3};
 
 use std::cmp::Ordering;
 use std::fmt::Display;
@@ -114,6 +114,7 @@ impl Basis {
     /// as Euler angles according to the given `EulerOrder`.
     ///
     /// _Godot equivalent: `Basis.from_euler(Vector3 euler, int order)`_
+    #[doc(alias = "from_euler_order")]
     pub fn from_euler(order: EulerOrder, angles: Vector3) -> Self {
         // Translated from "Basis::from_euler" in
         // https://github.com/godotengine/godot/blob/master/core/math/basis.cpp
@@ -177,6 +178,18 @@ impl Basis {
         RQuat::from_mat3(&self.orthonormalized().to_glam()).to_front()
     }
 
+    /// Returns the rotation of the matrix as a normalized axis and an angle in radians.
+    ///
+    /// Scale is ignored, like in [`to_quat()`](Self::to_quat). The angle is in `0..=PI`; for the identity, the axis is `Vector3::RIGHT`.
+    ///
+    /// _Godot equivalent: `Basis::get_axis_angle()` (C++ only)_
+    #[doc(alias = "get_axis_angle")]
+    pub fn to_axis_angle(self) -> (Vector3, real) {
+        let (axis, angle) = self.to_quat().to_glam().to_axis_angle();
+
+        (axis.to_front(), angle)
+    }
+
     const fn to_rows_array(self) -> [real; 9] {
         let [Vector3 {
             x: ax,
@@ -398,6 +411,49 @@ impl Basis {
         Self::from_cols(x, y, z)
     }
 
+    /// Returns the matrix with perpendicular columns, keeping their lengths ([`scale()`](Self::scale)).
+    ///
+    /// # Panics
+    ///
+    /// If the determinant of the matrix is 0.
+    ///
+    /// _Godot equivalent: `Basis::orthogonalize()` (C++ only)_
+    #[must_use]
+    pub fn orthogonalized(self) -> Self {
+        let scale = self.scale();
+
+        self.orthonormalized() * Self::from_scale(scale)
+    }
+
+    /// Returns `true` if the columns are (approximately) perpendicular to each other. They may have different lengths.
+    ///
+    /// _Godot equivalent: `Basis::is_orthogonal()` (C++ only)_
+    pub fn is_orthogonal(&self) -> bool {
+        let [a, b, c] = self.to_cols();
+
+        a.dot(b).is_zero_approx() && a.dot(c).is_zero_approx() && b.dot(c).is_zero_approx()
+    }
+
+    /// Returns `true` if the columns are perpendicular to each other and have the same length, i.e. the basis is a rotation (possibly
+    /// with reflection) combined with a uniform scale.
+    ///
+    /// _Godot equivalent: `Basis.is_conformal()`_
+    pub fn is_conformal(&self) -> bool {
+        let [a, b, c] = self.to_cols();
+        let length_squared = a.length_squared();
+
+        length_squared.approx_eq(&b.length_squared())
+            && length_squared.approx_eq(&c.length_squared())
+            && self.is_orthogonal()
+    }
+
+    /// Returns `true` if the basis is a pure rotation, without scale or reflection.
+    ///
+    /// _Godot equivalent: `Basis::is_rotation()` (C++ only)_
+    pub fn is_rotation(&self) -> bool {
+        self.is_conformal() && self.determinant().approx_eq(&1.0)
+    }
+
     /// Introduce an additional rotation around the given `axis` by `angle`
     /// (in radians). The axis must be a normalized vector.
     ///
@@ -407,6 +463,14 @@ impl Basis {
         Self::from_axis_angle(axis, angle) * self
     }
 
+    /// Returns the rotation that turns this basis into `to`, so that `self.rotation_difference(to) * self` has the orientation of `to`.
+    ///
+    /// Scale of both bases is ignored.
+    #[must_use]
+    pub fn rotation_difference(self, to: Self) -> Self {
+        to.orthonormalized() * self.orthonormalized().transposed()
+    }
+
     /// Assuming that the matrix is a proper rotation matrix, slerp performs
     /// a spherical-linear interpolation with another rotation matrix.
     ///
@@ -565,7 +629,7 @@ impl Mul for Basis {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self.glam2(&rhs, |a, b| a * b)
+        (RMat3Ops::from_front(&self) * RMat3Ops::from_front(&rhs)).to_front()
     }
 }
 
@@ -590,7 +654,7 @@ impl Mul<Vector3> for Basis {
     type Output = Vector3;
 
     fn mul(self, rhs: Vector3) -> Self::Output {
-        self.glam2(&rhs, |a, b| a * b)
+        (RMat3Ops::from_front(&self) * RVec3Ops::from_front(&rhs)).to_front()
     }
 }
 
@@ -604,7 +668,7 @@ unsafe impl GodotFfi for Basis {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
 }
 
-impl_godot_as_self!(Basis);
+impl_godot_as_self!(Basis; check_finite);
 
 /// The ordering used to interpret a set of euler angles as extrinsic
 /// rotations.
@@ -635,6 +699,67 @@ mod test {
         )
     }
 
+    #[test]
+    fn mul_uses_rows() {
+        let basis = Basis::from_rows(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+        let v = Vector3::new(1.0, -1.0, 2.0);
+
+        assert_eq!(basis * v, Vector3::new(5.0, 11.0, 17.0));
+        assert_eq!(
+            basis * Basis::IDENTITY.scaled(Vector3::new(2.0, 2.0, 2.0)),
+            basis * 2.0
+        );
+        assert_eq!((basis * basis).col_a(), basis * basis.col_a());
+    }
+
+    #[test]
+    fn axis_angle_roundtrip() {
+        let axis = Vector3::new(1.0, -2.0, 0.5).normalized();
+        let basis = Basis::from_axis_angle(axis, 1.3).scaled(Vector3::new(2.0, 2.0, 2.0));
+
+        let (result_axis, result_angle) = basis.to_axis_angle();
+        assert_eq_approx!(result_axis, axis);
+        assert_eq_approx!(result_angle, 1.3);
+
+        assert_eq!(Basis::IDENTITY.to_axis_angle(), (Vector3::RIGHT, 0.0));
+    }
+
+    #[test]
+    fn rotation_difference() {
+        let from = Basis::from_euler(EulerOrder::YXZ, Vector3::new(0.3, -0.7, 1.1));
+        let to = Basis::from_euler(EulerOrder::ZYX, Vector3::new(-0.4, 0.2, 2.0));
+
+        let difference = from.rotation_difference(to);
+        assert!(difference.is_rotation());
+        assert_eq_approx!(difference * from, to);
+    }
+
+    #[test]
+    fn orthogonality() {
+        let rotation = Basis::from_axis_angle(Vector3::UP, 0.8);
+        assert!(rotation.is_rotation());
+
+        let uniform = rotation * Basis::from_scale(Vector3::new(3.0, 3.0, 3.0));
+        assert!(uniform.is_conformal());
+        assert!(!uniform.is_rotation());
+
+        let non_uniform = rotation * Basis::from_scale(Vector3::new(1.0, 2.0, 3.0));
+        assert!(non_uniform.is_orthogonal());
+        assert!(!non_uniform.is_conformal());
+
+        let mut skewed = non_uniform;
+        skewed.set_col_b(skewed.col_b() + skewed.col_a() * 0.5);
+        assert!(!skewed.is_orthogonal());
+
+        let fixed = skewed.orthogonalized();
+        assert!(fixed.is_orthogonal());
+        assert_eq_approx!(fixed.scale(), skewed.scale());
+    }
+
     // Translated from Godot
     fn test_rotation(deg_original_euler: Vector3, rot_order: EulerOrder) {
         // This test:
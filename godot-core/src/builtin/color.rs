@@ -7,12 +7,13 @@
 
 use crate::builtin::inner::InnerColor;
 use crate::builtin::math::ApproxEq;
-use crate::builtin::GString;
+use crate::builtin::{ok_color, GString};
 
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use std::ops;
+use std::error::Error;
+use std::{fmt, ops};
 
 use super::meta::impl_godot_as_self;
 
@@ -104,10 +105,33 @@ impl Color {
     ///   Equivalent to `#RRGGBBAA`, i.e. each digit is repeated twice.
     /// - `#RGB` and `RGB`. Equivalent to `#RRGGBBff`.
     ///
-    /// Returns `None` if the format is invalid.
-    pub fn from_html<S: Into<GString>>(html: S) -> Option<Self> {
-        let html = html.into();
-        InnerColor::html_is_valid(html.clone()).then(|| InnerColor::html(html))
+    /// Returns an error if the format is invalid.
+    pub fn from_html<S: Into<GString>>(html: S) -> Result<Self, HtmlColorError> {
+        let html = html.into().to_string();
+        let html = html.as_str();
+        let error = |kind| HtmlColorError {
+            html: html.to_string(),
+            kind,
+        };
+
+        let digits = html.strip_prefix('#').unwrap_or(html);
+        let nibbles = digits
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8).ok_or(c))
+            .collect::<Result<Vec<u8>, char>>()
+            .map_err(|c| error(HtmlErrorKind::InvalidDigit(c)))?;
+
+        let bytes: Vec<u8> = match nibbles.len() {
+            3 | 4 => nibbles.iter().map(|n| n * 17).collect(),
+            6 | 8 => nibbles
+                .chunks(2)
+                .map(|pair| pair[0] << 4 | pair[1])
+                .collect(),
+            len => return Err(error(HtmlErrorKind::InvalidLength(len))),
+        };
+
+        let a = bytes.get(3).copied().unwrap_or(u8::MAX);
+        Ok(Self::from_rgba8(bytes[0], bytes[1], bytes[2], a))
     }
 
     /// Constructs a `Color` from a string, which can be either:
@@ -142,7 +166,27 @@ impl Color {
     /// hue (`h`), saturation (`s`), and value (`v`) are typically between 0.0 and 1.0. Alpha is
     /// set to 1; use [`Color::with_alpha`] to change it.
     pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
-        InnerColor::from_hsv(h, s, v, 1.0)
+        let v = v as f32;
+        if s == 0.0 {
+            return Self::from_rgb(v, v, v);
+        }
+
+        let h = (h * 6.0).rem_euclid(6.0);
+        let f = (h - h.floor()) as f32;
+        let s = s as f32;
+
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+
+        match h as u8 % 6 {
+            0 => Self::from_rgb(v, t, p),
+            1 => Self::from_rgb(q, v, p),
+            2 => Self::from_rgb(p, v, t),
+            3 => Self::from_rgb(p, q, v),
+            4 => Self::from_rgb(t, p, v),
+            _ => Self::from_rgb(v, p, q),
+        }
     }
 
     /// Constructs a `Color` from an [OK HSL
@@ -150,7 +194,17 @@ impl Color {
     /// and lightness (`l`) are typically between 0.0 and 1.0. Alpha is set to 1; use
     /// [`Color::with_alpha`] to change it.
     pub fn from_ok_hsl(h: f64, s: f64, l: f64) -> Self {
-        InnerColor::from_ok_hsl(h, s, l, 1.0)
+        let [r, g, b] = ok_color::okhsl_to_srgb([h as f32, s as f32, l as f32]);
+        Self::from_rgb(r, g, b).clamp_unit()
+    }
+
+    /// Constructs a `Color` from coordinates in the [Oklab color
+    /// space](https://bottosson.github.io/posts/oklab/): perceived lightness `l`, and the green-red
+    /// and blue-yellow axes `a` and `b`. The result is sRGB-encoded like other colors, and not
+    /// clamped. Alpha is set to 1; use [`Color::with_alpha`] to change it.
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Self {
+        let [r, g, b] = ok_color::oklab_to_linear_srgb([l, a, b]);
+        Self::from_rgb(r, g, b).linear_to_srgb()
     }
 
     /// Constructs a `Color` from an RGBE9995 format integer. This is a special OpenGL texture
@@ -212,7 +266,49 @@ impl Color {
         self.a = from_u8(a);
     }
 
-    // TODO add getters and setters for h, s, v (needs generated property wrappers)
+    /// Returns the hue, saturation and value of this color, each between 0.0 and 1.0 for colors
+    /// inside the 0 to 1 range. This is the inverse of [`Color::from_hsv`], ignoring alpha.
+    ///
+    /// The hue of a gray color is 0.
+    pub fn to_hsv(self) -> (f64, f64, f64) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if self.r == max {
+            (self.g - self.b) / delta
+        } else if self.g == max {
+            2.0 + (self.b - self.r) / delta
+        } else {
+            4.0 + (self.r - self.g) / delta
+        };
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        ((h as f64 / 6.0).rem_euclid(1.0), s as f64, max as f64)
+    }
+
+    /// Returns the hue, saturation and lightness of this color in the [OK HSL
+    /// profile](https://bottosson.github.io/posts/colorpicker/). This is the inverse of
+    /// [`Color::from_ok_hsl`], ignoring alpha.
+    pub fn to_ok_hsl(self) -> (f64, f64, f64) {
+        let [h, s, l] = ok_color::srgb_to_okhsl([self.r, self.g, self.b]);
+        (h as f64, s as f64, l as f64)
+    }
+
+    /// Returns the coordinates `(l, a, b)` of this color in the [Oklab color
+    /// space](https://bottosson.github.io/posts/oklab/). This is the inverse of
+    /// [`Color::from_oklab`], ignoring alpha.
+    ///
+    /// Distances in Oklab roughly match perceived color differences, which makes it suitable for
+    /// comparing colors and for generating gradients.
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let linear = self.srgb_to_linear();
+        let [l, a, b] = ok_color::linear_srgb_to_oklab([linear.r, linear.g, linear.b]);
+        (l, a, b)
+    }
 
     /// Returns the light intensity of the color, as a value between 0.0 and 1.0 (inclusive). This
     /// is useful when determining whether a color is light or dark. Colors with a luminance
@@ -238,6 +334,33 @@ impl Color {
         self.as_inner().lerp(to, weight)
     }
 
+    /// Interpolates between `self` and `to` in HSV space, taking the shorter way around the hue
+    /// circle. Alpha is interpolated linearly. The interpolation factor `weight` should be between
+    /// 0.0 and 1.0 (inclusive).
+    ///
+    /// Unlike [`lerp`][Self::lerp], intermediate colors keep their saturation, e.g. red to green
+    /// passes through yellow instead of brown.
+    #[must_use]
+    pub fn lerp_hsv(self, to: Color, weight: f64) -> Self {
+        let (h_from, s_from, v_from) = self.to_hsv();
+        let (h_to, s_to, v_to) = to.to_hsv();
+
+        let mut dh = h_to - h_from;
+        if dh > 0.5 {
+            dh -= 1.0;
+        } else if dh < -0.5 {
+            dh += 1.0;
+        }
+
+        let lerp = |from: f64, to: f64| from + (to - from) * weight;
+        Self::from_hsv(
+            (h_from + dh * weight).rem_euclid(1.0),
+            lerp(s_from, s_to),
+            lerp(v_from, v_to),
+        )
+        .with_alpha(lerp(self.a as f64, to.a as f64) as f32)
+    }
+
     /// Returns a new color with all components clamped between the components of `min` and `max`.
     #[must_use]
     pub fn clamp(self, min: Color, max: Color) -> Self {
@@ -270,7 +393,7 @@ impl Color {
     /// [`Color::srgb_to_linear`] which performs the opposite operation.
     #[must_use]
     pub fn linear_to_srgb(self) -> Self {
-        self.as_inner().linear_to_srgb()
+        self.map_rgb(ok_color::linear_to_srgb)
     }
 
     /// Returns the color converted to the linear color space. This method assumes the original
@@ -278,7 +401,7 @@ impl Color {
     /// opposite operation.
     #[must_use]
     pub fn srgb_to_linear(self) -> Self {
-        self.as_inner().srgb_to_linear()
+        self.map_rgb(ok_color::srgb_to_linear)
     }
 
     /// Returns the HTML color code representation of this color, as 8 lowercase hex digits in the
@@ -310,6 +433,14 @@ impl Color {
         ]))
     }
 
+    fn map_rgb(self, f: impl Fn(f32) -> f32) -> Self {
+        Self::from_rgba(f(self.r), f(self.g), f(self.b), self.a)
+    }
+
+    fn clamp_unit(self) -> Self {
+        self.map_rgb(|c| c.clamp(0.0, 1.0))
+    }
+
     fn as_inner(&self) -> InnerColor {
         InnerColor::from_outer(self)
     }
@@ -473,6 +604,47 @@ impl ops::Neg for Color {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// HTML parsing
+
+/// Error when parsing an HTML color code, see [`Color::from_html()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct HtmlColorError {
+    html: String,
+    kind: HtmlErrorKind,
+}
+
+impl HtmlColorError {
+    /// The string that failed to parse.
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+}
+
+impl fmt::Display for HtmlColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid HTML color \"{}\": ", self.html)?;
+
+        match self.kind {
+            HtmlErrorKind::InvalidLength(len) => {
+                write!(f, "expected 3, 4, 6 or 8 hex digits, found {len}")
+            }
+            HtmlErrorKind::InvalidDigit(c) => write!(f, "'{c}' is not a hex digit"),
+        }
+    }
+}
+
+impl Error for HtmlColorError {}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum HtmlErrorKind {
+    InvalidLength(usize),
+    InvalidDigit(char),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Channel conversions
+
 /// Converts a single channel byte to a float in the range 0 to 1.
 fn from_u8(byte: u8) -> f32 {
     byte as f32 / 255.0
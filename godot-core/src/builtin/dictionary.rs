@@ -7,7 +7,7 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::meta::{ConvertError, FromGodot, ToGodot};
 use crate::builtin::{inner, Variant, VariantArray};
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use std::marker::PhantomData;
@@ -113,6 +113,21 @@ impl Dictionary {
         Some(self.get_or_nil(key))
     }
 
+    /// Returns the value for the given key, converted to `T`.
+    ///
+    /// Returns `Err` if the key is absent or the value cannot be converted. To tell these cases apart, use [`Self::get`] and
+    /// [`Variant::try_to`].
+    pub fn get_as<K: ToGodot, T: FromGodot>(&self, key: K) -> Result<T, ConvertError> {
+        let key = key.to_variant();
+        match self.get(key.clone()) {
+            Some(value) => value.try_to(),
+            None => Err(ConvertError::with_cause_value(
+                "key not found in dictionary",
+                key,
+            )),
+        }
+    }
+
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
     ///
     /// This method does not let you differentiate `NIL` values stored as values from absent keys.
@@ -178,6 +193,44 @@ impl Dictionary {
         self.as_inner().merge(other, overwrite)
     }
 
+    /// Like [`extend_dictionary()`](Self::extend_dictionary), but takes `other` by reference.
+    ///
+    /// To insert Rust key-value pairs instead, use [`Extend`].
+    pub fn merge(&mut self, other: &Self, overwrite: bool) {
+        self.extend_dictionary(other.clone(), overwrite)
+    }
+
+    /// Keeps only the entries for which `predicate` returns `true`, removing all others.
+    ///
+    /// The predicate is called once per entry, in insertion order.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&Variant, &Variant) -> bool,
+    {
+        // Collect first, as removing during iteration would skip entries.
+        let removed: Vec<Variant> = self
+            .iter_shared()
+            .filter(|(key, value)| !predicate(key, value))
+            .map(|(key, _value)| key)
+            .collect();
+
+        for key in removed {
+            self.remove(key);
+        }
+    }
+
+    /// Removes all entries and returns them as an iterator of key-value pairs, in insertion order.
+    ///
+    /// The dictionary is empty once this method returns, even if the iterator is not consumed.
+    pub fn drain(&mut self) -> Drain {
+        let entries: Vec<(Variant, Variant)> = self.iter_shared().collect();
+        self.clear();
+
+        Drain {
+            entries: entries.into_iter(),
+        }
+    }
+
     /// Returns the number of entries in the dictionary.
     ///
     /// This is equivalent to `size` in Godot.
@@ -210,6 +263,32 @@ impl Dictionary {
         }
     }
 
+    /// Gets the entry for the given key, for in-place insertion or modification.
+    ///
+    /// This mirrors [`HashMap::entry()`](std::collections::HashMap::entry). Since values are variants that may be shared with other
+    /// references to the same dictionary, entries hand out copies of the stored values rather than references.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut counts = Dictionary::new();
+    /// for word in ["apple", "pear", "apple"] {
+    ///     counts
+    ///         .entry(word)
+    ///         .and_modify(|count| *count = (count.to::<i64>() + 1).to_variant())
+    ///         .or_insert(1);
+    /// }
+    ///
+    /// assert_eq!(counts.get_as::<_, i64>("apple").unwrap(), 2);
+    /// ```
+    pub fn entry<K: ToGodot>(&mut self, key: K) -> Entry<'_> {
+        let key = key.to_variant();
+        if self.contains_key(key.clone()) {
+            Entry::Occupied(OccupiedEntry { dict: self, key })
+        } else {
+            Entry::Vacant(VacantEntry { dict: self, key })
+        }
+    }
+
     /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(Variant, Variant)`.
     /// Each pair references the original `Dictionary`, but instead of a `&`-reference to key-value pairs as
     /// you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
@@ -549,6 +628,29 @@ impl<'a> Iterator for Iter<'a> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// An iterator over the key-value pairs removed from a `Dictionary`.
+///
+/// See [Dictionary::drain()].
+pub struct Drain {
+    entries: std::vec::IntoIter<(Variant, Variant)>,
+}
+
+impl Iterator for Drain {
+    type Item = (Variant, Variant);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Drain {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// An iterator over keys from a `Dictionary`.
 ///
 /// See [Dictionary::keys_shared()] for more information about iteration over dictionaries.
@@ -658,6 +760,145 @@ impl<'a, K: FromGodot> Iterator for TypedKeys<'a, K> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// A view into a single entry of a `Dictionary`, which is either occupied or vacant.
+///
+/// Constructed by [`Dictionary::entry()`].
+pub enum Entry<'a> {
+    Occupied(OccupiedEntry<'a>),
+    Vacant(VacantEntry<'a>),
+}
+
+impl<'a> Entry<'a> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant. Returns the (possibly new) value.
+    pub fn or_insert<V: ToGodot>(self, default: V) -> Variant {
+        match self {
+            Entry::Occupied(entry) => entry.get(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default()` if the entry is vacant. Returns the (possibly new) value.
+    pub fn or_insert_with<V: ToGodot, F: FnOnce() -> V>(self, default: F) -> Variant {
+        match self {
+            Entry::Occupied(entry) => entry.get(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Modifies the value if the entry is occupied, and writes it back to the dictionary.
+    pub fn and_modify<F: FnOnce(&mut Variant)>(self, modify: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                let mut value = entry.get();
+                modify(&mut value);
+                entry.insert(value);
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// An entry of a `Dictionary` whose key is present. Part of [`Entry`].
+pub struct OccupiedEntry<'a> {
+    dict: &'a mut Dictionary,
+    key: Variant,
+}
+
+impl<'a> OccupiedEntry<'a> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        &self.key
+    }
+
+    /// Returns the value of this entry.
+    pub fn get(&self) -> Variant {
+        self.dict.get_or_nil(self.key.clone())
+    }
+
+    /// Returns the value of this entry, converted to `T`.
+    pub fn get_as<T: FromGodot>(&self) -> Result<T, ConvertError> {
+        self.get().try_to()
+    }
+
+    /// Replaces the value of this entry, returning the previous value.
+    pub fn insert<V: ToGodot>(&mut self, value: V) -> Variant {
+        let old_value = self.get();
+        self.dict.set(self.key.clone(), value);
+        old_value
+    }
+
+    /// Removes this entry from the dictionary, returning its value.
+    pub fn remove(self) -> Variant {
+        let value = self.get();
+        self.dict.as_inner().erase(self.key);
+        value
+    }
+}
+
+/// An entry of a `Dictionary` whose key is absent. Part of [`Entry`].
+pub struct VacantEntry<'a> {
+    dict: &'a mut Dictionary,
+    key: Variant,
+}
+
+impl<'a> VacantEntry<'a> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        &self.key
+    }
+
+    /// Returns the key of this entry, taking ownership.
+    pub fn into_key(self) -> Variant {
+        self.key
+    }
+
+    /// Inserts `value` for this entry's key. Returns the inserted value.
+    pub fn insert<V: ToGodot>(self, value: V) -> Variant {
+        let value = value.to_variant();
+        self.dict.set(self.key, value.clone());
+        value
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialized as a sequence of `(key, value)` pairs, since keys can be of any type and many formats only support string keys in maps.
+    impl Serialize for Dictionary {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.iter_shared())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Dictionary {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let entries = Vec::<(Variant, Variant)>::deserialize(deserializer)?;
+            Ok(entries.into_iter().collect())
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Constructs [`Dictionary`] literals, close to Godot's own syntax.
 ///
 /// Any value can be used as a key, but to use an expression you need to surround it
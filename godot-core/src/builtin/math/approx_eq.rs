@@ -21,8 +21,31 @@ pub trait ApproxEq: PartialEq {
 /// Asserts that two values are approximately equal
 ///
 /// For comparison, this uses `ApproxEq::approx_eq` by default, or the provided `fn = ...` function.
+///
+/// With `epsilon = ...`, each component may differ by at most the given absolute value, and a failure lists the differing
+/// components. This requires [`ApproxComponents`][crate::test_util::ApproxComponents]. See also [`test_util`][crate::test_util].
 #[macro_export]
 macro_rules! assert_eq_approx {
+    ($actual:expr, $expected:expr, epsilon = $eps:expr $(,)?) => {
+        match (&$actual, &$expected) {
+            (a, b) => {
+                let epsilon: f64 = $eps;
+                if let Some(diff) = $crate::test_util::component_diff(a, b, epsilon) {
+                    panic!("\n  left: {:?},\n right: {:?}\ncomponents differing by more than {}:\n{}", a, b, epsilon, diff)
+                }
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, epsilon = $eps:expr, $($t:tt)+) => {
+        match (&$actual, &$expected) {
+            (a, b) => {
+                let epsilon: f64 = $eps;
+                if let Some(diff) = $crate::test_util::component_diff(a, b, epsilon) {
+                    panic!("\n  left: {:?},\n right: {:?}\ncomponents differing by more than {}:\n{}\n{}", a, b, epsilon, diff, format_args!($($t)+))
+                }
+            }
+        }
+    };
     ($actual:expr, $expected:expr, fn = $func:expr $(,)?) => {
         match ($actual, $expected) {
             (a, b) => assert!(($func)(&a, &b), "\n  left: {:?},\n right: {:?}", $actual, $expected)
@@ -51,6 +74,23 @@ macro_rules! assert_eq_approx {
 /// `func` for equality checking.
 #[macro_export]
 macro_rules! assert_ne_approx {
+    ($actual:expr, $expected:expr, epsilon = $eps:expr $(,)?) => {
+        match (&$actual, &$expected) {
+            (a, b) => {
+                let epsilon: f64 = $eps;
+                assert!($crate::test_util::component_diff(a, b, epsilon).is_some(), "\n  left: {:?},\n right: {:?}\nall components within {}", a, b, epsilon)
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, epsilon = $eps:expr, $($t:tt)+) => {
+        match (&$actual, &$expected) {
+            (a, b) => {
+                let epsilon: f64 = $eps;
+                assert!($crate::test_util::component_diff(a, b, epsilon).is_some(), "\n  left: {:?},\n right: {:?}\nall components within {}\n{}", a, b, epsilon, format_args!($($t)+))
+            }
+        }
+    };
+
     ($actual:expr, $expected:expr, fn = $func:expr $(, $($t:tt)* )?) => {
         #[allow(clippy::redundant_closure_call)]
         {
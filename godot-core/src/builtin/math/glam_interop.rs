@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions between Godot's math types and [glam](https://docs.rs/glam), enabled by the `glam` feature.
+//!
+//! Floating-point types map to the glam type of the same precision as [`real`](crate::builtin::real): `f32` types like `Vec3` by default,
+//! `f64` types like `DVec3` with `double-precision`. Integer vectors map to `IVec2/3/4`.
+//!
+//! Both Godot and glam store matrices column by column, and both use right-handed coordinates, so values keep their meaning:
+//! - [`Basis`] ↔ `Mat3`: the basis columns `x`, `y`, `z` become `x_axis`, `y_axis`, `z_axis`.
+//! - [`Transform2D`] ↔ `Affine2` and [`Transform3D`] ↔ `Affine3A` (`DAffine3` with `double-precision`): basis and origin become
+//!   `matrix2`/`matrix3` and `translation`.
+//! - [`Projection`] ↔ `Mat4`: the columns are kept. Note that Godot projections follow OpenGL clip space with depth in `-1..=1`, so the
+//!   matching glam constructors are the `_gl` variants, e.g. `Mat4::perspective_rh_gl()`.
+//! - [`Quaternion`] ↔ `Quat`: components are kept; both are stored as `x, y, z, w`.
+
+use crate::builtin::math::{GlamConv, GlamType};
+use crate::builtin::*;
+
+macro_rules! impl_glam_from {
+    ($($Godot:ty => $Glam:ty),* $(,)?) => {
+        $(
+            impl From<$Godot> for $Glam {
+                fn from(value: $Godot) -> Self {
+                    GlamConv::to_glam(&value)
+                }
+            }
+
+            impl From<$Glam> for $Godot {
+                fn from(value: $Glam) -> Self {
+                    GlamType::to_front(&value)
+                }
+            }
+        )*
+    };
+}
+
+impl_glam_from!(
+    Vector2 => RVec2,
+    Vector3 => RVec3,
+    Vector4 => RVec4,
+    Vector2i => glam::IVec2,
+    Vector3i => glam::IVec3,
+    Vector4i => glam::IVec4,
+    Quaternion => RQuat,
+    Basis => RMat3,
+    Transform2D => RAffine2,
+    Transform3D => RAffine3,
+    Projection => RMat4,
+);
+
+/// Returns the 4x4 matrix of the affine transform, with `(0, 0, 0, 1)` as last row.
+impl From<Transform3D> for RMat4 {
+    fn from(value: Transform3D) -> Self {
+        RMat4::from(GlamConv::to_glam(&value))
+    }
+}
+
+/// Returns the 3x3 matrix of the affine transform, with `(0, 0, 1)` as last row.
+impl From<Transform2D> for RMat3 {
+    fn from(value: Transform2D) -> Self {
+        RMat3::from(GlamConv::to_glam(&value))
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Scalar helpers from `@GlobalScope`, implemented in Rust so they don't go through the engine.
+
+use crate::builtin::real;
+
+use super::{ApproxEq, FloatExt};
+
+/// Smooth Hermite interpolation of `x` between `from` and `to`, clamped to `0.0 ..= 1.0`.
+///
+/// If `from` and `to` are approximately equal, returns `0.0` for `x <= from` and `1.0` otherwise.
+///
+/// _Godot equivalent: @GlobalScope.smoothstep()_
+pub fn smoothstep(from: real, to: real, x: real) -> real {
+    if from.approx_eq(&to) {
+        return if x <= from { 0.0 } else { 1.0 };
+    }
+
+    let s = ((x - from) / (to - from)).clamp(0.0, 1.0);
+    s * s * (3.0 - 2.0 * s)
+}
+
+/// Eases `x` (clamped to `0.0 ..= 1.0`) along a curve.
+///
+/// `curve` values `< -1.0` ease in-out, `-1.0 .. 0.0` out-in, `0.0 .. 1.0` ease out, `1.0` is linear and `> 1.0` eases in.
+/// A `curve` of `0.0` always returns `0.0`.
+///
+/// _Godot equivalent: @GlobalScope.ease()_
+pub fn ease(x: real, curve: real) -> real {
+    let x = x.clamp(0.0, 1.0);
+
+    if curve > 0.0 {
+        if curve < 1.0 {
+            1.0 - (1.0 - x).powf(1.0 / curve)
+        } else {
+            x.powf(curve)
+        }
+    } else if curve < 0.0 {
+        if x < 0.5 {
+            (x * 2.0).powf(-curve) * 0.5
+        } else {
+            (1.0 - (1.0 - (x - 0.5) * 2.0).powf(-curve)) * 0.5 + 0.5
+        }
+    } else {
+        0.0
+    }
+}
+
+/// Bounces `value` back and forth between `0.0` and `length`.
+///
+/// Returns `0.0` if `length` is `0.0`.
+///
+/// _Godot equivalent: @GlobalScope.pingpong()_
+pub fn ping_pong(value: real, length: real) -> real {
+    if length == 0.0 {
+        return 0.0;
+    }
+
+    let t = (value - length) / (length * 2.0);
+    ((t - t.floor()) * length * 2.0 - length).abs()
+}
+
+/// Wraps `value` into the range `min .. max`.
+///
+/// Returns `min` if the range is approximately empty.
+///
+/// _Godot equivalent: @GlobalScope.wrapf()_
+pub fn wrapf(value: real, min: real, max: real) -> real {
+    let range = max - min;
+    if range.is_zero_approx() {
+        return min;
+    }
+
+    let result = value - range * ((value - min) / range).floor();
+    if result.approx_eq(&max) {
+        min
+    } else {
+        result
+    }
+}
+
+/// Wraps `value` into the range `min .. max`.
+///
+/// Returns `min` if the range is empty.
+///
+/// _Godot equivalent: @GlobalScope.wrapi()_
+pub const fn wrapi(value: i64, min: i64, max: i64) -> i64 {
+    // Computed in i128, as the range and offsets overflow i64 for extreme bounds. The result always lies between min and max.
+    let (value, min, max) = (value as i128, min as i128, max as i128);
+    let range = max - min;
+    if range == 0 {
+        return min as i64;
+    }
+
+    (min + ((value - min) % range + range) % range) as i64
+}
+
+/// Moves `from` toward `to` by at most `delta`, without overshooting.
+///
+/// A negative `delta` moves away from `to`.
+///
+/// _Godot equivalent: @GlobalScope.move_toward()_
+pub fn move_toward(from: real, to: real, delta: real) -> real {
+    if (to - from).abs() <= delta {
+        to
+    } else {
+        from + (to - from).sign() * delta
+    }
+}
+
+/// Returns where `value` lies between `from` and `to`, as a factor in which `0.0` is `from` and `1.0` is `to`.
+///
+/// Inverse of [`FloatExt::lerp()`]. Values outside the range extrapolate.
+///
+/// _Godot equivalent: @GlobalScope.inverse_lerp()_
+pub fn inverse_lerp(from: real, to: real, value: real) -> real {
+    (value - from) / (to - from)
+}
+
+/// Maps `value` from the range `in_start ..= in_end` to `out_start ..= out_end`, extrapolating outside.
+///
+/// _Godot equivalent: @GlobalScope.remap()_
+pub fn remap(value: real, in_start: real, in_end: real, out_start: real, out_end: real) -> real {
+    out_start.lerp(out_end, inverse_lerp(in_start, in_end, value))
+}
+
+/// Converts an angle from degrees to radians.
+///
+/// _Godot equivalent: @GlobalScope.deg_to_rad()_
+pub fn deg_to_rad(degrees: real) -> real {
+    degrees.to_radians()
+}
+
+/// Converts an angle from radians to degrees.
+///
+/// _Godot equivalent: @GlobalScope.rad_to_deg()_
+pub fn rad_to_deg(radians: real) -> real {
+    radians.to_degrees()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::assert_eq_approx;
+    use crate::builtin::real_consts;
+
+    use super::*;
+
+    #[test]
+    fn smoothstep_and_ease() {
+        assert_eq!(smoothstep(0.0, 2.0, -1.0), 0.0);
+        assert_eq_approx!(smoothstep(0.0, 2.0, 1.0), 0.5);
+        assert_eq!(smoothstep(0.0, 2.0, 3.0), 1.0);
+        assert_eq!(smoothstep(1.0, 1.0, 1.0), 0.0);
+        assert_eq!(smoothstep(1.0, 1.0, 2.0), 1.0);
+
+        assert_eq_approx!(ease(0.5, 1.0), 0.5);
+        assert_eq_approx!(ease(0.5, 2.0), 0.25);
+        assert_eq_approx!(ease(0.5, 0.5), 0.75);
+        assert_eq_approx!(ease(0.25, -2.0), 0.125);
+        assert_eq_approx!(ease(0.75, -2.0), 0.875);
+        assert_eq!(ease(2.0, 3.0), 1.0);
+        assert_eq!(ease(0.5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq_approx!(ping_pong(3.0, 2.0), 1.0);
+        assert_eq_approx!(ping_pong(5.0, 2.0), 1.0);
+        assert_eq_approx!(ping_pong(-1.0, 2.0), 1.0);
+        assert_eq!(ping_pong(1.0, 0.0), 0.0);
+
+        assert_eq_approx!(wrapf(5.5, 0.0, 5.0), 0.5);
+        assert_eq_approx!(wrapf(-0.5, 0.0, 5.0), 4.5);
+        assert_eq_approx!(wrapf(5.0, 0.0, 5.0), 0.0);
+        assert_eq!(wrapf(3.0, 1.0, 1.0), 1.0);
+
+        assert_eq!(wrapi(7, 0, 5), 2);
+        assert_eq!(wrapi(-1, 0, 5), 4);
+        assert_eq!(wrapi(-6, -5, 5), 4);
+        assert_eq!(wrapi(3, 2, 2), 2);
+
+        // Extreme ranges must not overflow.
+        assert_eq!(wrapi(i64::MAX, i64::MIN, i64::MAX), i64::MIN);
+        assert_eq!(wrapi(i64::MIN, i64::MIN, i64::MAX), i64::MIN);
+        assert_eq!(wrapi(-1, i64::MIN, i64::MAX), -1);
+        assert_eq!(wrapi(i64::MIN, 0, i64::MAX), i64::MAX - 1);
+        assert_eq!(wrapi(i64::MAX, i64::MIN, 0), -1);
+    }
+
+    #[test]
+    fn interpolation() {
+        assert_eq!(move_toward(0.0, 10.0, 3.0), 3.0);
+        assert_eq!(move_toward(10.0, 0.0, 3.0), 7.0);
+        assert_eq!(move_toward(9.0, 10.0, 3.0), 10.0);
+
+        assert_eq_approx!(inverse_lerp(10.0, 20.0, 15.0), 0.5);
+        assert_eq_approx!(remap(15.0, 10.0, 20.0, 0.0, 100.0), 50.0);
+        assert_eq_approx!(remap(25.0, 10.0, 20.0, 0.0, 100.0), 150.0);
+
+        assert_eq_approx!(deg_to_rad(180.0), real_consts::PI);
+        assert_eq_approx!(rad_to_deg(deg_to_rad(42.0)), 42.0);
+    }
+}
@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions between Godot's math types and [mint](https://docs.rs/mint), enabled by the `mint` feature.
+//!
+//! mint is an interchange format understood by many math crates (cgmath, nalgebra, ultraviolet, glam, ...). Each Godot type converts
+//! with `From`/`Into` to and from its mint counterpart, and implements [`mint::IntoMint`] naming that counterpart:
+//!
+//! | Godot type      | mint type                          |
+//! |-----------------|------------------------------------|
+//! | `Vector2/3/4`   | `Vector2/3/4<real>`                |
+//! | `Vector2i/3i/4i`| `Vector2/3/4<i32>`                 |
+//! | `Quaternion`    | `Quaternion<real>`                 |
+//! | `Basis`         | `ColumnMatrix3<real>`              |
+//! | `Transform2D`   | `ColumnMatrix2x3<real>`            |
+//! | `Transform3D`   | `ColumnMatrix3x4<real>`            |
+//! | `Projection`    | `ColumnMatrix4<real>`              |
+//!
+//! Matrices are exchanged column by column, with the translation as last column of the transforms. `Basis` additionally converts to and
+//! from `RowMatrix3<real>`.
+
+use crate::builtin::*;
+
+macro_rules! impl_mint_vector {
+    ($($Godot:ident => $Mint:ident<$T:ty> { $($field:ident),+ }),* $(,)?) => {
+        $(
+            impl From<$Godot> for mint::$Mint<$T> {
+                fn from(v: $Godot) -> Self {
+                    Self { $($field: v.$field),+ }
+                }
+            }
+
+            impl From<mint::$Mint<$T>> for $Godot {
+                fn from(v: mint::$Mint<$T>) -> Self {
+                    Self::new($(v.$field),+)
+                }
+            }
+
+            impl mint::IntoMint for $Godot {
+                type MintType = mint::$Mint<$T>;
+            }
+        )*
+    };
+}
+
+impl_mint_vector!(
+    Vector2 => Vector2<real> { x, y },
+    Vector3 => Vector3<real> { x, y, z },
+    Vector4 => Vector4<real> { x, y, z, w },
+    Vector2i => Vector2<i32> { x, y },
+    Vector3i => Vector3<i32> { x, y, z },
+    Vector4i => Vector4<i32> { x, y, z, w },
+);
+
+impl From<Quaternion> for mint::Quaternion<real> {
+    fn from(q: Quaternion) -> Self {
+        Self {
+            v: mint::Vector3 {
+                x: q.x,
+                y: q.y,
+                z: q.z,
+            },
+            s: q.w,
+        }
+    }
+}
+
+impl From<mint::Quaternion<real>> for Quaternion {
+    fn from(q: mint::Quaternion<real>) -> Self {
+        Self::new(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl mint::IntoMint for Quaternion {
+    type MintType = mint::Quaternion<real>;
+}
+
+impl From<Basis> for mint::ColumnMatrix3<real> {
+    fn from(basis: Basis) -> Self {
+        Self {
+            x: basis.col_a().into(),
+            y: basis.col_b().into(),
+            z: basis.col_c().into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3<real>> for Basis {
+    fn from(m: mint::ColumnMatrix3<real>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl From<Basis> for mint::RowMatrix3<real> {
+    fn from(basis: Basis) -> Self {
+        let [x, y, z] = basis.rows;
+
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+        }
+    }
+}
+
+impl From<mint::RowMatrix3<real>> for Basis {
+    fn from(m: mint::RowMatrix3<real>) -> Self {
+        Self::from_rows(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl mint::IntoMint for Basis {
+    type MintType = mint::ColumnMatrix3<real>;
+}
+
+impl From<Transform2D> for mint::ColumnMatrix2x3<real> {
+    fn from(transform: Transform2D) -> Self {
+        Self {
+            x: transform.a.into(),
+            y: transform.b.into(),
+            z: transform.origin.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix2x3<real>> for Transform2D {
+    fn from(m: mint::ColumnMatrix2x3<real>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl mint::IntoMint for Transform2D {
+    type MintType = mint::ColumnMatrix2x3<real>;
+}
+
+impl From<Transform3D> for mint::ColumnMatrix3x4<real> {
+    fn from(transform: Transform3D) -> Self {
+        let basis = mint::ColumnMatrix3::from(transform.basis);
+
+        Self {
+            x: basis.x,
+            y: basis.y,
+            z: basis.z,
+            w: transform.origin.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3x4<real>> for Transform3D {
+    fn from(m: mint::ColumnMatrix3x4<real>) -> Self {
+        let basis = Basis::from_cols(m.x.into(), m.y.into(), m.z.into());
+
+        Self::new(basis, m.w.into())
+    }
+}
+
+impl mint::IntoMint for Transform3D {
+    type MintType = mint::ColumnMatrix3x4<real>;
+}
+
+impl From<Projection> for mint::ColumnMatrix4<real> {
+    fn from(projection: Projection) -> Self {
+        let [x, y, z, w] = projection.cols;
+
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+            w: w.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<real>> for Projection {
+    fn from(m: mint::ColumnMatrix4<real>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+    }
+}
+
+impl mint::IntoMint for Projection {
+    type MintType = mint::ColumnMatrix4<real>;
+}
@@ -8,10 +8,32 @@
 mod approx_eq;
 mod float;
 mod glam_helpers;
+#[cfg(feature = "glam-interop")]
+mod glam_interop;
+mod global_scope;
+#[cfg(feature = "mint")]
+mod mint_interop;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+#[cfg(feature = "rand")]
+mod rand_interop;
 
 pub use crate::{assert_eq_approx, assert_ne_approx};
 pub use approx_eq::ApproxEq;
 pub use float::FloatExt;
+pub use global_scope::{
+    deg_to_rad, ease, inverse_lerp, move_toward, ping_pong, rad_to_deg, remap, smoothstep, wrapf,
+    wrapi,
+};
+#[cfg(feature = "rand")]
+pub use rand_interop::{
+    UniformVector2, UniformVector2i, UniformVector3, UniformVector3i, UniformVector4,
+    UniformVector4i, UnitVector,
+};
+
+/// The version of [glam](https://docs.rs/glam) that Godot's math types convert to and from. Requires the `glam` feature.
+#[cfg(feature = "glam-interop")]
+pub use glam;
 
 // Internal glam re-exports
 pub(crate) use glam_helpers::*;
@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions between Godot's math types and [nalgebra](https://nalgebra.org), enabled by the `nalgebra` feature.
+//!
+//! nalgebra matrices are column-major like Godot's, so columns are kept. Conversions that cannot represent all values are one-way:
+//! - `Transform3D` → `Isometry3` keeps only rotation and translation; scale and shear of the basis are dropped.
+//! - `Transform3D` → `Matrix4` produces the homogeneous matrix, with `(0, 0, 0, 1)` as last row.
+
+use nalgebra as na;
+
+use crate::builtin::*;
+
+macro_rules! impl_nalgebra_vector {
+    ($($Godot:ident => $Na:ident { $($field:ident),+ }),* $(,)?) => {
+        $(
+            impl From<$Godot> for na::$Na<real> {
+                fn from(v: $Godot) -> Self {
+                    Self::new($(v.$field),+)
+                }
+            }
+
+            impl From<na::$Na<real>> for $Godot {
+                fn from(v: na::$Na<real>) -> Self {
+                    Self::new($(v.$field),+)
+                }
+            }
+        )*
+    };
+}
+
+impl_nalgebra_vector!(
+    Vector2 => Vector2 { x, y },
+    Vector3 => Vector3 { x, y, z },
+    Vector4 => Vector4 { x, y, z, w },
+    Vector2 => Point2 { x, y },
+    Vector3 => Point3 { x, y, z },
+);
+
+impl From<Quaternion> for na::Quaternion<real> {
+    fn from(q: Quaternion) -> Self {
+        Self::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<na::Quaternion<real>> for Quaternion {
+    fn from(q: na::Quaternion<real>) -> Self {
+        Self::new(q.i, q.j, q.k, q.w)
+    }
+}
+
+/// Normalizes the quaternion.
+impl From<Quaternion> for na::UnitQuaternion<real> {
+    fn from(q: Quaternion) -> Self {
+        Self::new_normalize(q.into())
+    }
+}
+
+impl From<na::UnitQuaternion<real>> for Quaternion {
+    fn from(q: na::UnitQuaternion<real>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+impl From<Basis> for na::Matrix3<real> {
+    fn from(basis: Basis) -> Self {
+        Self::from_columns(&[
+            basis.col_a().into(),
+            basis.col_b().into(),
+            basis.col_c().into(),
+        ])
+    }
+}
+
+impl From<na::Matrix3<real>> for Basis {
+    fn from(m: na::Matrix3<real>) -> Self {
+        let col = |i: usize| Vector3::new(m[(0, i)], m[(1, i)], m[(2, i)]);
+
+        Self::from_cols(col(0), col(1), col(2))
+    }
+}
+
+/// Drops scale and shear of the basis, see [`Basis::to_quat()`].
+impl From<Transform3D> for na::Isometry3<real> {
+    fn from(transform: Transform3D) -> Self {
+        let translation =
+            na::Translation3::new(transform.origin.x, transform.origin.y, transform.origin.z);
+
+        Self::from_parts(translation, transform.basis.to_quat().into())
+    }
+}
+
+impl From<na::Isometry3<real>> for Transform3D {
+    fn from(isometry: na::Isometry3<real>) -> Self {
+        let basis = Basis::from_quat(isometry.rotation.into());
+        let origin = isometry.translation.vector.into();
+
+        Self::new(basis, origin)
+    }
+}
+
+impl From<Transform3D> for na::Matrix4<real> {
+    fn from(transform: Transform3D) -> Self {
+        let basis = na::Matrix3::from(transform.basis);
+        let origin = na::Vector3::from(transform.origin);
+
+        let mut m = basis.to_homogeneous();
+        m.fixed_view_mut::<3, 1>(0, 3).copy_from(&origin);
+        m
+    }
+}
+
+impl From<Projection> for na::Matrix4<real> {
+    fn from(projection: Projection) -> Self {
+        Self::from_columns(&projection.cols.map(na::Vector4::from))
+    }
+}
+
+impl From<na::Matrix4<real>> for Projection {
+    fn from(m: na::Matrix4<real>) -> Self {
+        let col = |i: usize| Vector4::new(m[(0, i)], m[(1, i)], m[(2, i)], m[(3, i)]);
+
+        Self::from_cols(col(0), col(1), col(2), col(3))
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Random sampling of Godot's math types with [rand](https://docs.rs/rand), enabled by the `rand` feature.
+//!
+//! | Distribution                | Samples                                                                            |
+//! |-----------------------------|------------------------------------------------------------------------------------|
+//! | [`Standard`]                | `Vector2/3/4` in `[0, 1)` per component, `Vector2i/3i/4i` over all `i32`, opaque `Color`s |
+//! | [`Uniform`] / `gen_range()` | vectors between two corners, component by component                                |
+//! | [`UnitVector`]              | `Vector2`/`Vector3` of length 1, uniformly distributed over all directions         |
+//! | `Rect2`, `Aabb`             | points uniformly distributed inside the rectangle or box                           |
+//!
+//! `gen_range()` requires `PartialOrd`, which only the integer vectors implement; for float vectors, use [`Uniform::new()`] directly.
+//!
+//! [`Uniform`]: rand::distributions::Uniform
+//! [`Uniform::new()`]: rand::distributions::Uniform::new
+
+use rand::distributions::uniform::{
+    SampleBorrow, SampleUniform, UniformFloat, UniformInt, UniformSampler,
+};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::builtin::real_consts::TAU;
+use crate::builtin::*;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Standard and Uniform for vectors
+
+macro_rules! impl_rand_vector {
+    ($($Vector:ident => $Sampler:ident<$Uniform:ident<$T:ty>> { $($field:ident),+ }),* $(,)?) => {
+        $(
+            impl Distribution<$Vector> for Standard {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $Vector {
+                    $Vector { $($field: rng.gen()),+ }
+                }
+            }
+
+            #[doc = concat!("Uniform sampler for [`", stringify!($Vector), "`], sampling each component independently.")]
+            #[derive(Clone, Copy, Debug)]
+            pub struct $Sampler {
+                $($field: $Uniform<$T>),+
+            }
+
+            impl UniformSampler for $Sampler {
+                type X = $Vector;
+
+                fn new<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<$Vector> + Sized,
+                    B2: SampleBorrow<$Vector> + Sized,
+                {
+                    let (low, high) = (low.borrow(), high.borrow());
+                    Self { $($field: $Uniform::new(low.$field, high.$field)),+ }
+                }
+
+                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: SampleBorrow<$Vector> + Sized,
+                    B2: SampleBorrow<$Vector> + Sized,
+                {
+                    let (low, high) = (low.borrow(), high.borrow());
+                    Self { $($field: $Uniform::new_inclusive(low.$field, high.$field)),+ }
+                }
+
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $Vector {
+                    $Vector { $($field: self.$field.sample(rng)),+ }
+                }
+            }
+
+            impl SampleUniform for $Vector {
+                type Sampler = $Sampler;
+            }
+        )*
+    };
+}
+
+impl_rand_vector!(
+    Vector2 => UniformVector2<UniformFloat<real>> { x, y },
+    Vector3 => UniformVector3<UniformFloat<real>> { x, y, z },
+    Vector4 => UniformVector4<UniformFloat<real>> { x, y, z, w },
+    Vector2i => UniformVector2i<UniformInt<i32>> { x, y },
+    Vector3i => UniformVector3i<UniformInt<i32>> { x, y, z },
+    Vector4i => UniformVector4i<UniformInt<i32>> { x, y, z, w },
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Colors, directions and shapes
+
+/// Opaque colors with red, green and blue in `[0, 1)`.
+impl Distribution<Color> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        Color::from_rgb(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Distribution of unit vectors, uniform over the circle (`Vector2`) or sphere (`Vector3`).
+///
+/// ```no_run
+/// use godot::builtin::math::UnitVector;
+/// use godot::builtin::Vector3;
+/// use rand::Rng;
+///
+/// fn random_direction(rng: &mut impl Rng) -> Vector3 {
+///     rng.sample(UnitVector)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitVector;
+
+impl Distribution<Vector2> for UnitVector {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        Vector2::from_angle(rng.gen_range(0.0..TAU))
+    }
+}
+
+impl Distribution<Vector3> for UnitVector {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        // Archimedes: z is uniform on a sphere's axis, so picking z and the angle around the axis covers the surface uniformly.
+        let z: real = rng.gen_range(-1.0..=1.0);
+        let (sin, cos) = rng.gen_range(0.0..TAU).sin_cos();
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+
+        Vector3::new(radius * cos, radius * sin, z)
+    }
+}
+
+/// Points inside the rectangle, including its top-left edges.
+impl Distribution<Vector2> for Rect2 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        self.position + self.size * rng.gen::<Vector2>()
+    }
+}
+
+/// Points inside the box, including its faces towards `position`.
+impl Distribution<Vector3> for Aabb {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        self.position + self.size * rng.gen::<Vector3>()
+    }
+}
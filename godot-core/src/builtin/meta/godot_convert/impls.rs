@@ -8,8 +8,10 @@
 use crate::builtin::meta::{
     impl_godot_as_self, ConvertError, FromGodot, GodotConvert, GodotType, ToGodot,
 };
-use crate::builtin::Variant;
+use crate::builtin::{Dictionary, Variant, VariantArray};
 use godot_ffi as sys;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
 
 // The following ToGodot/FromGodot/Convert impls are auto-generated for each engine type, co-located with their definitions:
 // - enum
@@ -43,6 +45,13 @@ where
             None => Variant::nil(),
         }
     }
+
+    fn validate_engine_arg(&self) -> Result<(), String> {
+        match self {
+            Some(inner) => inner.validate_engine_arg(),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<T: FromGodot> FromGodot for Option<T>
@@ -84,6 +93,125 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Std collections
+//
+// Maps are converted to `Dictionary`, sequences and sets to an untyped `Array`. Elements are converted individually via `Variant`,
+// so conversion back fails if any key or element does not match.
+
+impl<K: GodotConvert, V: GodotConvert, S> GodotConvert for HashMap<K, V, S> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot, S> ToGodot for HashMap<K, V, S> {
+    fn to_godot(&self) -> Self::Via {
+        map_to_dictionary(self.iter())
+    }
+}
+
+impl<K, V, S> FromGodot for HashMap<K, V, S>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        dictionary_to_map(&via)
+    }
+}
+
+impl<K: GodotConvert, V: GodotConvert> GodotConvert for BTreeMap<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot> ToGodot for BTreeMap<K, V> {
+    fn to_godot(&self) -> Self::Via {
+        map_to_dictionary(self.iter())
+    }
+}
+
+impl<K: FromGodot + Ord, V: FromGodot> FromGodot for BTreeMap<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        dictionary_to_map(&via)
+    }
+}
+
+impl<T: GodotConvert, S> GodotConvert for HashSet<T, S> {
+    type Via = VariantArray;
+}
+
+impl<T: ToGodot, S> ToGodot for HashSet<T, S> {
+    fn to_godot(&self) -> Self::Via {
+        iter_to_array(self.iter())
+    }
+}
+
+impl<T, S> FromGodot for HashSet<T, S>
+where
+    T: FromGodot + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        array_to_collection(&via)
+    }
+}
+
+impl<T: GodotConvert> GodotConvert for VecDeque<T> {
+    type Via = VariantArray;
+}
+
+impl<T: ToGodot> ToGodot for VecDeque<T> {
+    fn to_godot(&self) -> Self::Via {
+        iter_to_array(self.iter())
+    }
+}
+
+impl<T: FromGodot> FromGodot for VecDeque<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        array_to_collection(&via)
+    }
+}
+
+fn map_to_dictionary<'a, K, V>(entries: impl Iterator<Item = (&'a K, &'a V)>) -> Dictionary
+where
+    K: ToGodot + 'a,
+    V: ToGodot + 'a,
+{
+    let mut dict = Dictionary::new();
+    for (key, value) in entries {
+        dict.set(key.to_variant(), value.to_variant());
+    }
+    dict
+}
+
+fn dictionary_to_map<K, V, C>(dict: &Dictionary) -> Result<C, ConvertError>
+where
+    K: FromGodot,
+    V: FromGodot,
+    C: FromIterator<(K, V)>,
+{
+    dict.iter_shared()
+        .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+        .collect()
+}
+
+fn iter_to_array<'a, T: ToGodot + 'a>(elements: impl Iterator<Item = &'a T>) -> VariantArray {
+    let mut array = VariantArray::new();
+    for element in elements {
+        array.push(element.to_variant());
+    }
+    array
+}
+
+fn array_to_collection<T: FromGodot, C: FromIterator<T>>(
+    array: &VariantArray,
+) -> Result<C, ConvertError> {
+    array
+        .iter_shared()
+        .map(|element| T::try_from_variant(&element))
+        .collect()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Builtin Godot types
 
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Deref, DerefMut};
+
+use crate::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+use crate::builtin::Variant;
+
+/// Wrapper that converts from Godot using GDScript's implicit conversions.
+///
+/// By default, [`FromGodot`] requires a `Variant` to hold exactly the expected type: an `int` cannot be passed to a `#[func]` parameter
+/// of type `f64`. Wrapping the parameter (or a field of a derived type) in `Lenient<T>` relaxes this, using
+/// [`FromGodot::try_from_variant_lenient()`]. Towards Godot, the inner value is passed unchanged.
+///
+/// Since any variant may be accepted, `Lenient<T>` appears as untyped (`Variant`) in Godot's type system.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::builtin::meta::Lenient;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Health {
+///     value: f64,
+/// }
+///
+/// #[godot_api]
+/// impl Health {
+///     // Accepts both `heal(5)` and `heal(5.0)` from GDScript.
+///     #[func]
+///     fn heal(&mut self, amount: Lenient<f64>) {
+///         self.value += *amount;
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Debug)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Lenient<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Lenient<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: GodotConvert> GodotConvert for Lenient<T> {
+    type Via = Variant;
+}
+
+impl<T: ToGodot> ToGodot for Lenient<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.0.to_variant()
+    }
+}
+
+impl<T: FromGodot> FromGodot for Lenient<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        T::try_from_variant_lenient(&via).map(Lenient)
+    }
+}
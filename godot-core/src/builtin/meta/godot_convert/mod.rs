@@ -7,10 +7,15 @@
 
 pub(crate) mod convert_error;
 mod impls;
+mod lenient;
+mod time;
 
 pub use convert_error::ConvertError;
+pub use lenient::Lenient;
+pub use time::Millis;
 
 use crate::builtin::Variant;
+use godot_ffi::GodotFfi;
 
 use super::{GodotFfiVariant, GodotType};
 
@@ -48,6 +53,14 @@ pub trait ToGodot: Sized + GodotConvert {
     fn to_variant(&self) -> Variant {
         self.to_godot().to_ffi().ffi_to_variant()
     }
+
+    /// Checks whether the value may be passed to an engine method, with the `debug-validation` feature in debug builds.
+    ///
+    /// Returns a description of the problem otherwise.
+    #[doc(hidden)]
+    fn validate_engine_arg(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// Defines the canonical conversion from Godot for a type.
@@ -86,6 +99,28 @@ pub trait FromGodot: Sized + GodotConvert {
         Self::try_from_variant(variant)
             .unwrap_or_else(|err| panic!("FromGodot::from_variant() failed: {err}"))
     }
+
+    /// Performs the conversion from a [`Variant`], accepting GDScript's implicit conversions.
+    ///
+    /// [`try_from_variant()`](Self::try_from_variant) requires the variant to hold exactly the type that `Self` is represented as.
+    /// This method additionally accepts variants that Godot converts implicitly, for example when passing arguments to typed
+    /// GDScript functions: `int` to `float` and back, `bool` to `int`, `String` to `StringName` or `NodePath`, etc.
+    /// `null` is accepted for `Option<T>` in both modes.
+    ///
+    /// To opt into this behavior for a whole type, parameter or field, see [`Lenient<T>`] and the `#[godot(lenient)]` attribute
+    /// of `#[derive(FromGodot)]`.
+    fn try_from_variant_lenient(variant: &Variant) -> Result<Self, ConvertError> {
+        let strict_err = match Self::try_from_variant(variant) {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+
+        let target = <<Self::Via as GodotType>::Ffi as GodotFfi>::variant_type();
+        match variant.try_convert_implicit(target) {
+            Some(converted) => Self::try_from_variant(&converted),
+            None => Err(strict_err),
+        }
+    }
 }
 
 pub(crate) fn into_ffi<T: ToGodot>(value: T) -> <T::Via as GodotType>::Ffi {
@@ -100,7 +135,17 @@ pub(crate) fn try_from_ffi<T: FromGodot>(
 }
 
 macro_rules! impl_godot_as_self {
-    ($T:ty) => {
+    // Types with floating-point components that must not be NaN or infinite when passed to the engine.
+    ($T:ty; check_finite) => {
+        $crate::builtin::meta::impl_godot_as_self!($T; fn validate_engine_arg(&self) -> Result<(), String> {
+            if self.is_finite() {
+                Ok(())
+            } else {
+                Err(format!("{self:?} contains NaN or infinite components"))
+            }
+        });
+    };
+    ($T:ty $(; $validate:item)?) => {
         impl $crate::builtin::meta::GodotConvert for $T {
             type Via = $T;
         }
@@ -115,6 +160,8 @@ macro_rules! impl_godot_as_self {
             fn into_godot(self) -> Self::Via {
                 self
             }
+
+            $($validate)?
         }
 
         impl $crate::builtin::meta::FromGodot for $T {
@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::time::{Duration, SystemTime};
+
+use crate::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+
+/// Durations are represented as `float` seconds, like `delta` in `_process()` or `Timer.wait_time`.
+///
+/// Negative, infinite or NaN values cannot be converted back. For integer milliseconds, use [`Millis`].
+impl GodotConvert for Duration {
+    type Via = f64;
+}
+
+impl ToGodot for Duration {
+    fn to_godot(&self) -> Self::Via {
+        self.as_secs_f64()
+    }
+}
+
+impl FromGodot for Duration {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Duration::try_from_secs_f64(via).map_err(|err| ConvertError::with_cause_value(err, via))
+    }
+}
+
+/// System time is represented as `float` seconds since the Unix epoch, like `Time.get_unix_time_from_system()`.
+///
+/// Times before the epoch are negative.
+impl GodotConvert for SystemTime {
+    type Via = f64;
+}
+
+impl ToGodot for SystemTime {
+    fn to_godot(&self) -> Self::Via {
+        match self.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(after) => after.as_secs_f64(),
+            Err(before) => -before.duration().as_secs_f64(),
+        }
+    }
+}
+
+impl FromGodot for SystemTime {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let offset = Duration::try_from_secs_f64(via.abs())
+            .map_err(|err| ConvertError::with_cause_value(err, via))?;
+
+        let time = if via >= 0.0 {
+            SystemTime::UNIX_EPOCH.checked_add(offset)
+        } else {
+            SystemTime::UNIX_EPOCH.checked_sub(offset)
+        };
+
+        time.ok_or_else(|| ConvertError::with_cause_value("time out of range", via))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Duration that converts to and from `int` milliseconds.
+///
+/// Godot APIs such as `Time.get_ticks_msec()` or `OS.delay_msec()` use integer milliseconds instead of float seconds, which is how
+/// [`Duration`] itself is converted. Sub-millisecond precision is truncated; durations exceeding `i64::MAX` milliseconds saturate.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::builtin::meta::Millis;
+/// use std::time::Duration;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Cooldown {
+///     remaining: Duration,
+/// }
+///
+/// #[godot_api]
+/// impl Cooldown {
+///     #[func]
+///     fn remaining_msec(&self) -> Millis {
+///         Millis(self.remaining)
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Millis(pub Duration);
+
+impl GodotConvert for Millis {
+    type Via = i64;
+}
+
+impl ToGodot for Millis {
+    fn to_godot(&self) -> Self::Via {
+        i64::try_from(self.0.as_millis()).unwrap_or(i64::MAX)
+    }
+}
+
+impl FromGodot for Millis {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        u64::try_from(via)
+            .map(|msec| Self(Duration::from_millis(msec)))
+            .map_err(|_| ConvertError::with_cause_value("negative duration", via))
+    }
+}
+
+impl From<Duration> for Millis {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<Millis> for Duration {
+    fn from(millis: Millis) -> Self {
+        millis.0
+    }
+}
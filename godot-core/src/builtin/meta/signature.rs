@@ -16,6 +16,7 @@ use sys::{BuiltinMethodBind, ClassMethodBind, UtilityFunctionBind};
 
 use crate::builtin::meta::*;
 use crate::builtin::Variant;
+use crate::engine::dynamic_call::{CallError, CallErrorKind};
 use crate::obj::InstanceId;
 
 #[doc(hidden)]
@@ -47,6 +48,16 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         varargs: &[Variant],
     ) -> Self::Ret;
 
+    /// Like [`out_class_varcall()`][Self::out_class_varcall], but returns an error instead of panicking.
+    unsafe fn try_out_class_varcall(
+        method_bind: ClassMethodBind,
+        method_name: &'static str,
+        object_ptr: sys::GDExtensionObjectPtr,
+        maybe_instance_id: Option<InstanceId>, // if not static
+        args: Self::Params,
+        varargs: &[Variant],
+    ) -> Result<Self::Ret, CallError>;
+
     unsafe fn out_utility_ptrcall_varargs(
         utility_fn: UtilityFunctionBind,
         method_name: &'static str,
@@ -81,6 +92,15 @@ pub trait PtrcallSignatureTuple {
         args: Self::Params,
     ) -> Self::Ret;
 
+    /// Like [`out_class_ptrcall()`][Self::out_class_ptrcall], but returns an error instead of panicking.
+    unsafe fn try_out_class_ptrcall<Rr: PtrcallReturn<Ret = Self::Ret>>(
+        method_bind: ClassMethodBind,
+        method_name: &'static str,
+        object_ptr: sys::GDExtensionObjectPtr,
+        maybe_instance_id: Option<InstanceId>, // if not static
+        args: Self::Params,
+    ) -> Result<Self::Ret, CallError>;
+
     unsafe fn out_builtin_ptrcall<Rr: PtrcallReturn<Ret = Self::Ret>>(
         builtin_fn: BuiltinMethodBind,
         method_name: &'static str,
@@ -172,6 +192,7 @@ macro_rules! impl_varcall_signature_for_tuple {
             }
 
             #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
                 method_name: &'static str,
@@ -181,10 +202,28 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Self::Ret {
                 //$crate::out!("out_class_varcall: {method_name}");
+                let args = ($($pn,)*);
+                let result = Self::try_out_class_varcall(method_bind, method_name, object_ptr, maybe_instance_id, args, varargs);
+                result.unwrap_or_else(|err| panic!("{err}"))
+            }
+
+            #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
+            unsafe fn try_out_class_varcall(
+                method_bind: ClassMethodBind,
+                method_name: &'static str,
+                object_ptr: sys::GDExtensionObjectPtr,
+                maybe_instance_id: Option<InstanceId>, // if not static
+                ($($pn,)*): Self::Params,
+                varargs: &[Variant],
+            ) -> Result<Self::Ret, CallError> {
+                check_method_loaded(method_bind, method_name)?;
+                validate_receiver(object_ptr, maybe_instance_id, method_name);
+                $( validate_arg(&$pn, method_name, $n); )*
 
                 // Note: varcalls are not safe from failing, if they happen through an object pointer -> validity check necessary.
                 if let Some(instance_id) = maybe_instance_id {
-                    crate::engine::ensure_object_alive(instance_id, object_ptr, method_name);
+                    check_object_alive(instance_id, object_ptr, method_name)?;
                 }
 
                 let class_fn = sys::interface_fn!(object_method_bind_call);
@@ -199,8 +238,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 variant_ptrs.extend(explicit_args.iter().map(Variant::var_sys_const));
                 variant_ptrs.extend(varargs.iter().map(Variant::var_sys_const));
 
+                let mut err = sys::default_call_error();
                 let variant = Variant::from_var_sys_init(|return_ptr| {
-                    let mut err = sys::default_call_error();
                     class_fn(
                         method_bind.0,
                         object_ptr,
@@ -209,16 +248,17 @@ macro_rules! impl_varcall_signature_for_tuple {
                         return_ptr,
                         std::ptr::addr_of_mut!(err),
                     );
-
-                    check_varcall_error(&err, method_name, &explicit_args, varargs);
                 });
 
+                check_varcall_error(&err, method_name, &explicit_args, varargs)?;
+
                 let result = <Self::Ret as FromGodot>::try_from_variant(&variant);
-                result.unwrap_or_else(|err| return_error::<Self::Ret>(method_name, err))
+                result.map_err(|err| bad_return_error(method_name, err))
             }
 
             // Note: this is doing a ptrcall, but uses variant conversions for it
             #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
             unsafe fn out_utility_ptrcall_varargs(
                 utility_fn: UtilityFunctionBind,
                 method_name: &str,
@@ -226,6 +266,7 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Self::Ret {
                 //$crate::out!("out_utility_ptrcall_varargs: {method_name}");
+                $( validate_arg(&$pn, method_name, $n); )*
                 let explicit_args: [Variant; $PARAM_COUNT] = [
                     $(
                         GodotFfiVariant::ffi_to_variant(&into_ffi($pn)),
@@ -239,6 +280,7 @@ macro_rules! impl_varcall_signature_for_tuple {
                 // Important: this calls from_sys_init_default().
                 let result = PtrcallReturnT::<$R>::call(|return_ptr| {
                     utility_fn(return_ptr, type_ptrs.as_ptr(), type_ptrs.len() as i32);
+                    sys::check_available_call("utility function", method_name);
                 });
                 result.unwrap_or_else(|err| return_error::<Self::Ret>(method_name, err))
             }
@@ -290,6 +332,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
             }
 
             #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
             unsafe fn out_class_ptrcall<Rr: PtrcallReturn<Ret = Self::Ret>>(
                 method_bind: ClassMethodBind,
                 method_name: &'static str,
@@ -298,8 +341,26 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 // $crate::out!("out_class_ptrcall: {method_name}");
+                let args = ($($pn,)*);
+                let result = Self::try_out_class_ptrcall::<Rr>(method_bind, method_name, object_ptr, maybe_instance_id, args);
+                result.unwrap_or_else(|err| panic!("{err}"))
+            }
+
+            #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
+            unsafe fn try_out_class_ptrcall<Rr: PtrcallReturn<Ret = Self::Ret>>(
+                method_bind: ClassMethodBind,
+                method_name: &'static str,
+                object_ptr: sys::GDExtensionObjectPtr,
+                maybe_instance_id: Option<InstanceId>, // if not static
+                ($($pn,)*): Self::Params,
+            ) -> Result<Self::Ret, CallError> {
+                check_method_loaded(method_bind, method_name)?;
+                validate_receiver(object_ptr, maybe_instance_id, method_name);
+                $( validate_arg(&$pn, method_name, $n); )*
+
                 if let Some(instance_id) = maybe_instance_id {
-                    crate::engine::ensure_object_alive(instance_id, object_ptr, method_name);
+                    check_object_alive(instance_id, object_ptr, method_name)?;
                 }
 
                 let class_fn = sys::interface_fn!(object_method_bind_ptrcall);
@@ -320,10 +381,11 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 let result = Rr::call(|return_ptr| {
                     class_fn(method_bind.0, object_ptr, type_ptrs.as_ptr(), return_ptr);
                 });
-                result.unwrap_or_else(|err| return_error::<Self::Ret>(method_name, err))
+                result.map_err(|err| bad_return_error(method_name, err))
             }
 
             #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
             unsafe fn out_builtin_ptrcall<Rr: PtrcallReturn<Ret = Self::Ret>>(
                 builtin_fn: BuiltinMethodBind,
                 method_name: &'static str,
@@ -331,6 +393,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 // $crate::out!("out_builtin_ptrcall: {method_name}");
+                $( validate_arg(&$pn, method_name, $n); )*
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
@@ -346,17 +409,20 @@ macro_rules! impl_ptrcall_signature_for_tuple {
 
                 let result = Rr::call(|return_ptr| {
                     builtin_fn(type_ptr, type_ptrs.as_ptr(), return_ptr, type_ptrs.len() as i32);
+                    sys::check_available_call("builtin method", method_name);
                 });
                 result.unwrap_or_else(|err| return_error::<Self::Ret>(method_name, err))
             }
 
             #[inline]
+            #[cfg_attr(all(feature = "debug-validation", debug_assertions), track_caller)]
             unsafe fn out_utility_ptrcall(
                 utility_fn: UtilityFunctionBind,
                 method_name: &'static str,
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 // $crate::out!("out_utility_ptrcall: {method_name}");
+                $( validate_arg(&$pn, method_name, $n); )*
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
@@ -372,6 +438,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
 
                 let result = PtrcallReturnT::<$R>::call(|return_ptr| {
                     utility_fn(return_ptr, arg_ptrs.as_ptr(), arg_ptrs.len() as i32);
+                    sys::check_available_call("utility function", method_name);
                 });
                 result.unwrap_or_else(|err| return_error::<Self::Ret>(method_name, err))
             }
@@ -471,16 +538,48 @@ fn return_error<R>(method_name: &str, err: ConvertError) -> ! {
     panic!("in method `{method_name}` at return type {return_ty}: {err}",);
 }
 
+fn bad_return_error(method_name: &str, err: ConvertError) -> CallError {
+    CallError::from_engine_method(CallErrorKind::BadReturn(err), method_name)
+}
+
+/// Methods of classes missing from the engine build are not loaded, see [`OptionalApi`](crate::engine::OptionalApi).
+fn check_method_loaded(method_bind: ClassMethodBind, method_name: &str) -> Result<(), CallError> {
+    if method_bind.is_loaded() {
+        Ok(())
+    } else {
+        Err(CallError::from_engine_method(
+            CallErrorKind::MethodNotLoaded,
+            method_name,
+        ))
+    }
+}
+
+fn check_object_alive(
+    instance_id: InstanceId,
+    object_ptr: sys::GDExtensionObjectPtr,
+    method_name: &'static str,
+) -> Result<(), CallError> {
+    if crate::engine::check_object_alive(instance_id, object_ptr, method_name) {
+        Ok(())
+    } else {
+        Err(CallError::from_engine_method(
+            CallErrorKind::ObjectFreed,
+            method_name,
+        ))
+    }
+}
+
 fn check_varcall_error<T>(
     err: &sys::GDExtensionCallError,
     fn_name: &str,
     explicit_args: &[T],
     varargs: &[Variant],
-) where
+) -> Result<(), CallError>
+where
     T: Debug + ToGodot,
 {
     if err.error == sys::GDEXTENSION_CALL_OK {
-        return;
+        return Ok(());
     }
 
     // TODO(optimize): split into non-generic, expensive parts after error check
@@ -492,9 +591,48 @@ fn check_varcall_error<T>(
     let explicit_args_str = join_to_string(explicit_args);
     let vararg_str = join_to_string(varargs);
 
-    let func_str = format!("{fn_name}({explicit_args_str}; varargs {vararg_str})");
+    let kind = CallErrorKind::Failed {
+        args: format!("{explicit_args_str}; varargs {vararg_str}"),
+        reason: sys::call_error_reason(err, &arg_types),
+    };
+
+    Err(CallError::from_engine_method(kind, fn_name))
+}
+
+/// Panics if `arg` must not be passed to the engine, reporting the Rust call site. Only with `debug-validation` in debug builds.
+#[cfg(all(feature = "debug-validation", debug_assertions))]
+#[track_caller]
+fn validate_arg<P: ToGodot>(arg: &P, method_name: &str, index: usize) {
+    if let Err(reason) = arg.validate_engine_arg() {
+        let param_ty = std::any::type_name::<P>();
+        panic!("invalid argument for engine method `{method_name}` at parameter [{index}] of type {param_ty}: {reason}");
+    }
+}
+
+#[cfg(not(all(feature = "debug-validation", debug_assertions)))]
+#[inline(always)]
+fn validate_arg<P: ToGodot>(_arg: &P, _method_name: &str, _index: usize) {}
 
-    sys::panic_call_error(err, &func_str, &arg_types);
+/// Panics if a non-static method is called on a null object. Only with `debug-validation` in debug builds.
+#[cfg(all(feature = "debug-validation", debug_assertions))]
+#[track_caller]
+fn validate_receiver(
+    object_ptr: sys::GDExtensionObjectPtr,
+    maybe_instance_id: Option<InstanceId>,
+    method_name: &str,
+) {
+    if maybe_instance_id.is_some() && object_ptr.is_null() {
+        panic!("engine method `{method_name}` called on null object pointer");
+    }
+}
+
+#[cfg(not(all(feature = "debug-validation", debug_assertions)))]
+#[inline(always)]
+fn validate_receiver(
+    _object_ptr: sys::GDExtensionObjectPtr,
+    _maybe_instance_id: Option<InstanceId>,
+    _method_name: &str,
+) {
 }
 
 fn join_to_string<T: Debug>(list: &[T]) -> String {
@@ -34,7 +34,7 @@
 //!   overloading would become impossible](https://github.com/kvark/mint/issues/75).
 
 // Re-export macros.
-pub use crate::{array, dict, real, reals, varray};
+pub use crate::{array, dict, gformat, npath, real, reals, sname, varray};
 
 #[doc(hidden)]
 pub mod __prelude_reexport {
@@ -58,14 +58,18 @@ pub mod __prelude_reexport {
     pub use string::{GString, NodePath, StringName};
     pub use transform2d::*;
     pub use transform3d::*;
+    pub use typed_dict::TypedDict;
     pub use variant::*;
     pub use vectors::*;
 
-    pub use crate::{array, dict, real, reals, varray};
+    pub use crate::{array, dict, gformat, npath, real, reals, sname, varray};
 }
 
 pub use __prelude_reexport::*;
 
+#[cfg(all(feature = "futures-core", since_api = "4.2"))]
+pub use signal_stream::{SignalArgs, SignalStream};
+
 /// Meta-information about variant types, properties and class names.
 pub mod meta;
 
@@ -74,17 +78,27 @@ pub mod math;
 
 /// Specialized types related to arrays.
 pub mod array {
-    pub use super::array_inner::Iter;
+    pub use super::array_inner::{Drain, Iter};
 }
 
 /// Specialized types related to dictionaries.
 pub mod dictionary {
-    pub use super::dictionary_inner::{Iter, Keys, TypedIter, TypedKeys};
+    pub use super::dictionary_inner::{
+        Drain, Entry, Iter, Keys, OccupiedEntry, TypedIter, TypedKeys, VacantEntry,
+    };
+}
+
+/// Adapters between byte arrays and [`std::io`].
+pub mod io {
+    pub use super::packed_byte_io::{PackedByteArrayReader, PackedByteArrayWriter};
 }
 
 /// Specialized types related to Godot's various string implementations.
 pub mod strings {
-    pub use super::string::TransientStringNameOrd;
+    pub use super::string::{LazyStringName, NodePathError, TransientStringNameOrd};
+
+    #[doc(hidden)]
+    pub use super::string::__node_path_syntax_error;
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -98,7 +112,11 @@ mod aabb;
 mod basis;
 mod callable;
 mod color;
+mod ok_color;
 mod packed_array;
+mod packed_byte_io;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod plane;
 mod projection;
 mod quaternion;
@@ -106,9 +124,12 @@ mod rect2;
 mod rect2i;
 mod rid;
 mod signal;
+#[cfg(all(feature = "futures-core", since_api = "4.2"))]
+mod signal_stream;
 mod string;
 mod transform2d;
 mod transform3d;
+mod typed_dict;
 mod variant;
 mod vectors;
 
@@ -0,0 +1,367 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Oklab and Okhsl color spaces.
+//!
+//! Port of Björn Ottosson's reference implementation (MIT licensed), which Godot ships as `thirdparty/misc/ok_color.h`.
+//! See <https://bottosson.github.io/posts/oklab/> and <https://bottosson.github.io/posts/colorpicker/>.
+
+use std::f32::consts::TAU;
+
+/// `[L, a, b]` in the Oklab color space.
+pub(super) type Lab = [f32; 3];
+
+/// `[r, g, b]`, either linear or sRGB-encoded.
+pub(super) type Rgb = [f32; 3];
+
+/// `[h, s, l]` in the Okhsl color space.
+pub(super) type Hsl = [f32; 3];
+
+pub(super) fn srgb_to_linear(a: f32) -> f32 {
+    if a > 0.04045 {
+        ((a + 0.055) / 1.055).powf(2.4)
+    } else {
+        a / 12.92
+    }
+}
+
+pub(super) fn linear_to_srgb(a: f32) -> f32 {
+    if a > 0.0031308 {
+        1.055 * a.powf(1.0 / 2.4) - 0.055
+    } else {
+        12.92 * a
+    }
+}
+
+pub(super) fn linear_srgb_to_oklab([r, g, b]: Rgb) -> Lab {
+    let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+    let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+    let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+    let l = l.cbrt();
+    let m = m.cbrt();
+    let s = s.cbrt();
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+pub(super) fn oklab_to_linear_srgb([lightness, a, b]: Lab) -> Rgb {
+    let l = lightness + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m = lightness - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s = lightness - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let l = l * l * l;
+    let m = m * m * m;
+    let s = s * s * s;
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+pub(super) fn okhsl_to_srgb([h, s, l]: Hsl) -> Rgb {
+    if l >= 1.0 {
+        return [1.0; 3];
+    } else if l <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let a = (TAU * h).cos();
+    let b = (TAU * h).sin();
+    let lightness = toe_inv(l);
+
+    let Chroma { c_0, c_mid, c_max } = chroma_bounds(lightness, a, b);
+
+    let chroma = if s < MID {
+        let t = MID_INV * s;
+        let k_1 = MID * c_0;
+        let k_2 = 1.0 - k_1 / c_mid;
+
+        t * k_1 / (1.0 - k_2 * t)
+    } else {
+        let t = (s - MID) / (1.0 - MID);
+        let k_0 = c_mid;
+        let k_1 = (1.0 - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+        let k_2 = 1.0 - k_1 / (c_max - c_mid);
+
+        k_0 + t * k_1 / (1.0 - k_2 * t)
+    };
+
+    oklab_to_linear_srgb([lightness, chroma * a, chroma * b]).map(linear_to_srgb)
+}
+
+pub(super) fn srgb_to_okhsl(rgb: Rgb) -> Hsl {
+    let [lightness, a, b] = linear_srgb_to_oklab(rgb.map(srgb_to_linear));
+    let l = toe(lightness);
+
+    let chroma = (a * a + b * b).sqrt();
+    if l >= 1.0 || l <= 0.0 || chroma < 1e-6 {
+        // Gray: hue is undefined and saturation is zero.
+        return [0.0, 0.0, l.clamp(0.0, 1.0)];
+    }
+
+    let a_ = a / chroma;
+    let b_ = b / chroma;
+    let h = 0.5 + 0.5 * (-b).atan2(-a) / std::f32::consts::PI;
+
+    let Chroma { c_0, c_mid, c_max } = chroma_bounds(lightness, a_, b_);
+
+    let s = if chroma < c_mid {
+        let k_1 = MID * c_0;
+        let k_2 = 1.0 - k_1 / c_mid;
+
+        let t = chroma / (k_1 + k_2 * chroma);
+        t * MID
+    } else {
+        let k_0 = c_mid;
+        let k_1 = (1.0 - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+        let k_2 = 1.0 - k_1 / (c_max - c_mid);
+
+        let t = (chroma - k_0) / (k_1 + k_2 * (chroma - k_0));
+        MID + (1.0 - MID) * t
+    };
+
+    [h, s, l]
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Gamut approximation
+
+const MID: f32 = 0.8;
+const MID_INV: f32 = 1.25;
+
+/// Cusp of the sRGB gamut for one hue, as lightness and chroma.
+struct Cusp {
+    l: f32,
+    c: f32,
+}
+
+/// Chroma values used to map saturation onto the sRGB gamut for one lightness and hue.
+struct Chroma {
+    c_0: f32,
+    c_mid: f32,
+    c_max: f32,
+}
+
+/// Maximum saturation `S = C/L` that fits in sRGB for a hue; `a` and `b` must be normalized.
+fn compute_max_saturation(a: f32, b: f32) -> f32 {
+    let (k, w) = if -1.881_703_3 * a - 0.809_364_9 * b > 1.0 {
+        // Red component.
+        (
+            [
+                1.190_862_8,
+                1.765_767_3,
+                0.596_626_4,
+                0.755_152,
+                0.567_712_4,
+            ],
+            [4.076_741_7, -3.307_711_6, 0.230_969_94],
+        )
+    } else if 1.814_441_1 * a - 1.194_452_8 * b > 1.0 {
+        // Green component.
+        (
+            [
+                0.739_565_15,
+                -0.459_544_04,
+                0.082_854_27,
+                0.125_410_7,
+                0.145_032_04,
+            ],
+            [-1.268_438, 2.609_757_4, -0.341_319_38],
+        )
+    } else {
+        // Blue component.
+        (
+            [
+                1.357_336_5,
+                -0.009_157_99,
+                -1.151_302_1,
+                -0.505_596_06,
+                0.006_921_67,
+            ],
+            [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+        )
+    };
+
+    // Polynomial approximation, refined with one step of Halley's method.
+    let s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+    let k_l = 0.396_337_78 * a + 0.215_803_76 * b;
+    let k_m = -0.105_561_346 * a - 0.063_854_17 * b;
+    let k_s = -0.089_484_18 * a - 1.291_485_5 * b;
+
+    let l_ = 1.0 + s * k_l;
+    let m_ = 1.0 + s * k_m;
+    let s_ = 1.0 + s * k_s;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s3 = s_ * s_ * s_;
+
+    let l_ds = 3.0 * k_l * l_ * l_;
+    let m_ds = 3.0 * k_m * m_ * m_;
+    let s_ds = 3.0 * k_s * s_ * s_;
+
+    let l_ds2 = 6.0 * k_l * k_l * l_;
+    let m_ds2 = 6.0 * k_m * k_m * m_;
+    let s_ds2 = 6.0 * k_s * k_s * s_;
+
+    let f = w[0] * l + w[1] * m + w[2] * s3;
+    let f1 = w[0] * l_ds + w[1] * m_ds + w[2] * s_ds;
+    let f2 = w[0] * l_ds2 + w[1] * m_ds2 + w[2] * s_ds2;
+
+    s - f * f1 / (f1 * f1 - 0.5 * f * f2)
+}
+
+fn find_cusp(a: f32, b: f32) -> Cusp {
+    let s_cusp = compute_max_saturation(a, b);
+
+    let [r, g, b] = oklab_to_linear_srgb([1.0, s_cusp * a, s_cusp * b]);
+    let l_cusp = (1.0 / r.max(g).max(b)).cbrt();
+
+    Cusp {
+        l: l_cusp,
+        c: l_cusp * s_cusp,
+    }
+}
+
+/// Finds `t` such that the line `(L0, 0) → (L1, C1)` crosses the sRGB gamut boundary at `L0 * (1 - t) + t * L1`.
+fn find_gamut_intersection(a: f32, b: f32, l1: f32, c1: f32, l0: f32, cusp: &Cusp) -> f32 {
+    if (l1 - l0) * cusp.c - (cusp.l - l0) * c1 <= 0.0 {
+        // Lower half: the triangle approximation is exact.
+        return cusp.c * l0 / (c1 * cusp.l + cusp.c * (l0 - l1));
+    }
+
+    // Upper half: refine the triangle approximation with one step of Halley's method.
+    let t = cusp.c * (l0 - 1.0) / (c1 * (cusp.l - 1.0) + cusp.c * (l0 - l1));
+
+    let dl = l1 - l0;
+    let dc = c1;
+
+    let k_l = 0.396_337_78 * a + 0.215_803_76 * b;
+    let k_m = -0.105_561_346 * a - 0.063_854_17 * b;
+    let k_s = -0.089_484_18 * a - 1.291_485_5 * b;
+
+    let l_dt = dl + dc * k_l;
+    let m_dt = dl + dc * k_m;
+    let s_dt = dl + dc * k_s;
+
+    let lightness = l0 * (1.0 - t) + t * l1;
+    let chroma = t * c1;
+
+    let l_ = lightness + chroma * k_l;
+    let m_ = lightness + chroma * k_m;
+    let s_ = lightness + chroma * k_s;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    let ldt = 3.0 * l_dt * l_ * l_;
+    let mdt = 3.0 * m_dt * m_ * m_;
+    let sdt = 3.0 * s_dt * s_ * s_;
+
+    let ldt2 = 6.0 * l_dt * l_dt * l_;
+    let mdt2 = 6.0 * m_dt * m_dt * m_;
+    let sdt2 = 6.0 * s_dt * s_dt * s_;
+
+    let step = |w: [f32; 3]| {
+        let f = w[0] * l + w[1] * m + w[2] * s - 1.0;
+        let f1 = w[0] * ldt + w[1] * mdt + w[2] * sdt;
+        let f2 = w[0] * ldt2 + w[1] * mdt2 + w[2] * sdt2;
+
+        let u = f1 / (f1 * f1 - 0.5 * f * f2);
+        if u >= 0.0 {
+            -f * u
+        } else {
+            f32::MAX
+        }
+    };
+
+    let t_r = step([4.076_741_7, -3.307_711_6, 0.230_969_94]);
+    let t_g = step([-1.268_438, 2.609_757_4, -0.341_319_38]);
+    let t_b = step([-0.004_196_086_3, -0.703_418_6, 1.707_614_7]);
+
+    t + t_r.min(t_g).min(t_b)
+}
+
+fn toe(x: f32) -> f32 {
+    const K_1: f32 = 0.206;
+    const K_2: f32 = 0.03;
+    const K_3: f32 = (1.0 + K_1) / (1.0 + K_2);
+
+    0.5 * (K_3 * x - K_1 + ((K_3 * x - K_1) * (K_3 * x - K_1) + 4.0 * K_2 * K_3 * x).sqrt())
+}
+
+fn toe_inv(x: f32) -> f32 {
+    const K_1: f32 = 0.206;
+    const K_2: f32 = 0.03;
+    const K_3: f32 = (1.0 + K_1) / (1.0 + K_2);
+
+    (x * x + K_1 * x) / (K_3 * (x + K_2))
+}
+
+/// Smooth approximation of the cusp location, as `[S, T]` with `S = C/L` and `T = C/(1-L)`.
+fn st_mid(a: f32, b: f32) -> [f32; 2] {
+    let s = 0.115_169_93
+        + 1.0
+            / (7.447_789_7
+                + 4.159_012_4 * b
+                + a * (-2.195_573_4
+                    + 1.751_984 * b
+                    + a * (-2.137_049_5 - 10.023_01 * b
+                        + a * (-4.248_945_7 + 5.387_708 * b + 4.698_91 * a))));
+
+    let t = 0.112_396_42
+        + 1.0
+            / (1.613_203_2 - 0.681_243_8 * b
+                + a * (0.403_706_12
+                    + 0.901_481_2 * b
+                    + a * (-0.270_879_43
+                        + 0.612_239_9 * b
+                        + a * (0.002_992_15 - 0.453_995_68 * b - 0.146_618_72 * a))));
+
+    [s, t]
+}
+
+fn chroma_bounds(lightness: f32, a: f32, b: f32) -> Chroma {
+    let cusp = find_cusp(a, b);
+
+    let c_max = find_gamut_intersection(a, b, lightness, 1.0, lightness, &cusp);
+    let st_max = [cusp.c / cusp.l, cusp.c / (1.0 - cusp.l)];
+
+    // Scale factor to compensate for the curved part of the gamut shape.
+    let k = c_max / (lightness * st_max[0]).min((1.0 - lightness) * st_max[1]);
+
+    // Soft minimum instead of a sharp triangle shape, for smooth chroma values.
+    let c_mid = {
+        let [s_mid, t_mid] = st_mid(a, b);
+        let c_a = lightness * s_mid;
+        let c_b = (1.0 - lightness) * t_mid;
+
+        0.9 * k
+            * (1.0 / (1.0 / c_a.powi(4) + 1.0 / c_b.powi(4)))
+                .sqrt()
+                .sqrt()
+    };
+
+    // The shape is independent of hue here, so S and T are roughly their average values.
+    let c_0 = {
+        let c_a = lightness * 0.4;
+        let c_b = (1.0 - lightness) * 0.8;
+
+        (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt()
+    };
+
+    Chroma { c_0, c_mid, c_max }
+}
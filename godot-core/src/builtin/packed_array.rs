@@ -100,22 +100,37 @@ macro_rules! impl_packed_array {
             }
 
             /// Converts this array to a Rust vector, making a copy of its contents.
+            ///
+            /// The elements are copied in bulk from the array's storage; see [`into_vec()`](Self::into_vec) for performance notes.
             pub fn to_vec(&self) -> Vec<$Element> {
-                let len = self.len();
-                let mut vec = Vec::with_capacity(len);
-                if len > 0 {
-                    let ptr = self.ptr(0);
-                    for offset in 0..to_isize(len) {
-                        // SAFETY: Packed arrays are stored contiguously in memory, so we can use
-                        // pointer arithmetic instead of going through `$operator_index_const` for
-                        // every index.
-                        // Note that we do need to use `.clone()` because `GString` is refcounted;
-                        // we can't just do a memcpy.
-                        let element = unsafe { (*ptr.offset(offset)).clone() };
-                        vec.push(element);
-                    }
+                self.as_slice().to_vec()
+            }
+
+            #[doc = concat!("Creates a `", stringify!($PackedArray), "` from a Rust vector, consuming it.")]
+            ///
+            /// Godot cannot take over memory allocated by Rust, so the elements are moved into a newly allocated array. This costs one
+            /// allocation plus a single pass over the elements, which compiles to a `memcpy` for plain element types like numbers and
+            /// vectors. No element is cloned, so for `GString` this also avoids reference-counting overhead.
+            pub fn from_vec(vec: Vec<$Element>) -> Self {
+                let mut array = Self::new();
+                if vec.is_empty() {
+                    return array;
                 }
-                vec
+
+                array.resize(vec.len());
+                for (slot, element) in array.as_mut_slice().iter_mut().zip(vec) {
+                    *slot = element;
+                }
+                array
+            }
+
+            /// Converts this array into a Rust vector.
+            ///
+            /// Like in the other direction, Rust cannot take over memory allocated by Godot, and the buffer may be shared with other
+            /// copies of the array (copy-on-write). This allocates a vector and copies the elements over in bulk, which is a `memcpy` for
+            /// plain element types. For read-only access, prefer [`as_slice()`](Self::as_slice), which does not copy at all.
+            pub fn into_vec(self) -> Vec<$Element> {
+                self.to_vec()
             }
 
             /// Clears the array, removing all elements.
@@ -403,24 +418,44 @@ macro_rules! impl_packed_array {
         impl From<&[$Element]> for $PackedArray {
             fn from(slice: &[$Element]) -> Self {
                 let mut array = Self::new();
-                let len = slice.len();
-                if len == 0 {
+                if slice.is_empty() {
                     return array;
                 }
-                array.resize(len);
-                let ptr = array.ptr_mut(0);
-                for (i, element) in slice.iter().enumerate() {
-                    // SAFETY: The array contains exactly `len` elements, stored contiguously in memory.
-                    unsafe {
-                        // `GString` does not implement `Copy` so we have to call `.clone()`
-                        // here.
-                        *ptr.offset(to_isize(i)) = element.clone();
-                    }
-                }
+
+                array.resize(slice.len());
+                array.as_mut_slice().clone_from_slice(slice);
                 array
             }
         }
 
+        #[doc = concat!("Creates a `", stringify!($PackedArray), "` from a vector, see [`", stringify!($PackedArray), "::from_vec()`].")]
+        impl From<Vec<$Element>> for $PackedArray {
+            fn from(vec: Vec<$Element>) -> Self {
+                Self::from_vec(vec)
+            }
+        }
+
+        #[doc = concat!("Creates a vector from a `", stringify!($PackedArray), "`, see [`", stringify!($PackedArray), "::into_vec()`].")]
+        impl From<$PackedArray> for Vec<$Element> {
+            fn from(array: $PackedArray) -> Self {
+                array.into_vec()
+            }
+        }
+
+        /// Same as [`as_slice()`](Self::as_slice), for APIs generic over slice-like containers.
+        impl AsRef<[$Element]> for $PackedArray {
+            fn as_ref(&self) -> &[$Element] {
+                self.as_slice()
+            }
+        }
+
+        /// Same as [`as_mut_slice()`](Self::as_mut_slice); makes the array unique if its buffer is shared.
+        impl AsMut<[$Element]> for $PackedArray {
+            fn as_mut(&mut self) -> &mut [$Element] {
+                self.as_mut_slice()
+            }
+        }
+
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from an iterator.")]
         impl FromIterator<$Element> for $PackedArray {
             fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
@@ -433,17 +468,39 @@ macro_rules! impl_packed_array {
         #[doc = concat!("Extends a`", stringify!($PackedArray), "` with the contents of an iterator")]
         impl Extend<$Element> for $PackedArray {
             fn extend<I: IntoIterator<Item = $Element>>(&mut self, iter: I) {
-                // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`.
-                // Otherwise we could use it to pre-allocate based on `iter.size_hint()`.
-                //
-                // A faster implementation using `resize()` and direct pointer writes might still be
-                // possible.
-                for item in iter.into_iter() {
+                // The GDExtension API does not offer the equivalent of `Vec::reserve`. Instead, resize once for the elements that
+                // `size_hint()` guarantees and write them through a slice, rather than making one engine call per element.
+                let mut iter = iter.into_iter();
+                let (guaranteed, _) = iter.size_hint();
+                if guaranteed > 0 {
+                    let start = self.len();
+                    self.resize(start + guaranteed);
+
+                    let mut written = 0;
+                    for (slot, item) in self.as_mut_slice()[start..].iter_mut().zip(iter.by_ref()) {
+                        *slot = item;
+                        written += 1;
+                    }
+
+                    // Only if the iterator reported a wrong lower bound.
+                    if written < guaranteed {
+                        self.resize(start + written);
+                    }
+                }
+
+                for item in iter {
                     self.push(item);
                 }
             }
         }
 
+        #[doc = concat!("Extends a`", stringify!($PackedArray), "` with copies of the elements of an iterator over references")]
+        impl<'a> Extend<&'a $Element> for $PackedArray {
+            fn extend<I: IntoIterator<Item = &'a $Element>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().cloned())
+            }
+        }
+
         impl_builtin_froms!($PackedArray; VariantArray => $from_array);
 
         impl fmt::Debug for $PackedArray {
@@ -453,6 +510,28 @@ macro_rules! impl_packed_array {
             }
         }
 
+        /// Serialized as a sequence of elements.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $PackedArray {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.collect_seq(self.as_slice())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $PackedArray {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let elements = <Vec<$Element> as serde::Deserialize>::deserialize(deserializer)?;
+                Ok(Self::from(elements.as_slice()))
+            }
+        }
+
         impl fmt::Display for $PackedArray {
             /// Formats `PackedArray` to match Godot's string representation.
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -664,3 +743,52 @@ impl_packed_array!(
         PartialEq => packed_color_array_operator_equal;
     },
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions between PackedStringArray and Rust strings
+
+impl PackedStringArray {
+    /// Converts this array to a vector of Godot strings. Same as [`to_vec()`](Self::to_vec).
+    ///
+    /// Cloning a `GString` only increments a reference count, so no string data is copied.
+    pub fn to_vec_gstring(&self) -> Vec<GString> {
+        self.to_vec()
+    }
+
+    /// Converts this array to a vector of Rust strings, converting each element to UTF-8.
+    pub fn to_vec_string(&self) -> Vec<String> {
+        self.as_slice().iter().map(String::from).collect()
+    }
+}
+
+/// Creates a `PackedStringArray` from an iterator over Rust strings. The array is sized once for the iterator's lower size bound.
+impl<'a> FromIterator<&'a str> for PackedStringArray {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut array = PackedStringArray::default();
+        array.extend(iter);
+        array
+    }
+}
+
+/// Creates a `PackedStringArray` from an iterator over Rust strings. The array is sized once for the iterator's lower size bound.
+impl FromIterator<String> for PackedStringArray {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut array = PackedStringArray::default();
+        array.extend(iter);
+        array
+    }
+}
+
+/// Extends a `PackedStringArray` with Rust strings, writing them in place instead of pushing one by one.
+impl<'a> Extend<&'a str> for PackedStringArray {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(GString::from))
+    }
+}
+
+/// Extends a `PackedStringArray` with Rust strings, writing them in place instead of pushing one by one.
+impl Extend<String> for PackedStringArray {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(GString::from))
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+use crate::builtin::PackedByteArray;
+
+impl PackedByteArray {
+    /// Returns a reader over the bytes, implementing [`Read`], [`BufRead`] and [`Seek`].
+    ///
+    /// Reads directly from the array's storage, without copying it.
+    pub fn reader(&self) -> PackedByteArrayReader<'_> {
+        PackedByteArrayReader {
+            cursor: io::Cursor::new(self.as_slice()),
+        }
+    }
+
+    /// Returns a writer into the array, implementing [`Write`] and [`Seek`].
+    ///
+    /// The writer starts at position 0 and overwrites existing bytes; writing past the end grows the array. To append instead,
+    /// seek to [`SeekFrom::End(0)`](SeekFrom::End) first, or clear the array.
+    pub fn writer(&mut self) -> PackedByteArrayWriter<'_> {
+        PackedByteArrayWriter {
+            array: self,
+            position: 0,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Reads from a [`PackedByteArray`], see [`PackedByteArray::reader()`].
+///
+/// ```no_run
+/// use std::io::Read;
+/// use godot::prelude::*;
+///
+/// let bytes = PackedByteArray::from(&b"hello"[..]);
+/// let mut text = String::new();
+/// bytes.reader().read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "hello");
+/// ```
+#[derive(Clone, Debug)]
+pub struct PackedByteArrayReader<'a> {
+    cursor: io::Cursor<&'a [u8]>,
+}
+
+impl<'a> PackedByteArrayReader<'a> {
+    /// Current position, in bytes from the start.
+    pub fn position(&self) -> u64 {
+        self.cursor.position()
+    }
+
+    /// The bytes not read yet.
+    pub fn remaining_slice(&self) -> &'a [u8] {
+        let data = *self.cursor.get_ref();
+        let position = usize::try_from(self.cursor.position()).unwrap_or(usize::MAX);
+        &data[position.min(data.len())..]
+    }
+}
+
+impl Read for PackedByteArrayReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.cursor.read(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.cursor.read_exact(buf)
+    }
+}
+
+impl BufRead for PackedByteArrayReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.cursor.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.cursor.consume(amt)
+    }
+}
+
+impl Seek for PackedByteArrayReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.cursor.seek(pos)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Writes into a [`PackedByteArray`], see [`PackedByteArray::writer()`].
+///
+/// ```no_run
+/// use std::io::Write;
+/// use godot::prelude::*;
+///
+/// let mut bytes = PackedByteArray::new();
+/// write!(bytes.writer(), "{} + {}", 1, 2).unwrap();
+/// assert_eq!(bytes.as_slice(), b"1 + 2");
+/// ```
+#[derive(Debug)]
+pub struct PackedByteArrayWriter<'a> {
+    array: &'a mut PackedByteArray,
+    position: usize,
+}
+
+impl PackedByteArrayWriter<'_> {
+    /// Current position, in bytes from the start.
+    pub fn position(&self) -> u64 {
+        self.position as u64
+    }
+}
+
+impl Write for PackedByteArrayWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let end = self.position.checked_add(buf.len()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "write past maximum array size")
+        })?;
+
+        // Resizing fills any gap left by seeking past the end with zeros.
+        if end > self.array.len() {
+            self.array.resize(end);
+        }
+
+        self.array.as_mut_slice()[self.position..end].copy_from_slice(buf);
+        self.position = end;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for PackedByteArrayWriter<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(offset) => (0, i64::try_from(offset).unwrap_or(i64::MAX)),
+            SeekFrom::End(offset) => (self.array.len() as i64, offset),
+            SeekFrom::Current(offset) => (self.position as i64, offset),
+        };
+
+        let position = base
+            .checked_add(offset)
+            .and_then(|position| usize::try_from(position).ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )
+            })?;
+
+        self.position = position;
+        Ok(position as u64)
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parallel iteration with [rayon](https://docs.rs/rayon), enabled by the `rayon` feature.
+
+use rayon::prelude::*;
+
+use crate::builtin::meta::{FromGodot, GodotType, ToGodot};
+use crate::builtin::*;
+
+macro_rules! impl_packed_par_iter {
+    ($($PackedArray:ident => $Element:ty),* $(,)?) => {
+        $(
+            impl $PackedArray {
+                /// Returns a parallel iterator over the elements, see [`as_slice()`](Self::as_slice).
+                ///
+                /// Requires the `rayon` feature.
+                pub fn par_iter(&self) -> rayon::slice::Iter<'_, $Element> {
+                    self.as_slice().par_iter()
+                }
+
+                /// Returns a parallel iterator over mutable references to the elements, see [`as_mut_slice()`](Self::as_mut_slice).
+                ///
+                /// Requires the `rayon` feature.
+                pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, $Element> {
+                    self.as_mut_slice().par_iter_mut()
+                }
+            }
+        )*
+    };
+}
+
+// `PackedStringArray` is excluded, as `GString` is reference-counted without synchronization and thus not `Sync`.
+impl_packed_par_iter!(
+    PackedByteArray => u8,
+    PackedInt32Array => i32,
+    PackedInt64Array => i64,
+    PackedFloat32Array => f32,
+    PackedFloat64Array => f64,
+    PackedVector2Array => Vector2,
+    PackedVector3Array => Vector3,
+    PackedColorArray => Color,
+);
+
+impl<T: GodotType + FromGodot + Send> Array<T> {
+    /// Applies `f` to all elements in parallel and collects the results into a new array.
+    ///
+    /// `Array` itself cannot be shared across threads, so the elements are converted to `T` up front, mapped on rayon's thread pool,
+    /// and the results are written to the new array on the calling thread. This pays off when `f` does significant work per element.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Panics
+    /// If an element cannot be converted to `T`, or if `f` panics.
+    pub fn par_map<U, F>(&self, f: F) -> Array<U>
+    where
+        U: GodotType + ToGodot + Send,
+        F: Fn(T) -> U + Send + Sync,
+    {
+        let results: Vec<U> = Vec::from(self).into_par_iter().map(f).collect();
+        results.into_iter().collect()
+    }
+}
@@ -36,6 +36,24 @@ pub struct Plane {
 }
 
 impl Plane {
+    /// The YZ plane, with its normal pointing in +X direction.
+    pub const PLANE_YZ: Self = Self {
+        normal: Vector3::RIGHT,
+        d: 0.0,
+    };
+
+    /// The XZ plane, with its normal pointing in +Y direction.
+    pub const PLANE_XZ: Self = Self {
+        normal: Vector3::UP,
+        d: 0.0,
+    };
+
+    /// The XY plane, with its normal pointing in +Z direction.
+    pub const PLANE_XY: Self = Self {
+        normal: Vector3::BACK,
+        d: 0.0,
+    };
+
     /// Creates a new `Plane` from the `normal` and the distance from the origin `d`.
     ///
     /// # Panics
@@ -114,10 +132,10 @@ impl Plane {
     ///
     /// _Godot equivalent: `Plane()`_
     #[inline]
-    pub fn invalid() -> Self {
+    pub const fn invalid() -> Self {
         Self {
-            normal: Default::default(),
-            d: Default::default(),
+            normal: Vector3::ZERO,
+            d: 0.0,
         }
     }
 
@@ -325,6 +343,16 @@ mod test {
         );
     }
 
+    #[test]
+    fn axis_plane_constants() {
+        assert_eq!(
+            Plane::PLANE_YZ,
+            Plane::from_normal_at_origin(Vector3::RIGHT)
+        );
+        assert_eq!(Plane::PLANE_XZ, Plane::from_normal_at_origin(Vector3::UP));
+        assert_eq!(Plane::PLANE_XY, Plane::from_normal_at_origin(Vector3::BACK));
+    }
+
     #[test]
     #[should_panic]
     fn new_unnormalized_panics() {
@@ -270,6 +270,19 @@ impl Projection {
         RMat4::perspective_rh_gl(fov_y, aspect, near, far).to_front()
     }
 
+    /// Creates a new Projection that projects positions using a perspective
+    /// projection with the given Y-axis field of view (in degrees), X:Y aspect
+    /// ratio and near clipping distance, without a far clipping plane.
+    ///
+    /// Depth is reversed: the near plane maps to depth `1` and infinity to
+    /// depth `0`, which distributes floating-point precision evenly over
+    /// distance. Note that this is the depth convention of Vulkan/Direct3D
+    /// reverse-Z rendering, not the `[-1, 1]` OpenGL range used by the other
+    /// constructors, so [`z_far()`][Self::z_far] is meaningless for the result.
+    pub fn create_perspective_infinite_reverse_z(fov_y: real, aspect: real, near: real) -> Self {
+        RMat4::perspective_infinite_reverse_rh(fov_y.to_radians(), aspect, near).to_front()
+    }
+
     /// Creates a new Projection that projects positions using a perspective
     /// projection with the given Y-axis field of view (in degrees), X:Y aspect
     /// ratio, and clipping distances. The projection is adjusted for a
@@ -385,6 +398,15 @@ impl Projection {
         self.as_inner().get_projection_plane(plane as i64)
     }
 
+    /// Returns all six clipping planes of this Projection, in the order of
+    /// [`ProjectionPlane`]: near, far, left, top, right, bottom.
+    ///
+    /// The plane normals point outwards, so a point is inside the frustum if it
+    /// is not [above](Plane::is_point_over) any of them.
+    pub fn frustum_planes(&self) -> [Plane; 6] {
+        ProjectionPlane::ALL.map(|plane| self.get_projection_plane(plane))
+    }
+
     /// Returns the dimensions of the viewport plane that this Projection
     /// projects positions onto, divided by two.
     ///
@@ -415,6 +437,25 @@ impl Projection {
         self.glam(|mat| mat.inverse())
     }
 
+    /// Maps a point from screen space back to the 3D space this Projection
+    /// projects from.
+    ///
+    /// The X and Y components of `point` are pixel coordinates inside `rect`,
+    /// with Y pointing down. The Z component is the normalized device depth,
+    /// `-1` on the near plane and `1` on the far plane.
+    pub fn unproject(&self, point: Vector3, rect: Rect2) -> Vector3 {
+        let normalized = (Vector2::new(point.x, point.y) - rect.position) / rect.size;
+        let ndc = Vector4::new(
+            normalized.x * 2.0 - 1.0,
+            1.0 - normalized.y * 2.0,
+            point.z,
+            1.0,
+        );
+
+        let v = self.inverse() * ndc;
+        Vector3::new(v.x, v.y, v.z) / v.w
+    }
+
     /// Returns `true` if this Projection performs an orthogonal projection.
     ///
     /// _Godot equivalent: `Projection.is_orthogonal()`_
@@ -553,6 +594,16 @@ pub enum ProjectionPlane {
 }
 
 impl ProjectionPlane {
+    /// All planes, ordered by their integer value.
+    pub const ALL: [Self; 6] = [
+        Self::Near,
+        Self::Far,
+        Self::Left,
+        Self::Top,
+        Self::Right,
+        Self::Bottom,
+    ];
+
     /// Convert from one of GDScript's `Projection.PLANE_*` integer constants.
     pub fn try_from_ord(ord: i64) -> Option<Self> {
         match ord {
@@ -26,7 +26,10 @@ pub struct Quaternion {
 }
 
 impl Quaternion {
-    pub fn new(x: real, y: real, z: real, w: real) -> Self {
+    /// The identity quaternion, representing no rotation.
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: real, y: real, z: real, w: real) -> Self {
         Self { x, y, z, w }
     }
 
@@ -50,10 +53,108 @@ impl Quaternion {
         Self::new(x, y, z, w)
     }
 
+    /// Creates the shortest rotation that turns direction `from` into direction `to`.
+    ///
+    /// Both vectors are normalized first. If they point in opposite directions, the rotation is by PI around an arbitrary perpendicular axis.
+    ///
+    /// # Panics
+    /// If either vector is zero.
+    ///
+    /// _Godot equivalent: `Quaternion(Vector3 arc_from, Vector3 arc_to)`_
+    pub fn from_arc(from: Vector3, to: Vector3) -> Self {
+        assert!(
+            !from.is_zero_approx() && !to.is_zero_approx(),
+            "Quaternion::from_arc() requires non-zero vectors, got {from:?} and {to:?}"
+        );
+
+        RQuat::from_rotation_arc(from.normalized().to_glam(), to.normalized().to_glam()).to_front()
+    }
+
+    /// Creates a rotation whose forward axis (-Z) points in `direction`, with the up axis (+Y) as close to `up` as possible.
+    ///
+    /// This is the rotation of [`Basis::new_looking_at()`], computed without calling into the engine.
+    ///
+    /// # Panics
+    /// If `direction` is zero or parallel to `up`.
+    #[doc(alias = "look_at")]
+    pub fn new_looking_at(direction: Vector3, up: Vector3) -> Self {
+        let z = -direction.normalized();
+        let x = up.cross(z);
+        assert!(
+            !x.is_zero_approx(),
+            "Quaternion::new_looking_at(): direction {direction:?} must be non-zero and not parallel to up {up:?}"
+        );
+
+        let x = x.normalized();
+        let y = z.cross(x);
+
+        Basis::from_cols(x, y, z).to_quat()
+    }
+
     // TODO: Constructors.
-    // pub fn from_vector_vector(arc_to: Vector3, arc_from: Vector3) -> Self {}
     // pub fn from_basis(basis: Basis) -> Self {}
 
+    /// Splits the rotation into a swing and a twist around `axis`, such that `self == swing * twist`.
+    ///
+    /// The twist is the part of the rotation around `axis`, the swing rotates `axis` to its final direction. This is useful to limit
+    /// the rotation of joints, e.g. the twist of a forearm. If the rotation turns `axis` by PI, the twist is the identity.
+    ///
+    /// Returns `(swing, twist)`.
+    ///
+    /// # Panics
+    /// If `axis` is not normalized.
+    pub fn swing_twist(self, axis: Vector3) -> (Self, Self) {
+        assert!(
+            axis.is_normalized(),
+            "Quaternion axis {axis:?} is not normalized."
+        );
+
+        let projected = axis * Vector3::new(self.x, self.y, self.z).dot(axis);
+        let twist = Self::new(projected.x, projected.y, projected.z, self.w);
+
+        let twist = if twist.length_squared().is_zero_approx() {
+            Self::default()
+        } else {
+            twist.normalized()
+        };
+
+        (self * twist.inverse(), twist)
+    }
+
+    /// Rotates by `angular_velocity` (axis scaled by speed in radians per second, in global space) for a duration of `delta` seconds.
+    ///
+    /// Returns the normalized result, so repeated integration does not accumulate errors in length.
+    pub fn integrate(self, angular_velocity: Vector3, delta: real) -> Self {
+        let angle = angular_velocity.length() * delta;
+        if angle.is_zero_approx() {
+            return self;
+        }
+
+        let step = Self::from_axis_angle(angular_velocity.normalized(), angle);
+        (step * self).normalized()
+    }
+
+    /// Returns the angular velocity that rotates this orientation to `to` in `delta` seconds, along the shortest path.
+    ///
+    /// This is the inverse of [`integrate()`](Self::integrate): `self.integrate(self.angular_velocity_to(to, delta), delta)` is `to`.
+    ///
+    /// Returns [`Vector3::ZERO`] if `delta` is not positive, since no finite velocity reaches `to` in that time.
+    pub fn angular_velocity_to(self, to: Self, delta: real) -> Vector3 {
+        if delta.is_nan() || delta <= 0.0 {
+            return Vector3::ZERO;
+        }
+
+        let mut difference = to * self.inverse();
+
+        // q and -q represent the same rotation; the one with positive w takes the shorter way.
+        if difference.w < 0.0 {
+            difference = -difference;
+        }
+
+        let (axis, angle) = difference.normalized().to_glam().to_axis_angle();
+        axis.to_front() * (angle / delta)
+    }
+
     pub fn angle_to(self, to: Self) -> real {
         self.glam2(&to, RQuat::angle_between)
     }
@@ -287,7 +388,7 @@ unsafe impl GodotFfi for Quaternion {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
 }
 
-impl_godot_as_self!(Quaternion);
+impl_godot_as_self!(Quaternion; check_finite);
 
 impl std::fmt::Display for Quaternion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -297,7 +398,7 @@ impl std::fmt::Display for Quaternion {
 
 impl Default for Quaternion {
     fn default() -> Self {
-        Self::new(0.0, 0.0, 0.0, 1.0)
+        Self::IDENTITY
     }
 }
 
@@ -97,6 +97,19 @@ mod real_mod {
     /// A 3D affine transform from [`glam`], which can represent translation, rotation, scaling and
     /// shear. Using a floating-point format compatible with [`real`].
     pub type RAffine3 = glam::Affine3A;
+
+    /// The [`glam`] vector used for arithmetic on `Vector3`. With the `simd` feature, this is the 16-byte aligned SIMD type.
+    #[cfg(feature = "simd")]
+    pub type RVec3Ops = glam::Vec3A;
+    /// The [`glam`] vector used for arithmetic on `Vector3`. With the `simd` feature, this is the 16-byte aligned SIMD type.
+    #[cfg(not(feature = "simd"))]
+    pub type RVec3Ops = glam::Vec3;
+    /// The [`glam`] matrix used for arithmetic on `Basis`. With the `simd` feature, this is the SIMD type.
+    #[cfg(feature = "simd")]
+    pub type RMat3Ops = glam::Mat3A;
+    /// The [`glam`] matrix used for arithmetic on `Basis`. With the `simd` feature, this is the SIMD type.
+    #[cfg(not(feature = "simd"))]
+    pub type RMat3Ops = glam::Mat3;
 }
 
 #[cfg(feature = "double-precision")]
@@ -165,6 +178,11 @@ mod real_mod {
     /// A 3D affine transform from [`glam`], which can represent translation, rotation, scaling and
     /// shear. Using a floating-point format compatible with [`real`].
     pub type RAffine3 = glam::DAffine3;
+
+    /// The [`glam`] vector used for arithmetic on `Vector3`. glam has no SIMD types for `f64`, so this is independent of `simd`.
+    pub type RVec3Ops = glam::DVec3;
+    /// The [`glam`] matrix used for arithmetic on `Basis`. glam has no SIMD types for `f64`, so this is independent of `simd`.
+    pub type RMat3Ops = glam::DMat3;
 }
 
 // Public symbols (note that macro `real!` is re-exported in `lib.rs`)
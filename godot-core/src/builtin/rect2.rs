@@ -77,6 +77,7 @@ impl Rect2 {
     }
 
     /// Whether `self` covers at least the entire area of `b` (and possibly more).
+    #[doc(alias = "contains_rect")]
     #[inline]
     pub fn encloses(&self, b: Rect2) -> bool {
         let end = self.end();
@@ -92,6 +93,7 @@ impl Rect2 {
     ///
     /// Note: This method is not reliable for `Rect2` with a negative size. Use [`abs`][Self::abs]
     /// to get a positive sized equivalent rectangle for expanding.
+    #[doc(alias = "expand_to_point")]
     #[inline]
     pub fn expand(&self, to: Vector2) -> Self {
         self.merge(Rect2::new(to, Vector2::ZERO))
@@ -241,6 +243,37 @@ impl Rect2 {
         self.size = end - self.position
     }
 
+    /// Returns the four corners, clockwise starting at `position`: top-left, top-right, bottom-right, bottom-left.
+    ///
+    /// "Top" refers to the smaller Y coordinate, as in Godot's 2D coordinate system.
+    #[inline]
+    pub fn corners(&self) -> [Vector2; 4] {
+        let start = self.position;
+        let end = self.end();
+
+        [
+            start,
+            Vector2::new(end.x, start.y),
+            end,
+            Vector2::new(start.x, end.y),
+        ]
+    }
+
+    /// Returns the four edges as `(from, to)` corner pairs running clockwise, indexed by [`RectSide`].
+    ///
+    /// `rect.edges()[RectSide::Top as usize]` is the edge from the top-left to the top-right corner.
+    #[inline]
+    pub fn edges(&self) -> [(Vector2, Vector2); 4] {
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+
+        [
+            (bottom_left, top_left),
+            (top_left, top_right),
+            (top_right, bottom_right),
+            (bottom_right, bottom_left),
+        ]
+    }
+
     /// Assert that the size of the `Rect2` is not negative.
     ///
     /// Certain functions will fail to give a correct result if the size is negative.
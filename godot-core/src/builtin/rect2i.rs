@@ -60,10 +60,10 @@ impl Rect2i {
 
     /// Create a new `Rect2i` with the first corner at `position` and the opposite corner at `end`.
     #[inline]
-    pub fn from_corners(position: Vector2i, end: Vector2i) -> Self {
+    pub const fn from_corners(position: Vector2i, end: Vector2i) -> Self {
         Self {
             position,
-            size: end - position,
+            size: Vector2i::new(end.x - position.x, end.y - position.y),
         }
     }
 
@@ -84,6 +84,37 @@ impl Rect2i {
         self.size = end - self.position
     }
 
+    /// Returns the four corners, clockwise starting at `position`: top-left, top-right, bottom-right, bottom-left.
+    ///
+    /// "Top" refers to the smaller Y coordinate, as in Godot's 2D coordinate system.
+    #[inline]
+    pub fn corners(self) -> [Vector2i; 4] {
+        let start = self.position;
+        let end = self.end();
+
+        [
+            start,
+            Vector2i::new(end.x, start.y),
+            end,
+            Vector2i::new(start.x, end.y),
+        ]
+    }
+
+    /// Returns the four edges as `(from, to)` corner pairs running clockwise, indexed by [`RectSide`].
+    ///
+    /// `rect.edges()[RectSide::Top as usize]` is the edge from the top-left to the top-right corner.
+    #[inline]
+    pub fn edges(self) -> [(Vector2i, Vector2i); 4] {
+        let [top_left, top_right, bottom_right, bottom_left] = self.corners();
+
+        [
+            (bottom_left, top_left),
+            (top_left, top_right),
+            (top_right, bottom_right),
+            (bottom_right, bottom_left),
+        ]
+    }
+
     /// Returns a `Rect2i` with equivalent position and area, modified so that the top-left corner
     /// is the origin and `width` and `height` are positive.
     #[inline]
@@ -97,6 +128,7 @@ impl Rect2i {
     ///
     /// Any `Rect2i` encloses itself, i.e. an enclosed `Rect2i` does is not required to be a
     /// proper sub-rect.
+    #[doc(alias = "contains_rect")]
     #[inline]
     pub const fn encloses(&self, other: Self) -> bool {
         self.assert_nonnegative();
@@ -111,6 +143,7 @@ impl Rect2i {
     }
 
     /// Returns a copy of this `Rect2i` expanded so that the borders align with the given point.
+    #[doc(alias = "expand_to_point")]
     #[inline]
     pub fn expand(self, to: Vector2i) -> Self {
         self.assert_nonnegative();
@@ -601,4 +634,11 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&rect, expected_json);
     }
+
+    #[test]
+    fn const_from_corners() {
+        const RECT: Rect2i = Rect2i::from_corners(Vector2i::new(-2, 1), Vector2i::new(3, 5));
+
+        assert_eq!(RECT, Rect2i::from_components(-2, 1, 5, 4));
+    }
 }
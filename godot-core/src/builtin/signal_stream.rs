@@ -0,0 +1,216 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+use crate::builtin::meta::{ConvertError, FromGodot};
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+
+/// Tuple of signal arguments that can be converted from the variants passed on emission.
+///
+/// Implemented for tuples of up to 6 elements, each implementing [`FromGodot`]. Use `()` for signals without arguments.
+pub trait SignalArgs: Sized + 'static {
+    /// Converts the arguments of one emission. Fails if the number of arguments or their types do not match.
+    fn from_args(args: &[Variant]) -> Result<Self, ConvertError>;
+}
+
+macro_rules! impl_signal_args {
+    ($( $Arg:ident ),*) => {
+        impl<$( $Arg: FromGodot + 'static ),*> SignalArgs for ($( $Arg, )*) {
+            #[allow(unused_variables, unused_mut, clippy::unused_unit)]
+            fn from_args(args: &[Variant]) -> Result<Self, ConvertError> {
+                const LEN: usize = <[&str]>::len(&[$( stringify!($Arg) ),*]);
+                if args.len() != LEN {
+                    return Err(ConvertError::with_cause_value(
+                        format!("expected {LEN} signal arguments, got {}", args.len()),
+                        args,
+                    ));
+                }
+
+                let mut args = args.iter();
+                Ok(($(
+                    $Arg::try_from_variant(args.next().unwrap())?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_signal_args!();
+impl_signal_args!(A1);
+impl_signal_args!(A1, A2);
+impl_signal_args!(A1, A2, A3);
+impl_signal_args!(A1, A2, A3, A4);
+impl_signal_args!(A1, A2, A3, A4, A5);
+impl_signal_args!(A1, A2, A3, A4, A5, A6);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Asynchronous stream over the emissions of a signal.
+///
+/// Each time the signal is emitted, the stream yields its arguments converted to `Args`. Emissions are buffered until polled, so none
+/// are lost between two `next().await` calls. The stream ends when the signal's object is freed; dropping the stream disconnects it.
+///
+/// Created by [`Signal::to_stream()`]. Signals are emitted on the main thread, so the stream must be polled by an executor running there.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use futures::StreamExt as _;
+/// # async fn example(timer: Gd<godot::engine::Timer>) {
+/// let signal = Signal::from_object_signal(&timer, "timeout");
+/// let mut timeouts = signal.to_stream::<()>();
+///
+/// while let Some(()) = timeouts.next().await {
+///     godot_print!("tick");
+/// }
+/// # }
+/// ```
+///
+/// # Panics
+/// When polled after an emission whose arguments cannot be converted to `Args`.
+pub struct SignalStream<Args: SignalArgs> {
+    signal: Signal,
+    id: u64,
+    _args: PhantomData<fn() -> Args>,
+}
+
+impl<Args: SignalArgs> SignalStream<Args> {
+    /// Connects a new stream to `signal`.
+    pub fn new(signal: &Signal) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+        QUEUES.with(|queues| queues.borrow_mut().insert(id, EmissionQueue::default()));
+        signal.connect(Callable::from_custom(Forwarder { id }), 0);
+
+        Self {
+            signal: signal.clone(),
+            id,
+            _args: PhantomData,
+        }
+    }
+}
+
+impl<Args: SignalArgs> Stream for SignalStream<Args> {
+    type Item = Args;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Args>> {
+        let next = QUEUES.with(|queues| {
+            let mut queues = queues.borrow_mut();
+            let queue = queues.get_mut(&self.id)?;
+
+            match queue.emissions.pop_front() {
+                Some(args) => Some(Poll::Ready(args)),
+                None if queue.is_closed => None,
+                None => {
+                    queue.waker = Some(cx.waker().clone());
+                    Some(Poll::Pending)
+                }
+            }
+        });
+
+        match next {
+            Some(Poll::Ready(args)) => {
+                let args = Args::from_args(&args).unwrap_or_else(|err| {
+                    panic!(
+                        "signal {:?} emitted arguments not convertible to {}: {err}",
+                        self.signal.name(),
+                        std::any::type_name::<Args>()
+                    )
+                });
+                Poll::Ready(Some(args))
+            }
+            Some(Poll::Pending) => Poll::Pending,
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<Args: SignalArgs> Drop for SignalStream<Args> {
+    fn drop(&mut self) {
+        // Custom callables compare by ID, so a new forwarder identifies the existing connection.
+        let callable = Callable::from_custom(Forwarder { id: self.id });
+        if !self.signal.is_null() && self.signal.is_connected(callable.clone()) {
+            self.signal.disconnect(callable);
+        }
+
+        QUEUES.with(|queues| queues.borrow_mut().remove(&self.id));
+    }
+}
+
+impl Signal {
+    /// Returns a stream that yields the arguments of each emission of this signal, see [`SignalStream`].
+    pub fn to_stream<Args: SignalArgs>(&self) -> SignalStream<Args> {
+        SignalStream::new(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct EmissionQueue {
+    emissions: VecDeque<Vec<Variant>>,
+    waker: Option<Waker>,
+    is_closed: bool,
+}
+
+thread_local! {
+    // Variants are not Send, so emissions are stored per thread and only referenced by ID from the (Send) callable.
+    static QUEUES: RefCell<HashMap<u64, EmissionQueue>> = RefCell::new(HashMap::new());
+}
+
+/// Callable connected to the signal, which pushes emissions to the queue of its stream.
+#[derive(PartialEq, Hash)]
+struct Forwarder {
+    id: u64,
+}
+
+impl Forwarder {
+    fn update_queue(&self, update: impl FnOnce(&mut EmissionQueue)) {
+        // Wake outside the borrow, as executors may poll the stream right away.
+        let waker = QUEUES.with(|queues| {
+            let mut queues = queues.borrow_mut();
+            let queue = queues.get_mut(&self.id)?;
+            update(queue);
+            queue.waker.take()
+        });
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl RustCallable for Forwarder {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        let args = args.iter().map(|&arg| arg.clone()).collect();
+        self.update_queue(|queue| queue.emissions.push_back(args));
+
+        Ok(Variant::nil())
+    }
+}
+
+impl Drop for Forwarder {
+    fn drop(&mut self) {
+        // The connection is gone, e.g. because the object was freed. `try_with` as this may run during thread teardown.
+        let _ = QUEUES.try_with(|_| self.update_queue(|queue| queue.is_closed = true));
+    }
+}
+
+impl fmt::Display for Forwarder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SignalStream#{}", self.id)
+    }
+}
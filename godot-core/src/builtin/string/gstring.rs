@@ -5,14 +5,23 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::{convert::Infallible, ffi::c_char, fmt, str::FromStr};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::Ordering,
+    convert::Infallible,
+    ffi::c_char,
+    fmt,
+    ops::{self, Bound, Range, RangeBounds},
+    str::FromStr,
+};
 
 use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::inner;
 use crate::builtin::meta::impl_godot_as_self;
+use crate::builtin::{inner, PackedStringArray};
 
 use super::string_chars::validate_unicode_scalar_sequence;
 use super::{NodePath, StringName};
@@ -63,6 +72,40 @@ impl GString {
         Self::default()
     }
 
+    /// Creates a string from format arguments, as produced by [`format_args!`]. Usually invoked through [`gformat!`](crate::gformat).
+    ///
+    /// Compared to `GString::from(format!(...))`, this does not allocate a new Rust `String` for each call; a thread-local buffer is reused
+    /// instead. Arguments without placeholders are passed to Godot directly.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        // Larger buffers are released after use, so that a single long message does not keep its memory alive.
+        const MAX_RETAINED_CAPACITY: usize = 4096;
+
+        thread_local! {
+            static BUFFER: RefCell<String> = RefCell::new(String::new());
+        }
+
+        if let Some(literal) = args.as_str() {
+            return Self::from(literal);
+        }
+
+        BUFFER.with(|buffer| {
+            // The buffer is already borrowed if a `Display` impl among the arguments uses `gformat!` itself.
+            let Ok(mut buffer) = buffer.try_borrow_mut() else {
+                return Self::from(fmt::format(args));
+            };
+
+            buffer.clear();
+            fmt::write(&mut *buffer, args)
+                .expect("a formatting trait implementation returned an error");
+            let result = Self::from(buffer.as_str());
+
+            if buffer.capacity() > MAX_RETAINED_CAPACITY {
+                *buffer = String::new();
+            }
+            result
+        })
+    }
+
     fn from_opaque(opaque: OpaqueString) -> Self {
         Self { opaque }
     }
@@ -83,6 +126,268 @@ impl GString {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Returns a copy that is safe to use as a file name, replacing characters not allowed in file names with `_`.
+    ///
+    /// Use this for user-provided names, e.g. of save slots or screenshots. Path separators are replaced as well, and the names `.` and
+    /// `..` become `_` and `__`, so the result cannot refer to another directory.
+    pub fn validate_filename(&self) -> GString {
+        let validated = self.as_inner().validate_filename();
+
+        // Godot keeps the special directory names as-is.
+        match validated.to_string().as_str() {
+            "." => GString::from("_"),
+            ".." => GString::from("__"),
+            _ => validated,
+        }
+    }
+
+    /// Returns a copy that is safe to use as a node name, replacing characters with special meaning in node paths (such as `/`, `:`, `.`,
+    /// `@`, `%` and `"`) with `_`.
+    ///
+    /// A node name produced this way can be used as a single segment of a [`NodePath`](crate::builtin::NodePath).
+    pub fn validate_node_name(&self) -> GString {
+        self.as_inner().validate_node_name()
+    }
+
+    /// Escapes the string for display in a `RichTextLabel` with BBCode enabled, so that text such as player names cannot inject tags.
+    ///
+    /// Square brackets are replaced with the `[lb]` and `[rb]` tags.
+    pub fn escape_bbcode(&self) -> GString {
+        let string = self.to_string();
+        if !string.contains(['[', ']']) {
+            return self.clone();
+        }
+
+        let mut escaped = String::with_capacity(string.len() + 8);
+        for c in string.chars() {
+            match c {
+                '[' => escaped.push_str("[lb]"),
+                ']' => escaped.push_str("[rb]"),
+                _ => escaped.push(c),
+            }
+        }
+
+        escaped.into()
+    }
+
+    /// Converts the string into a conventional translation key: ASCII letters and digits in upper case, separated by single `_`.
+    ///
+    /// For example, `"Start game!"` becomes `"START_GAME"`. Other characters act as separators, and leading/trailing ones are dropped.
+    /// This is useful to derive keys for `TranslationServer` from generated or user-facing names.
+    pub fn to_translation_key(&self) -> GString {
+        let mut key = String::with_capacity(self.len());
+        for word in self
+            .to_string()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            if !key.is_empty() {
+                key.push('_');
+            }
+            key.push_str(&word.to_ascii_uppercase());
+        }
+
+        key.into()
+    }
+
+    /// Appends `string` to the end.
+    pub fn push_str(&mut self, string: &str) {
+        if !string.is_empty() {
+            self.push_gstring(&GString::from(string));
+        }
+    }
+
+    /// Appends the character `c` to the end.
+    pub fn push(&mut self, c: char) {
+        // SAFETY: `self` is a valid, exclusively borrowed string; the operator modifies it in place.
+        unsafe {
+            interface_fn!(string_operator_plus_eq_char)(self.sys_mut() as _, c as u32);
+        }
+    }
+
+    /// Inserts `string` before the character at `index`.
+    ///
+    /// # Panics
+    /// If `index` is greater than the [length](Self::len).
+    pub fn insert(&mut self, index: usize, string: &str) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "GString::insert: index {index} out of bounds (len {len})"
+        );
+
+        *self = self.as_inner().insert(index as i64, string.into());
+    }
+
+    /// Replaces the characters in `range` with `replace_with`.
+    ///
+    /// Indices are counted in characters, like [`len()`](Self::len).
+    ///
+    /// # Panics
+    /// If the range is decreasing or extends past the end of the string.
+    pub fn replace_range(&mut self, range: impl RangeBounds<usize>, replace_with: &str) {
+        let Range { start, end } = self.char_range(range, "replace_range");
+
+        let inner = self.as_inner();
+        let mut result = inner.substr(0, start as i64);
+        result.push_str(replace_with);
+        result.push_gstring(&inner.substr(end as i64, -1));
+        *self = result;
+    }
+
+    fn push_gstring(&mut self, other: &GString) {
+        // SAFETY: `self` is a valid, exclusively borrowed string; `other` is valid and cannot alias `self`.
+        unsafe {
+            interface_fn!(string_operator_plus_eq_string)(
+                self.sys_mut() as _,
+                other.sys_const() as _,
+            );
+        }
+    }
+
+    /// Returns an iterator over the characters, reading Godot's internal UTF-32 buffer directly.
+    ///
+    /// Code points that are not valid `char`s (unpaired surrogates or out-of-range values, which Godot allows) are replaced with
+    /// [`char::REPLACEMENT_CHARACTER`]. For a slice, see [`chars_checked()`](Self::chars_checked).
+    pub fn chars(&self) -> impl DoubleEndedIterator<Item = char> + ExactSizeIterator + '_ {
+        self.code_points()
+            .iter()
+            .map(|&c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Converts to a Rust string, replacing invalid code points with [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// Decodes in a single pass on the Rust side, unlike `String::from()` which has Godot create an intermediate UTF-8 buffer. Returns
+    /// `Cow::Borrowed` for the empty string.
+    pub fn as_str_lossy(&self) -> Cow<'_, str> {
+        if self.is_empty() {
+            return Cow::Borrowed("");
+        }
+
+        let mut string = String::with_capacity(self.utf8_len());
+        string.extend(self.chars());
+        Cow::Owned(string)
+    }
+
+    /// Number of characters (Unicode code points); same as [`len()`](Self::len).
+    pub fn char_len(&self) -> usize {
+        self.code_points().len()
+    }
+
+    /// Number of bytes of the UTF-8 encoding, as in [`as_str_lossy()`](Self::as_str_lossy). Computed without encoding the string.
+    pub fn utf8_len(&self) -> usize {
+        self.chars().map(char::len_utf8).sum()
+    }
+
+    /// Returns `true` if the string starts with `prefix`, without converting either string.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        let mut chars = self.chars();
+        prefix.chars().all(|c| chars.next() == Some(c))
+    }
+
+    /// Returns `true` if the string ends with `suffix`, without converting either string.
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        let mut chars = self.chars().rev();
+        suffix.chars().rev().all(|c| chars.next() == Some(c))
+    }
+
+    /// Returns the characters in `range` as a new string.
+    ///
+    /// Indices are counted in characters, like [`len()`](Self::len), so slicing never splits a character.
+    ///
+    /// # Panics
+    /// If the range is decreasing or extends past the end of the string.
+    pub fn substr(&self, range: impl RangeBounds<usize>) -> GString {
+        let Range { start, end } = self.char_range(range, "substr");
+        self.as_inner().substr(start as i64, (end - start) as i64)
+    }
+
+    /// Returns the character at `index`, or `None` if out of bounds.
+    ///
+    /// Invalid code points are returned as [`char::REPLACEMENT_CHARACTER`], see [`chars()`](Self::chars).
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        self.chars().nth(index)
+    }
+
+    /// Returns the character index of the first occurrence of `pattern`, or `None` if not found.
+    ///
+    /// Searches the internal UTF-32 buffer directly. An empty pattern is found at index 0.
+    pub fn find(&self, pattern: &str) -> Option<usize> {
+        let pattern = to_code_points(pattern);
+        if pattern.is_empty() {
+            return Some(0);
+        }
+
+        self.code_points()
+            .windows(pattern.len())
+            .position(|window| window == pattern)
+    }
+
+    /// Returns the character index of the last occurrence of `pattern`, or `None` if not found.
+    ///
+    /// An empty pattern is found at index [`len()`](Self::len).
+    pub fn rfind(&self, pattern: &str) -> Option<usize> {
+        let pattern = to_code_points(pattern);
+        if pattern.is_empty() {
+            return Some(self.char_len());
+        }
+
+        self.code_points()
+            .windows(pattern.len())
+            .rposition(|window| window == pattern)
+    }
+
+    /// Splits the string at each occurrence of `delimiter`. Empty parts are kept, so the result has one more element than there are
+    /// delimiters.
+    ///
+    /// # Panics
+    /// If `delimiter` is empty.
+    pub fn split(&self, delimiter: &str) -> PackedStringArray {
+        assert!(
+            !delimiter.is_empty(),
+            "GString::split: delimiter must not be empty"
+        );
+
+        self.as_inner().split(delimiter.into(), true, 0)
+    }
+
+    /// Resolves `range` to character indices.
+    fn char_range(&self, range: impl RangeBounds<usize>, method: &str) -> Range<usize> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "GString::{method}: range {start}..{end} out of bounds (len {len})"
+        );
+
+        start..end
+    }
+
+    /// Godot's internal UTF-32 buffer, which may contain values that are not valid `char`s.
+    fn code_points(&self) -> &[u32] {
+        // SAFETY: Godot strings are stored contiguously as 32-bit code points; any `u32` value is valid to read.
+        unsafe {
+            let s = self.string_sys();
+            let len = interface_fn!(string_to_utf32_chars)(s, std::ptr::null_mut(), 0);
+            let ptr = interface_fn!(string_operator_index_const)(s, 0);
+
+            // Even when len == 0, from_raw_parts requires ptr != 0
+            if ptr.is_null() {
+                return &[];
+            }
+            std::slice::from_raw_parts(ptr, len as usize)
+        }
+    }
+
     /// Gets the internal chars slice from a [`GString`].
     ///
     /// Note: This operation is *O*(*n*). Consider using [`chars_unchecked`][Self::chars_unchecked]
@@ -144,6 +449,165 @@ impl GString {
     }
 }
 
+fn to_code_points(string: &str) -> Vec<u32> {
+    string.chars().map(u32::from).collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Wrappers around Godot's String methods
+
+impl GString {
+    /// Similarity to `other` between 0.0 (nothing in common) and 1.0 (equal), based on the
+    /// [Sørensen–Dice coefficient](https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient) of character pairs.
+    pub fn similarity(&self, other: &GString) -> f64 {
+        self.as_inner().similarity(other.clone())
+    }
+
+    /// Compares case-sensitively, in lexicographic order of code points.
+    #[doc(alias = "casecmp_to")]
+    pub fn case_cmp(&self, other: &GString) -> Ordering {
+        self.as_inner().casecmp_to(other.clone()).cmp(&0)
+    }
+
+    /// Compares case-insensitively, in lexicographic order of code points.
+    #[doc(alias = "nocasecmp_to")]
+    pub fn nocase_cmp(&self, other: &GString) -> Ordering {
+        self.as_inner().nocasecmp_to(other.clone()).cmp(&0)
+    }
+
+    /// Compares case-insensitively in natural order, where digit sequences are compared by value: `"file2"` comes before `"file10"`.
+    ///
+    /// Useful for sorting file names or other lists shown to users.
+    #[doc(alias = "naturalnocasecmp_to")]
+    pub fn natural_nocase_cmp(&self, other: &GString) -> Ordering {
+        self.as_inner().naturalnocasecmp_to(other.clone()).cmp(&0)
+    }
+
+    /// Parses the string as an integer, or returns `None` if it is not a valid integer (see `is_valid_int` in Godot).
+    #[doc(alias = "to_int")]
+    pub fn parse_int(&self) -> Option<i64> {
+        let inner = self.as_inner();
+        inner.is_valid_int().then(|| inner.to_int())
+    }
+
+    /// Parses the string as a float, or returns `None` if it is not a valid float (see `is_valid_float` in Godot).
+    #[doc(alias = "to_float")]
+    pub fn parse_float(&self) -> Option<f64> {
+        let inner = self.as_inner();
+        inner.is_valid_float().then(|| inner.to_float())
+    }
+
+    /// Returns `true` if the string is a valid identifier: only letters, digits and underscores, not starting with a digit.
+    pub fn is_valid_identifier(&self) -> bool {
+        self.as_inner().is_valid_identifier()
+    }
+
+    /// Returns `true` if the string is a valid file name, see also [`validate_filename()`](Self::validate_filename).
+    pub fn is_valid_filename(&self) -> bool {
+        self.as_inner().is_valid_filename()
+    }
+
+    /// Converts `"Some text"` or `"some_text"` to `"someText"`.
+    pub fn to_camel_case(&self) -> GString {
+        self.as_inner().to_camel_case()
+    }
+
+    /// Converts `"Some text"` or `"some_text"` to `"SomeText"`.
+    pub fn to_pascal_case(&self) -> GString {
+        self.as_inner().to_pascal_case()
+    }
+
+    /// Converts `"Some text"` or `"someText"` to `"some_text"`.
+    pub fn to_snake_case(&self) -> GString {
+        self.as_inner().to_snake_case()
+    }
+
+    /// Converts to a human-readable form like in the editor's inspector, e.g. `"move_speed"` to `"Move Speed"`.
+    pub fn capitalize(&self) -> GString {
+        self.as_inner().capitalize()
+    }
+
+    /// Normalizes a path: removes `.` segments, resolves `..` segments and collapses repeated `/`.
+    pub fn simplify_path(&self) -> GString {
+        self.as_inner().simplify_path()
+    }
+
+    /// Joins `file` to this path with a `/`, e.g. `"res://levels"` and `"one.tscn"` to `"res://levels/one.tscn"`.
+    pub fn path_join(&self, file: &GString) -> GString {
+        self.as_inner().path_join(file.clone())
+    }
+
+    /// The directory part of a path, e.g. `"res://levels"` for `"res://levels/one.tscn"`.
+    #[doc(alias = "get_base_dir")]
+    pub fn base_dir(&self) -> GString {
+        self.as_inner().get_base_dir()
+    }
+
+    /// The file part of a path, e.g. `"one.tscn"` for `"res://levels/one.tscn"`.
+    #[doc(alias = "get_file")]
+    pub fn file_name(&self) -> GString {
+        self.as_inner().get_file()
+    }
+
+    /// The path without its extension, e.g. `"res://levels/one"` for `"res://levels/one.tscn"`.
+    #[doc(alias = "get_basename")]
+    pub fn basename(&self) -> GString {
+        self.as_inner().get_basename()
+    }
+
+    /// The extension of a path without the dot, e.g. `"tscn"` for `"res://levels/one.tscn"`, or `None` if there is none.
+    #[doc(alias = "get_extension")]
+    pub fn extension(&self) -> Option<GString> {
+        let extension = self.as_inner().get_extension();
+        (!extension.is_empty()).then_some(extension)
+    }
+
+    /// Escapes special characters as in C string literals, e.g. a newline as `\n`.
+    pub fn c_escape(&self) -> GString {
+        self.as_inner().c_escape()
+    }
+
+    /// Reverses [`c_escape()`](Self::c_escape).
+    pub fn c_unescape(&self) -> GString {
+        self.as_inner().c_unescape()
+    }
+
+    /// Escapes special characters for use inside a JSON string.
+    pub fn json_escape(&self) -> GString {
+        self.as_inner().json_escape()
+    }
+
+    /// Escapes `&`, `<` and `>` for XML; with `escape_quotes`, also `"` and `'`.
+    pub fn xml_escape(&self, escape_quotes: bool) -> GString {
+        self.as_inner().xml_escape(escape_quotes)
+    }
+
+    /// Reverses [`xml_escape()`](Self::xml_escape).
+    pub fn xml_unescape(&self) -> GString {
+        self.as_inner().xml_unescape()
+    }
+
+    /// Percent-encodes the string for use in a URL.
+    pub fn uri_encode(&self) -> GString {
+        self.as_inner().uri_encode()
+    }
+
+    /// Decodes a percent-encoded URL string.
+    pub fn uri_decode(&self) -> GString {
+        self.as_inner().uri_decode()
+    }
+
+    /// MD5 hash of the UTF-8 encoding, as 32 hex digits.
+    pub fn md5_text(&self) -> GString {
+        self.as_inner().md5_text()
+    }
+
+    /// SHA-256 hash of the UTF-8 encoding, as 64 hex digits.
+    pub fn sha256_text(&self) -> GString {
+        self.as_inner().sha256_text()
+    }
+}
+
 // SAFETY:
 // - `move_return_ptr`
 //   Nothing special needs to be done beyond a `std::mem::swap` when returning a String.
@@ -206,6 +670,40 @@ impl fmt::Debug for GString {
     }
 }
 
+impl ops::AddAssign<&str> for GString {
+    fn add_assign(&mut self, rhs: &str) {
+        self.push_str(rhs)
+    }
+}
+
+impl ops::AddAssign<char> for GString {
+    fn add_assign(&mut self, rhs: char) {
+        self.push(rhs)
+    }
+}
+
+impl ops::AddAssign<&GString> for GString {
+    fn add_assign(&mut self, rhs: &GString) {
+        self.push_gstring(rhs)
+    }
+}
+
+impl ops::AddAssign<GString> for GString {
+    fn add_assign(&mut self, rhs: GString) {
+        self.push_gstring(&rhs)
+    }
+}
+
+/// Compares character by character, without converting either string.
+impl PartialEq<str> for GString {
+    fn eq(&self, other: &str) -> bool {
+        let mut chars = self.chars();
+        other.chars().all(|c| chars.next() == Some(c)) && chars.next().is_none()
+    }
+}
+
+impl_str_eq!(GString);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion from/into Rust string-types
 
@@ -359,3 +857,23 @@ mod serialize {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Creates a [`GString`] using interpolation of runtime expressions, like Rust's standard `format!` macro.
+///
+/// Avoids the intermediate `String` of `GString::from(format!(...))`, see [`GString::from_fmt()`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let hp = 42;
+/// let pos = Vector2::new(1.0, 2.0);
+/// let label: GString = gformat!("hp: {hp}, pos: {pos}");
+/// ```
+#[macro_export]
+macro_rules! gformat {
+    ($($args:tt)*) => {
+        $crate::builtin::GString::from_fmt(format_args!($($args)*))
+    };
+}
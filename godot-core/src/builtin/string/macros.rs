@@ -31,3 +31,26 @@ macro_rules! impl_rust_string_conv {
         }
     };
 }
+
+/// Implements comparisons with `&str` and the reverse comparisons with `str`/`&str`, based on an existing `PartialEq<str>` impl.
+macro_rules! impl_str_eq {
+    ($Ty:ty) => {
+        impl PartialEq<&str> for $Ty {
+            fn eq(&self, other: &&str) -> bool {
+                *self == **other
+            }
+        }
+
+        impl PartialEq<$Ty> for str {
+            fn eq(&self, other: &$Ty) -> bool {
+                *other == *self
+            }
+        }
+
+        impl PartialEq<$Ty> for &str {
+            fn eq(&self, other: &$Ty) -> bool {
+                *other == **self
+            }
+        }
+    };
+}
@@ -7,15 +7,17 @@
 
 //! Godot-types that are Strings.
 
-mod gstring;
+// Modules exporting declarative macros must appear first.
 mod macros;
+
+mod gstring;
 mod node_path;
 mod string_chars;
 mod string_name;
 
 pub use gstring::*;
-pub use node_path::NodePath;
-pub use string_name::{StringName, TransientStringNameOrd};
+pub use node_path::{__node_path_syntax_error, NodePath, NodePathError};
+pub use string_name::{LazyStringName, StringName, TransientStringNameOrd};
 
 use super::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
 
@@ -44,3 +46,31 @@ impl FromGodot for String {
         Ok(via.to_string())
     }
 }
+
+/// Non-UTF-8 paths are converted lossily, replacing invalid sequences with `U+FFFD`.
+impl GodotConvert for &std::path::Path {
+    type Via = GString;
+}
+
+impl ToGodot for &std::path::Path {
+    fn to_godot(&self) -> Self::Via {
+        GString::from(self.to_string_lossy().as_ref())
+    }
+}
+
+/// Non-UTF-8 paths are converted lossily, replacing invalid sequences with `U+FFFD`.
+impl GodotConvert for std::path::PathBuf {
+    type Via = GString;
+}
+
+impl ToGodot for std::path::PathBuf {
+    fn to_godot(&self) -> Self::Via {
+        self.as_path().to_godot()
+    }
+}
+
+impl FromGodot for std::path::PathBuf {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(Self::from(via.to_string()))
+    }
+}
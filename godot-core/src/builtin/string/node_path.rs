@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::error::Error;
 use std::fmt;
 
 use godot_ffi as sys;
@@ -26,10 +27,134 @@ impl NodePath {
         Self { opaque }
     }
 
+    /// Parses `path`, checking its syntax first.
+    ///
+    /// Unlike `NodePath::from()`, which accepts any string, this rejects paths with empty node names (`"A//B"`, `"A/"`) or empty
+    /// subnames (`"A::b"`, `"A:"`). To check the syntax of literals at compile time, use [`npath!`](crate::npath).
+    pub fn parse(path: &str) -> Result<Self, NodePathError> {
+        match find_syntax_error(path) {
+            Some(kind) => Err(NodePathError {
+                path: path.to_string(),
+                kind,
+            }),
+            None => Ok(Self::from(path)),
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.as_inner().is_empty()
     }
 
+    /// Returns `true` if the path starts at the scene tree root, i.e. begins with `/`.
+    pub fn is_absolute(&self) -> bool {
+        self.as_inner().is_absolute()
+    }
+
+    /// Number of node names in the path, e.g. 2 for `"Player/Sprite:texture"`.
+    pub fn name_count(&self) -> usize {
+        self.as_inner().get_name_count() as usize
+    }
+
+    /// Returns the node name at `index`, e.g. `"Sprite"` for index 1 in `"Player/Sprite:texture"`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn name(&self, index: usize) -> StringName {
+        let count = self.name_count();
+        assert!(
+            index < count,
+            "NodePath name index {index} out of bounds (count {count})"
+        );
+
+        self.as_inner().get_name(index as i64)
+    }
+
+    /// Returns all node names in the path.
+    pub fn names(&self) -> Vec<StringName> {
+        (0..self.name_count()).map(|i| self.name(i)).collect()
+    }
+
+    /// Number of subnames (property or resource names after `:`), e.g. 1 for `"Player/Sprite:texture"`.
+    pub fn subname_count(&self) -> usize {
+        self.as_inner().get_subname_count() as usize
+    }
+
+    /// Returns the subname at `index`, e.g. `"texture"` for index 0 in `"Player/Sprite:texture"`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn subname(&self, index: usize) -> StringName {
+        let count = self.subname_count();
+        assert!(
+            index < count,
+            "NodePath subname index {index} out of bounds (count {count})"
+        );
+
+        self.as_inner().get_subname(index as i64)
+    }
+
+    /// Returns all subnames in the path.
+    pub fn subnames(&self) -> Vec<StringName> {
+        (0..self.subname_count()).map(|i| self.subname(i)).collect()
+    }
+
+    /// Appends `path` to this path, like [`Path::join()`](std::path::Path::join).
+    ///
+    /// If `path` is absolute, it is returned unchanged. The result has the subnames of `path`.
+    ///
+    /// # Panics
+    /// If this path has subnames, since nodes cannot be nested below a property.
+    pub fn join(&self, path: &NodePath) -> NodePath {
+        if path.is_absolute() {
+            return path.clone();
+        }
+        assert_eq!(
+            self.subname_count(),
+            0,
+            "cannot join a path onto {self:?}, which has subnames"
+        );
+
+        let mut names = self.names();
+        names.extend(path.names());
+        Self::compose(self.is_absolute(), &names, &path.subnames())
+    }
+
+    /// Returns the path without its last node name and without subnames, or `None` if the path has no node names.
+    ///
+    /// For example, the parent of `"Player/Sprite:texture"` is `"Player"`, and the parent of `"Player"` is the empty path.
+    pub fn parent(&self) -> Option<NodePath> {
+        let mut names = self.names();
+        names.pop()?;
+
+        Some(Self::compose(self.is_absolute(), &names, &[]))
+    }
+
+    /// Returns the path with the node names turned into subnames, e.g. `":position:x"` for `"position:x"`.
+    ///
+    /// _Godot equivalent: `get_as_property_path`_
+    pub fn to_property_path(&self) -> NodePath {
+        self.as_inner().get_as_property_path()
+    }
+
+    fn compose(is_absolute: bool, names: &[StringName], subnames: &[StringName]) -> Self {
+        let mut path = String::new();
+        if is_absolute {
+            path.push('/');
+        }
+        for (i, name) in names.iter().enumerate() {
+            if i > 0 {
+                path.push('/');
+            }
+            path.push_str(&name.to_string());
+        }
+        for subname in subnames {
+            path.push(':');
+            path.push_str(&subname.to_string());
+        }
+
+        Self::from(path)
+    }
+
     /// Returns a 32-bit integer hash value representing the string.
     pub fn hash(&self) -> u32 {
         self.as_inner()
@@ -111,6 +236,15 @@ impl fmt::Debug for NodePath {
 
 impl_rust_string_conv!(NodePath);
 
+/// Converts to [`GString`] for the comparison, which allocates.
+impl PartialEq<str> for NodePath {
+    fn eq(&self, other: &str) -> bool {
+        GString::from(self) == *other
+    }
+}
+
+impl_str_eq!(NodePath);
+
 impl<S> From<S> for NodePath
 where
     S: AsRef<str>,
@@ -157,6 +291,136 @@ impl From<StringName> for NodePath {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Syntax validation
+
+/// Error when parsing a [`NodePath`] with invalid syntax, see [`NodePath::parse()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct NodePathError {
+    path: String,
+    kind: SyntaxErrorKind,
+}
+
+impl NodePathError {
+    /// The path that failed to parse.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for NodePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid node path \"{}\": {}",
+            self.path,
+            self.kind.description()
+        )
+    }
+}
+
+impl Error for NodePathError {}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum SyntaxErrorKind {
+    EmptyName,
+    EmptySubname,
+}
+
+impl SyntaxErrorKind {
+    const fn description(self) -> &'static str {
+        match self {
+            Self::EmptyName => "node names must not be empty",
+            Self::EmptySubname => "subnames must not be empty",
+        }
+    }
+}
+
+/// Checks the structure `[/]name/name/...[:subname:subname...]`; names themselves are not validated.
+const fn find_syntax_error(path: &str) -> Option<SyntaxErrorKind> {
+    let bytes = path.as_bytes();
+    let mut i = 0;
+    if i < bytes.len() && bytes[i] == b'/' {
+        i += 1;
+    }
+
+    let mut segment_len = 0;
+    let mut after_separator = false;
+    while i < bytes.len() && bytes[i] != b':' {
+        if bytes[i] == b'/' {
+            if segment_len == 0 {
+                return Some(SyntaxErrorKind::EmptyName);
+            }
+            segment_len = 0;
+            after_separator = true;
+        } else {
+            segment_len += 1;
+        }
+        i += 1;
+    }
+
+    if segment_len == 0 && after_separator {
+        return Some(SyntaxErrorKind::EmptyName);
+    }
+
+    if i < bytes.len() {
+        // Skip the first ':'; each subname must be non-empty, including the last one.
+        i += 1;
+        segment_len = 0;
+        while i < bytes.len() {
+            if bytes[i] == b':' {
+                if segment_len == 0 {
+                    return Some(SyntaxErrorKind::EmptySubname);
+                }
+                segment_len = 0;
+            } else {
+                segment_len += 1;
+            }
+            i += 1;
+        }
+
+        if segment_len == 0 {
+            return Some(SyntaxErrorKind::EmptySubname);
+        }
+    }
+
+    None
+}
+
+/// Used by [`npath!`](crate::npath) to validate literals at compile time.
+#[doc(hidden)]
+pub const fn __node_path_syntax_error(path: &str) -> Option<&'static str> {
+    match find_syntax_error(path) {
+        Some(kind) => Some(kind.description()),
+        None => None,
+    }
+}
+
+/// Creates a [`NodePath`] from a string literal, checking its syntax at compile time.
+///
+/// See [`NodePath::parse()`] for the rules; an invalid path is a compile error.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let sprite_texture: NodePath = npath!("Player/Sprite:texture");
+/// ```
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// let invalid = npath!("Player//Sprite");
+/// ```
+#[macro_export]
+macro_rules! npath {
+    ($path:literal) => {{
+        const _: () = if let Some(error) = $crate::builtin::strings::__node_path_syntax_error($path)
+        {
+            panic!("{}", error)
+        };
+        $crate::builtin::NodePath::from($path)
+    }};
+}
+
 #[cfg(feature = "serde")]
 mod serialize {
     use super::*;
@@ -6,6 +6,7 @@
  */
 
 use std::fmt;
+use std::sync::OnceLock;
 
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
@@ -19,13 +20,16 @@ use crate::builtin::{GString, NodePath};
 /// StringNames are immutable strings designed for representing unique names. StringName ensures that only
 /// one instance of a given name exists.
 ///
-/// # Ordering
+/// # Hashing and ordering
 ///
-/// In Godot, `StringName`s are **not** ordered lexicographically, and the ordering relation is **not** stable across multiple runs of your
-/// application. Therefore, this type does not implement `PartialOrd` and `Ord`, as it would be very easy to introduce bugs by accidentally
-/// relying on lexicographical ordering.
+/// Since equal names share one interned instance, `Hash` and `Ord` are based on the identity of that instance. Both are *O*(1) and do not
+/// call into Godot, which makes `StringName` well suited as a key in `HashMap` and `BTreeMap`, e.g. for method or input action tables.
 ///
-/// Instead, we provide [`transient_ord()`][Self::transient_ord] for ordering relations.
+/// However, the order is **not** lexicographic, and neither order nor hash values are **stable** across multiple runs of your application.
+/// Do not persist them, and do not rely on the iteration order of a `BTreeMap<StringName, T>` for anything shown to users. For
+/// lexicographic ordering, convert to `GString` (significantly slower).
+///
+/// The Godot hash of the string contents is available through [`hash()`][Self::hash].
 #[repr(C)]
 pub struct StringName {
     opaque: sys::types::OpaqueStringName,
@@ -111,6 +115,12 @@ impl StringName {
         TransientStringNameOrd(self)
     }
 
+    /// Address of the interned name data, which is the same for all equal names (null for the empty name).
+    fn interned_ptr(&self) -> usize {
+        // SAFETY: A StringName consists of a single pointer to its interned data, which `sys()` points to.
+        unsafe { *(self.sys() as *const usize) }
+    }
+
     ffi_methods! {
         type sys::GDExtensionStringNamePtr = *mut Opaque;
 
@@ -173,9 +183,27 @@ impl_builtin_traits! {
         Clone => string_name_construct_copy;
         Drop => string_name_destroy;
         Eq => string_name_operator_equal;
-        // Do not provide PartialOrd or Ord. Even though Godot provides a `operator <`, it is non-lexicographic and non-deterministic
-        // (based on pointers). See transient_ord() method.
-        Hash;
+        // Hash and Ord are implemented manually, based on the interned pointer.
+    }
+}
+
+/// Hashes the identity of the interned name, see [type-level docs](StringName#hashing-and-ordering).
+impl std::hash::Hash for StringName {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.interned_ptr().hash(state)
+    }
+}
+
+/// Non-lexicographic and non-stable order, see [type-level docs](StringName#hashing-and-ordering).
+impl PartialOrd for StringName {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringName {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.interned_ptr().cmp(&other.interned_ptr())
     }
 }
 
@@ -206,6 +234,15 @@ unsafe impl Send for StringName {}
 
 impl_rust_string_conv!(StringName);
 
+/// Converts to [`GString`] for the comparison, which allocates.
+impl PartialEq<str> for StringName {
+    fn eq(&self, other: &str) -> bool {
+        GString::from(self) == *other
+    }
+}
+
+impl_str_eq!(StringName);
+
 impl<S> From<S> for StringName
 where
     S: AsRef<str>,
@@ -321,6 +358,79 @@ impl<'a> Ord for TransientStringNameOrd<'a> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Interning
+
+/// A `StringName` that is created on first access and reused afterwards, typically stored in a `static`.
+///
+/// Constructing a `StringName` from a string hashes it and looks it up in Godot's global name table. For names that are used repeatedly,
+/// e.g. signal or method names passed each frame, it is cheaper to construct them once. The [`sname!`](crate::sname) macro declares such
+/// a static inline.
+///
+/// ```no_run
+/// use godot::builtin::strings::LazyStringName;
+///
+/// static JUMP: LazyStringName = LazyStringName::new("jump");
+///
+/// fn is_jump(action: &godot::builtin::StringName) -> bool {
+///     action == JUMP.get()
+/// }
+/// ```
+///
+/// Access is thread-safe. The name must not be accessed before the engine is initialized. It is never destroyed, so it must also not be
+/// used across a re-initialization of the library (e.g. hot reloading).
+pub struct LazyStringName {
+    name: &'static str,
+    cell: OnceLock<StringName>,
+}
+
+impl LazyStringName {
+    /// Declares a name without creating it yet.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            cell: OnceLock::new(),
+        }
+    }
+
+    /// Returns the name, creating it on first access.
+    pub fn get(&self) -> &StringName {
+        self.cell.get_or_init(|| StringName::from(self.name))
+    }
+
+    /// The string from which the name is created.
+    pub fn as_str(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Debug for LazyStringName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyStringName").field(&self.name).finish()
+    }
+}
+
+/// Returns a [`StringName`] for a string literal, creating it only once per call site.
+///
+/// Declares a [`LazyStringName`](crate::builtin::strings::LazyStringName) static and returns a clone of its value, which only increments a
+/// reference count. Use this instead of `StringName::from("...")` in code that runs often.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn on_hit(mut node: Gd<Node>) {
+/// node.emit_signal(sname!("hit"), &[]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! sname {
+    ($name:literal) => {{
+        static NAME: $crate::builtin::strings::LazyStringName =
+            $crate::builtin::strings::LazyStringName::new($name);
+        NAME.get().clone()
+    }};
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // serde support
 
@@ -346,6 +346,42 @@ impl Mul<Rect2> for Transform2D {
     }
 }
 
+impl Mul<Transform2D> for Vector2 {
+    type Output = Vector2;
+
+    /// Inverse-transforms this vector by `rhs`, under the assumption that the basis of `rhs` is orthonormal (rotation and
+    /// translation only). For transforms with scale or skew, use `rhs.affine_inverse() * self` instead.
+    ///
+    /// Matches GDScript's `vector * transform`.
+    fn mul(self, rhs: Transform2D) -> Self::Output {
+        let v = self - rhs.origin;
+
+        Vector2::new(rhs.a.dot(v), rhs.b.dot(v))
+    }
+}
+
+impl Mul<Transform2D> for Rect2 {
+    type Output = Rect2;
+
+    /// Inverse-transforms each of the four corners of this rectangle by `rhs` (see `Vector2 * Transform2D`), then creates a
+    /// `Rect2` containing all of them.
+    ///
+    /// Matches GDScript's `rect * transform`.
+    fn mul(self, rhs: Transform2D) -> Self::Output {
+        let end = self.end();
+        let corners = [
+            Vector2::new(self.position.x, end.y),
+            Vector2::new(end.x, self.position.y),
+            end,
+        ];
+
+        corners.into_iter().fold(
+            Rect2::new(self.position * rhs, Vector2::ZERO),
+            |rect, corner| rect.expand(corner * rhs),
+        )
+    }
+}
+
 impl ApproxEq for Transform2D {
     /// Returns if the two transforms are approximately equal, by comparing each component separately.
     #[inline]
@@ -385,7 +421,7 @@ unsafe impl GodotFfi for Transform2D {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
 }
 
-impl_godot_as_self!(Transform2D);
+impl_godot_as_self!(Transform2D; check_finite);
 
 /// A 2x2 matrix, typically used as an orthogonal basis for [`Transform2D`].
 ///
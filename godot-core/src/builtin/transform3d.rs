@@ -288,17 +288,33 @@ impl From<Basis> for Transform3D {
 impl Mul for Transform3D {
     type Output = Self;
 
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: Self) -> Self::Output {
         self.glam2(&rhs, |a, b| a * b)
     }
+
+    // Composed from the `Basis` and `Vector3` operations, which use SIMD types with this feature.
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.basis * rhs.basis,
+            self.basis * rhs.origin + self.origin,
+        )
+    }
 }
 
 impl Mul<Vector3> for Transform3D {
     type Output = Vector3;
 
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: Vector3) -> Self::Output {
         self.glam2(&rhs, |t, v| t.transform_point3(v))
     }
+
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.basis * rhs + self.origin
+    }
 }
 
 impl Mul<real> for Transform3D {
@@ -352,6 +368,60 @@ impl Mul<Plane> for Transform3D {
     }
 }
 
+impl Mul<Transform3D> for Vector3 {
+    type Output = Vector3;
+
+    /// Inverse-transforms this vector by `rhs`, under the assumption that the basis of `rhs` is orthonormal (rotation and
+    /// translation only). For transforms with scale or shear, use `rhs.affine_inverse() * self` instead.
+    ///
+    /// Matches GDScript's `vector * transform`.
+    fn mul(self, rhs: Transform3D) -> Self::Output {
+        rhs.basis.transposed() * (self - rhs.origin)
+    }
+}
+
+impl Mul<Transform3D> for Aabb {
+    type Output = Aabb;
+
+    /// Inverse-transforms each of the eight corners of this box by `rhs` (see `Vector3 * Transform3D`), then creates an
+    /// `Aabb` containing all of them.
+    ///
+    /// Matches GDScript's `aabb * transform`.
+    fn mul(self, rhs: Transform3D) -> Self::Output {
+        let start = self.position;
+        let end = self.end();
+
+        let corner = |i: usize| {
+            let pick = |bit: usize, min: real, max: real| if i & bit == 0 { min } else { max };
+            let point = Vector3::new(
+                pick(1, start.x, end.x),
+                pick(2, start.y, end.y),
+                pick(4, start.z, end.z),
+            );
+
+            point * rhs
+        };
+
+        (1..8).fold(Aabb::new(corner(0), Vector3::ZERO), |aabb, i| {
+            aabb.expand(corner(i))
+        })
+    }
+}
+
+impl Mul<Transform3D> for Plane {
+    type Output = Plane;
+
+    /// Inverse-transforms this plane by `rhs`, under the assumption that the basis of `rhs` is orthonormal.
+    ///
+    /// Matches GDScript's `plane * transform`.
+    fn mul(self, rhs: Transform3D) -> Self::Output {
+        let point = rhs.affine_inverse() * (self.normal * self.d);
+        let normal = (rhs.basis.transposed() * self.normal).normalized();
+
+        Plane::from_point_normal(point, normal)
+    }
+}
+
 impl ApproxEq for Transform3D {
     /// Returns if the two transforms are approximately equal, by comparing `basis` and `origin` separately.
     fn approx_eq(&self, other: &Self) -> bool {
@@ -392,7 +462,7 @@ unsafe impl GodotFfi for Transform3D {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
 }
 
-impl_godot_as_self!(Transform3D);
+impl_godot_as_self!(Transform3D; check_finite);
 
 #[cfg(test)]
 mod test {
@@ -409,6 +479,39 @@ mod test {
         Vector3::new(10.0, 11.0, 12.0),
     );
 
+    /// Reference implementation of `t * v` without glam, using plain scalar arithmetic.
+    fn xform_scalar(t: Transform3D, v: Vector3) -> Vector3 {
+        let row = |i: usize| {
+            let r = t.basis.rows[i];
+            r.x * v.x + r.y * v.y + r.z * v.z
+        };
+
+        Vector3::new(row(0), row(1), row(2)) + t.origin
+    }
+
+    #[test]
+    fn mul_matches_scalar() {
+        let other = Transform3D::new(
+            Basis::from_cols(
+                Vector3::new(-1.0, 0.5, 2.0),
+                Vector3::new(3.0, -2.0, 0.0),
+                Vector3::new(0.25, 4.0, -3.0),
+            ),
+            Vector3::new(-5.0, 6.0, 0.5),
+        );
+        let v = Vector3::new(2.0, -3.0, 0.5);
+
+        assert_eq!(DUMMY_TRANSFORM * v, xform_scalar(DUMMY_TRANSFORM, v));
+
+        // Composition must match applying both transforms in sequence.
+        let composed = DUMMY_TRANSFORM * other;
+        assert_eq!(
+            composed * v,
+            xform_scalar(DUMMY_TRANSFORM, xform_scalar(other, v))
+        );
+        assert_eq!(composed.origin, xform_scalar(DUMMY_TRANSFORM, other.origin));
+    }
+
     #[test]
     fn translation() {
         let offset = Vector3::new(1.0, 2.0, 3.0);
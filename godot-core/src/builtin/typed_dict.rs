@@ -0,0 +1,252 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::dictionary::{TypedIter, TypedKeys};
+use crate::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+use crate::builtin::Dictionary;
+use crate::property::{Export, PropertyHintInfo, Var};
+
+/// A [`Dictionary`] whose keys are all of type `K` and whose values are all of type `V`.
+///
+/// Godot dictionaries are untyped, so this is a Rust-side view: keys and values are converted on every access, and the contents are
+/// validated once when converting from an untyped dictionary (e.g. in [`try_from_dictionary()`](Self::try_from_dictionary) or when
+/// receiving the dictionary from Godot). Use it for dictionaries that act as homogeneous maps.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// let mut scores = TypedDict::<GString, i64>::new();
+/// scores.insert("alice".into(), 3);
+/// scores.insert("bob".into(), 5);
+///
+/// assert_eq!(scores.get(&"bob".into()), Some(5));
+/// let total: i64 = scores.iter_shared().map(|(_name, score)| score).sum();
+/// assert_eq!(total, 8);
+/// ```
+///
+/// Like `Dictionary`, this type has reference semantics: clones refer to the same underlying data. Godot code holding the same dictionary
+/// can still insert entries of other types; accessing such entries from Rust panics, or returns an error with the `try_` methods.
+pub struct TypedDict<K, V> {
+    inner: Dictionary,
+    _types: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V> TypedDict<K, V> {
+    /// Constructs an empty dictionary.
+    pub fn new() -> Self {
+        Self::from_unchecked(Dictionary::new())
+    }
+
+    fn from_unchecked(inner: Dictionary) -> Self {
+        Self {
+            inner,
+            _types: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Returns the underlying untyped dictionary.
+    pub fn as_dictionary(&self) -> &Dictionary {
+        &self.inner
+    }
+
+    /// Converts into the underlying untyped dictionary.
+    pub fn into_dictionary(self) -> Dictionary {
+        self.inner
+    }
+
+    /// Returns a shallow copy, see [`Dictionary::duplicate_shallow()`].
+    pub fn duplicate_shallow(&self) -> Self {
+        Self::from_unchecked(self.inner.duplicate_shallow())
+    }
+
+    /// Returns a deep copy, see [`Dictionary::duplicate_deep()`].
+    pub fn duplicate_deep(&self) -> Self {
+        Self::from_unchecked(self.inner.duplicate_deep())
+    }
+}
+
+impl<K: FromGodot, V: FromGodot> TypedDict<K, V> {
+    /// Wraps `dictionary`, checking that all keys convert to `K` and all values to `V`.
+    ///
+    /// The dictionary is not copied; the result refers to the same data.
+    pub fn try_from_dictionary(dictionary: Dictionary) -> Result<Self, ConvertError> {
+        for (key, value) in dictionary.iter_shared() {
+            K::try_from_variant(&key)?;
+            V::try_from_variant(&value)?;
+        }
+
+        Ok(Self::from_unchecked(dictionary))
+    }
+
+    /// Returns an iterator over the entries, converted to `(K, V)`.
+    ///
+    /// See [`Dictionary::iter_shared()`] for the semantics of iterating while the dictionary is modified.
+    ///
+    /// # Panics
+    /// If an entry cannot be converted, which is only possible if it was inserted through another reference to the dictionary.
+    pub fn iter_shared(&self) -> TypedIter<'_, K, V> {
+        self.inner.iter_shared().typed()
+    }
+
+    /// Returns an iterator over the keys, converted to `K`.
+    ///
+    /// # Panics
+    /// If a key cannot be converted, see [`iter_shared()`](Self::iter_shared).
+    pub fn keys_shared(&self) -> TypedKeys<'_, K> {
+        self.inner.keys_shared().typed()
+    }
+}
+
+impl<K: ToGodot, V: FromGodot> TypedDict<K, V> {
+    /// Returns the value for `key`, or `None` if absent.
+    ///
+    /// # Panics
+    /// If the value cannot be converted to `V`. Use [`try_get()`](Self::try_get) to handle this case.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.try_get(key).unwrap_or_else(|err| {
+            panic!(
+                "TypedDict: value cannot be converted to {}: {err}",
+                std::any::type_name::<V>()
+            )
+        })
+    }
+
+    /// Returns the value for `key`, or `Ok(None)` if absent. Fails if the value cannot be converted to `V`.
+    pub fn try_get(&self, key: &K) -> Result<Option<V>, ConvertError> {
+        self.inner
+            .get(key.to_variant())
+            .map(|value| V::try_from_variant(&value))
+            .transpose()
+    }
+
+    /// Removes the entry for `key` and returns its value, or `None` if absent.
+    ///
+    /// # Panics
+    /// If the removed value cannot be converted to `V`.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.inner.remove(key.to_variant()).map(|value| value.to())
+    }
+}
+
+impl<K: ToGodot, V: ToGodot + FromGodot> TypedDict<K, V> {
+    /// Inserts `value` under `key` and returns the previous value, if any.
+    ///
+    /// # Panics
+    /// If the previous value cannot be converted to `V`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.inner
+            .insert(key.to_variant(), value.to_variant())
+            .map(|old| old.to())
+    }
+}
+
+impl<K: ToGodot, V> TypedDict<K, V> {
+    /// Returns `true` if the dictionary contains `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.inner.contains_key(key.to_variant())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Traits
+
+/// Creates a new reference to the same data, like [`Dictionary::clone()`].
+impl<K, V> Clone for TypedDict<K, V> {
+    fn clone(&self) -> Self {
+        Self::from_unchecked(self.inner.clone())
+    }
+}
+
+impl<K, V> Default for TypedDict<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> PartialEq for TypedDict<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K, V> fmt::Debug for TypedDict<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<K, V> fmt::Display for TypedDict<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<K: ToGodot, V: ToGodot> Extend<(K, V)> for TypedDict<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for TypedDict<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::from_unchecked(Dictionary::from_iter(iter))
+    }
+}
+
+impl<K, V> GodotConvert for TypedDict<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K, V> ToGodot for TypedDict<K, V> {
+    fn to_godot(&self) -> Self::Via {
+        self.inner.clone()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.inner
+    }
+}
+
+impl<K: FromGodot, V: FromGodot> FromGodot for TypedDict<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_dictionary(via)
+    }
+}
+
+impl<K: FromGodot, V: FromGodot> Var for TypedDict<K, V> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value)
+    }
+}
+
+impl<K: FromGodot, V: FromGodot> Export for TypedDict<K, V> {
+    fn default_export_info() -> PropertyHintInfo {
+        PropertyHintInfo::with_hint_none("Dictionary")
+    }
+}
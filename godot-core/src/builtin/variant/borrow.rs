@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{interface_fn, GodotFfi};
+
+use crate::builtin::meta::{ConvertError, FromVariantError, GodotType};
+use crate::builtin::*;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Builtin types that a [`Variant`] can lend by reference, see [`Variant::try_to_ref()`].
+///
+/// Implemented for types that are stored inside the variant in their native representation: strings, arrays, dictionaries, packed arrays,
+/// callables and signals. Small `Copy` types like `i64` or `Vector2` are cheap to convert with [`Variant::try_to()`] instead.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait VariantBorrow: GodotFfi + private::Sealed {
+    /// Additional check after the variant type matched, e.g. for the element type of arrays.
+    #[doc(hidden)]
+    fn check_borrowed(&self) -> Result<(), ConvertError> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_variant_borrow {
+    ($( $Ty:ty ),* $(,)?) => {
+        $(
+            impl private::Sealed for $Ty {}
+            impl VariantBorrow for $Ty {}
+        )*
+    };
+}
+
+impl_variant_borrow!(
+    GString,
+    StringName,
+    NodePath,
+    Callable,
+    Signal,
+    Dictionary,
+    PackedByteArray,
+    PackedInt32Array,
+    PackedInt64Array,
+    PackedFloat32Array,
+    PackedFloat64Array,
+    PackedStringArray,
+    PackedVector2Array,
+    PackedVector3Array,
+    PackedColorArray,
+);
+
+impl<T: GodotType> private::Sealed for Array<T> {}
+impl<T: GodotType> VariantBorrow for Array<T> {
+    fn check_borrowed(&self) -> Result<(), ConvertError> {
+        self.check_type()
+    }
+}
+
+impl Variant {
+    /// Borrows the value of type `T` stored in this variant, without copying it or touching its reference count.
+    ///
+    /// Unlike [`try_to()`](Self::try_to), this does not create a new `GString`, `Array`, etc. This is useful in hot code paths that only
+    /// need to inspect a variant. The reference is tied to the variant's lifetime; clone it to keep the value beyond that.
+    ///
+    /// Like `try_to()`, this fails if the variant holds another type. No conversions take place, e.g. a `StringName` variant cannot be
+    /// borrowed as `GString`, and an untyped array cannot be borrowed as `Array<i64>`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn count_items(value: &Variant) -> usize {
+    ///     match value.try_to_ref::<VariantArray>() {
+    ///         Ok(array) => array.len(),
+    ///         Err(_) => 0,
+    ///     }
+    /// }
+    /// ```
+    pub fn try_to_ref<T: VariantBorrow>(&self) -> Result<&T, ConvertError> {
+        let expected = T::variant_type();
+        if self.sys_type() != expected.sys() {
+            return Err(FromVariantError::BadType {
+                expected,
+                got: self.get_type(),
+            }
+            .into_error(self));
+        }
+
+        // SAFETY: the variant holds a value of type `T`. For the types implementing `VariantBorrow`, the internal pointer points to the
+        // engine's representation of that value, which has the same layout as `T`. The value lives as long as the variant is not modified,
+        // which the shared borrow of `self` guarantees.
+        let value = unsafe {
+            let getter = interface_fn!(get_variant_get_internal_ptr_func)(expected.sys())
+                .expect("internal pointer getter for builtin type");
+
+            &*(getter(self.var_sys()) as *const T)
+        };
+
+        value.check_borrowed()?;
+        Ok(value)
+    }
+}
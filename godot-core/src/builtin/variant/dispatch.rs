@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+use crate::engine::{utilities, Object};
+use crate::obj::Gd;
+
+macro_rules! impl_variant_dispatch {
+    ($( $Name:ident($Ty:ty), )*) => {
+        /// Contents of a [`Variant`], as a Rust enum with one case per [`VariantType`].
+        ///
+        /// Obtained through [`Variant::dispatch()`]. This allows exhaustive `match` over the possible types, instead of chaining
+        /// `get_type()` checks and `try_to()` calls:
+        ///
+        /// ```no_run
+        /// use godot::prelude::*;
+        ///
+        /// fn describe(value: &Variant) -> String {
+        ///     match value.dispatch() {
+        ///         VariantDispatch::Nil => "nothing".to_string(),
+        ///         VariantDispatch::Int(int) => format!("integer {int}"),
+        ///         VariantDispatch::String(string) => format!("string {string}"),
+        ///         VariantDispatch::Object(object) => format!("object of class {}", object.get_class()),
+        ///         _ => format!("other: {value}"),
+        ///     }
+        /// }
+        /// ```
+        ///
+        /// Each case holds the value converted to its Rust type; values with reference semantics (`Array`, `Dictionary`, objects)
+        /// still refer to the same underlying data. A variant holding a null object dispatches to `Nil`, consistent with
+        /// [`Variant::get_type()`]. A variant holding a freed object dispatches to `Nil` as well, even though its type is still
+        /// reported as `Object`.
+        #[derive(Clone, Debug)]
+        pub enum VariantDispatch {
+            Nil,
+            $( $Name($Ty), )*
+        }
+
+        impl VariantDispatch {
+            /// The type of the contained value.
+            pub fn variant_type(&self) -> VariantType {
+                match self {
+                    Self::Nil => VariantType::Nil,
+                    $( Self::$Name(_) => VariantType::$Name, )*
+                }
+            }
+        }
+
+        impl Variant {
+            /// Converts this variant into an enum of its possible contents, for exhaustive matching.
+            ///
+            /// See [`VariantDispatch`] for details.
+            pub fn dispatch(&self) -> VariantDispatch {
+                match self.get_type() {
+                    VariantType::Nil => VariantDispatch::Nil,
+                    // Freed objects cannot be converted to `Gd`.
+                    VariantType::Object if !utilities::is_instance_valid(self.clone()) => VariantDispatch::Nil,
+                    $( VariantType::$Name => VariantDispatch::$Name(self.to::<$Ty>()), )*
+                }
+            }
+        }
+
+        impl From<&Variant> for VariantDispatch {
+            fn from(variant: &Variant) -> Self {
+                variant.dispatch()
+            }
+        }
+
+        impl From<VariantDispatch> for Variant {
+            fn from(dispatch: VariantDispatch) -> Self {
+                match dispatch {
+                    VariantDispatch::Nil => Variant::nil(),
+                    $( VariantDispatch::$Name(value) => value.to_variant(), )*
+                }
+            }
+        }
+    };
+}
+
+impl_variant_dispatch!(
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(GString),
+    Vector2(Vector2),
+    Vector2i(Vector2i),
+    Rect2(Rect2),
+    Rect2i(Rect2i),
+    Vector3(Vector3),
+    Vector3i(Vector3i),
+    Transform2D(Transform2D),
+    Vector4(Vector4),
+    Vector4i(Vector4i),
+    Plane(Plane),
+    Quaternion(Quaternion),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform3D(Transform3D),
+    Projection(Projection),
+    Color(Color),
+    StringName(StringName),
+    NodePath(NodePath),
+    Rid(Rid),
+    Object(Gd<Object>),
+    Callable(Callable),
+    Signal(Signal),
+    Dictionary(Dictionary),
+    Array(VariantArray),
+    PackedByteArray(PackedByteArray),
+    PackedInt32Array(PackedInt32Array),
+    PackedInt64Array(PackedInt64Array),
+    PackedFloat32Array(PackedFloat32Array),
+    PackedFloat64Array(PackedFloat64Array),
+    PackedStringArray(PackedStringArray),
+    PackedVector2Array(PackedVector2Array),
+    PackedVector3Array(PackedVector3Array),
+    PackedColorArray(PackedColorArray),
+);
@@ -64,6 +64,34 @@ macro_rules! impl_ffi_variant {
             }
         }
 
+        impl_ffi_variant!(@godot_type $T $(, $godot_type_name)?);
+    };
+
+    // Types stored inline in a variant are converted without calling into Godot, see Variant::from_inline().
+    (@inline $T:ty, $variant_type:ident $(; $godot_type_name:ident)?) => {
+        impl GodotFfiVariant for $T {
+            fn ffi_to_variant(&self) -> Variant {
+                // SAFETY: $T has the layout of Godot's inline type $variant_type.
+                unsafe { Variant::from_inline(sys::$variant_type, *self) }
+            }
+
+            fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+                // SAFETY: see above.
+                match unsafe { variant.to_inline(sys::$variant_type) } {
+                    Some(value) => Ok(value),
+                    None => Err(FromVariantError::BadType {
+                        expected: Self::variant_type(),
+                        got: variant.get_type(),
+                    }
+                    .into_error(variant)),
+                }
+            }
+        }
+
+        impl_ffi_variant!(@godot_type $T $(, $godot_type_name)?);
+    };
+
+    (@godot_type $T:ty $(, $godot_type_name:ident)?) => {
         impl GodotType for $T {
             type Ffi = Self;
 
@@ -105,15 +133,15 @@ mod impls {
     use super::*;
 
     impl_ffi_variant!(Aabb, aabb_to_variant, aabb_from_variant; AABB);
-    impl_ffi_variant!(bool, bool_to_variant, bool_from_variant);
+    impl_ffi_variant!(@inline bool, GDEXTENSION_VARIANT_TYPE_BOOL);
     impl_ffi_variant!(Basis, basis_to_variant, basis_from_variant);
     impl_ffi_variant!(Callable, callable_to_variant, callable_from_variant);
-    impl_ffi_variant!(Vector2, vector2_to_variant, vector2_from_variant);
-    impl_ffi_variant!(Vector3, vector3_to_variant, vector3_from_variant);
-    impl_ffi_variant!(Vector4, vector4_to_variant, vector4_from_variant);
-    impl_ffi_variant!(Vector2i, vector2i_to_variant, vector2i_from_variant);
-    impl_ffi_variant!(Vector3i, vector3i_to_variant, vector3i_from_variant);
-    impl_ffi_variant!(Vector4i, vector4i_to_variant, vector4i_from_variant);
+    impl_ffi_variant!(@inline Vector2, GDEXTENSION_VARIANT_TYPE_VECTOR2);
+    impl_ffi_variant!(@inline Vector3, GDEXTENSION_VARIANT_TYPE_VECTOR3);
+    impl_ffi_variant!(@inline Vector4, GDEXTENSION_VARIANT_TYPE_VECTOR4);
+    impl_ffi_variant!(@inline Vector2i, GDEXTENSION_VARIANT_TYPE_VECTOR2I);
+    impl_ffi_variant!(@inline Vector3i, GDEXTENSION_VARIANT_TYPE_VECTOR3I);
+    impl_ffi_variant!(@inline Vector4i, GDEXTENSION_VARIANT_TYPE_VECTOR4I);
     impl_ffi_variant!(Quaternion, quaternion_to_variant, quaternion_from_variant);
     impl_ffi_variant!(Color, color_to_variant, color_from_variant);
     impl_ffi_variant!(GString, string_to_variant, string_from_variant; String);
@@ -137,8 +165,8 @@ mod impls {
     impl_ffi_variant!(Transform2D, transform_2d_to_variant, transform_2d_from_variant);
     impl_ffi_variant!(Transform3D, transform_3d_to_variant, transform_3d_from_variant);
     impl_ffi_variant!(Dictionary, dictionary_to_variant, dictionary_from_variant);
-    impl_ffi_variant!(i64, int_to_variant, int_from_variant; int);
-    impl_ffi_variant!(f64, float_to_variant, float_from_variant; float);
+    impl_ffi_variant!(@inline i64, GDEXTENSION_VARIANT_TYPE_INT; int);
+    impl_ffi_variant!(@inline f64, GDEXTENSION_VARIANT_TYPE_FLOAT; float);
     
 }
 
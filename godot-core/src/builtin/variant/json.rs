@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json::{Map, Number, Value};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+
+/// Conversions between `Variant` and [`serde_json::Value`].
+///
+/// Unlike the `serde` representation of `Variant`, these conversions produce plain JSON, as exchanged with web APIs. Type information
+/// that JSON cannot express is lost, so only a subset of variants round-trips.
+impl Variant {
+    /// Converts a JSON value to a variant.
+    ///
+    /// | JSON                | Variant                                                                     |
+    /// |---------------------|-----------------------------------------------------------------------------|
+    /// | `null`              | nil                                                                         |
+    /// | `true`, `false`     | `bool`                                                                      |
+    /// | number              | `int` if representable as `i64`, otherwise `float` (including `1.0`)        |
+    /// | string              | `String` ([`GString`])                                                      |
+    /// | array               | `Array` ([`VariantArray`]), converting elements recursively                 |
+    /// | object              | [`Dictionary`] with `String` keys, converting values recursively            |
+    pub fn from_json_value(value: &Value) -> Variant {
+        match value {
+            Value::Null => Variant::nil(),
+            Value::Bool(b) => b.to_variant(),
+            Value::Number(number) => match number.as_i64() {
+                Some(int) => int.to_variant(),
+                // Floats, and integers above i64::MAX (which are rounded).
+                None => number.as_f64().unwrap_or(f64::NAN).to_variant(),
+            },
+            Value::String(s) => GString::from(s.as_str()).to_variant(),
+            Value::Array(elements) => elements
+                .iter()
+                .map(Variant::from_json_value)
+                .collect::<VariantArray>()
+                .to_variant(),
+            Value::Object(entries) => entries
+                .iter()
+                .map(|(key, value)| (GString::from(key.as_str()), Variant::from_json_value(value)))
+                .collect::<Dictionary>()
+                .to_variant(),
+        }
+    }
+
+    /// Converts this variant to a JSON value.
+    ///
+    /// This is the inverse of [`from_json_value()`](Self::from_json_value), with the following additions:
+    /// * `StringName` and `NodePath` are converted to JSON strings.
+    /// * Packed arrays are converted to JSON arrays.
+    /// * Dictionary keys must be strings, string names, node paths, `int` or `bool`; the latter two are formatted as strings.
+    /// * `float` values are always JSON numbers with a fractional part, e.g. `1.0`. NaN and infinity cannot be represented.
+    ///
+    /// All other types (vectors, colors, objects, callables, ...) result in an error. Convert them to one of the above types first.
+    pub fn to_json_value(&self) -> Result<Value, JsonValueError> {
+        let value = match self.get_type() {
+            VariantType::Nil => Value::Null,
+            VariantType::Bool => Value::Bool(self.to::<bool>()),
+            VariantType::Int => Value::from(self.to::<i64>()),
+            VariantType::Float => {
+                let float = self.to::<f64>();
+                let number = Number::from_f64(float).ok_or(JsonValueError {
+                    kind: JsonValueErrorKind::NonFiniteFloat(float),
+                })?;
+                Value::Number(number)
+            }
+            VariantType::String => Value::String(self.to::<GString>().to_string()),
+            VariantType::StringName => Value::String(self.to::<StringName>().to_string()),
+            VariantType::NodePath => Value::String(self.to::<NodePath>().to_string()),
+            VariantType::Array => array_to_json(self.to::<VariantArray>().iter_shared())?,
+            VariantType::Dictionary => {
+                let mut map = Map::new();
+                for (key, value) in self.to::<Dictionary>().iter_shared() {
+                    map.insert(json_key(&key)?, value.to_json_value()?);
+                }
+                Value::Object(map)
+            }
+            VariantType::PackedByteArray => {
+                packed_to_json(self.to::<PackedByteArray>().as_slice())?
+            }
+            VariantType::PackedInt32Array => {
+                packed_to_json(self.to::<PackedInt32Array>().as_slice())?
+            }
+            VariantType::PackedInt64Array => {
+                packed_to_json(self.to::<PackedInt64Array>().as_slice())?
+            }
+            VariantType::PackedFloat32Array => {
+                packed_to_json(self.to::<PackedFloat32Array>().as_slice())?
+            }
+            VariantType::PackedFloat64Array => {
+                packed_to_json(self.to::<PackedFloat64Array>().as_slice())?
+            }
+            VariantType::PackedStringArray => {
+                packed_to_json(self.to::<PackedStringArray>().as_slice())?
+            }
+            other => {
+                return Err(JsonValueError {
+                    kind: JsonValueErrorKind::UnsupportedType(other),
+                })
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn array_to_json(elements: impl Iterator<Item = Variant>) -> Result<Value, JsonValueError> {
+    elements
+        .map(|element| element.to_json_value())
+        .collect::<Result<Vec<_>, _>>()
+        .map(Value::Array)
+}
+
+fn packed_to_json<T: ToGodot>(elements: &[T]) -> Result<Value, JsonValueError> {
+    array_to_json(elements.iter().map(ToGodot::to_variant))
+}
+
+fn json_key(key: &Variant) -> Result<String, JsonValueError> {
+    match key.get_type() {
+        VariantType::String => Ok(key.to::<GString>().to_string()),
+        VariantType::StringName => Ok(key.to::<StringName>().to_string()),
+        VariantType::NodePath => Ok(key.to::<NodePath>().to_string()),
+        VariantType::Int => Ok(key.to::<i64>().to_string()),
+        VariantType::Bool => Ok(key.to::<bool>().to_string()),
+        other => Err(JsonValueError {
+            kind: JsonValueErrorKind::UnsupportedKey(other),
+        }),
+    }
+}
+
+/// Error when converting a `Variant` to JSON with [`Variant::to_json_value()`].
+#[derive(Debug)]
+pub struct JsonValueError {
+    kind: JsonValueErrorKind,
+}
+
+#[derive(Debug)]
+enum JsonValueErrorKind {
+    UnsupportedType(VariantType),
+    UnsupportedKey(VariantType),
+    NonFiniteFloat(f64),
+}
+
+impl fmt::Display for JsonValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            JsonValueErrorKind::UnsupportedType(ty) => {
+                write!(f, "variant type {ty:?} cannot be represented in JSON")
+            }
+            JsonValueErrorKind::UnsupportedKey(ty) => {
+                write!(
+                    f,
+                    "dictionary key of type {ty:?} cannot be a JSON object key"
+                )
+            }
+            JsonValueErrorKind::NonFiniteFloat(float) => {
+                write!(f, "float {float} cannot be represented in JSON")
+            }
+        }
+    }
+}
+
+impl Error for JsonValueError {}
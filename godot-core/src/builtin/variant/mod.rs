@@ -11,10 +11,23 @@ use std::{fmt, ptr};
 use sys::types::OpaqueVariant;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
+#[cfg(since_api = "4.1")]
+mod borrow;
+mod dispatch;
 mod impls;
-
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(since_api = "4.1")]
+pub use borrow::VariantBorrow;
+pub use dispatch::VariantDispatch;
 pub use sys::{VariantOperator, VariantType};
 
+#[cfg(feature = "serde_json")]
+pub use json::JsonValueError;
+
 use super::meta::{impl_godot_as_self, ConvertError, FromGodot, ToGodot};
 
 /// Godot variant type, able to store a variety of different types.
@@ -59,6 +72,13 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Convert to type `T`, accepting GDScript's implicit conversions. Returns `Err` on failure.
+    ///
+    /// Equivalent to `T::try_from_variant_lenient(&self)`; see [`FromGodot::try_from_variant_lenient()`] for the accepted conversions.
+    pub fn try_to_lenient<T: FromGodot>(&self) -> Result<T, ConvertError> {
+        T::try_from_variant_lenient(self)
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`Self::get_type`].
@@ -163,6 +183,43 @@ impl Variant {
         }
     }
 
+    /// Converts this variant to type `target`, if Godot considers the conversion implicit (e.g. `int` to `float`).
+    ///
+    /// Returns `None` if the conversion is not allowed or fails.
+    pub(crate) fn try_convert_implicit(&self, target: VariantType) -> Option<Variant> {
+        let from = self.get_type();
+        if from == target {
+            return Some(self.clone());
+        }
+
+        let is_implicit =
+            unsafe { interface_fn!(variant_can_convert_strict)(from.sys(), target.sys()) } != 0;
+        if !is_implicit {
+            return None;
+        }
+
+        let args_sys = [self.var_sys_const()];
+        let mut error = sys::default_call_error();
+
+        let result = unsafe {
+            Variant::from_var_sys_init_or_init_default(|variant_ptr| {
+                interface_fn!(variant_construct)(
+                    target.sys(),
+                    variant_ptr,
+                    args_sys.as_ptr(),
+                    args_sys.len() as i32,
+                    ptr::addr_of_mut!(error),
+                )
+            })
+        };
+
+        if error.error == sys::GDEXTENSION_CALL_OK {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn sys_type(&self) -> sys::GDExtensionVariantType {
         unsafe {
             let ty: sys::GDExtensionVariantType = interface_fn!(variant_get_type)(self.var_sys());
@@ -170,6 +227,92 @@ impl Variant {
         }
     }
 
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Fast paths for types stored inline
+    //
+    // Godot's `Variant` consists of a type tag, followed by the value at an offset of 8 bytes (`_data` is `alignas(8)`). Values such as
+    // `bool`, `int`, `float` and vectors are stored directly in there and own no resources, so they can be read and written without FFI
+    // calls. Debug builds verify that the type tag agrees with Godot.
+
+    const INLINE_VALUE_OFFSET: usize = 8;
+
+    /// Type tag read from the variant's memory. Unlike [`get_type()`][Self::get_type], does not call into Godot.
+    fn inline_type(&self) -> sys::GDExtensionVariantType {
+        let base = std::ptr::addr_of!(self.opaque).cast::<sys::GDExtensionVariantType>();
+
+        // SAFETY: the type tag is at the start of each variant, and Variant is aligned to 8 bytes.
+        unsafe { std::ptr::read(base) }
+    }
+
+    /// Whether Godot stores values of `variant_type` inline without owning any resources, i.e. destroying them is a no-op.
+    fn is_inline_type(variant_type: sys::GDExtensionVariantType) -> bool {
+        matches!(
+            variant_type,
+            sys::GDEXTENSION_VARIANT_TYPE_NIL
+                | sys::GDEXTENSION_VARIANT_TYPE_BOOL
+                | sys::GDEXTENSION_VARIANT_TYPE_INT
+                | sys::GDEXTENSION_VARIANT_TYPE_FLOAT
+                | sys::GDEXTENSION_VARIANT_TYPE_VECTOR2
+                | sys::GDEXTENSION_VARIANT_TYPE_VECTOR2I
+                | sys::GDEXTENSION_VARIANT_TYPE_RECT2
+                | sys::GDEXTENSION_VARIANT_TYPE_RECT2I
+                | sys::GDEXTENSION_VARIANT_TYPE_VECTOR3
+                | sys::GDEXTENSION_VARIANT_TYPE_VECTOR3I
+                | sys::GDEXTENSION_VARIANT_TYPE_VECTOR4
+                | sys::GDEXTENSION_VARIANT_TYPE_VECTOR4I
+                | sys::GDEXTENSION_VARIANT_TYPE_PLANE
+                | sys::GDEXTENSION_VARIANT_TYPE_QUATERNION
+                | sys::GDEXTENSION_VARIANT_TYPE_COLOR
+                | sys::GDEXTENSION_VARIANT_TYPE_RID
+        )
+    }
+
+    /// Creates a variant holding `value`, without calling into Godot.
+    ///
+    /// # Safety
+    /// `variant_type` must be an inline type (see [`is_inline_type()`][Self::is_inline_type]), and `T` must have the same layout as
+    /// Godot's representation of it.
+    pub(crate) unsafe fn from_inline<T: Copy>(
+        variant_type: sys::GDExtensionVariantType,
+        value: T,
+    ) -> Self {
+        assert!(Self::INLINE_VALUE_OFFSET + std::mem::size_of::<T>() <= OpaqueVariant::SIZE);
+        debug_assert!(Self::is_inline_type(variant_type));
+
+        // All-zero memory is a nil variant.
+        let mut variant = Self::from_opaque(std::mem::zeroed());
+
+        let base = std::ptr::addr_of_mut!(variant.opaque).cast::<u8>();
+        std::ptr::write(base.cast::<sys::GDExtensionVariantType>(), variant_type);
+        std::ptr::write(base.add(Self::INLINE_VALUE_OFFSET).cast::<T>(), value);
+
+        debug_assert_eq!(
+            variant.sys_type(),
+            variant_type,
+            "unexpected Variant layout"
+        );
+        variant
+    }
+
+    /// Reads the value of a variant holding `variant_type`, without calling into Godot. Returns `None` if the variant has another type.
+    ///
+    /// # Safety
+    /// Same as [`from_inline()`][Self::from_inline].
+    pub(crate) unsafe fn to_inline<T: Copy>(
+        &self,
+        variant_type: sys::GDExtensionVariantType,
+    ) -> Option<T> {
+        if self.inline_type() != variant_type {
+            return None;
+        }
+        debug_assert_eq!(self.sys_type(), variant_type, "unexpected Variant layout");
+
+        let base = std::ptr::addr_of!(self.opaque).cast::<u8>();
+        Some(std::ptr::read(
+            base.add(Self::INLINE_VALUE_OFFSET).cast::<T>(),
+        ))
+    }
+
     /// Return Godot's string representation of the variant.
     ///
     /// See also `Display` impl.
@@ -328,6 +471,11 @@ impl Clone for Variant {
 
 impl Drop for Variant {
     fn drop(&mut self) {
+        // Inline values own no resources, so Godot's destructor would do nothing.
+        if Self::is_inline_type(self.inline_type()) {
+            return;
+        }
+
         unsafe {
             interface_fn!(variant_destroy)(self.var_sys());
         }
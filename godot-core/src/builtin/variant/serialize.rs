@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Serde support for `Variant`.
+//!
+//! A variant is serialized like a Rust enum with one case per variant type, e.g. `{"Int":3}` or `{"Vector2":{"x":1.0,"y":2.0}}` in JSON,
+//! and `"Nil"` for nil. This keeps the type information, so that values round-trip exactly also in non-self-describing formats.
+//! `Object`, `Callable`, `Signal` and `Rid` cannot be serialized.
+
+use std::fmt;
+
+use serde::de::{self, EnumAccess, Unexpected, VariantAccess, Visitor};
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+
+macro_rules! impl_variant_serde {
+    ($( $index:literal => $Name:ident($Ty:ty), )*) => {
+        const VARIANT_NAMES: &[&str] = &["Nil", $( stringify!($Name), )*];
+
+        impl Serialize for Variant {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self.get_type() {
+                    VariantType::Nil => serializer.serialize_unit_variant("Variant", 0, "Nil"),
+                    $(
+                        VariantType::$Name => serializer.serialize_newtype_variant(
+                            "Variant",
+                            $index,
+                            stringify!($Name),
+                            &self.to::<$Ty>(),
+                        ),
+                    )*
+                    other => Err(ser::Error::custom(format_args!(
+                        "cannot serialize Variant of type {other:?}"
+                    ))),
+                }
+            }
+        }
+
+        fn deserialize_content<'de, A>(index: u32, content: A) -> Result<Variant, A::Error>
+        where
+            A: VariantAccess<'de>,
+        {
+            match index {
+                $(
+                    $index => content.newtype_variant::<$Ty>().map(|value| value.to_variant()),
+                )*
+                _ => content.unit_variant().map(|()| Variant::nil()),
+            }
+        }
+    };
+}
+
+impl_variant_serde!(
+    1 => Bool(bool),
+    2 => Int(i64),
+    3 => Float(f64),
+    4 => String(GString),
+    5 => Vector2(Vector2),
+    6 => Vector2i(Vector2i),
+    7 => Rect2(Rect2),
+    8 => Rect2i(Rect2i),
+    9 => Vector3(Vector3),
+    10 => Vector3i(Vector3i),
+    11 => Transform2D(Transform2D),
+    12 => Vector4(Vector4),
+    13 => Vector4i(Vector4i),
+    14 => Plane(Plane),
+    15 => Quaternion(Quaternion),
+    16 => Aabb(Aabb),
+    17 => Basis(Basis),
+    18 => Transform3D(Transform3D),
+    19 => Projection(Projection),
+    20 => Color(Color),
+    21 => StringName(StringName),
+    22 => NodePath(NodePath),
+    23 => Dictionary(Dictionary),
+    24 => Array(VariantArray),
+    25 => PackedByteArray(PackedByteArray),
+    26 => PackedInt32Array(PackedInt32Array),
+    27 => PackedInt64Array(PackedInt64Array),
+    28 => PackedFloat32Array(PackedFloat32Array),
+    29 => PackedFloat64Array(PackedFloat64Array),
+    30 => PackedStringArray(PackedStringArray),
+    31 => PackedVector2Array(PackedVector2Array),
+    32 => PackedVector3Array(PackedVector3Array),
+    33 => PackedColorArray(PackedColorArray),
+);
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("Variant", VARIANT_NAMES, VariantVisitor)
+    }
+}
+
+struct VariantVisitor;
+
+impl<'de> Visitor<'de> for VariantVisitor {
+    type Value = Variant;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Variant")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (Tag(index), content) = data.variant()?;
+        deserialize_content(index, content)
+    }
+}
+
+/// Index into `VARIANT_NAMES`, deserialized from either the name or the index.
+struct Tag(u32);
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(TagVisitor)
+    }
+}
+
+struct TagVisitor;
+
+impl<'de> Visitor<'de> for TagVisitor {
+    type Value = Tag;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Variant type")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u32::try_from(value) {
+            Ok(index) if (index as usize) < VARIANT_NAMES.len() => Ok(Tag(index)),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        VARIANT_NAMES
+            .iter()
+            .position(|name| *name == value)
+            .map(|index| Tag(index as u32))
+            .ok_or_else(|| E::unknown_variant(value, VARIANT_NAMES))
+    }
+}
@@ -7,6 +7,7 @@
 
 mod vector_macros;
 
+mod rounding_mode;
 mod vector2;
 mod vector2i;
 mod vector3;
@@ -15,6 +16,7 @@ mod vector4;
 mod vector4i;
 mod vector_axis;
 
+pub use rounding_mode::*;
 pub use vector2::*;
 pub use vector2i::*;
 pub use vector3::*;
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::real;
+
+/// How floating-point components are rounded when converting to an integer vector.
+///
+/// Used by [`Vector2i::from_vector2_rounded()`][crate::builtin::Vector2i::from_vector2_rounded] and its 3D/4D counterparts.
+/// Values outside the `i32` range saturate, and `NaN` becomes `0`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum RoundingMode {
+    /// Round toward zero. This is what Godot does when constructing e.g. `Vector2i(Vector2)`.
+    #[default]
+    Truncate,
+
+    /// Round toward negative infinity, like `Vector2.floor()`. Use this to find the grid cell containing a point.
+    Floor,
+
+    /// Round toward positive infinity, like `Vector2.ceil()`.
+    Ceil,
+
+    /// Round to the nearest integer, with halfway cases away from zero, like `Vector2.round()`.
+    Round,
+}
+
+impl RoundingMode {
+    pub(super) fn apply(self, value: real) -> i32 {
+        let rounded = match self {
+            RoundingMode::Truncate => value.trunc(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Round => value.round(),
+        };
+
+        rounded as i32
+    }
+}
@@ -246,6 +246,9 @@ impl_float_vector_glam_fns!(Vector2, real);
 impl_float_vector_component_fns!(Vector2, real, (x, y));
 impl_vector_operators!(Vector2, real, (x, y));
 impl_from_tuple_for_vector2x!(Vector2, real);
+impl_vector_component_utils!(Vector2, real, (x: with_x, y: with_y));
+impl_float_vector_axis_fns!(Vector2, Vector2Axis, (x: X, y: Y));
+impl_vector_swizzles!(Vector2, Vector2, yx => (y, x));
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
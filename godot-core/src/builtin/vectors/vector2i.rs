@@ -24,6 +24,9 @@ use std::fmt;
 /// required. Note that the values are limited to 32 bits, and unlike [`Vector2`] this cannot be
 /// configured with an engine build option. Use `i64` or [`PackedInt64Array`][crate::builtin::PackedInt64Array]
 /// if 64-bit values are needed.
+///
+/// `/` and `%` operate component-wise and round toward zero, like in GDScript. Dividing by zero panics, where GDScript
+/// reports an error instead.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -42,6 +45,12 @@ impl Vector2i {
     /// Vector with all components set to `1`.
     pub const ONE: Self = Self::splat(1);
 
+    /// Vector with all components set to `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// Vector with all components set to `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// Unit vector in -X direction (right in 2D coordinate system).
     pub const LEFT: Self = Self::new(-1, 0);
 
@@ -125,8 +134,11 @@ impl fmt::Display for Vector2i {
 impl_common_vector_fns!(Vector2i, i32);
 impl_integer_vector_glam_fns!(Vector2i, real);
 impl_integer_vector_component_fns!(Vector2i, real, (x, y));
+impl_integer_vector_fns!(Vector2i, Vector2, from_vector2_rounded, (x, y));
 impl_vector_operators!(Vector2i, i32, (x, y));
 impl_from_tuple_for_vector2x!(Vector2i, i32);
+impl_vector_component_utils!(Vector2i, i32, (x: with_x, y: with_y));
+impl_vector_swizzles!(Vector2i, Vector2i, yx => (y, x));
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
@@ -188,4 +200,85 @@ mod test {
         assert_eq!(Vector2i::new(15, 15).max_axis(), None);
         assert_eq!(Vector2i::new(15, 15).min_axis(), None);
     }
+
+    #[test]
+    fn clamp_and_overflow() {
+        let v = Vector2i::new(-5, 12);
+        assert_eq!(
+            v.clamp(Vector2i::ZERO, Vector2i::new(10, 10)),
+            Vector2i::new(0, 10)
+        );
+        assert_eq!(v.clampi(-2, 2), Vector2i::new(-2, 2));
+
+        let max = Vector2i::splat(i32::MAX);
+        assert_eq!(max.saturating_add(Vector2i::ONE), max);
+        assert_eq!(
+            Vector2i::splat(i32::MIN).saturating_sub(Vector2i::ONE),
+            Vector2i::splat(i32::MIN)
+        );
+        assert_eq!(
+            max.saturating_mul(Vector2i::new(2, -2)),
+            Vector2i::new(i32::MAX, i32::MIN)
+        );
+        assert_eq!(max.wrapping_add(Vector2i::ONE), Vector2i::splat(i32::MIN));
+        assert_eq!(Vector2i::splat(i32::MIN).wrapping_sub(Vector2i::ONE), max);
+        assert_eq!(max.wrapping_mul(Vector2i::splat(2)), Vector2i::splat(-2));
+    }
+
+    #[test]
+    fn grid_lengths() {
+        let v = Vector2i::new(-3, 4);
+        assert_eq!(v.manhattan_length(), 7);
+        assert_eq!(v.chebyshev_length(), 4);
+
+        let extreme = Vector2i::splat(i32::MIN);
+        assert_eq!(extreme.manhattan_length(), 2 * (i32::MAX as i64 + 1));
+        assert_eq!(extreme.chebyshev_length(), i32::MAX as i64 + 1);
+    }
+
+    #[test]
+    fn rounded_conversion() {
+        use crate::builtin::RoundingMode;
+
+        let rounded = |mode| Vector2i::from_vector2_rounded(Vector2::new(-1.5, 2.5), mode);
+        assert_eq!(rounded(RoundingMode::Truncate), Vector2i::new(-1, 2));
+        assert_eq!(rounded(RoundingMode::Floor), Vector2i::new(-2, 2));
+        assert_eq!(rounded(RoundingMode::Ceil), Vector2i::new(-1, 3));
+        assert_eq!(rounded(RoundingMode::Round), Vector2i::new(-2, 3));
+
+        let out_of_range = Vector2::new(real::NAN, 1e20);
+        assert_eq!(
+            Vector2i::from_vector2_rounded(out_of_range, RoundingMode::Floor),
+            Vector2i::new(0, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn div_rem_truncate() {
+        let v = Vector2i::new(-7, 7);
+        assert_eq!(v / 2, Vector2i::new(-3, 3));
+        assert_eq!(v % 2, Vector2i::new(-1, 1));
+        assert_eq!(v % Vector2i::new(3, -3), Vector2i::new(-1, 1));
+
+        let mut w = v;
+        w %= Vector2i::new(4, 5);
+        assert_eq!(w, Vector2i::new(-3, 2));
+        w %= 2;
+        assert_eq!(w, Vector2i::new(-1, 0));
+    }
+
+    #[test]
+    fn const_lookup_table() {
+        const NEIGHBORS: [Vector2i; 4] = [
+            Vector2i::UP,
+            Vector2i::RIGHT,
+            Vector2i::DOWN,
+            Vector2i::LEFT,
+        ];
+        const BOUNDS: [Vector2i; 2] = [Vector2i::MIN, Vector2i::MAX.with_y(0)];
+
+        let sum = NEIGHBORS.iter().fold(Vector2i::ZERO, |acc, &v| acc + v);
+        assert_eq!(sum, Vector2i::ZERO);
+        assert_eq!(BOUNDS[1], Vector2i::new(i32::MAX, 0));
+    }
 }
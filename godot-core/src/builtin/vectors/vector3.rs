@@ -11,7 +11,7 @@ use sys::{ffi_methods, GodotFfi};
 use crate::builtin::math::{FloatExt, GlamConv, GlamType};
 use crate::builtin::meta::impl_godot_as_self;
 use crate::builtin::vectors::Vector3Axis;
-use crate::builtin::{real, Basis, RVec3, Vector3i};
+use crate::builtin::{real, Basis, RVec3, RVec3Ops, Vector2, Vector3i};
 
 use std::fmt;
 
@@ -46,6 +46,9 @@ impl Vector3 {
     /// Vector with all components set to `1.0`.
     pub const ONE: Self = Self::splat(1.0);
 
+    /// Vector with all components set to `real::INFINITY`.
+    pub const INF: Self = Self::splat(real::INFINITY);
+
     /// Unit vector in -X direction. Can be interpreted as left in an untransformed 3D world.
     pub const LEFT: Self = Self::new(-1.0, 0.0, 0.0);
 
@@ -110,7 +113,9 @@ impl Vector3 {
     }
 
     pub fn cross(self, with: Self) -> Self {
-        Self::from_glam(self.to_glam().cross(with.to_glam()))
+        RVec3Ops::from_front(&self)
+            .cross(RVec3Ops::from_front(&with))
+            .to_front()
     }
 
     pub fn direction_to(self, to: Self) -> Self {
@@ -126,7 +131,7 @@ impl Vector3 {
     }
 
     pub fn dot(self, with: Self) -> real {
-        self.to_glam().dot(with.to_glam())
+        RVec3Ops::from_front(&self).dot(RVec3Ops::from_front(&with))
     }
 
     pub fn floor(self) -> Self {
@@ -275,7 +280,31 @@ impl fmt::Display for Vector3 {
 impl_common_vector_fns!(Vector3, real);
 impl_float_vector_glam_fns!(Vector3, real);
 impl_float_vector_component_fns!(Vector3, real, (x, y, z));
+#[cfg(not(feature = "simd"))]
 impl_vector_operators!(Vector3, real, (x, y, z));
+#[cfg(feature = "simd")]
+impl_vector_operators_glam!(Vector3, real, RVec3Ops);
+impl_vector_component_utils!(Vector3, real, (x: with_x, y: with_y, z: with_z));
+impl_float_vector_axis_fns!(Vector3, Vector3Axis, (x: X, y: Y, z: Z));
+impl_vector_swizzles!(
+    Vector3,
+    Vector2,
+    xy => (x, y),
+    xz => (x, z),
+    yx => (y, x),
+    yz => (y, z),
+    zx => (z, x),
+    zy => (z, y),
+);
+impl_vector_swizzles!(
+    Vector3,
+    Vector3,
+    xzy => (x, z, y),
+    yxz => (y, x, z),
+    yzx => (y, z, x),
+    zxy => (z, x, y),
+    zyx => (z, y, x),
+);
 impl_from_tuple_for_vector3x!(Vector3, real);
 
 // SAFETY:
@@ -325,6 +354,29 @@ mod test {
     use crate::builtin::math::assert_eq_approx;
     use crate::builtin::real_consts::{SQRT_2, TAU};
 
+    #[test]
+    fn swizzles() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.xz(), Vector2::new(1.0, 3.0));
+        assert_eq!(v.zy(), Vector2::new(3.0, 2.0));
+        assert_eq!(v.yzx(), Vector3::new(2.0, 3.0, 1.0));
+        assert_eq!(v.with_y(5.0), Vector3::new(1.0, 5.0, 3.0));
+    }
+
+    #[test]
+    fn component_utils() {
+        let v = Vector3::new(-1.5, 4.0, 2.0);
+
+        assert_eq!(v.map(real::abs), Vector3::new(1.5, 4.0, 2.0));
+        assert_eq!(v.min_component(), -1.5);
+        assert_eq!(v.max_component(), 4.0);
+        assert_eq!(v.max_axis(), Some(Vector3Axis::Y));
+        assert_eq!(v.min_axis(), Some(Vector3Axis::X));
+        assert_eq!(Vector3::new(4.0, 1.0, 4.0).max_axis(), None);
+        assert_eq!(Vector3::new(4.0, 1.0, 4.0).min_axis(), Some(Vector3Axis::Y));
+    }
+
     // Translated from Godot
     #[test]
     #[allow(clippy::excessive_precision)]
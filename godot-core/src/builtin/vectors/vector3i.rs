@@ -13,7 +13,7 @@ use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{FloatExt, GlamConv, GlamType};
 use crate::builtin::meta::impl_godot_as_self;
-use crate::builtin::{real, RVec3, Vector3, Vector3Axis};
+use crate::builtin::{real, RVec3, Vector2i, Vector3, Vector3Axis};
 
 /// Vector used for 3D math using integer coordinates.
 ///
@@ -24,6 +24,9 @@ use crate::builtin::{real, RVec3, Vector3, Vector3Axis};
 /// required. Note that the values are limited to 32 bits, and unlike [`Vector3`] this cannot be
 /// configured with an engine build option. Use `i64` or [`PackedInt64Array`][crate::builtin::PackedInt64Array]
 /// if 64-bit values are needed.
+///
+/// `/` and `%` operate component-wise and round toward zero, like in GDScript. Dividing by zero panics, where GDScript
+/// reports an error instead.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -45,6 +48,12 @@ impl Vector3i {
     /// Vector with all components set to `1`.
     pub const ONE: Self = Self::splat(1);
 
+    /// Vector with all components set to `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// Vector with all components set to `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// Unit vector in -X direction.
     pub const LEFT: Self = Self::new(-1, 0, 0);
 
@@ -156,8 +165,29 @@ impl fmt::Display for Vector3i {
 impl_common_vector_fns!(Vector3i, i32);
 impl_integer_vector_glam_fns!(Vector3i, real);
 impl_integer_vector_component_fns!(Vector3i, real, (x, y, z));
+impl_integer_vector_fns!(Vector3i, Vector3, from_vector3_rounded, (x, y, z));
 impl_vector_operators!(Vector3i, i32, (x, y, z));
 impl_from_tuple_for_vector3x!(Vector3i, i32);
+impl_vector_component_utils!(Vector3i, i32, (x: with_x, y: with_y, z: with_z));
+impl_vector_swizzles!(
+    Vector3i,
+    Vector2i,
+    xy => (x, y),
+    xz => (x, z),
+    yx => (y, x),
+    yz => (y, z),
+    zx => (z, x),
+    zy => (z, y),
+);
+impl_vector_swizzles!(
+    Vector3i,
+    Vector3i,
+    xzy => (x, z, y),
+    yxz => (y, x, z),
+    yzx => (y, z, x),
+    zxy => (z, x, y),
+    zyx => (z, y, x),
+);
 
 // SAFETY:
 // This type is represented as `Self` in Godot, so `*mut Self` is sound.
@@ -227,4 +257,22 @@ mod test {
         assert_eq!(Vector3i::new(15, 15, 25).max_axis(), Some(Vector3Axis::Z));
         assert_eq!(Vector3i::new(15, 5, 15).min_axis(), Some(Vector3Axis::Y));
     }
+
+    #[test]
+    fn rounded_conversion_and_rem() {
+        use crate::builtin::RoundingMode;
+
+        let v = Vector3::new(0.5, -0.5, 1.9);
+        assert_eq!(
+            Vector3i::from_vector3_rounded(v, RoundingMode::Floor),
+            Vector3i::new(0, -1, 1)
+        );
+        assert_eq!(
+            Vector3i::from_vector3_rounded(v, RoundingMode::Round),
+            Vector3i::new(1, -1, 2)
+        );
+
+        assert_eq!(Vector3i::new(5, -5, 6) % 4, Vector3i::new(1, -1, 2));
+        assert_eq!(Vector3i::new(1, -2, 3).manhattan_length(), 6);
+    }
 }
@@ -10,7 +10,7 @@ use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{FloatExt, GlamConv, GlamType};
 use crate::builtin::meta::impl_godot_as_self;
-use crate::builtin::{real, RVec4, Vector4i};
+use crate::builtin::{real, RVec4, Vector2, Vector3, Vector4Axis, Vector4i};
 
 use std::fmt;
 
@@ -40,11 +40,34 @@ pub struct Vector4 {
     pub w: real,
 }
 
+#[cfg(not(feature = "simd"))]
 impl_vector_operators!(Vector4, real, (x, y, z, w));
+#[cfg(feature = "simd")]
+impl_vector_operators_glam!(Vector4, real, RVec4);
 impl_common_vector_fns!(Vector4, real);
 impl_float_vector_glam_fns!(Vector4, real);
 impl_float_vector_component_fns!(Vector4, real, (x, y, z, w));
 impl_from_tuple_for_vector4x!(Vector4, real);
+impl_vector_component_utils!(Vector4, real, (x: with_x, y: with_y, z: with_z, w: with_w));
+impl_float_vector_axis_fns!(Vector4, Vector4Axis, (x: X, y: Y, z: Z, w: W));
+impl_vector_swizzles!(
+    Vector4,
+    Vector2,
+    xy => (x, y),
+    xz => (x, z),
+    xw => (x, w),
+    yz => (y, z),
+    yw => (y, w),
+    zw => (z, w),
+);
+impl_vector_swizzles!(
+    Vector4,
+    Vector3,
+    xyz => (x, y, z),
+    xyw => (x, y, w),
+    xzw => (x, z, w),
+    yzw => (y, z, w),
+);
 
 impl Vector4 {
     /// Returns a `Vector4` with the given components.
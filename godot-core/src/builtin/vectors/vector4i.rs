@@ -10,7 +10,7 @@ use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::{FloatExt, GlamConv, GlamType};
 use crate::builtin::meta::impl_godot_as_self;
-use crate::builtin::{real, RVec4, Vector4, Vector4Axis};
+use crate::builtin::{real, RVec4, Vector2i, Vector3i, Vector4, Vector4Axis};
 
 use std::fmt;
 
@@ -22,6 +22,9 @@ use std::fmt;
 /// required. Note that the values are limited to 32 bits, and unlike [`Vector4`] this cannot be
 /// configured with an engine build option. Use `i64` or [`PackedInt64Array`][crate::builtin::PackedInt64Array]
 /// if 64-bit values are needed.
+///
+/// `/` and `%` operate component-wise and round toward zero, like in GDScript. Dividing by zero panics, where GDScript
+/// reports an error instead.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -42,8 +45,28 @@ pub struct Vector4i {
 impl_vector_operators!(Vector4i, i32, (x, y, z, w));
 impl_integer_vector_glam_fns!(Vector4i, real);
 impl_integer_vector_component_fns!(Vector4i, real, (x, y, z, w));
+impl_integer_vector_fns!(Vector4i, Vector4, from_vector4_rounded, (x, y, z, w));
 impl_common_vector_fns!(Vector4i, i32);
 impl_from_tuple_for_vector4x!(Vector4i, i32);
+impl_vector_component_utils!(Vector4i, i32, (x: with_x, y: with_y, z: with_z, w: with_w));
+impl_vector_swizzles!(
+    Vector4i,
+    Vector2i,
+    xy => (x, y),
+    xz => (x, z),
+    xw => (x, w),
+    yz => (y, z),
+    yw => (y, w),
+    zw => (z, w),
+);
+impl_vector_swizzles!(
+    Vector4i,
+    Vector3i,
+    xyz => (x, y, z),
+    xyw => (x, y, w),
+    xzw => (x, z, w),
+    yzw => (y, z, w),
+);
 
 impl Vector4i {
     /// Returns a `Vector4i` with the given components.
@@ -115,6 +138,12 @@ impl Vector4i {
     /// One vector, a vector with all components set to `1`.
     pub const ONE: Self = Self::splat(1);
 
+    /// Min vector, a vector with all components set to `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// Max vector, a vector with all components set to `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// Converts the corresponding `glam` type to `Self`.
     fn from_glam(v: glam::IVec4) -> Self {
         Self::new(v.x, v.y, v.z, v.w)
@@ -179,6 +208,18 @@ impl GlamConv for Vector4i {
 mod test {
     use super::*;
 
+    #[test]
+    fn swizzles_and_components() {
+        let v = Vector4i::new(1, -2, 3, 4);
+
+        assert_eq!(v.yw(), Vector2i::new(-2, 4));
+        assert_eq!(v.xyz(), Vector3i::new(1, -2, 3));
+        assert_eq!(v.with_w(0), Vector4i::new(1, -2, 3, 0));
+        assert_eq!(v.map(|c| c * 2), Vector4i::new(2, -4, 6, 8));
+        assert_eq!(v.min_component(), -2);
+        assert_eq!(v.max_component(), 4);
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector4i::new(1, 3, 5, 0);
@@ -223,4 +264,12 @@ mod test {
             Some(Vector4Axis::W),
         );
     }
+
+    #[test]
+    fn grid_lengths_and_clamp() {
+        let v = Vector4i::new(1, -6, 3, 0);
+        assert_eq!(v.manhattan_length(), 10);
+        assert_eq!(v.chebyshev_length(), 6);
+        assert_eq!(v.clampi(0, 2), Vector4i::new(1, 0, 2, 0));
+    }
 }
@@ -190,6 +190,77 @@ macro_rules! impl_vector_operators {
     }
 }
 
+/// Implements the same operators as `impl_vector_operators!`, but performs the arithmetic on a glam type.
+///
+/// Used with the `simd` feature, where the glam type is backed by SIMD registers.
+macro_rules! impl_vector_operators_glam {
+    (@binary $Vector:ty, $Scalar:ty, $Glam:ty, $Operator:ident, $func:ident, $AssignOperator:ident, $assign_func:ident) => {
+        impl std::ops::$Operator for $Vector {
+            type Output = Self;
+            #[inline]
+            fn $func(self, rhs: $Vector) -> Self::Output {
+                std::ops::$Operator::$func(<$Glam>::from_front(&self), <$Glam>::from_front(&rhs)).to_front()
+            }
+        }
+
+        impl std::ops::$AssignOperator for $Vector {
+            #[inline]
+            fn $assign_func(&mut self, rhs: $Vector) {
+                *self = std::ops::$Operator::$func(*self, rhs);
+            }
+        }
+    };
+
+    (@scalar $Vector:ty, $Scalar:ty, $Glam:ty, $Operator:ident, $func:ident, $AssignOperator:ident, $assign_func:ident) => {
+        impl std::ops::$Operator<$Scalar> for $Vector {
+            type Output = Self;
+            #[inline]
+            fn $func(self, rhs: $Scalar) -> Self::Output {
+                std::ops::$Operator::$func(<$Glam>::from_front(&self), rhs).to_front()
+            }
+        }
+
+        impl std::ops::$AssignOperator<$Scalar> for $Vector {
+            #[inline]
+            fn $assign_func(&mut self, rhs: $Scalar) {
+                *self = std::ops::$Operator::$func(*self, rhs);
+            }
+        }
+    };
+
+    (
+        // Name of the vector type to be implemented, for example `Vector3`.
+        $Vector:ty,
+        // Type of each individual component, for example `real`.
+        $Scalar:ty,
+        // glam type performing the arithmetic, for example `glam::Vec3A`. Must implement `GlamType<Mapped = $Vector>`.
+        $Glam:ty
+    ) => {
+        impl std::ops::Neg for $Vector {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                (-<$Glam>::from_front(&self)).to_front()
+            }
+        }
+
+        impl_vector_operators_glam!(@binary $Vector, $Scalar, $Glam, Add, add, AddAssign, add_assign);
+        impl_vector_operators_glam!(@binary $Vector, $Scalar, $Glam, Sub, sub, SubAssign, sub_assign);
+        impl_vector_operators_glam!(@binary $Vector, $Scalar, $Glam, Mul, mul, MulAssign, mul_assign);
+        impl_vector_operators_glam!(@binary $Vector, $Scalar, $Glam, Div, div, DivAssign, div_assign);
+        impl_vector_operators_glam!(@scalar $Vector, $Scalar, $Glam, Mul, mul, MulAssign, mul_assign);
+        impl_vector_operators_glam!(@scalar $Vector, $Scalar, $Glam, Div, div, DivAssign, div_assign);
+
+        impl std::ops::Mul<$Vector> for $Scalar {
+            type Output = $Vector;
+            #[inline]
+            fn mul(self, rhs: $Vector) -> Self::Output {
+                (self * <$Glam>::from_front(&rhs)).to_front()
+            }
+        }
+    };
+}
+
 /// Implements `Index` and `IndexMut` for a vector type, using an enum to indicate the desired axis.
 macro_rules! impl_vector_index {
     (
@@ -448,6 +519,226 @@ macro_rules! impl_integer_vector_component_fns {
     };
 }
 
+/// Implements clamping, overflow-aware arithmetic, grid distances, rounded conversion and `%` for integer vectors.
+macro_rules! impl_integer_vector_fns {
+    (
+        // Name of the vector type.
+        $Vector:ty,
+        // Name of the corresponding floating-point vector type, for example `Vector2`.
+        $FloatVector:ty,
+        // Name of the rounding conversion from `$FloatVector`, for example `from_vector2_rounded`.
+        $from_rounded:ident,
+        // Names of the components, with parentheses, for example `(x, y)`.
+        ($($comp:ident),*)
+    ) => {
+        impl $Vector {
+            /// A new vector with each component clamped between the corresponding components of `min` and `max`.
+            ///
+            /// # Panics
+            /// If any component of `min` is greater than the one of `max`.
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self::new($( self.$comp.clamp(min.$comp, max.$comp) ),*)
+            }
+
+            /// A new vector with each component clamped between `min` and `max`.
+            ///
+            /// # Panics
+            /// If `min` is greater than `max`.
+            #[inline]
+            pub fn clampi(self, min: i32, max: i32) -> Self {
+                Self::new($( self.$comp.clamp(min, max) ),*)
+            }
+
+            /// Component-wise addition, saturating at the `i32` bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                Self::new($( self.$comp.saturating_add(rhs.$comp) ),*)
+            }
+
+            /// Component-wise subtraction, saturating at the `i32` bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                Self::new($( self.$comp.saturating_sub(rhs.$comp) ),*)
+            }
+
+            /// Component-wise multiplication, saturating at the `i32` bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                Self::new($( self.$comp.saturating_mul(rhs.$comp) ),*)
+            }
+
+            /// Component-wise addition, wrapping around at the `i32` bounds like Godot does.
+            #[inline]
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                Self::new($( self.$comp.wrapping_add(rhs.$comp) ),*)
+            }
+
+            /// Component-wise subtraction, wrapping around at the `i32` bounds like Godot does.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                Self::new($( self.$comp.wrapping_sub(rhs.$comp) ),*)
+            }
+
+            /// Component-wise multiplication, wrapping around at the `i32` bounds like Godot does.
+            #[inline]
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                Self::new($( self.$comp.wrapping_mul(rhs.$comp) ),*)
+            }
+
+            /// Sum of the absolute components, i.e. the number of orthogonal steps from the origin.
+            ///
+            /// Returned as `i64`, so it cannot overflow.
+            #[inline]
+            pub fn manhattan_length(self) -> i64 {
+                0 $( + (self.$comp as i64).abs() )*
+            }
+
+            /// Largest absolute component, i.e. the number of steps from the origin when diagonal moves are allowed.
+            ///
+            /// Returned as `i64`, so it cannot overflow.
+            #[inline]
+            pub fn chebyshev_length(self) -> i64 {
+                0_i64 $( .max((self.$comp as i64).abs()) )*
+            }
+
+            #[doc = concat!("Constructs a new `", stringify!($Vector), "` from a [`", stringify!($FloatVector), "`], rounding each component according to `mode`.")]
+            ///
+            /// Components outside the `i32` range saturate, and `NaN` becomes `0`.
+            #[inline]
+            pub fn $from_rounded(v: $FloatVector, mode: $crate::builtin::RoundingMode) -> Self {
+                Self::new($( mode.apply(v.$comp) ),*)
+            }
+        }
+
+        impl_vector_vector_binary_operator!($Vector, i32, ($($comp),*), Rem, rem);
+        impl_vector_scalar_binary_operator!($Vector, i32, ($($comp),*), Rem, rem);
+        impl_vector_vector_assign_operator!($Vector, i32, ($($comp),*), RemAssign, rem_assign);
+        impl_vector_scalar_assign_operator!($Vector, i32, ($($comp),*), RemAssign, rem_assign);
+    };
+}
+
+/// Implements swizzle methods, which return a vector made of the given components, for example `xz()`.
+macro_rules! impl_vector_swizzles {
+    (
+        // Name of the vector type.
+        $Vector:ty,
+        // Type of the returned vector.
+        $Output:ty,
+        // Method names and their components, for example `xz => (x, z)`.
+        $( $name:ident => ($($comp:ident),+) ),* $(,)?
+    ) => {
+        impl $Vector {
+            $(
+                #[doc = concat!("Returns `(", stringify!($($comp),+), ")` as a new vector.")]
+                #[inline]
+                pub const fn $name(self) -> $Output {
+                    <$Output>::new($(self.$comp),+)
+                }
+            )*
+        }
+    };
+}
+
+/// Implements `with_*()` setters, `map()` and component-wise minimum and maximum for any vector type.
+macro_rules! impl_vector_component_utils {
+    (
+        // Name of the vector type.
+        $Vector:ty,
+        // Type of each individual component, for example `real`.
+        $Scalar:ty,
+        // Names of the components with their setters, for example `(x: with_x, y: with_y)`.
+        ($first:ident: $first_with:ident $(, $comp:ident: $with:ident)*)
+    ) => {
+        impl $Vector {
+            #[doc = concat!("Returns a copy of the vector with `", stringify!($first), "` replaced.")]
+            #[inline]
+            pub const fn $first_with(mut self, $first: $Scalar) -> Self {
+                self.$first = $first;
+                self
+            }
+
+            $(
+                #[doc = concat!("Returns a copy of the vector with `", stringify!($comp), "` replaced.")]
+                #[inline]
+                pub const fn $with(mut self, $comp: $Scalar) -> Self {
+                    self.$comp = $comp;
+                    self
+                }
+            )*
+
+            /// Returns a new vector with `f` applied to each component, in order.
+            #[inline]
+            pub fn map(self, mut f: impl FnMut($Scalar) -> $Scalar) -> Self {
+                Self::new(f(self.$first) $(, f(self.$comp))*)
+            }
+
+            /// Returns the smallest component.
+            #[inline]
+            pub fn min_component(self) -> $Scalar {
+                let min = self.$first;
+                $( let min = min.min(self.$comp); )*
+                min
+            }
+
+            /// Returns the largest component.
+            #[inline]
+            pub fn max_component(self) -> $Scalar {
+                let max = self.$first;
+                $( let max = max.max(self.$comp); )*
+                max
+            }
+        }
+    };
+}
+
+/// Implements `max_axis()` and `min_axis()` for floating-point vectors, like the integer vectors already have.
+macro_rules! impl_float_vector_axis_fns {
+    (
+        // Name of the vector type.
+        $Vector:ty,
+        // Type of the axis enum, for example `Vector3Axis`.
+        $Axis:ident,
+        // Names of the components with their axes, for example `(x: X, y: Y)`.
+        ($($comp:ident: $Variant:ident),*)
+    ) => {
+        impl $Vector {
+            /// Axis of the vector's highest value. [`None`] if several components share the highest value.
+            pub fn max_axis(self) -> Option<$Axis> {
+                let components = [$(($Axis::$Variant, self.$comp)),*];
+                super::vector_macros::unique_extreme(components, |a, b| a > b)
+            }
+
+            /// Axis of the vector's lowest value. [`None`] if several components share the lowest value.
+            pub fn min_axis(self) -> Option<$Axis> {
+                let components = [$(($Axis::$Variant, self.$comp)),*];
+                super::vector_macros::unique_extreme(components, |a, b| a < b)
+            }
+        }
+    };
+}
+
+/// Returns the axis whose value is preferred over all others by `is_better`, or `None` on a tie.
+pub(super) fn unique_extreme<A: Copy, const N: usize>(
+    components: [(A, crate::builtin::real); N],
+    is_better: impl Fn(crate::builtin::real, crate::builtin::real) -> bool,
+) -> Option<A> {
+    let (mut best_axis, mut best) = components[0];
+    let mut is_tie = false;
+
+    for &(axis, value) in &components[1..] {
+        if is_better(value, best) {
+            best_axis = axis;
+            best = value;
+            is_tie = false;
+        } else if value == best {
+            is_tie = true;
+        }
+    }
+
+    (!is_tie).then_some(best_axis)
+}
+
 macro_rules! impl_from_tuple_for_vector2x {
     (
         $Vector:ty,
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, PackedStringArray, StringName};
+use crate::engine::{EditorFileSystem, EditorFileSystemDirectory};
+use crate::obj::Gd;
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::{meta::FromGodot, Callable, Variant};
+
+/// Extension trait for project-wide asset inspection through the editor's [`EditorFileSystem`].
+///
+/// The editor filesystem is only available in the editor, obtained with `EditorInterface::singleton().get_resource_filesystem()`. This
+/// trait provides a flat list of assets instead of a manual walk over [`EditorFileSystemDirectory`] trees, Rust closures as change
+/// notifications and reimports from slices.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{EditorInterface, EditorFileSystemExt as _};
+///
+/// let mut fs = EditorInterface::singleton().get_resource_filesystem().unwrap();
+/// for asset in fs.assets() {
+///     if asset.class == StringName::from("CompressedTexture2D") && !asset.import_valid {
+///         godot_warn!("texture needs reimport: {}", asset.path);
+///     }
+/// }
+/// ```
+pub trait EditorFileSystemExt {
+    /// Lists all files known to the editor filesystem, depth-first in directory order.
+    ///
+    /// Returns an empty list if the filesystem has not been scanned yet.
+    fn assets(&mut self) -> Vec<AssetInfo>;
+
+    /// Reimports the given resource paths, e.g. after changing their `.import` settings.
+    fn reimport(&mut self, paths: &[GString]);
+
+    /// Invokes `callback` whenever the editor filesystem has changed (files added, removed or moved).
+    ///
+    /// Returns the connected callable, which can be passed to `disconnect()`.
+    #[cfg(since_api = "4.2")]
+    fn on_filesystem_changed<F>(&mut self, callback: F) -> Callable
+    where
+        F: FnMut() + Send + Sync + 'static;
+
+    /// Invokes `callback` with the paths of resources after they have been reimported.
+    ///
+    /// Returns the connected callable, which can be passed to `disconnect()`.
+    #[cfg(since_api = "4.2")]
+    fn on_resources_reimported<F>(&mut self, callback: F) -> Callable
+    where
+        F: FnMut(PackedStringArray) + Send + Sync + 'static;
+}
+
+impl EditorFileSystemExt for EditorFileSystem {
+    fn assets(&mut self) -> Vec<AssetInfo> {
+        let mut assets = Vec::new();
+        let Some(root) = self.get_filesystem() else {
+            return assets;
+        };
+
+        // Explicit stack; reversed so that subdirectories are visited in order.
+        let mut stack = vec![root];
+        while let Some(mut dir) = stack.pop() {
+            for i in 0..dir.get_file_count() {
+                assets.push(AssetInfo {
+                    path: dir.get_file_path(i),
+                    class: dir.get_file_type(i),
+                    import_valid: dir.get_file_import_is_valid(i),
+                });
+            }
+
+            let subdirs: Vec<Gd<EditorFileSystemDirectory>> = (0..dir.get_subdir_count())
+                .filter_map(|i| dir.get_subdir(i))
+                .collect();
+            stack.extend(subdirs.into_iter().rev());
+        }
+
+        assets
+    }
+
+    fn reimport(&mut self, paths: &[GString]) {
+        self.reimport_files(PackedStringArray::from(paths));
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn on_filesystem_changed<F>(&mut self, mut callback: F) -> Callable
+    where
+        F: FnMut() + Send + Sync + 'static,
+    {
+        let callable = Callable::from_fn("on_filesystem_changed", move |_args| {
+            callback();
+            Ok(Variant::nil())
+        });
+
+        self.connect("filesystem_changed".into(), callable.clone());
+        callable
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn on_resources_reimported<F>(&mut self, mut callback: F) -> Callable
+    where
+        F: FnMut(PackedStringArray) + Send + Sync + 'static,
+    {
+        let callable = Callable::from_fn("on_resources_reimported", move |args| {
+            let paths = args
+                .first()
+                .and_then(|arg| PackedStringArray::try_from_variant(arg).ok())
+                .unwrap_or_default();
+
+            callback(paths);
+            Ok(Variant::nil())
+        });
+
+        self.connect("resources_reimported".into(), callable.clone());
+        callable
+    }
+}
+
+/// A file tracked by the editor filesystem, as returned by [`EditorFileSystemExt::assets()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetInfo {
+    /// Full resource path, e.g. `res://textures/grass.png`.
+    pub path: GString,
+
+    /// Resource type as detected by the editor, e.g. `CompressedTexture2D` or `PackedScene`.
+    pub class: StringName,
+
+    /// Whether the last import of this file succeeded. Always `true` for files that are not imported.
+    pub import_valid: bool,
+}
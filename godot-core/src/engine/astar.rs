@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::builtin::{real, Rect2i, Vector2, Vector2i, Vector3};
+use crate::engine::a_star_grid_2d::DiagonalMode;
+use crate::engine::{AStar2D, AStar3D, AStarGrid2D};
+use crate::obj::{Gd, NewGd};
+
+macro_rules! impl_astar_graph {
+    (
+        $(#[$attr:meta])*
+        $Graph:ident, $AStar:ident, $Vector:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $Graph<K> {
+            astar: Gd<$AStar>,
+            ids: HashMap<K, i64>,
+            keys: HashMap<i64, K>,
+            next_id: i64,
+        }
+
+        impl<K: Eq + Hash + Clone> $Graph<K> {
+            #[doc = concat!("Creates an empty graph, backed by a new `", stringify!($AStar), "`.")]
+            pub fn new() -> Self {
+                Self {
+                    astar: $AStar::new_gd(),
+                    ids: HashMap::new(),
+                    keys: HashMap::new(),
+                    next_id: 0,
+                }
+            }
+
+            /// Adds a point at `position`, or moves it there if `key` already exists (resetting its weight scale).
+            pub fn add_point(&mut self, key: K, position: $Vector) {
+                let id = match self.ids.get(&key) {
+                    Some(&id) => id,
+                    None => {
+                        let id = self.next_id;
+                        self.next_id += 1;
+                        self.ids.insert(key.clone(), id);
+                        self.keys.insert(id, key);
+                        id
+                    }
+                };
+
+                self.astar.add_point(id, position);
+            }
+
+            /// Removes the point and all its connections. Returns `false` if `key` was not added.
+            pub fn remove_point(&mut self, key: &K) -> bool {
+                let Some(id) = self.ids.remove(key) else {
+                    return false;
+                };
+
+                self.keys.remove(&id);
+                self.astar.remove_point(id);
+                true
+            }
+
+            /// Returns `true` if a point was added under `key`.
+            pub fn contains(&self, key: &K) -> bool {
+                self.ids.contains_key(key)
+            }
+
+            /// Position of the point, or `None` if `key` was not added.
+            pub fn position(&self, key: &K) -> Option<$Vector> {
+                self.ids.get(key).map(|&id| self.astar.get_point_position(id))
+            }
+
+            /// Multiplies the cost of travelling _into_ this point. The default is 1.
+            ///
+            /// # Panics
+            /// If `key` was not added.
+            pub fn set_weight_scale(&mut self, key: &K, weight_scale: real) {
+                let id = self.id(key);
+                self.astar.set_point_weight_scale(id, weight_scale);
+            }
+
+            /// Disabled points are skipped by path finding.
+            ///
+            /// # Panics
+            /// If `key` was not added.
+            pub fn set_disabled(&mut self, key: &K, disabled: bool) {
+                let id = self.id(key);
+                self.astar.set_point_disabled_ex(id).disabled(disabled).done();
+            }
+
+            /// Connects two points. With `bidirectional == false`, the path can only go from `from` to `to`.
+            ///
+            /// # Panics
+            /// If either key was not added.
+            pub fn connect(&mut self, from: &K, to: &K, bidirectional: bool) {
+                let (from, to) = (self.id(from), self.id(to));
+                self.astar
+                    .connect_points_ex(from, to)
+                    .bidirectional(bidirectional)
+                    .done();
+            }
+
+            /// Removes a connection between two points; `bidirectional == false` only removes the `from → to` direction.
+            ///
+            /// # Panics
+            /// If either key was not added.
+            pub fn disconnect(&mut self, from: &K, to: &K, bidirectional: bool) {
+                let (from, to) = (self.id(from), self.id(to));
+                self.astar
+                    .disconnect_points_ex(from, to)
+                    .bidirectional(bidirectional)
+                    .done();
+            }
+
+            /// Returns `true` if the path can go from `from` to `to`.
+            pub fn are_connected(&self, from: &K, to: &K) -> bool {
+                match (self.ids.get(from), self.ids.get(to)) {
+                    (Some(&from), Some(&to)) => self
+                        .astar
+                        .are_points_connected_ex(from, to)
+                        .bidirectional(false)
+                        .done(),
+                    _ => false,
+                }
+            }
+
+            /// The enabled point closest to `position`, or `None` if there is none.
+            pub fn closest_point(&self, position: $Vector) -> Option<&K> {
+                let id = self.astar.get_closest_point(position);
+                self.keys.get(&id)
+            }
+
+            /// Shortest path from `from` to `to`, including both ends.
+            ///
+            /// Returns `None` if there is no path or either key was not added.
+            pub fn find_path(&self, from: &K, to: &K) -> Option<Vec<K>> {
+                let (from, to) = self.ids_of(from, to)?;
+                let path = self.astar.get_id_path(from, to);
+
+                non_empty(path.as_slice().iter().map(|id| self.keys[id].clone()).collect())
+            }
+
+            /// Positions along the shortest path from `from` to `to`, including both ends.
+            ///
+            /// Returns `None` if there is no path or either key was not added.
+            pub fn find_point_path(&self, from: &K, to: &K) -> Option<Vec<$Vector>> {
+                let (from, to) = self.ids_of(from, to)?;
+
+                non_empty(self.astar.get_point_path(from, to).to_vec())
+            }
+
+            /// Number of points.
+            pub fn len(&self) -> usize {
+                self.ids.len()
+            }
+
+            /// Returns `true` if no points were added.
+            pub fn is_empty(&self) -> bool {
+                self.ids.is_empty()
+            }
+
+            /// Iterates over the keys of all points, in arbitrary order.
+            pub fn keys(&self) -> impl Iterator<Item = &K> {
+                self.ids.keys()
+            }
+
+            /// The underlying Godot object.
+            ///
+            /// Adding or removing points through it bypasses the key mapping, so those points are not visible to this wrapper.
+            pub fn as_gd(&self) -> &Gd<$AStar> {
+                &self.astar
+            }
+
+            fn id(&self, key: &K) -> i64 {
+                *self
+                    .ids
+                    .get(key)
+                    .unwrap_or_else(|| panic!("{}: key was not added", stringify!($Graph)))
+            }
+
+            fn ids_of(&self, from: &K, to: &K) -> Option<(i64, i64)> {
+                Some((*self.ids.get(from)?, *self.ids.get(to)?))
+            }
+        }
+
+        impl<K: Eq + Hash + Clone> Default for $Graph<K> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_astar_graph!(
+    /// Path finding over a graph of 2D points, identified by keys of type `K` instead of integer IDs.
+    ///
+    /// Wraps [`AStar2D`] and keeps the mapping between keys and Godot's point IDs on the Rust side.
+    ///
+    /// ```no_run
+    /// use godot::engine::AStarGraph2D;
+    /// use godot::builtin::Vector2;
+    ///
+    /// let mut graph = AStarGraph2D::new();
+    /// graph.add_point("town", Vector2::new(0.0, 0.0));
+    /// graph.add_point("bridge", Vector2::new(5.0, 0.0));
+    /// graph.add_point("castle", Vector2::new(5.0, 5.0));
+    /// graph.connect(&"town", &"bridge", true);
+    /// graph.connect(&"bridge", &"castle", true);
+    ///
+    /// assert_eq!(graph.find_path(&"town", &"castle"), Some(vec!["town", "bridge", "castle"]));
+    /// ```
+    AStarGraph2D, AStar2D, Vector2
+);
+
+impl_astar_graph!(
+    /// Path finding over a graph of 3D points, identified by keys of type `K` instead of integer IDs.
+    ///
+    /// Wraps [`AStar3D`] and keeps the mapping between keys and Godot's point IDs on the Rust side. See [`AStarGraph2D`] for an example.
+    AStarGraph3D, AStar3D, Vector3
+);
+
+/// Path finding on a 2D grid, wrapping [`AStarGrid2D`].
+///
+/// Cells are addressed by `Vector2i`. Paths are returned as `Vec`s of cells or of their positions, which are the cells' coordinates
+/// scaled by the cell size.
+#[derive(Debug)]
+pub struct AStarGrid {
+    grid: Gd<AStarGrid2D>,
+}
+
+impl AStarGrid {
+    /// Creates a grid spanning `region`, with all cells walkable.
+    ///
+    /// # Panics
+    /// Before Godot 4.1 (or if `GDEXT_MIN_GODOT_API` is older), if `region` does not start at the origin.
+    pub fn new(region: Rect2i, cell_size: Vector2) -> Self {
+        let mut grid = AStarGrid2D::new_gd();
+
+        #[cfg(since_min_api = "4.1")]
+        grid.set_region(region);
+        #[cfg(before_min_api = "4.1")]
+        {
+            assert_eq!(
+                region.position,
+                Vector2i::ZERO,
+                "AStarGrid: regions must start at the origin before Godot 4.1"
+            );
+            grid.set_size(region.size);
+        }
+
+        grid.set_cell_size(cell_size);
+        grid.update();
+
+        Self { grid }
+    }
+
+    /// Sets whether paths may move diagonally.
+    pub fn set_diagonal_mode(&mut self, mode: DiagonalMode) {
+        self.grid.set_diagonal_mode(mode);
+    }
+
+    /// Returns `true` if `cell` lies inside the grid's region.
+    pub fn contains(&self, cell: Vector2i) -> bool {
+        self.grid.is_in_boundsv(cell)
+    }
+
+    /// Solid cells cannot be entered.
+    ///
+    /// # Panics
+    /// If `cell` lies outside the grid's region.
+    pub fn set_solid(&mut self, cell: Vector2i, solid: bool) {
+        assert!(
+            self.contains(cell),
+            "AStarGrid: cell {cell:?} out of bounds"
+        );
+        self.grid.set_point_solid_ex(cell).solid(solid).done();
+    }
+
+    /// Returns `true` if `cell` is solid. Cells outside the region are reported as solid.
+    pub fn is_solid(&self, cell: Vector2i) -> bool {
+        !self.contains(cell) || self.grid.is_point_solid(cell)
+    }
+
+    /// Shortest path of cells from `from` to `to`, including both ends.
+    ///
+    /// Returns `None` if there is no path or either cell is outside the region.
+    pub fn find_path(&self, from: Vector2i, to: Vector2i) -> Option<Vec<Vector2i>> {
+        if !self.contains(from) || !self.contains(to) {
+            return None;
+        }
+
+        non_empty(self.grid.get_id_path(from, to).iter_shared().collect())
+    }
+
+    /// Positions along the shortest path from `from` to `to`, including both ends.
+    ///
+    /// Returns `None` if there is no path or either cell is outside the region.
+    pub fn find_point_path(&self, from: Vector2i, to: Vector2i) -> Option<Vec<Vector2>> {
+        if !self.contains(from) || !self.contains(to) {
+            return None;
+        }
+
+        non_empty(self.grid.get_point_path(from, to).to_vec())
+    }
+
+    /// The underlying Godot object.
+    pub fn as_gd(&self) -> &Gd<AStarGrid2D> {
+        &self.grid
+    }
+}
+
+/// Godot returns an empty path if there is none.
+fn non_empty<T>(path: Vec<T>) -> Option<Vec<T>> {
+    (!path.is_empty()).then_some(path)
+}
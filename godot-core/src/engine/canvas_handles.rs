@@ -0,0 +1,198 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Color, RealConv, Vector2};
+use crate::engine::global::MouseButton;
+use crate::engine::{Control, InputEvent, InputEventMouseButton, InputEventMouseMotion};
+use crate::obj::Gd;
+
+/// Draggable handles in the 2D editor viewport, without a gizmo class.
+///
+/// An `EditorPlugin` can draw over the 2D canvas and intercept its input by overriding `handles()`, `forward_canvas_draw_over_viewport()`
+/// and `forward_canvas_gui_input()`. This type implements the usual handle logic for both: it draws circles at the handle positions,
+/// picks a handle on left mouse press and reports drags as [`HandleEvent`]s.
+///
+/// Handle positions are in viewport coordinates of the overlay. For a point `local` in a `Node2D`, this is
+/// `node.get_global_transform_with_canvas() * local`; the inverse maps a dragged position back to the node.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{CanvasHandles, Control, EditorPlugin, HandleEvent, IEditorPlugin, InputEvent};
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, editor_plugin, base=EditorPlugin)]
+/// struct PivotPlugin {
+///     target: Option<Gd<Node2D>>,
+///     handles: CanvasHandles,
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorPlugin for PivotPlugin {
+///     fn handles(&self, object: Gd<Object>) -> bool {
+///         object.is_class("Node2D".into())
+///     }
+///
+///     fn edit(&mut self, object: Option<Gd<Object>>) {
+///         self.target = object.and_then(|obj| obj.try_cast::<Node2D>().ok());
+///     }
+///
+///     fn forward_canvas_draw_over_viewport(&mut self, mut overlay: Gd<Control>) {
+///         let Some(target) = &self.target else { return };
+///         let origin = target.get_global_transform_with_canvas() * Vector2::ZERO;
+///         self.handles.set_positions([origin]);
+///         self.handles.draw(&mut overlay);
+///     }
+///
+///     fn forward_canvas_gui_input(&mut self, event: Gd<InputEvent>) -> bool {
+///         let Some(target) = &mut self.target else { return false };
+///         match self.handles.handle_input(&event) {
+///             Some(HandleEvent::Dragged { position, .. }) => {
+///                 let to_canvas = target.get_global_transform_with_canvas();
+///                 let global = target.get_global_transform() * (to_canvas.affine_inverse() * position);
+///                 target.set_global_position(global);
+///                 self.base_mut().update_overlays();
+///                 true
+///             }
+///             Some(_) => true,
+///             None => false,
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanvasHandles {
+    positions: Vec<Vector2>,
+    radius: real,
+    color: Color,
+    active_color: Color,
+    dragged: Option<usize>,
+}
+
+impl CanvasHandles {
+    /// Radius used by [`Default`], in viewport pixels.
+    pub const DEFAULT_RADIUS: real = 6.0;
+
+    /// Creates handles with the given radius (in viewport pixels) and color.
+    pub fn new(radius: real, color: Color) -> Self {
+        Self {
+            positions: Vec::new(),
+            radius,
+            color,
+            active_color: Color::WHITE,
+            dragged: None,
+        }
+    }
+
+    /// Replaces the handle positions, in viewport coordinates. Handle indices correspond to the iteration order.
+    ///
+    /// Usually called each time the overlay is drawn, as the canvas may have been panned or zoomed. An ongoing drag continues if its
+    /// handle index still exists.
+    pub fn set_positions(&mut self, positions: impl IntoIterator<Item = Vector2>) {
+        self.positions.clear();
+        self.positions.extend(positions);
+
+        if matches!(self.dragged, Some(index) if index >= self.positions.len()) {
+            self.dragged = None;
+        }
+    }
+
+    /// Current handle positions, in viewport coordinates.
+    pub fn positions(&self) -> &[Vector2] {
+        &self.positions
+    }
+
+    /// Index of the handle currently being dragged, if any.
+    pub fn dragged(&self) -> Option<usize> {
+        self.dragged
+    }
+
+    /// Color of the handle that is being dragged.
+    pub fn set_active_color(&mut self, color: Color) {
+        self.active_color = color;
+    }
+
+    /// Returns the index of the topmost handle at `position`, if any. Later handles are drawn on top of earlier ones.
+    pub fn handle_at(&self, position: Vector2) -> Option<usize> {
+        self.positions
+            .iter()
+            .rposition(|handle| handle.distance_to(position) <= self.radius)
+    }
+
+    /// Draws all handles onto the overlay passed to `forward_canvas_draw_over_viewport()`.
+    pub fn draw(&self, overlay: &mut Gd<Control>) {
+        let outline = Color::from_rgba(0.0, 0.0, 0.0, 0.6);
+
+        for (index, &position) in self.positions.iter().enumerate() {
+            let color = if self.dragged == Some(index) {
+                self.active_color
+            } else {
+                self.color
+            };
+
+            overlay.draw_circle(position, (self.radius + 1.0).as_f32(), outline);
+            overlay.draw_circle(position, self.radius.as_f32(), color);
+        }
+    }
+
+    /// Processes an event passed to `forward_canvas_gui_input()`.
+    ///
+    /// Returns `Some` if the event concerns a handle, in which case `forward_canvas_gui_input()` should return `true` to consume it.
+    /// After a drag, call `update_overlays()` on the plugin so that the handles are redrawn.
+    pub fn handle_input(&mut self, event: &Gd<InputEvent>) -> Option<HandleEvent> {
+        let event = match event.clone().try_cast::<InputEventMouseButton>() {
+            Ok(button) => return self.handle_button(button),
+            Err(event) => event,
+        };
+
+        let motion = event.try_cast::<InputEventMouseMotion>().ok()?;
+        let index = self.dragged?;
+        let position = motion.get_position();
+        self.positions[index] = position;
+
+        Some(HandleEvent::Dragged { index, position })
+    }
+
+    fn handle_button(&mut self, button: Gd<InputEventMouseButton>) -> Option<HandleEvent> {
+        if button.get_button_index() != MouseButton::LEFT {
+            return None;
+        }
+
+        let position = button.get_position();
+        if button.is_pressed() {
+            let index = self.handle_at(position)?;
+            self.dragged = Some(index);
+            Some(HandleEvent::Pressed { index })
+        } else {
+            let index = self.dragged.take()?;
+            Some(HandleEvent::Released {
+                index,
+                position: self.positions[index],
+            })
+        }
+    }
+}
+
+impl Default for CanvasHandles {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_RADIUS, Color::from_rgb(1.0, 0.5, 0.0))
+    }
+}
+
+/// Interaction with a handle, returned by [`CanvasHandles::handle_input()`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HandleEvent {
+    /// The handle was clicked and is now being dragged.
+    Pressed { index: usize },
+
+    /// The handle was moved to `position`, in viewport coordinates.
+    Dragged { index: usize, position: Vector2 },
+
+    /// The drag ended with the handle at `position`, in viewport coordinates. Suitable for committing an undo/redo action.
+    Released { index: usize, position: Vector2 },
+}
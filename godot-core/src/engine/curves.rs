@@ -0,0 +1,387 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::math::FloatExt;
+use crate::builtin::{real, Color, PackedColorArray, PackedFloat32Array, Vector2, Vector3};
+use crate::engine::curve::TangentMode;
+use crate::engine::gradient::InterpolationMode;
+use crate::engine::{Curve, Curve2D, Curve3D, Gradient};
+use crate::obj::{Gd, NewGd};
+
+#[cfg(since_min_api = "4.2")]
+use crate::engine::gradient::ColorSpace;
+
+/// Snapshot of a [`Curve`], sampled in Rust.
+///
+/// Reads the curve at its bake resolution once on construction; [`sample()`][Self::sample] then interpolates between those values
+/// like `Curve.sample_baked()`, without calling into the engine. Later changes to the curve are not reflected; call
+/// [`rebake()`][Self::rebake] to pick them up.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::BakedCurve;
+///
+/// let falloff = BakedCurve::from_points([Vector2::new(0.0, 1.0), Vector2::new(0.7, 0.8), Vector2::new(1.0, 0.0)]);
+/// let heights: Vec<real> = (0..256).map(|x| falloff.sample(x as real / 255.0)).collect();
+/// ```
+#[derive(Clone, Debug)]
+pub struct BakedCurve {
+    curve: Gd<Curve>,
+    values: Vec<real>,
+}
+
+impl BakedCurve {
+    /// Bakes `curve`.
+    pub fn new(curve: Gd<Curve>) -> Self {
+        let mut baked = Self {
+            curve,
+            values: Vec::new(),
+        };
+        baked.rebake();
+        baked
+    }
+
+    /// Creates a new `Curve` through `points`, given as `(offset, value)` with offsets between 0 and 1.
+    ///
+    /// Tangents are linear, so the curve consists of straight segments. The value range of the curve is widened to include all values.
+    pub fn from_points(points: impl IntoIterator<Item = Vector2>) -> Self {
+        let points: Vec<Vector2> = points.into_iter().collect();
+        let mut curve = Curve::new_gd();
+
+        // Set the range first, so that values are not clamped to the default 0..1.
+        let (min, max) = points.iter().fold((0.0, 1.0), |(min, max), point| {
+            (point.y.min(min), point.y.max(max))
+        });
+        curve.set_min_value(min);
+        curve.set_max_value(max);
+
+        for point in points {
+            curve
+                .add_point_ex(point)
+                .left_mode(TangentMode::LINEAR)
+                .right_mode(TangentMode::LINEAR)
+                .done();
+        }
+
+        Self::new(curve)
+    }
+
+    /// Reads the curve again, after it has been modified.
+    pub fn rebake(&mut self) {
+        let resolution = self.curve.get_bake_resolution().max(2);
+        let last = (resolution - 1) as real;
+
+        self.values = (0..resolution)
+            .map(|i| self.curve.sample_baked(i as real / last))
+            .collect();
+    }
+
+    /// Value of the curve at `offset`, between 0 and 1. Offsets outside that range are clamped.
+    pub fn sample(&self, offset: real) -> real {
+        let position = offset.clamp(0.0, 1.0) * (self.values.len() - 1) as real;
+        let index = position.floor() as usize;
+
+        match self.values.get(index + 1) {
+            Some(&next) => self.values[index].lerp(next, position - index as real),
+            None => self.values[self.values.len() - 1],
+        }
+    }
+
+    /// Baked values, evenly spaced between offsets 0 and 1.
+    pub fn values(&self) -> &[real] {
+        &self.values
+    }
+
+    /// The underlying Godot object.
+    pub fn as_gd(&self) -> &Gd<Curve> {
+        &self.curve
+    }
+}
+
+macro_rules! impl_baked_path {
+    (
+        $(#[$attr:meta])*
+        $Baked:ident, $Curve:ident, $Vector:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $Baked {
+            curve: Gd<$Curve>,
+            points: Vec<$Vector>,
+            distances: Vec<real>,
+        }
+
+        impl $Baked {
+            /// Bakes `curve`.
+            pub fn new(curve: Gd<$Curve>) -> Self {
+                let mut baked = Self {
+                    curve,
+                    points: Vec::new(),
+                    distances: Vec::new(),
+                };
+                baked.rebake();
+                baked
+            }
+
+            #[doc = concat!("Creates a new `", stringify!($Curve), "` through `points`, connected by straight segments.")]
+            pub fn from_points(points: impl IntoIterator<Item = $Vector>) -> Self {
+                let mut curve = $Curve::new_gd();
+                for point in points {
+                    curve.add_point(point);
+                }
+
+                Self::new(curve)
+            }
+
+            /// Reads the baked points of the curve again, after it has been modified.
+            pub fn rebake(&mut self) {
+                self.points = self.curve.get_baked_points().to_vec();
+
+                let mut length = 0.0;
+                let mut previous = self.points.first().copied();
+                self.distances = self
+                    .points
+                    .iter()
+                    .map(|&point| {
+                        length += previous.replace(point).map_or(0.0, |prev| prev.distance_to(point));
+                        length
+                    })
+                    .collect();
+            }
+
+            /// Position on the curve at distance `offset` from the start, like `sample_baked()` without cubic interpolation.
+            ///
+            /// Offsets outside `0..=length()` are clamped. Returns zero if the curve has no points.
+            pub fn sample(&self, offset: real) -> $Vector {
+                let Some(&last) = self.points.last() else {
+                    return $Vector::ZERO;
+                };
+
+                let offset = offset.clamp(0.0, self.length());
+                let next = self.distances.partition_point(|&distance| distance <= offset);
+                if next == 0 || next == self.points.len() {
+                    return if next == 0 { self.points[0] } else { last };
+                }
+
+                let start = self.distances[next - 1];
+                let weight = (offset - start) / (self.distances[next] - start);
+                self.points[next - 1].lerp(self.points[next], weight)
+            }
+
+            /// Position on the curve at `ratio` of its length, between 0 and 1.
+            pub fn sample_ratio(&self, ratio: real) -> $Vector {
+                self.sample(ratio * self.length())
+            }
+
+            /// Total length of the baked curve.
+            pub fn length(&self) -> real {
+                self.distances.last().copied().unwrap_or(0.0)
+            }
+
+            /// Baked points, spaced by the curve's bake interval.
+            pub fn points(&self) -> &[$Vector] {
+                &self.points
+            }
+
+            /// The underlying Godot object.
+            pub fn as_gd(&self) -> &Gd<$Curve> {
+                &self.curve
+            }
+        }
+    };
+}
+
+impl_baked_path!(
+    /// Snapshot of the baked points of a [`Curve2D`], sampled in Rust.
+    ///
+    /// Reads the baked points once on construction; [`sample()`][Self::sample] then interpolates between them by distance, without
+    /// calling into the engine. Later changes to the curve are not reflected; call [`rebake()`][Self::rebake] to pick them up.
+    BakedCurve2D, Curve2D, Vector2
+);
+
+impl_baked_path!(
+    /// Snapshot of the baked points of a [`Curve3D`], sampled in Rust.
+    ///
+    /// Reads the baked points once on construction; [`sample()`][Self::sample] then interpolates between them by distance, without
+    /// calling into the engine. Later changes to the curve are not reflected; call [`rebake()`][Self::rebake] to pick them up.
+    ///
+    /// Up vectors and tilts are not included.
+    BakedCurve3D, Curve3D, Vector3
+);
+
+/// Snapshot of the color stops of a [`Gradient`], sampled in Rust.
+///
+/// Reads offsets, colors, interpolation mode and (since Godot 4.2) color space once on construction; [`sample()`][Self::sample] then
+/// computes colors like `Gradient.sample()`, without calling into the engine. Later changes to the gradient are not reflected; call
+/// [`rebake()`][Self::rebake] to pick them up.
+#[derive(Clone, Debug)]
+pub struct BakedGradient {
+    gradient: Gd<Gradient>,
+    offsets: Vec<f32>,
+    colors: Vec<Color>,
+    /// Colors converted to the space in which they are interpolated.
+    interpolated: Vec<Color>,
+    mode: InterpolationMode,
+    space: InterpolationSpace,
+}
+
+impl BakedGradient {
+    /// Bakes `gradient`.
+    pub fn new(gradient: Gd<Gradient>) -> Self {
+        let mut baked = Self {
+            gradient,
+            offsets: Vec::new(),
+            colors: Vec::new(),
+            interpolated: Vec::new(),
+            mode: InterpolationMode::LINEAR,
+            space: InterpolationSpace::Srgb,
+        };
+        baked.rebake();
+        baked
+    }
+
+    /// Creates a new `Gradient` from `(offset, color)` stops, with linear interpolation.
+    pub fn from_stops(stops: impl IntoIterator<Item = (f32, Color)>) -> Self {
+        let (offsets, colors): (PackedFloat32Array, PackedColorArray) = stops.into_iter().unzip();
+
+        // Offsets first: setting them resizes the stops, and colors are then assigned in order.
+        let mut gradient = Gradient::new_gd();
+        gradient.set_offsets(offsets);
+        gradient.set_colors(colors);
+
+        Self::new(gradient)
+    }
+
+    /// Reads the gradient again, after it has been modified.
+    pub fn rebake(&mut self) {
+        let offsets = self.gradient.get_offsets();
+        let colors = self.gradient.get_colors();
+
+        let mut stops: Vec<(f32, Color)> = offsets
+            .as_slice()
+            .iter()
+            .copied()
+            .zip(colors.as_slice().iter().copied())
+            .collect();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        (self.offsets, self.colors) = stops.into_iter().unzip();
+
+        self.mode = self.gradient.get_interpolation_mode();
+        self.space = InterpolationSpace::of(&self.gradient);
+        self.interpolated = self
+            .colors
+            .iter()
+            .map(|&color| self.space.to_space(color))
+            .collect();
+    }
+
+    /// Color of the gradient at `offset`. Offsets before the first or after the last stop return the color of that stop.
+    ///
+    /// Returns opaque black if the gradient has no stops.
+    pub fn sample(&self, offset: f32) -> Color {
+        let second = self.offsets.partition_point(|&stop| stop <= offset);
+        if second == 0 || second == self.offsets.len() {
+            return match second {
+                0 => self.colors.first(),
+                _ => self.colors.last(),
+            }
+            .copied()
+            .unwrap_or(Color::BLACK);
+        }
+
+        let first = second - 1;
+        let weight = (offset - self.offsets[first]) / (self.offsets[second] - self.offsets[first]);
+
+        let from = self.interpolated[first];
+        let to = self.interpolated[second];
+        let color = match self.mode {
+            InterpolationMode::CONSTANT => return self.colors[first],
+            InterpolationMode::CUBIC => {
+                let pre = self.interpolated[first.saturating_sub(1)];
+                let post = self.interpolated[(second + 1).min(self.offsets.len() - 1)];
+                let cubic = |from: f32, to: f32, pre: f32, post: f32| {
+                    from.cubic_interpolate(to, pre, post, weight)
+                };
+
+                Color::from_rgba(
+                    cubic(from.r, to.r, pre.r, post.r),
+                    cubic(from.g, to.g, pre.g, post.g),
+                    cubic(from.b, to.b, pre.b, post.b),
+                    cubic(from.a, to.a, pre.a, post.a),
+                )
+            }
+            _ => Color::from_rgba(
+                from.r.lerp(to.r, weight),
+                from.g.lerp(to.g, weight),
+                from.b.lerp(to.b, weight),
+                from.a.lerp(to.a, weight),
+            ),
+        };
+
+        self.space.to_srgb(color)
+    }
+
+    /// Offsets of the color stops, in ascending order.
+    pub fn offsets(&self) -> &[f32] {
+        &self.offsets
+    }
+
+    /// Colors of the stops, in the order of [`offsets()`][Self::offsets].
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// The underlying Godot object.
+    pub fn as_gd(&self) -> &Gd<Gradient> {
+        &self.gradient
+    }
+}
+
+/// Color space in which a gradient interpolates.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum InterpolationSpace {
+    Srgb,
+    #[cfg_attr(before_min_api = "4.2", allow(dead_code))]
+    LinearSrgb,
+    #[cfg_attr(before_min_api = "4.2", allow(dead_code))]
+    Oklab,
+}
+
+impl InterpolationSpace {
+    #[cfg(since_min_api = "4.2")]
+    fn of(gradient: &Gd<Gradient>) -> Self {
+        match gradient.get_interpolation_color_space() {
+            ColorSpace::LINEAR_SRGB => Self::LinearSrgb,
+            ColorSpace::OKLAB => Self::Oklab,
+            _ => Self::Srgb,
+        }
+    }
+
+    #[cfg(before_min_api = "4.2")]
+    fn of(_gradient: &Gd<Gradient>) -> Self {
+        Self::Srgb
+    }
+
+    fn to_space(self, color: Color) -> Color {
+        match self {
+            Self::Srgb => color,
+            Self::LinearSrgb => color.srgb_to_linear(),
+            Self::Oklab => {
+                let (l, a, b) = color.to_oklab();
+                Color::from_rgba(l, a, b, color.a)
+            }
+        }
+    }
+
+    fn to_srgb(self, color: Color) -> Color {
+        match self {
+            Self::Srgb => color,
+            Self::LinearSrgb => color.linear_to_srgb(),
+            Self::Oklab => Color::from_oklab(color.r, color.g, color.b).with_alpha(color.a),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Type-checked dynamic method calls.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::meta::{ConvertError, FromGodot};
+use crate::builtin::{StringName, Variant};
+use crate::engine::Object;
+use crate::obj::{Gd, Inherits};
+
+pub use crate::call;
+
+/// Calls a method dynamically by name, with arguments and return value checked through the type system.
+///
+/// This is a typed alternative to [`Object::call()`], which accepts a `&[Variant]` slice and returns a `Variant`. Instead, the macro
+/// accepts arguments of any type implementing [`ToGodot`](crate::builtin::meta::ToGodot) -- this is checked at compile time -- and
+/// converts the return value into the expected type `R: FromGodot`. The method name is converted to a `StringName` only once per call site.
+///
+/// The result is a `Result<R, CallError>`. Errors are returned when:
+/// * the object has been freed,
+/// * the object has no method with the given name,
+/// * the returned `Variant` cannot be converted to `R`.
+///
+/// The return type is usually inferred, but can also be specified explicitly with `-> R`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::dynamic_call::{call, CallError};
+///
+/// fn apply_damage(enemy: &Gd<Node>, amount: i64) -> Result<bool, CallError> {
+///     // Calls `func take_damage(amount: int, source: String) -> bool` defined in GDScript.
+///     call!(enemy, take_damage(amount, GString::from("trap")))
+/// }
+///
+/// # let enemy: Gd<Node> = unimplemented!();
+/// let health = call!(enemy, get_health() -> i64).unwrap_or(0);
+/// ```
+#[macro_export]
+macro_rules! call {
+    ($obj:expr, $method:ident ( $($arg:expr),* $(,)? ) -> $Ret:ty) => {
+        $crate::call!($obj, $method($($arg),*)).map(|ret: $Ret| ret)
+    };
+
+    ($obj:expr, $method:ident ( $($arg:expr),* $(,)? )) => {{
+        static METHOD_NAME: ::std::sync::OnceLock<$crate::builtin::StringName> = ::std::sync::OnceLock::new();
+        let method_name = METHOD_NAME.get_or_init(|| $crate::builtin::StringName::from(stringify!($method)));
+
+        $crate::engine::dynamic_call::try_call(
+            &$obj,
+            method_name,
+            &[$( $crate::builtin::meta::ToGodot::to_variant(&$arg) ),*],
+        )
+    }};
+}
+
+/// Calls `method_name` on `obj` with the given arguments, converting the return value to `R`.
+///
+/// Prefer the [`call!`](crate::call) macro, which checks argument types at compile time and caches the method name.
+pub fn try_call<T, R>(
+    obj: &Gd<T>,
+    method_name: &StringName,
+    args: &[Variant],
+) -> Result<R, CallError>
+where
+    T: Inherits<Object>,
+    R: FromGodot,
+{
+    if !obj.is_instance_valid() {
+        return Err(CallError::new(
+            CallErrorKind::ObjectFreed,
+            method_name,
+            None,
+        ));
+    }
+
+    let mut obj = obj.clone().upcast::<Object>();
+    if !obj.has_method(method_name.clone()) {
+        let class = obj.get_class().to_string();
+        return Err(CallError::new(
+            CallErrorKind::MethodNotFound,
+            method_name,
+            Some(class),
+        ));
+    }
+
+    let ret = obj.call(method_name.clone(), args);
+    R::try_from_variant(&ret).map_err(|err| {
+        let class = obj.get_class().to_string();
+        CallError::new(CallErrorKind::BadReturn(err), method_name, Some(class))
+    })
+}
+
+/// Error returned by [`call!`](crate::call) and [`try_call()`], as well as the `try_*` engine methods generated with the
+/// `codegen-try-methods` feature.
+#[derive(Debug)]
+pub struct CallError {
+    kind: CallErrorKind,
+    method_name: String,
+    class_name: Option<String>,
+}
+
+impl CallError {
+    fn new(kind: CallErrorKind, method_name: &StringName, class_name: Option<String>) -> Self {
+        Self {
+            kind,
+            method_name: method_name.to_string(),
+            class_name,
+        }
+    }
+
+    /// Error from a generated engine method, where the class name is not known at runtime.
+    pub(crate) fn from_engine_method(kind: CallErrorKind, method_name: &str) -> Self {
+        Self {
+            kind,
+            method_name: method_name.to_string(),
+            class_name: None,
+        }
+    }
+
+    /// Name of the method that was called.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// Whether the call failed because the object was freed before or during the call.
+    pub fn is_object_freed(&self) -> bool {
+        matches!(self.kind, CallErrorKind::ObjectFreed)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum CallErrorKind {
+    ObjectFreed,
+    MethodNotFound,
+    /// The method's class is not part of this engine build (see [`OptionalApi`](crate::engine::OptionalApi)), or the method is newer
+    /// than the running Godot version.
+    MethodNotLoaded,
+    /// Godot rejected a varcall, e.g. due to wrong argument types or count.
+    Failed {
+        args: String,
+        reason: String,
+    },
+    BadReturn(ConvertError),
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = &self.method_name;
+        let class = self.class_name.as_deref().unwrap_or("<freed>");
+
+        match &self.kind {
+            CallErrorKind::ObjectFreed => write!(f, "cannot call `{method}()` on freed object"),
+            CallErrorKind::MethodNotFound => {
+                write!(f, "class `{class}` has no method `{method}()`")
+            }
+            CallErrorKind::MethodNotLoaded => write!(
+                f,
+                "method `{method}` is not available: its class is not part of this engine build, or it requires a newer Godot version"
+            ),
+            CallErrorKind::Failed { args, reason } => {
+                write!(f, "call to `{method}({args})` failed: {reason}")
+            }
+            CallErrorKind::BadReturn(err) => match &self.class_name {
+                Some(class) => write!(
+                    f,
+                    "return value of `{class}::{method}()` has unexpected type: {err}"
+                ),
+                None => write!(f, "return value of `{method}()` has unexpected type: {err}"),
+            },
+        }
+    }
+}
+
+impl Error for CallError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            CallErrorKind::BadReturn(err) => Some(err),
+            _ => None,
+        }
+    }
+}
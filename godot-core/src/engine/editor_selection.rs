@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::engine::{EditorInterface, EditorSelection, Node, Object};
+use crate::obj::{Gd, Inherits};
+
+#[cfg(since_min_api = "4.2")]
+use crate::builtin::Transform2D;
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Variant};
+#[cfg(since_min_api = "4.2")]
+use crate::engine::Camera3D;
+
+/// Extension trait for typed access to the nodes selected in the editor.
+///
+/// Obtain the selection with `EditorInterface::singleton().get_selection()`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{EditorInterface, EditorSelectionExt as _};
+///
+/// let mut selection = EditorInterface::singleton().get_selection().unwrap();
+/// selection.on_selection_changed(|nodes| {
+///     godot_print!("{} nodes selected", nodes.len());
+/// });
+///
+/// for sprite in selection.selected_nodes::<Sprite2D>() {
+///     godot_print!("selected sprite: {}", sprite.get_name());
+/// }
+/// ```
+pub trait EditorSelectionExt {
+    /// Returns all selected nodes of type `T` (or derived), in selection order. Nodes of other types are skipped.
+    fn selected_nodes<T: Inherits<Node>>(&self) -> Vec<Gd<T>>;
+
+    /// Replaces the current selection with `nodes`.
+    fn select_only<T: Inherits<Node>>(&mut self, nodes: &[Gd<T>]);
+
+    /// Invokes `callback` with the selected nodes whenever the selection changes.
+    ///
+    /// Returns the connected callable, which can be passed to `disconnect()`.
+    #[cfg(since_api = "4.2")]
+    fn on_selection_changed<F>(&mut self, callback: F) -> Callable
+    where
+        F: FnMut(Vec<Gd<Node>>) + Send + Sync + 'static;
+}
+
+impl EditorSelectionExt for Gd<EditorSelection> {
+    fn selected_nodes<T: Inherits<Node>>(&self) -> Vec<Gd<T>> {
+        self.clone()
+            .get_selected_nodes()
+            .iter_shared()
+            .filter_map(|node| node.try_cast::<T>().ok())
+            .collect()
+    }
+
+    fn select_only<T: Inherits<Node>>(&mut self, nodes: &[Gd<T>]) {
+        self.clear();
+        for node in nodes {
+            self.add_node(node.clone().upcast());
+        }
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn on_selection_changed<F>(&mut self, mut callback: F) -> Callable
+    where
+        F: FnMut(Vec<Gd<Node>>) + Send + Sync + 'static,
+    {
+        // Gd is not Send; look the selection up again on each emission.
+        let selection_id = self.instance_id();
+        let callable = Callable::from_fn("on_selection_changed", move |_args| {
+            if let Ok(selection) = Gd::<EditorSelection>::try_from_instance_id(selection_id) {
+                callback(selection.selected_nodes());
+            }
+            Ok(Variant::nil())
+        });
+
+        self.connect("selection_changed".into(), callable.clone());
+        callable
+    }
+}
+
+/// Extension trait for inspector and viewport interaction through [`EditorInterface`].
+pub trait EditorInterfaceExt {
+    /// Shows `object` in the inspector.
+    fn inspect<T: Inherits<Object>>(&mut self, object: &Gd<T>);
+
+    /// Shows `object` in the inspector and scrolls to `property`.
+    fn inspect_property<T: Inherits<Object>>(&mut self, object: &Gd<T>, property: &str);
+
+    /// Camera of the 3D editor viewport with index `viewport` (0 to 3, depending on the split layout).
+    ///
+    /// The editor positions this camera according to its own navigation; changes to its transform only last until the user moves the view.
+    #[cfg(since_min_api = "4.2")]
+    fn editor_camera_3d(&mut self, viewport: usize) -> Option<Gd<Camera3D>>;
+
+    /// Current view transform of the 2D editor, mapping canvas to viewport coordinates (pan and zoom).
+    ///
+    /// This and [`editor_camera_3d()`](Self::editor_camera_3d) are only available if `GDEXT_MIN_GODOT_API` is unset or at least 4.2.
+    #[cfg(since_min_api = "4.2")]
+    fn editor_canvas_transform(&mut self) -> Option<Transform2D>;
+}
+
+impl EditorInterfaceExt for EditorInterface {
+    fn inspect<T: Inherits<Object>>(&mut self, object: &Gd<T>) {
+        self.inspect_object(object.clone().upcast());
+    }
+
+    fn inspect_property<T: Inherits<Object>>(&mut self, object: &Gd<T>, property: &str) {
+        self.inspect_object_ex(object.clone().upcast())
+            .for_property(property.into())
+            .done();
+    }
+
+    #[cfg(since_min_api = "4.2")]
+    fn editor_camera_3d(&mut self, viewport: usize) -> Option<Gd<Camera3D>> {
+        let index = i32::try_from(viewport).ok()?;
+        self.get_editor_viewport_3d_ex()
+            .idx(index)
+            .done()?
+            .get_camera_3d()
+    }
+
+    #[cfg(since_min_api = "4.2")]
+    fn editor_canvas_transform(&mut self) -> Option<Transform2D> {
+        let viewport = self.get_editor_viewport_2d()?;
+        Some(viewport.get_global_canvas_transform())
+    }
+}
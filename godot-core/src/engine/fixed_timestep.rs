@@ -0,0 +1,149 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Runs a simulation at a fixed rate, independent of frame rate and physics ticks.
+///
+/// Each frame, pass the frame's `delta` to [`advance()`](Self::advance). The accumulated time is consumed in steps of fixed length, invoking
+/// the tick closure once per step (possibly zero or multiple times per frame). The returned interpolation alpha tells how far the remaining
+/// time is into the next step, so rendering can blend between the previous and current simulation state.
+///
+/// Because every tick sees the same step length, the simulation is deterministic for the same sequence of ticks.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::FixedTimestep;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D)]
+/// struct Simulation {
+///     timestep: FixedTimestep,
+///     previous: Vector2,
+///     current: Vector2,
+///     velocity: Vector2,
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Simulation {
+///     fn init(base: Base<Node2D>) -> Self {
+///         Self {
+///             timestep: FixedTimestep::from_rate(120.0),
+///             previous: Vector2::ZERO,
+///             current: Vector2::ZERO,
+///             velocity: Vector2::new(50.0, 0.0),
+///             base,
+///         }
+///     }
+///
+///     fn process(&mut self, delta: f64) {
+///         let Self { timestep, previous, current, velocity, .. } = self;
+///         let alpha = timestep.advance(delta, |step| {
+///             *previous = *current;
+///             *current += *velocity * step as real;
+///         });
+///
+///         let position = self.previous.lerp(self.current, alpha as real);
+///         self.base_mut().set_position(position);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedTimestep {
+    step: f64,
+    accumulator: f64,
+    max_steps_per_frame: u32,
+}
+
+impl FixedTimestep {
+    /// Default for [`with_max_steps_per_frame()`](Self::with_max_steps_per_frame).
+    pub const DEFAULT_MAX_STEPS_PER_FRAME: u32 = 8;
+
+    /// Creates a timestep with steps of `step` seconds.
+    ///
+    /// # Panics
+    /// If `step` is not a positive, finite number.
+    pub fn new(step: f64) -> Self {
+        assert!(
+            step > 0.0 && step.is_finite(),
+            "fixed timestep must be positive and finite, got {step}"
+        );
+
+        Self {
+            step,
+            accumulator: 0.0,
+            max_steps_per_frame: Self::DEFAULT_MAX_STEPS_PER_FRAME,
+        }
+    }
+
+    /// Creates a timestep running `ticks_per_second` steps per second.
+    ///
+    /// # Panics
+    /// If `ticks_per_second` is not a positive, finite number.
+    pub fn from_rate(ticks_per_second: f64) -> Self {
+        Self::new(1.0 / ticks_per_second)
+    }
+
+    /// Limits the number of steps run in a single [`advance()`](Self::advance) call.
+    ///
+    /// If a frame takes so long that more steps would be needed, the excess time is dropped and the simulation slows down, instead of
+    /// falling further behind with every frame.
+    ///
+    /// # Panics
+    /// If `max_steps` is 0.
+    pub fn with_max_steps_per_frame(mut self, max_steps: u32) -> Self {
+        assert!(max_steps > 0, "max steps per frame must be at least 1");
+        self.max_steps_per_frame = max_steps;
+        self
+    }
+
+    /// Length of a single step in seconds.
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Adds `delta` seconds and invokes `tick` with the step length once for each full step that has accumulated.
+    ///
+    /// Negative, NaN and infinite values of `delta` are ignored, so that a single bad frame cannot stall the simulation. At most
+    /// [`with_max_steps_per_frame()`](Self::with_max_steps_per_frame) steps are run.
+    ///
+    /// Returns the interpolation alpha, see [`alpha()`](Self::alpha).
+    pub fn advance<F>(&mut self, delta: f64, mut tick: F) -> f64
+    where
+        F: FnMut(f64),
+    {
+        if delta.is_finite() && delta > 0.0 {
+            self.accumulator += delta;
+        }
+
+        let mut steps = 0;
+        while self.accumulator >= self.step {
+            if steps == self.max_steps_per_frame {
+                // Drop whole steps that could not be run, keep the fraction for interpolation.
+                self.accumulator %= self.step;
+                break;
+            }
+
+            tick(self.step);
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+
+        self.alpha()
+    }
+
+    /// Fraction of the next step that has already accumulated, in `[0, 1)`.
+    ///
+    /// Use this to interpolate between the state before and after the last step.
+    pub fn alpha(&self) -> f64 {
+        self.accumulator / self.step
+    }
+
+    /// Discards accumulated time, e.g. after loading a level or unpausing.
+    pub fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
@@ -0,0 +1,224 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed access to the [`Geometry2D`] and [`Geometry3D`] singletons.
+//!
+//! Polygons and point sets are passed as slices and returned as `Vec`s. Polygon operations such as [`clip_polygons`] return an empty
+//! `Vec` if there is no resulting polygon. Intersection points that the engine reports as `null`, and failed triangulations, are
+//! returned as `Option`.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::engine::geometry;
+//!
+//! let square = [Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(2.0, 2.0), Vector2::new(0.0, 2.0)];
+//! let shifted = square.map(|v| v + Vector2::new(1.0, 1.0));
+//!
+//! for polygon in geometry::intersect_polygons(&square, &shifted) {
+//!     godot_print!("overlap: {polygon:?}");
+//! }
+//! ```
+
+use crate::builtin::meta::FromGodot;
+use crate::builtin::{
+    real, Array, PackedInt32Array, PackedVector2Array, Variant, Vector2, Vector3,
+};
+use crate::engine::geometry_2d::PolyJoinType;
+use crate::engine::{Geometry2D, Geometry3D};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 2D polygon operations
+
+/// Subtracts `polygon_b` from `polygon_a`.
+///
+/// The result may contain several polygons if `polygon_b` splits `polygon_a`, or holes, which are returned as clockwise polygons
+/// (see [`is_polygon_clockwise()`]).
+pub fn clip_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    to_polygons(Geometry2D::singleton().clip_polygons(packed(polygon_a), packed(polygon_b)))
+}
+
+/// Intersects `polygon_a` with `polygon_b`, returning the overlapping areas.
+pub fn intersect_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    to_polygons(Geometry2D::singleton().intersect_polygons(packed(polygon_a), packed(polygon_b)))
+}
+
+/// Merges `polygon_a` and `polygon_b`, returning their union.
+///
+/// Holes in the union are returned as clockwise polygons (see [`is_polygon_clockwise()`]).
+pub fn merge_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    to_polygons(Geometry2D::singleton().merge_polygons(packed(polygon_a), packed(polygon_b)))
+}
+
+/// Returns the areas covered by exactly one of `polygon_a` and `polygon_b` (symmetric difference).
+pub fn exclude_polygons(polygon_a: &[Vector2], polygon_b: &[Vector2]) -> Vec<Vec<Vector2>> {
+    to_polygons(Geometry2D::singleton().exclude_polygons(packed(polygon_a), packed(polygon_b)))
+}
+
+/// Cuts the parts of `polyline` that lie inside `polygon`, returning the remaining pieces.
+pub fn clip_polyline_with_polygon(polyline: &[Vector2], polygon: &[Vector2]) -> Vec<Vec<Vector2>> {
+    to_polygons(
+        Geometry2D::singleton().clip_polyline_with_polygon(packed(polyline), packed(polygon)),
+    )
+}
+
+/// Returns the pieces of `polyline` that lie inside `polygon`.
+pub fn intersect_polyline_with_polygon(
+    polyline: &[Vector2],
+    polygon: &[Vector2],
+) -> Vec<Vec<Vector2>> {
+    to_polygons(
+        Geometry2D::singleton().intersect_polyline_with_polygon(packed(polyline), packed(polygon)),
+    )
+}
+
+/// Grows (positive `delta`) or shrinks (negative `delta`) `polygon`, with corners shaped by `join`.
+///
+/// Shrinking can split a polygon into several, or make it disappear entirely.
+pub fn offset_polygon(polygon: &[Vector2], delta: real, join: PolyJoinType) -> Vec<Vec<Vector2>> {
+    to_polygons(
+        Geometry2D::singleton()
+            .offset_polygon_ex(packed(polygon), delta)
+            .join_type(join)
+            .done(),
+    )
+}
+
+/// Returns `true` if `point` is inside `polygon` or on its boundary.
+pub fn is_point_in_polygon(point: Vector2, polygon: &[Vector2]) -> bool {
+    Geometry2D::singleton().is_point_in_polygon(point, packed(polygon))
+}
+
+/// Returns `true` if the vertices of `polygon` are in clockwise order.
+///
+/// In Godot's 2D coordinate system, where Y points down, "clockwise" is as seen on screen.
+pub fn is_polygon_clockwise(polygon: &[Vector2]) -> bool {
+    Geometry2D::singleton().is_polygon_clockwise(packed(polygon))
+}
+
+/// Computes the convex hull of `points`.
+///
+/// The hull is returned as a closed polygon in counter-clockwise order, i.e. the first point is repeated at the end.
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    Geometry2D::singleton().convex_hull(packed(points)).to_vec()
+}
+
+/// Triangulates the simple polygon `polygon` with ear clipping.
+///
+/// Each triangle consists of three indices into `polygon`. Returns `None` if the polygon cannot be triangulated, e.g. because
+/// it intersects itself or has fewer than three vertices.
+pub fn triangulate_polygon(polygon: &[Vector2]) -> Option<Vec<[usize; 3]>> {
+    let indices = Geometry2D::singleton().triangulate_polygon(packed(polygon));
+    let triangles = to_triangles(indices);
+
+    (!triangles.is_empty()).then_some(triangles)
+}
+
+/// Computes the Delaunay triangulation of `points`.
+///
+/// Each triangle consists of three indices into `points`. Returns no triangles if all points are collinear.
+pub fn triangulate_delaunay(points: &[Vector2]) -> Vec<[usize; 3]> {
+    to_triangles(Geometry2D::singleton().triangulate_delaunay(packed(points)))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 2D intersections
+
+/// Returns the intersection point of the segments `from_a → to_a` and `from_b → to_b`, if they intersect.
+pub fn segment_intersection(
+    from_a: Vector2,
+    to_a: Vector2,
+    from_b: Vector2,
+    to_b: Vector2,
+) -> Option<Vector2> {
+    to_point(Geometry2D::singleton().segment_intersects_segment(from_a, to_a, from_b, to_b))
+}
+
+/// Returns the intersection point of the infinite lines through `from_a` and `from_b` with directions `dir_a` and `dir_b`.
+///
+/// Returns `None` if the lines are parallel.
+pub fn line_intersection(
+    from_a: Vector2,
+    dir_a: Vector2,
+    from_b: Vector2,
+    dir_b: Vector2,
+) -> Option<Vector2> {
+    to_point(Geometry2D::singleton().line_intersects_line(from_a, dir_a, from_b, dir_b))
+}
+
+/// Returns the point on the segment `from → to` that is closest to `point`.
+pub fn closest_point_to_segment_2d(point: Vector2, from: Vector2, to: Vector2) -> Vector2 {
+    Geometry2D::singleton().get_closest_point_to_segment(point, from, to)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// 3D intersections
+
+/// Returns the point where the segment `from → to` crosses the triangle `[a, b, c]`, if any.
+pub fn segment_triangle_intersection(
+    from: Vector3,
+    to: Vector3,
+    [a, b, c]: [Vector3; 3],
+) -> Option<Vector3> {
+    to_point(Geometry3D::singleton().segment_intersects_triangle(from, to, a, b, c))
+}
+
+/// Returns the point where the ray from `from` in direction `dir` hits the triangle `[a, b, c]`, if any.
+pub fn ray_triangle_intersection(
+    from: Vector3,
+    dir: Vector3,
+    [a, b, c]: [Vector3; 3],
+) -> Option<Vector3> {
+    to_point(Geometry3D::singleton().ray_intersects_triangle(from, dir, a, b, c))
+}
+
+/// Returns the two closest points between the segments `p1 → p2` and `q1 → q2`, as `(on_p, on_q)`.
+pub fn closest_points_between_segments(
+    p1: Vector3,
+    p2: Vector3,
+    q1: Vector3,
+    q2: Vector3,
+) -> (Vector3, Vector3) {
+    let points = Geometry3D::singleton().get_closest_points_between_segments(p1, p2, q1, q2);
+
+    (points.get(0), points.get(1))
+}
+
+/// Returns the point on the segment `from → to` that is closest to `point`.
+pub fn closest_point_to_segment_3d(point: Vector3, from: Vector3, to: Vector3) -> Vector3 {
+    Geometry3D::singleton().get_closest_point_to_segment(point, from, to)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions
+
+fn packed(points: &[Vector2]) -> PackedVector2Array {
+    PackedVector2Array::from(points)
+}
+
+fn to_polygons(polygons: Array<PackedVector2Array>) -> Vec<Vec<Vector2>> {
+    polygons
+        .iter_shared()
+        .map(|polygon| polygon.to_vec())
+        .collect()
+}
+
+fn to_triangles(indices: PackedInt32Array) -> Vec<[usize; 3]> {
+    indices
+        .as_slice()
+        .chunks_exact(3)
+        .map(|t| [t[0] as usize, t[1] as usize, t[2] as usize])
+        .collect()
+}
+
+/// Maps the engine's "point or `null`" results to `Option`.
+fn to_point<T: FromGodot>(variant: Variant) -> Option<T> {
+    if variant.is_nil() {
+        None
+    } else {
+        Some(variant.to())
+    }
+}
@@ -23,6 +23,7 @@ impl std::fmt::Display for IoError {
             ErrorData::Load(err) => err.fmt(f),
             ErrorData::Save(err) => err.fmt(f),
             ErrorData::GFile(err) => err.fmt(f),
+            ErrorData::Config(err) => err.fmt(f),
         }
     }
 }
@@ -71,6 +72,26 @@ impl IoError {
         }
     }
 
+    pub(crate) fn config_loading(error: GodotError, path: String) -> Self {
+        Self {
+            data: ErrorData::Config(ConfigError {
+                saving: false,
+                path,
+                godot_error: error,
+            }),
+        }
+    }
+
+    pub(crate) fn config_saving(error: GodotError, path: String) -> Self {
+        Self {
+            data: ErrorData::Config(ConfigError {
+                saving: true,
+                path,
+                godot_error: error,
+            }),
+        }
+    }
+
     pub(crate) fn check_unique_open_file_access(
         file_access: Gd<FileAccess>,
     ) -> Result<Gd<FileAccess>, Self> {
@@ -102,6 +123,7 @@ enum ErrorData {
     Load(LoaderError),
     Save(SaverError),
     GFile(GFileError),
+    Config(ConfigError),
 }
 
 #[derive(Debug)]
@@ -176,3 +198,23 @@ impl std::fmt::Display for GFileError {
         }
     }
 }
+
+#[derive(Debug)]
+struct ConfigError {
+    saving: bool,
+    path: String,
+    godot_error: GodotError,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.saving { "save" } else { "load" };
+        let path = &self.path;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't {verb} config file: '{path}'; Godot error: {godot_error:?}"
+        )
+    }
+}
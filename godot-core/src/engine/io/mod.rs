@@ -7,8 +7,10 @@
 
 mod gfile;
 mod io_error;
+mod plugin_config;
 mod resources;
 
 pub use gfile::GFile;
 pub use io_error::*;
+pub use plugin_config::PluginConfig;
 pub use resources::{load, save, try_load, try_save};
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::GString;
+use crate::engine::global::Error as GodotError;
+use crate::engine::{ConfigFile, DirAccess, IoError};
+use crate::obj::{Gd, NewGd};
+
+/// Per-project configuration of an editor plugin, stored as a `ConfigFile`.
+///
+/// By default, the configuration of plugin `name` lives in `res://.godot/plugins/<name>.cfg`. The `.godot` directory holds project-local
+/// editor state and is usually not committed to version control. Use [`open_path()`](Self::open_path) for another location, e.g. a file
+/// next to the plugin that _is_ shared.
+///
+/// Values are organized in sections and keys, like in any `ConfigFile`. Changes are kept in memory until [`save()`](Self::save).
+///
+/// # Migrations
+/// The configuration stores a version number, which [`migrate()`](Self::migrate) uses to bring older files up to date:
+///
+/// ```no_run
+/// use godot::engine::PluginConfig;
+///
+/// fn rename_grid_key(config: &mut PluginConfig) {
+///     if let Some(size) = config.get::<i64>("grid", "size") {
+///         config.set("grid", "cell_size", &size);
+///         config.erase("grid", "size");
+///     }
+/// }
+///
+/// let mut config = PluginConfig::open("level_tools").expect("config readable");
+/// config.migrate(&[rename_grid_key]);
+///
+/// let cell_size = config.get_or("grid", "cell_size", 32_i64);
+/// config.save().expect("config writable");
+/// ```
+pub struct PluginConfig {
+    file: Gd<ConfigFile>,
+    path: GString,
+}
+
+impl PluginConfig {
+    const META_SECTION: &'static str = "meta";
+    const VERSION_KEY: &'static str = "config_version";
+
+    /// Opens the configuration of plugin `plugin_name`, stored in `res://.godot/plugins/<plugin_name>.cfg`.
+    ///
+    /// If the file does not exist yet, the configuration starts empty.
+    pub fn open(plugin_name: &str) -> Result<Self, IoError> {
+        Self::open_path(format!("res://.godot/plugins/{plugin_name}.cfg"))
+    }
+
+    /// Opens a configuration at a custom `path`.
+    ///
+    /// If the file does not exist yet, the configuration starts empty.
+    pub fn open_path(path: impl Into<GString>) -> Result<Self, IoError> {
+        let path = path.into();
+        let mut file = ConfigFile::new_gd();
+
+        match file.load(path.clone()) {
+            GodotError::OK | GodotError::ERR_FILE_NOT_FOUND => Ok(Self { file, path }),
+            err => Err(IoError::config_loading(err, path.to_string())),
+        }
+    }
+
+    /// Path of the underlying file.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    /// Returns the value of `key` in `section`, or `None` if absent.
+    ///
+    /// A value that cannot be converted to `T` (e.g. after manual editing) is treated as absent.
+    pub fn get<T: FromGodot>(&self, section: &str, key: &str) -> Option<T> {
+        if !self.file.has_section_key(section.into(), key.into()) {
+            return None;
+        }
+
+        let value = self.file.get_value(section.into(), key.into());
+        T::try_from_variant(&value).ok()
+    }
+
+    /// Returns the value of `key` in `section`, or `default` if absent or of another type.
+    pub fn get_or<T: FromGodot>(&self, section: &str, key: &str, default: T) -> T {
+        self.get(section, key).unwrap_or(default)
+    }
+
+    /// Sets the value of `key` in `section`, creating both if necessary.
+    pub fn set<T: ToGodot>(&mut self, section: &str, key: &str, value: &T) {
+        self.file
+            .set_value(section.into(), key.into(), value.to_variant());
+    }
+
+    /// Removes `key` from `section`. Does nothing if absent.
+    pub fn erase(&mut self, section: &str, key: &str) {
+        if self.file.has_section_key(section.into(), key.into()) {
+            self.file.erase_section_key(section.into(), key.into());
+        }
+    }
+
+    /// Version of the stored configuration, as set by [`migrate()`](Self::migrate). 0 for new or unversioned files.
+    pub fn version(&self) -> usize {
+        self.get(Self::META_SECTION, Self::VERSION_KEY)
+            .map_or(0, |version: i64| version.max(0) as usize)
+    }
+
+    /// Applies all migrations that have not yet been applied, in order.
+    ///
+    /// `migrations[i]` upgrades the configuration from version `i` to `i + 1`. Afterwards, the version equals `migrations.len()`; only
+    /// append to this list, never reorder or remove entries. Returns `true` if any migration ran, in which case the configuration should
+    /// be saved.
+    pub fn migrate(&mut self, migrations: &[fn(&mut PluginConfig)]) -> bool {
+        let current = self.version();
+        if current >= migrations.len() {
+            return false;
+        }
+
+        for migration in &migrations[current..] {
+            migration(self);
+        }
+
+        let version = migrations.len() as i64;
+        self.set(Self::META_SECTION, Self::VERSION_KEY, &version);
+        true
+    }
+
+    /// Writes the configuration to its file, creating parent directories as needed.
+    pub fn save(&mut self) -> Result<(), IoError> {
+        let dir = self.path.as_inner().get_base_dir();
+        let result = match DirAccess::make_dir_recursive_absolute(dir) {
+            GodotError::OK => self.file.save(self.path.clone()),
+            err => err,
+        };
+
+        if result == GodotError::OK {
+            Ok(())
+        } else {
+            Err(IoError::config_saving(result, self.path.to_string()))
+        }
+    }
+}
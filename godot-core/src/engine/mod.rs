@@ -17,12 +17,50 @@ pub use crate::gen::utilities;
 
 use crate::sys;
 
+mod asset_scan;
+mod astar;
+mod canvas_handles;
+mod curves;
+pub mod dynamic_call;
+mod editor_selection;
+mod fixed_timestep;
+pub mod geometry;
 mod io;
+mod noise_sampling;
+mod object_meta;
+mod object_properties;
+mod optional_api;
+pub mod profiling;
+pub mod raw_interface;
+pub mod regex;
+#[cfg(feature = "rand")]
+mod rng;
+mod scene_diff;
 mod script_instance;
+mod script_port;
+mod stable_ref;
+mod syntax_highlighting;
 pub mod translate;
-
+pub mod variant_bytes;
+
+pub use asset_scan::{AssetInfo, EditorFileSystemExt};
+pub use astar::{AStarGraph2D, AStarGraph3D, AStarGrid};
+pub use canvas_handles::{CanvasHandles, HandleEvent};
+pub use curves::{BakedCurve, BakedCurve2D, BakedCurve3D, BakedGradient};
+pub use editor_selection::{EditorInterfaceExt, EditorSelectionExt};
+pub use fixed_timestep::FixedTimestep;
 pub use io::*;
+pub use noise_sampling::{NoiseExt, NoiseImage};
+pub use object_meta::{MetaEntries, MetaError, ObjectMetaExt};
+pub use object_properties::{ObjectPropertiesExt, PropertyView};
+pub use optional_api::{ApiVersion, EngineExt, OptionalApi};
+#[cfg(feature = "rand")]
+pub use rng::GodotRng;
+pub use scene_diff::{PatchError, SceneChange, SceneDiff};
 pub use script_instance::{create_script_instance, ScriptInstance};
+pub use script_port::RustSkeleton;
+pub use stable_ref::{StableId, StableRef, STABLE_ID_META};
+pub use syntax_highlighting::LineHighlighting;
 
 #[cfg(debug_assertions)]
 use crate::builtin::meta::ClassName;
@@ -176,12 +214,22 @@ pub(crate) fn ensure_object_alive(
     old_object_ptr: sys::GDExtensionObjectPtr,
     method_name: &'static str,
 ) {
-    let new_object_ptr = object_ptr_from_id(instance_id);
-
     assert!(
-        !new_object_ptr.is_null(),
+        check_object_alive(instance_id, old_object_ptr, method_name),
         "{method_name}: access to instance with ID {instance_id} after it has been freed"
     );
+}
+
+/// Like [`ensure_object_alive()`], but returns `false` instead of panicking if the object has been freed.
+pub(crate) fn check_object_alive(
+    instance_id: InstanceId,
+    old_object_ptr: sys::GDExtensionObjectPtr,
+    method_name: &'static str,
+) -> bool {
+    let new_object_ptr = object_ptr_from_id(instance_id);
+    if new_object_ptr.is_null() {
+        return false;
+    }
 
     // This should not happen, as reuse of instance IDs was fixed according to https://github.com/godotengine/godot/issues/32383,
     // namely in PR https://github.com/godotengine/godot/pull/36189. Double-check to make sure.
@@ -189,6 +237,8 @@ pub(crate) fn ensure_object_alive(
         new_object_ptr, old_object_ptr,
         "{method_name}: instance ID {instance_id} points to a stale, reused object. Please report this to gdext maintainers."
     );
+
+    true
 }
 
 #[cfg(debug_assertions)]
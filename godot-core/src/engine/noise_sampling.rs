@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::PackedByteArray;
+use crate::engine::image::Format;
+use crate::engine::{Image, Noise};
+use crate::obj::Gd;
+
+/// Extension trait to sample [`Noise`] (e.g. `FastNoiseLite`) in bulk.
+///
+/// Godot has no bulk API for float samples. The `fill_*` methods therefore still call into the engine once per sample, but write into a
+/// caller-provided slice, so a chunk of terrain can reuse one buffer. [`image_2d()`][Self::image_2d] samples a whole grid in a single
+/// engine call and exposes the result without copying, at the cost of 8-bit precision.
+///
+/// Grids are laid out row by row, with x varying fastest, then y, then z. Sample `(x, y)` of a 2D grid is at index `y * width + x`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{FastNoiseLite, NoiseExt as _};
+///
+/// let noise = FastNoiseLite::new_gd();
+///
+/// let mut heights = vec![0.0; 64 * 64];
+/// noise.fill_2d([0.0, 0.0], 1.0, 64, &mut heights);
+/// ```
+pub trait NoiseExt {
+    /// Samples the noise at `start`, `start + step`, ... until `out` is full.
+    fn fill_1d(&self, start: f32, step: f32, out: &mut [f32]);
+
+    /// Samples a grid of `width` columns and `out.len() / width` rows, starting at `origin` with `step` between neighbors.
+    ///
+    /// # Panics
+    /// If `out.len()` is not a multiple of `width`.
+    fn fill_2d(&self, origin: [f32; 2], step: f32, width: usize, out: &mut [f32]);
+
+    /// Samples a grid of `width * height` slices and `out.len() / (width * height)` layers, starting at `origin` with `step` between
+    /// neighbors.
+    ///
+    /// # Panics
+    /// If `out.len()` is not a multiple of `width * height`.
+    fn fill_3d(&self, origin: [f32; 3], step: f32, [width, height]: [usize; 2], out: &mut [f32]);
+
+    /// Samples a `width × height` grid at integer coordinates, starting at the noise's offset, in one engine call.
+    ///
+    /// Values are mapped from `[-1, 1]` to `[0, 255]` without normalization, so neighboring images line up.
+    fn image_2d(&self, width: usize, height: usize) -> Option<NoiseImage>;
+}
+
+impl NoiseExt for Noise {
+    fn fill_1d(&self, start: f32, step: f32, out: &mut [f32]) {
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.get_noise_1d(start + i as f32 * step);
+        }
+    }
+
+    fn fill_2d(&self, [x0, y0]: [f32; 2], step: f32, width: usize, out: &mut [f32]) {
+        check_grid(out.len(), width);
+
+        for (y, row) in out.chunks_exact_mut(width).enumerate() {
+            let y = y0 + y as f32 * step;
+            for (x, value) in row.iter_mut().enumerate() {
+                *value = self.get_noise_2d(x0 + x as f32 * step, y);
+            }
+        }
+    }
+
+    fn fill_3d(
+        &self,
+        [x0, y0, z0]: [f32; 3],
+        step: f32,
+        [width, height]: [usize; 2],
+        out: &mut [f32],
+    ) {
+        check_grid(out.len(), width * height);
+
+        for (z, layer) in out.chunks_exact_mut(width * height).enumerate() {
+            let z = z0 + z as f32 * step;
+            for (y, row) in layer.chunks_exact_mut(width).enumerate() {
+                let y = y0 + y as f32 * step;
+                for (x, value) in row.iter_mut().enumerate() {
+                    *value = self.get_noise_3d(x0 + x as f32 * step, y, z);
+                }
+            }
+        }
+    }
+
+    fn image_2d(&self, width: usize, height: usize) -> Option<NoiseImage> {
+        let mut image = self
+            .get_image_ex(width as i32, height as i32)
+            .normalize(false)
+            .done()?;
+
+        if image.get_format() != Format::L8 {
+            image.convert(Format::L8);
+        }
+
+        let data = image.get_data();
+        Some(NoiseImage {
+            image,
+            data,
+            width,
+            height,
+        })
+    }
+}
+
+/// Noise sampled by [`NoiseExt::image_2d()`], one byte per sample.
+///
+/// The bytes are shared with the image, not copied.
+#[derive(Clone, Debug)]
+pub struct NoiseImage {
+    image: Gd<Image>,
+    data: PackedByteArray,
+    width: usize,
+    height: usize,
+}
+
+impl NoiseImage {
+    /// The samples as bytes, row by row. `0` corresponds to a noise value of -1 and `255` to 1.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    /// The sample at `(x, y)`, mapped back to `[-1, 1]`.
+    ///
+    /// # Panics
+    /// If `(x, y)` lies outside the image.
+    pub fn get(&self, x: usize, y: usize) -> f32 {
+        assert!(
+            x < self.width && y < self.height,
+            "NoiseImage: ({x}, {y}) out of bounds for {}x{}",
+            self.width,
+            self.height
+        );
+
+        self.as_bytes()[y * self.width + x] as f32 / 127.5 - 1.0
+    }
+
+    /// Converts all samples back to `[-1, 1]`, e.g. to combine with [`NoiseExt::fill_2d()`] results.
+    pub fn to_f32_vec(&self) -> Vec<f32> {
+        self.as_bytes()
+            .iter()
+            .map(|&byte| byte as f32 / 127.5 - 1.0)
+            .collect()
+    }
+
+    /// Number of samples per row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The underlying image, e.g. to create a texture from it.
+    pub fn as_gd(&self) -> &Gd<Image> {
+        &self.image
+    }
+}
+
+fn check_grid(len: usize, stride: usize) {
+    assert!(
+        stride != 0 && len % stride == 0,
+        "NoiseExt: slice of length {len} is not a whole number of rows of {stride}"
+    );
+}
@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::meta::{ConvertError, FromGodot, ToGodot};
+use crate::builtin::{Array, StringName, Variant};
+use crate::engine::Object;
+
+/// Extension trait with typed access to an object's metadata.
+///
+/// Godot's [`Object::set_meta()`] and [`Object::get_meta()`] operate on `Variant`, and the latter silently returns `null` (with an error
+/// printed) if the entry is absent. The methods in this trait convert to/from Rust types and report missing entries or type mismatches
+/// through [`MetaError`].
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::ObjectMetaExt as _;
+///
+/// let mut node = Node::new_alloc();
+/// node.set_meta_typed("spawn_wave", &3_i64);
+///
+/// let wave: i64 = node.get_meta_typed("spawn_wave").expect("metadata present");
+/// assert_eq!(wave, 3);
+///
+/// for (name, value) in node.meta_entries() {
+///     godot_print!("{name} = {value}");
+/// }
+/// # node.free();
+/// ```
+pub trait ObjectMetaExt {
+    /// Stores `value` under the metadata entry `name`, overwriting any previous value.
+    fn set_meta_typed<T: ToGodot>(&mut self, name: impl Into<StringName>, value: &T);
+
+    /// Retrieves the metadata entry `name` and converts it to `T`.
+    ///
+    /// Returns an error if no such entry exists, or if the stored value cannot be converted to `T`.
+    fn get_meta_typed<T: FromGodot>(&self, name: impl Into<StringName>) -> Result<T, MetaError>;
+
+    /// Iterates over all metadata entries as `(name, value)` pairs.
+    ///
+    /// The list of names is determined once when this method is called; entries removed during iteration yield `null` values.
+    fn meta_entries(&self) -> MetaEntries<'_>;
+}
+
+impl ObjectMetaExt for Object {
+    fn set_meta_typed<T: ToGodot>(&mut self, name: impl Into<StringName>, value: &T) {
+        self.set_meta(name.into(), value.to_variant());
+    }
+
+    fn get_meta_typed<T: FromGodot>(&self, name: impl Into<StringName>) -> Result<T, MetaError> {
+        let name = name.into();
+
+        // Check first; get_meta() prints an error for absent entries without a default.
+        if !self.has_meta(name.clone()) {
+            return Err(MetaError {
+                name,
+                kind: MetaErrorKind::NotFound,
+            });
+        }
+
+        let variant = self.get_meta(name.clone());
+        T::try_from_variant(&variant).map_err(|err| MetaError {
+            name,
+            kind: MetaErrorKind::BadType(err),
+        })
+    }
+
+    fn meta_entries(&self) -> MetaEntries<'_> {
+        MetaEntries {
+            object: self,
+            names: self.get_meta_list(),
+            next_index: 0,
+        }
+    }
+}
+
+/// Iterator over an object's metadata, returned by [`ObjectMetaExt::meta_entries()`].
+pub struct MetaEntries<'a> {
+    object: &'a Object,
+    names: Array<StringName>,
+    next_index: usize,
+}
+
+impl<'a> Iterator for MetaEntries<'a> {
+    type Item = (StringName, Variant);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let name = self.names.try_get(self.next_index)?;
+        self.next_index += 1;
+
+        let value = self
+            .object
+            .get_meta_ex(name.clone())
+            .default(Variant::nil())
+            .done();
+
+        Some((name, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.names.len().saturating_sub(self.next_index);
+        (remaining, Some(remaining))
+    }
+}
+
+/// Error when accessing metadata through [`ObjectMetaExt::get_meta_typed()`].
+#[derive(Debug)]
+pub struct MetaError {
+    name: StringName,
+    kind: MetaErrorKind,
+}
+
+impl MetaError {
+    /// Name of the metadata entry that was accessed.
+    pub fn name(&self) -> &StringName {
+        &self.name
+    }
+
+    /// Returns `true` if the entry does not exist (as opposed to having the wrong type).
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.kind, MetaErrorKind::NotFound)
+    }
+}
+
+#[derive(Debug)]
+enum MetaErrorKind {
+    NotFound,
+    BadType(ConvertError),
+}
+
+impl fmt::Display for MetaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+
+        match &self.kind {
+            MetaErrorKind::NotFound => write!(f, "metadata entry '{name}' not found"),
+            MetaErrorKind::BadType(err) => {
+                write!(f, "metadata entry '{name}' has unexpected type: {err}")
+            }
+        }
+    }
+}
+
+impl Error for MetaError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            MetaErrorKind::NotFound => None,
+            MetaErrorKind::BadType(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ConvertError;
+use crate::builtin::{StringName, Variant};
+use crate::engine::Object;
+
+/// Extension trait to read and write lists of properties of an object, with pre-built names or typed views.
+///
+/// When synchronizing many properties per frame (debug overlays, serialization, networking), the names are best stored as
+/// `StringName` once, rather than converted from strings on every access. The methods in this trait take such pre-built names.
+///
+/// Godot has no API to access several properties in one call, so each property is still read or written with its own engine call,
+/// like [`Object::get()`] and [`Object::set()`]. The number of FFI round trips is thus the same as with individual accesses.
+///
+/// For typed access, declare a struct deriving [`PropertyView`] and use [`read_properties()`](Self::read_properties) and
+/// [`write_properties()`](Self::write_properties).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::ObjectPropertiesExt as _;
+/// use godot::register::PropertyView;
+///
+/// #[derive(PropertyView)]
+/// struct Motion {
+///     position: Vector2,
+///     rotation: f32,
+///     #[property(rename = "visible")]
+///     shown: bool,
+/// }
+///
+/// # let node: Gd<Node2D> = unimplemented!();
+/// let motion: Motion = node.read_properties().expect("properties have expected types");
+/// godot_print!("at {} rotated {}", motion.position, motion.rotation);
+/// ```
+pub trait ObjectPropertiesExt {
+    /// Returns the values of all properties in `names`, in the same order.
+    ///
+    /// Non-existent properties yield `null`, like [`Object::get()`]. Performs one engine call per property.
+    fn get_many(&self, names: &[StringName]) -> Vec<Variant>;
+
+    /// Sets all properties in `entries` to the respective values, in order. Performs one engine call per property.
+    fn set_many(&mut self, entries: &[(StringName, Variant)]);
+
+    /// Reads the properties declared by `V` and converts them into a typed view.
+    ///
+    /// Returns an error if any property cannot be converted to the corresponding field type.
+    fn read_properties<V: PropertyView>(&self) -> Result<V, ConvertError>;
+
+    /// Writes all fields of `view` to the corresponding properties.
+    fn write_properties<V: PropertyView>(&mut self, view: &V);
+}
+
+impl ObjectPropertiesExt for Object {
+    fn get_many(&self, names: &[StringName]) -> Vec<Variant> {
+        names.iter().map(|name| self.get(name.clone())).collect()
+    }
+
+    fn set_many(&mut self, entries: &[(StringName, Variant)]) {
+        for (name, value) in entries {
+            self.set(name.clone(), value.clone());
+        }
+    }
+
+    fn read_properties<V: PropertyView>(&self) -> Result<V, ConvertError> {
+        V::from_property_values(self.get_many(V::property_names()))
+    }
+
+    fn write_properties<V: PropertyView>(&mut self, view: &V) {
+        let values = view.to_property_values();
+        for (name, value) in V::property_names().iter().zip(values) {
+            self.set(name.clone(), value);
+        }
+    }
+}
+
+/// Typed view on a fixed set of object properties.
+///
+/// This trait is usually derived with `#[derive(PropertyView)]` on a struct with named fields. Each field corresponds to the property of
+/// the same name, unless renamed with `#[property(rename = "...")]`. Field types must implement both `FromGodot` and `ToGodot`.
+///
+/// See [`ObjectPropertiesExt`] for usage.
+pub trait PropertyView: Sized {
+    /// Property names, in the order of [`from_property_values()`](Self::from_property_values) and
+    /// [`to_property_values()`](Self::to_property_values).
+    fn property_names() -> &'static [StringName];
+
+    /// Constructs the view from property values, ordered like [`property_names()`](Self::property_names).
+    fn from_property_values(values: Vec<Variant>) -> Result<Self, ConvertError>;
+
+    /// Returns the property values of this view, ordered like [`property_names()`](Self::property_names).
+    fn to_property_values(&self) -> Vec<Variant>;
+}
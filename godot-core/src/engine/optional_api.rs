@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{GString, StringName};
+use crate::engine::{ClassDb, Engine, Os};
+use crate::obj::{Gd, GodotClass};
+use crate::sys;
+
+/// Extension trait for runtime queries about the engine build.
+pub trait EngineExt {
+    /// Returns `true` if the class `class_name` exists in the running engine.
+    ///
+    /// Engine builds can be compiled without certain modules (e.g. navigation, XR or physics engines), whose classes are then missing.
+    /// To access such classes in a typed way, see [`OptionalApi`].
+    fn has_class(&self, class_name: impl Into<StringName>) -> bool;
+
+    /// Returns `true` if the running engine or export has the feature tag `tag`, such as `"mobile"`, `"editor"` or a custom export tag.
+    ///
+    /// See [Feature tags](https://docs.godotengine.org/en/stable/tutorials/export/feature_tags.html) in the Godot docs.
+    fn has_feature(&self, tag: impl Into<GString>) -> bool;
+
+    /// Version of the running Godot engine.
+    ///
+    /// Can be older than [`ApiVersion::compiled()`] if `GDEXT_MIN_GODOT_API` is set. Check it before calling methods that are newer than
+    /// the oldest supported version; such methods are not available in older engines.
+    fn api_version() -> ApiVersion
+    where
+        Self: Sized;
+}
+
+impl EngineExt for Engine {
+    fn has_class(&self, class_name: impl Into<StringName>) -> bool {
+        ClassDb::singleton().class_exists(class_name.into())
+    }
+
+    fn has_feature(&self, tag: impl Into<GString>) -> bool {
+        Os::singleton().has_feature(tag.into())
+    }
+
+    fn api_version() -> ApiVersion {
+        ApiVersion::from_triple(sys::GdextBuild::godot_runtime_version_triple())
+    }
+}
+
+/// Version of the Godot API, as `major.minor.patch`.
+///
+/// Versions compare in lexicographical order, so they can be checked against each other:
+/// ```no_run
+/// use godot::engine::{ApiVersion, Engine, EngineExt};
+///
+/// if Engine::api_version().is_at_least(4, 2) {
+///     // Use APIs introduced in Godot 4.2.
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ApiVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl ApiVersion {
+    /// Version of the Godot API against which gdext was compiled.
+    pub fn compiled() -> Self {
+        Self::from_triple(sys::GdextBuild::godot_static_version_triple())
+    }
+
+    /// Returns `true` if this version is `major.minor` or newer, regardless of patch.
+    pub fn is_at_least(&self, major: u8, minor: u8) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    fn from_triple((major, minor, patch): (u8, u8, u8)) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Access to an engine class that may be missing from the running engine build.
+///
+/// Godot can be compiled without modules, and engine classes from those modules then do not exist. Using them directly panics, e.g. when
+/// calling `singleton()`. `OptionalApi` checks the availability once and returns `None` instead, so the extension can disable the
+/// related features and keep running.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{NavigationServer3D, OptionalApi};
+///
+/// let navigation = OptionalApi::<NavigationServer3D>::detect();
+/// match navigation.singleton() {
+///     Some(_server) => godot_print!("navigation enabled"),
+///     None => godot_warn!("navigation module not available; AI uses direct paths"),
+/// }
+/// ```
+pub struct OptionalApi<T: GodotClass> {
+    is_available: bool,
+    _class: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotClass> OptionalApi<T> {
+    /// Checks whether class `T` exists in the running engine.
+    pub fn detect() -> Self {
+        Self {
+            is_available: Engine::singleton().has_class(T::class_name().to_string_name()),
+            _class: PhantomData,
+        }
+    }
+
+    /// Returns `true` if class `T` exists in the running engine.
+    pub fn is_available(&self) -> bool {
+        self.is_available
+    }
+
+    /// Returns the singleton of class `T`, or `None` if the class does not exist or is not a singleton.
+    pub fn singleton(&self) -> Option<Gd<T>> {
+        if !self.is_available {
+            return None;
+        }
+
+        let name = T::class_name().to_string_name();
+        let engine = Engine::singleton();
+        if !engine.has_singleton(name.clone()) {
+            return None;
+        }
+
+        engine.get_singleton(name)?.try_cast::<T>().ok()
+    }
+
+    /// Creates a new instance of class `T`, or returns `None` if the class does not exist or cannot be instantiated.
+    ///
+    /// The caller is responsible for freeing the instance, unless `T` is reference-counted.
+    pub fn instantiate(&self) -> Option<Gd<T>> {
+        if !self.is_available {
+            return None;
+        }
+
+        let name = T::class_name().to_string_name();
+        let mut class_db = ClassDb::singleton();
+        if !class_db.can_instantiate(name.clone()) {
+            return None;
+        }
+
+        class_db.instantiate(name).try_to::<Gd<T>>().ok()
+    }
+}
+
+impl<T: GodotClass> Clone for OptionalApi<T> {
+    fn clone(&self) -> Self {
+        Self {
+            is_available: self.is_available,
+            _class: PhantomData,
+        }
+    }
+}
+
+impl<T: GodotClass> fmt::Debug for OptionalApi<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptionalApi")
+            .field("class", &T::class_name())
+            .field("is_available", &self.is_available)
+            .finish()
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Frame markers and named scopes for profiling Rust code.
+//!
+//! Call [`frame_begin()`] and [`frame_end()`] once per frame, e.g. at the start and end of the `process()` function of an autoload or the
+//! main scene. In between, wrap code of interest in [`scope()`]. The measurements are reported to all of the following, so that Rust and
+//! engine work can be analyzed in the same timeline:
+//!
+//! - **Godot profiler** (Godot 4.2+): each scope appears as a custom monitor `Rust/<name>` in the debugger's _Monitors_ tab, showing the
+//!   time spent in it during the last frame, in milliseconds. The time between frame markers appears as `Rust/frame`.
+//! - **[Tracy](https://github.com/wolfpld/tracy)**, with the `tracy` crate feature: scopes become zones and frame ends become frame marks.
+//! - **[puffin](https://github.com/EmbarkStudios/puffin)**, with the `puffin` crate feature: scopes become puffin scopes, and each frame
+//!   end starts a new puffin frame. Recording must be enabled with `puffin::set_scopes_on(true)`.
+//!
+//! ```no_run
+//! # use godot::prelude::*;
+//! use godot::engine::profiling;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Node)]
+//! struct GameLoop {
+//!     base: Base<Node>,
+//! }
+//!
+//! #[godot_api]
+//! impl INode for GameLoop {
+//!     fn process(&mut self, _delta: f64) {
+//!         profiling::frame_begin();
+//!
+//!         {
+//!             let _scope = profiling::scope("pathfinding");
+//!             // ...
+//!         }
+//!
+//!         let _scope = profiling::scope("ai");
+//!         // ...
+//!         drop(_scope);
+//!
+//!         profiling::frame_end();
+//!     }
+//! }
+//! ```
+//!
+//! Scopes may be opened on any thread; their times are summed per name. Frame markers and the Godot monitors belong to the main thread.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Name of the scope covering the time between [`frame_begin()`] and [`frame_end()`].
+pub const FRAME_SCOPE: &str = "frame";
+
+/// Measures the time until it is dropped, see [`scope()`].
+#[must_use = "the scope ends when dropped; bind it to a variable like `_scope` (not `_`)"]
+pub struct Scope {
+    name: &'static str,
+    start: Instant,
+    #[cfg(feature = "tracy-client")]
+    _tracy_span: Option<tracy_client::Span>,
+    #[cfg(feature = "puffin")]
+    _puffin_scope: Option<puffin::ProfilerScope>,
+}
+
+/// Starts a named scope, which ends when the returned guard is dropped.
+///
+/// Time spent in scopes with the same name is summed up per frame.
+#[track_caller]
+pub fn scope(name: &'static str) -> Scope {
+    #[cfg(any(feature = "tracy-client", feature = "puffin"))]
+    let location = std::panic::Location::caller();
+
+    Scope {
+        name,
+        start: Instant::now(),
+        #[cfg(feature = "tracy-client")]
+        _tracy_span: tracy_client::Client::running()
+            .map(|client| client.span_alloc(Some(name), "", location.file(), location.line(), 0)),
+        #[cfg(feature = "puffin")]
+        _puffin_scope: puffin::are_scopes_on()
+            .then(|| puffin::ProfilerScope::new(name, location.file(), "")),
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        record_scope_time(self.name, self.start.elapsed());
+    }
+}
+
+/// Marks the beginning of a frame.
+pub fn frame_begin() {
+    #[cfg(feature = "tracy-client")]
+    let _ = tracy_client::Client::start();
+
+    lock_stats().frame_start = Some(Instant::now());
+}
+
+/// Marks the end of a frame, publishing the times measured since the last call.
+///
+/// Scopes still open at this point are attributed to the next frame.
+pub fn frame_end() {
+    let new_names = {
+        let mut stats = lock_stats();
+        if let Some(start) = stats.frame_start.take() {
+            stats.current.insert(FRAME_SCOPE, start.elapsed());
+        }
+
+        // Keep reporting known scopes that did not run this frame, as 0.
+        let current = std::mem::take(&mut stats.current);
+        for time in stats.last_frame.values_mut() {
+            *time = Duration::ZERO;
+        }
+
+        let mut new_names = Vec::new();
+        for (name, time) in current {
+            if stats.last_frame.insert(name, time).is_none() {
+                new_names.push(name);
+            }
+        }
+        new_names
+    };
+
+    #[cfg(since_api = "4.2")]
+    for name in new_names {
+        add_godot_monitor(name);
+    }
+    #[cfg(before_api = "4.2")]
+    let _ = new_names;
+
+    #[cfg(feature = "tracy-client")]
+    if let Some(client) = tracy_client::Client::running() {
+        client.frame_mark();
+    }
+
+    #[cfg(feature = "puffin")]
+    puffin::GlobalProfiler::lock().new_frame();
+}
+
+/// Time spent in the scope `name` during the last completed frame.
+///
+/// Returns `None` if no such scope has ended before the last [`frame_end()`]. Use [`FRAME_SCOPE`] for the whole frame.
+pub fn last_frame_time(name: &str) -> Option<Duration> {
+    lock_stats().last_frame.get(name).copied()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Adds `elapsed` to the time of scope `name` in the current frame. Also used for spans of `log::GodotTracingLayer`.
+pub(crate) fn record_scope_time(name: &'static str, elapsed: Duration) {
+    *lock_stats().current.entry(name).or_default() += elapsed;
+}
+
+#[derive(Default)]
+struct Stats {
+    frame_start: Option<Instant>,
+    current: HashMap<&'static str, Duration>,
+    last_frame: HashMap<&'static str, Duration>,
+}
+
+fn lock_stats() -> MutexGuard<'static, Stats> {
+    static STATS: OnceLock<Mutex<Stats>> = OnceLock::new();
+
+    // A panic while holding the lock cannot leave the stats inconsistent, so ignore poisoning.
+    STATS
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(since_api = "4.2")]
+fn add_godot_monitor(name: &'static str) {
+    crate::tools::monitor(format!("Rust/{name}"), move || {
+        let time = last_frame_time(name).unwrap_or_default();
+        time.as_secs_f64() * 1000.0
+    });
+}
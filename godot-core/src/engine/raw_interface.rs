@@ -0,0 +1,95 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Direct access to the GDExtension C interface, for engine functionality that godot-rust does not wrap (yet).
+//!
+//! New Godot versions regularly add interface functions, e.g. for XR or rendering, before godot-rust provides an API for them. This
+//! module hands out the raw building blocks to call them: the function pointers, object pointers, class tags and the library pointer.
+//! No safety is provided beyond that; signatures and pointer lifetimes follow `gdextension_interface.h` of the Godot version in use.
+//!
+//! ```no_run
+//! use godot::engine::raw_interface::{self, GDExtensionConstObjectPtr};
+//! use godot::engine::Node;
+//! use godot::obj::NewAlloc;
+//!
+//! // Declared as in gdextension_interface.h: typedef GDObjectInstanceID (*GDExtensionInterfaceObjectGetInstanceId)(...);
+//! type ObjectGetInstanceId = Option<unsafe extern "C" fn(p_object: GDExtensionConstObjectPtr) -> u64>;
+//!
+//! let node = Node::new_alloc();
+//! let get_instance_id: ObjectGetInstanceId = unsafe { raw_interface::get_interface_fn("object_get_instance_id") };
+//!
+//! if let Some(get_instance_id) = get_instance_id {
+//!     let id = unsafe { get_instance_id(raw_interface::object_ptr(&node)) };
+//!     assert_eq!(id as i64, node.instance_id().to_i64());
+//! }
+//! node.free();
+//! ```
+
+use std::ffi::c_void;
+
+use crate::builtin::meta::ClassName;
+use crate::obj::{Gd, GodotClass};
+use crate::sys;
+use sys::interface_fn;
+
+pub use sys::{
+    GDExtensionBool, GDExtensionClassLibraryPtr, GDExtensionConstObjectPtr,
+    GDExtensionConstStringNamePtr, GDExtensionConstTypePtr, GDExtensionConstVariantPtr,
+    GDExtensionInterfaceFunctionPtr, GDExtensionObjectPtr, GDExtensionStringNamePtr,
+    GDExtensionTypePtr, GDExtensionVariantPtr,
+};
+
+/// Loads the GDExtension interface function `name`, as a function pointer of type `F`.
+///
+/// `F` is an `Option` of the function pointer type, which is `None` if the running Godot version does not provide the function.
+/// As lookups are not cached, fetch functions once and keep them, rather than before every call.
+///
+/// # Panics
+/// If `F` does not have the size of a function pointer, or `name` contains a nul byte.
+///
+/// # Safety
+/// `F` must be `Option<unsafe extern "C" fn(...) -> ...>` with the exact signature that `gdextension_interface.h` declares for `name`.
+#[cfg(since_api = "4.1")]
+pub unsafe fn get_interface_fn<F: Copy>(name: &str) -> F {
+    use std::ffi::CString;
+    use std::mem::size_of;
+
+    assert_eq!(
+        size_of::<F>(),
+        size_of::<GDExtensionInterfaceFunctionPtr>(),
+        "get_interface_fn::<F>(): F must be an Option of a function pointer"
+    );
+
+    let name = CString::new(name).expect("interface function name must not contain nul bytes");
+    let function = sys::get_proc_address(&name);
+
+    // SAFETY: same size checked above; the caller guarantees that F is the optional function pointer type of `name`.
+    std::mem::transmute_copy::<GDExtensionInterfaceFunctionPtr, F>(&function)
+}
+
+/// Pointer to the Godot object, as expected by interface functions.
+///
+/// The pointer is valid as long as the object is alive. It does not keep the object alive, nor affect reference counting.
+pub fn object_ptr<T: GodotClass>(object: &Gd<T>) -> GDExtensionObjectPtr {
+    object.obj_sys()
+}
+
+/// Class tag that Godot uses to identify the class `class_name`, e.g. in `object_cast_to`.
+///
+/// Returns null if no such class is registered.
+pub fn class_tag(class_name: ClassName) -> *mut c_void {
+    // SAFETY: the class name is a valid StringName for the duration of the call.
+    unsafe { interface_fn!(classdb_get_class_tag)(class_name.string_sys()) }
+}
+
+/// Pointer identifying this extension library, which interface functions for class registration expect.
+///
+/// If the binary contains multiple extension libraries, this is the one most recently (de)initialized.
+pub fn library_ptr() -> GDExtensionClassLibraryPtr {
+    // SAFETY: only called once the library is loaded.
+    unsafe { sys::get_library() }
+}
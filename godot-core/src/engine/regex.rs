@@ -0,0 +1,319 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Regular expressions backed by Godot's [`RegEx`] class (PCRE2 syntax).
+//!
+//! [`Regex`] follows the API of the [`regex`](https://docs.rs/regex) crates: matches borrow from the searched `&str` and report byte
+//! offsets, so they can be used for slicing directly. Godot counts in characters; the conversion is done by this module.
+//!
+//! ```no_run
+//! use godot::engine::regex::Regex;
+//!
+//! let re = Regex::new(r"(?<key>\w+)=(?<value>\d+)").unwrap();
+//! for caps in re.captures_iter("hp=10 mp=25") {
+//!     let key = caps.name("key").unwrap().as_str();
+//!     let value: i32 = caps["value"].parse().unwrap();
+//!     println!("{key}: {value}");
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, Range};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{GString, Variant};
+use crate::engine::global;
+use crate::engine::{RegEx, RegExMatch};
+use crate::obj::{Gd, NewGd};
+
+/// A compiled regular expression.
+#[derive(Clone)]
+pub struct Regex {
+    inner: Gd<RegEx>,
+}
+
+impl Regex {
+    /// Compiles `pattern`.
+    ///
+    /// Godot additionally prints compilation errors to its console.
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let mut inner = RegEx::new_gd();
+        let error = inner.compile(pattern.into());
+
+        if error == global::Error::OK && inner.is_valid() {
+            Ok(Self { inner })
+        } else {
+            Err(RegexError {
+                pattern: pattern.to_string(),
+                error,
+            })
+        }
+    }
+
+    /// The pattern this expression was compiled from.
+    pub fn as_str(&self) -> GString {
+        self.inner.get_pattern()
+    }
+
+    /// Number of capture groups, including the implicit group 0 for the whole match.
+    pub fn captures_len(&self) -> usize {
+        self.inner.get_group_count() as usize + 1
+    }
+
+    /// Names of the named capture groups.
+    pub fn capture_names(&self) -> Vec<GString> {
+        self.inner.get_names().to_vec()
+    }
+
+    /// Returns `true` if the expression matches anywhere in `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.inner.search(text.into()).is_some()
+    }
+
+    /// Returns the leftmost match in `text`.
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        self.captures(text).and_then(|caps| caps.get(0))
+    }
+
+    /// Returns all non-overlapping matches in `text`.
+    pub fn find_iter<'t>(&self, text: &'t str) -> impl Iterator<Item = Match<'t>> {
+        self.captures_iter(text).filter_map(|caps| caps.get(0))
+    }
+
+    /// Returns the capture groups of the leftmost match in `text`.
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        let found = self.inner.search(text.into())?;
+        let offsets = CharOffsets::new(text);
+
+        Some(Captures::new(text, &offsets, &found))
+    }
+
+    /// Returns the capture groups of all non-overlapping matches in `text`.
+    pub fn captures_iter<'t>(&self, text: &'t str) -> impl Iterator<Item = Captures<'t>> {
+        let offsets = CharOffsets::new(text);
+        let found = self.inner.search_all(text.into());
+
+        (0..found.len()).map(move |i| Captures::new(text, &offsets, &found.get(i)))
+    }
+
+    /// Replaces the leftmost match with `replacement`, in which `$1` or `${name}` refer to capture groups.
+    pub fn replace(&self, text: &str, replacement: &str) -> GString {
+        self.inner.sub(text.into(), replacement.into())
+    }
+
+    /// Replaces all non-overlapping matches with `replacement`, in which `$1` or `${name}` refer to capture groups.
+    pub fn replace_all(&self, text: &str, replacement: &str) -> GString {
+        self.inner
+            .sub_ex(text.into(), replacement.into())
+            .all(true)
+            .done()
+    }
+
+    /// The underlying Godot object.
+    pub fn as_gd(&self) -> &Gd<RegEx> {
+        &self.inner
+    }
+}
+
+impl fmt::Debug for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Regex").field(&self.as_str()).finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A single match or capture group, borrowing from the searched text.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    /// Byte offset of the start of the match in the searched text.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Byte offset of the end of the match in the searched text (exclusive).
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Byte range of the match in the searched text.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// The matched text.
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.range()]
+    }
+
+    /// Returns `true` if the match is empty.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Length of the match in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+/// The capture groups of one match. Group 0 is the whole match.
+///
+/// Groups can be accessed by index or name with [`get()`](Self::get) and [`name()`](Self::name), or with `caps[1]` and `caps["name"]`,
+/// which panic if the group did not participate in the match.
+#[derive(Clone, Debug)]
+pub struct Captures<'t> {
+    text: &'t str,
+    groups: Vec<Option<Range<usize>>>,
+    names: Vec<(String, usize)>,
+}
+
+impl<'t> Captures<'t> {
+    fn new(text: &'t str, offsets: &CharOffsets, found: &Gd<RegExMatch>) -> Self {
+        let group_count = found.get_group_count() as usize + 1;
+        let groups = (0..group_count)
+            .map(|group| {
+                let group = (group as i64).to_variant();
+                let start = found.get_start_ex().group(group.clone()).done();
+                let end = found.get_end_ex().group(group).done();
+
+                // Godot reports -1 for groups that did not participate in the match.
+                (start >= 0 && end >= 0)
+                    .then(|| offsets.byte_offset(start as usize)..offsets.byte_offset(end as usize))
+            })
+            .collect();
+
+        let names = found
+            .get_names()
+            .iter_shared()
+            .map(|(name, group): (Variant, Variant)| {
+                (name.to::<GString>().to_string(), group.to::<i64>() as usize)
+            })
+            .collect();
+
+        Self {
+            text,
+            groups,
+            names,
+        }
+    }
+
+    /// Returns the group at `index`, or `None` if it did not participate in the match or does not exist.
+    pub fn get(&self, index: usize) -> Option<Match<'t>> {
+        let range = self.groups.get(index)?.clone()?;
+
+        Some(Match {
+            text: self.text,
+            start: range.start,
+            end: range.end,
+        })
+    }
+
+    /// Returns the group named `name`, or `None` if it did not participate in the match or does not exist.
+    pub fn name(&self, name: &str) -> Option<Match<'t>> {
+        let (_, index) = self
+            .names
+            .iter()
+            .find(|(group_name, _)| group_name == name)?;
+        self.get(*index)
+    }
+
+    /// Number of groups, including group 0. Same as [`Regex::captures_len()`].
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Always `false`, as there is at least group 0. Provided for consistency with `len()`.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns an iterator over all groups, starting with group 0.
+    pub fn iter(&self) -> impl Iterator<Item = Option<Match<'t>>> + '_ {
+        (0..self.len()).map(|index| self.get(index))
+    }
+}
+
+impl Index<usize> for Captures<'_> {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        self.get(index)
+            .unwrap_or_else(|| panic!("no capture group at index {index}"))
+            .as_str()
+    }
+}
+
+impl<'n> Index<&'n str> for Captures<'_> {
+    type Output = str;
+
+    fn index(&self, name: &'n str) -> &str {
+        self.name(name)
+            .unwrap_or_else(|| panic!("no capture group named '{name}'"))
+            .as_str()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when compiling a [`Regex`].
+#[derive(Debug)]
+pub struct RegexError {
+    pattern: String,
+    error: global::Error,
+}
+
+impl RegexError {
+    /// The pattern that failed to compile.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid regular expression `{}` ({:?})",
+            self.pattern, self.error
+        )
+    }
+}
+
+impl Error for RegexError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Maps Godot's character offsets to byte offsets in a `&str`.
+struct CharOffsets {
+    /// Byte offset of each character, plus the total length.
+    bytes: Vec<usize>,
+}
+
+impl CharOffsets {
+    fn new(text: &str) -> Self {
+        let bytes = text
+            .char_indices()
+            .map(|(byte, _)| byte)
+            .chain(std::iter::once(text.len()))
+            .collect();
+
+        Self { bytes }
+    }
+
+    fn byte_offset(&self, char_offset: usize) -> usize {
+        self.bytes[char_offset]
+    }
+}
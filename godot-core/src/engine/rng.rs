@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use rand::{RngCore, SeedableRng};
+
+use crate::engine::RandomNumberGenerator;
+use crate::obj::{Gd, NewGd};
+
+/// Godot's [`RandomNumberGenerator`] as a [`rand::RngCore`].
+///
+/// Lets Rust code that is generic over `rand::Rng` draw from the same seeded sequence as GDScript. Seeding with
+/// [`seed_from_u64()`][SeedableRng::seed_from_u64] is equivalent to setting `RandomNumberGenerator.seed`.
+///
+/// ```no_run
+/// use godot::engine::GodotRng;
+/// use godot::builtin::Vector2;
+/// use rand::{Rng, SeedableRng};
+///
+/// let mut rng = GodotRng::seed_from_u64(42);
+/// let point: Vector2 = rng.gen();
+/// ```
+pub struct GodotRng {
+    inner: Gd<RandomNumberGenerator>,
+}
+
+impl GodotRng {
+    /// Creates a generator with a random seed.
+    pub fn new() -> Self {
+        let mut inner = RandomNumberGenerator::new_gd();
+        inner.randomize();
+
+        Self { inner }
+    }
+
+    /// Draws from an existing generator, e.g. one shared with GDScript. Both sides advance the same state.
+    pub fn from_gd(inner: Gd<RandomNumberGenerator>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying Godot object.
+    pub fn as_gd(&self) -> &Gd<RandomNumberGenerator> {
+        &self.inner
+    }
+}
+
+impl Default for GodotRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RngCore for GodotRng {
+    fn next_u32(&mut self) -> u32 {
+        self.inner.randi()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand::rand_core::impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for GodotRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        let mut inner = RandomNumberGenerator::new_gd();
+        inner.set_seed(state);
+
+        Self { inner }
+    }
+}
@@ -0,0 +1,356 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{GString, NodePath, StringName, Variant};
+use crate::engine::global::PropertyUsageFlags;
+use crate::engine::{ClassDb, Node};
+use crate::obj::{EngineBitfield, Gd};
+
+/// Structural difference between two node trees.
+///
+/// A diff is computed from two trees with [`SceneDiff::compute()`] and describes how to turn the first tree into the second one.
+/// It can be inspected through [`changes()`](Self::changes), or applied to a tree with [`apply()`](Self::apply).
+///
+/// Nodes are matched by name, recursively from the root. The following aspects are compared:
+/// * Presence of child nodes. Nodes only present in one tree are added or removed.
+/// * Node classes. If a node with the same name has a different class, it is removed and re-added with the new class.
+/// * Property values, limited to properties that are stored in scene files (usage flag `STORAGE`). This includes exported properties and
+///   metadata. Properties only present on one node, e.g. metadata or script variables, are set or removed.
+///
+/// Node order among siblings is not compared.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::SceneDiff;
+///
+/// fn sync_variant(base: &Gd<Node>, variant: &Gd<Node>, mut target: Gd<Node>) {
+///     let diff = SceneDiff::compute(base, variant);
+///     for change in diff.changes() {
+///         godot_print!("{change}");
+///     }
+///
+///     diff.apply(&mut target).expect("target has same structure as base");
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct SceneDiff {
+    changes: Vec<SceneChange>,
+}
+
+impl SceneDiff {
+    /// Computes the changes necessary to turn the tree rooted at `from` into the tree rooted at `to`.
+    ///
+    /// The root nodes themselves are always matched against each other, regardless of their names.
+    pub fn compute(from: &Gd<Node>, to: &Gd<Node>) -> Self {
+        let mut changes = Vec::new();
+        diff_nodes(from, to, ".", &mut changes);
+
+        Self { changes }
+    }
+
+    /// All changes in the order in which they are applied.
+    pub fn changes(&self) -> &[SceneChange] {
+        &self.changes
+    }
+
+    /// Returns `true` if both trees are structurally equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies all changes to the tree rooted at `root`.
+    ///
+    /// Nodes that are added become owned by `root`'s owner, or `root` itself if it has none. This ensures they are saved together
+    /// with the scene. Removed nodes are detached from their parent and queued for deletion.
+    ///
+    /// Changes are applied in order. If one of them fails, the error is returned and the remaining changes are not applied;
+    /// the tree may thus be left partially patched.
+    pub fn apply(&self, root: &mut Gd<Node>) -> Result<(), PatchError> {
+        let owner = root.get_owner().unwrap_or_else(|| root.clone());
+
+        for change in self.changes.iter() {
+            change.apply_to(root, &owner)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Single change in a [`SceneDiff`].
+///
+/// Paths are relative to the root node of the diff; the root node itself has path `.`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SceneChange {
+    /// A node of class `class` is added below `parent`, with the given stored properties.
+    AddNode {
+        parent: NodePath,
+        name: StringName,
+        class: StringName,
+        properties: Vec<(StringName, Variant)>,
+    },
+
+    /// The node at `path` (and all its children) is removed.
+    RemoveNode { path: NodePath },
+
+    /// The property `property` of the node at `path` changes from `old_value` to `new_value`.
+    ///
+    /// `old_value` is nil if the property did not exist before.
+    SetProperty {
+        path: NodePath,
+        property: StringName,
+        old_value: Variant,
+        new_value: Variant,
+    },
+
+    /// The property `property` of the node at `path`, with value `old_value`, no longer exists.
+    ///
+    /// Applied by setting the property to nil, which removes metadata entries (`metadata/...`). Properties declared by the node's class
+    /// or script cannot be removed and are reset to nil, if their type allows.
+    RemoveProperty {
+        path: NodePath,
+        property: StringName,
+        old_value: Variant,
+    },
+}
+
+impl SceneChange {
+    fn apply_to(&self, root: &Gd<Node>, owner: &Gd<Node>) -> Result<(), PatchError> {
+        match self {
+            SceneChange::AddNode {
+                parent,
+                name,
+                class,
+                properties,
+            } => {
+                let mut parent = find_node(root, parent)?;
+
+                let instance = ClassDb::singleton().instantiate(class.clone());
+                let mut node = instance.try_to::<Gd<Node>>().map_err(|_| PatchError {
+                    path: parent.get_path(),
+                    message: format!("cannot instantiate class `{class}` as node"),
+                })?;
+
+                node.set_name(name.clone().into());
+                for (property, value) in properties {
+                    node.set(property.clone(), value.clone());
+                }
+
+                parent.add_child(node.clone());
+                node.set_owner(owner.clone());
+            }
+            SceneChange::RemoveNode { path } => {
+                let mut node = find_node(root, path)?;
+                if let Some(mut parent) = node.get_parent() {
+                    parent.remove_child(node.clone());
+                }
+                node.queue_free();
+            }
+            SceneChange::SetProperty {
+                path,
+                property,
+                new_value,
+                ..
+            } => {
+                let mut node = find_node(root, path)?;
+                node.set(property.clone(), new_value.clone());
+            }
+            SceneChange::RemoveProperty { path, property, .. } => {
+                let mut node = find_node(root, path)?;
+                node.set(property.clone(), Variant::nil());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Paths are formatted like Godot's `NodePath`: `Parent/Child` for nodes and `Parent/Child:property` for their properties, with the root
+/// node as `.` and its properties as `:property`.
+impl fmt::Display for SceneChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneChange::AddNode {
+                parent,
+                name,
+                class,
+                ..
+            } => {
+                let path = child_path(&parent.to_string(), name);
+                write!(f, "+ {path} ({class})")
+            }
+            SceneChange::RemoveNode { path } => write!(f, "- {path}"),
+            SceneChange::SetProperty {
+                path,
+                property,
+                old_value,
+                new_value,
+            } => {
+                let path = property_path(path, property);
+                write!(f, "~ {path}: {old_value} -> {new_value}")
+            }
+            SceneChange::RemoveProperty {
+                path,
+                property,
+                old_value,
+            } => {
+                let path = property_path(path, property);
+                write!(f, "- {path}: {old_value}")
+            }
+        }
+    }
+}
+
+/// Error when applying a [`SceneDiff`] to a tree that does not match the expected structure.
+#[derive(Debug)]
+pub struct PatchError {
+    path: NodePath,
+    message: String,
+}
+
+impl PatchError {
+    /// Path of the node at which the patch failed.
+    pub fn path(&self) -> &NodePath {
+        &self.path
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot patch node at `{}`: {}", self.path, self.message)
+    }
+}
+
+impl Error for PatchError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn diff_nodes(from: &Gd<Node>, to: &Gd<Node>, path: &str, changes: &mut Vec<SceneChange>) {
+    let old_properties = stored_properties(from);
+    let new_properties = stored_properties(to);
+
+    for (property, old_value) in old_properties.iter() {
+        if !new_properties.iter().any(|(name, _)| name == property) {
+            changes.push(SceneChange::RemoveProperty {
+                path: NodePath::from(path),
+                property: property.clone(),
+                old_value: old_value.clone(),
+            });
+        }
+    }
+
+    for (property, new_value) in new_properties {
+        let old_value = old_properties
+            .iter()
+            .find(|(name, _)| *name == property)
+            .map(|(_, value)| value.clone());
+
+        if old_value.as_ref() != Some(&new_value) {
+            changes.push(SceneChange::SetProperty {
+                path: NodePath::from(path),
+                property,
+                old_value: old_value.unwrap_or_default(),
+                new_value,
+            });
+        }
+    }
+
+    let old_children = from.get_children();
+    let new_children = to.get_children();
+
+    // Removals first, so that re-added nodes (class change) don't collide with existing names.
+    for old_child in old_children.iter_shared() {
+        let name = old_child.get_name();
+        let counterpart = new_children.iter_shared().find(|c| c.get_name() == name);
+
+        match counterpart {
+            Some(new_child) if new_child.get_class() == old_child.get_class() => {}
+            _ => changes.push(SceneChange::RemoveNode {
+                path: NodePath::from(child_path(path, &name)),
+            }),
+        }
+    }
+
+    for new_child in new_children.iter_shared() {
+        let name = new_child.get_name();
+        let counterpart = old_children.iter_shared().find(|c| c.get_name() == name);
+
+        match counterpart {
+            Some(old_child) if old_child.get_class() == new_child.get_class() => {
+                diff_nodes(&old_child, &new_child, &child_path(path, &name), changes);
+            }
+            _ => add_subtree(&new_child, path, changes),
+        }
+    }
+}
+
+fn add_subtree(node: &Gd<Node>, parent_path: &str, changes: &mut Vec<SceneChange>) {
+    let name = node.get_name();
+
+    changes.push(SceneChange::AddNode {
+        parent: NodePath::from(parent_path),
+        name: name.clone(),
+        class: StringName::from(node.get_class()),
+        properties: stored_properties(node),
+    });
+
+    let path = child_path(parent_path, &name);
+    for child in node.get_children().iter_shared() {
+        add_subtree(&child, &path, changes);
+    }
+}
+
+/// Properties that Godot would serialize into a scene file, excluding the node name (used for matching).
+fn stored_properties(node: &Gd<Node>) -> Vec<(StringName, Variant)> {
+    let storage = PropertyUsageFlags::STORAGE.ord() as i64;
+
+    node.get_property_list()
+        .iter_shared()
+        .filter_map(|info| {
+            let usage = info.get("usage")?.try_to::<i64>().ok()?;
+            let name = info.get("name")?.try_to::<GString>().ok()?;
+
+            if usage & storage == 0 || name == GString::from("name") {
+                return None;
+            }
+
+            let name = StringName::from(name);
+            let value = node.get(name.clone());
+            Some((name, value))
+        })
+        .collect()
+}
+
+/// Property path in `NodePath` syntax, e.g. `Child:position`, or `:position` for the root.
+fn property_path(path: &NodePath, property: &StringName) -> String {
+    let path = path.to_string();
+    if path == "." {
+        format!(":{property}")
+    } else {
+        format!("{path}:{property}")
+    }
+}
+
+fn child_path(parent_path: &str, name: &StringName) -> String {
+    if parent_path == "." {
+        name.to_string()
+    } else {
+        format!("{parent_path}/{name}")
+    }
+}
+
+fn find_node(root: &Gd<Node>, path: &NodePath) -> Result<Gd<Node>, PatchError> {
+    root.get_node_or_null(path.clone())
+        .ok_or_else(|| PatchError {
+            path: path.clone(),
+            message: "node not found".to_string(),
+        })
+}
@@ -0,0 +1,523 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Array, Dictionary, GString, StringName, Variant, VariantType};
+use crate::engine::global::{PropertyHint, PropertyUsageFlags};
+use crate::engine::Script;
+use crate::obj::Gd;
+
+/// Skeleton of a Rust class, generated from a script to help porting it to Rust.
+///
+/// Godot's script reflection is used to read the script's base class, properties, methods and signals. The skeleton declares:
+/// - a struct deriving `GodotClass`, with `#[export]` fields for exported properties and `#[var]` fields for other script variables,
+/// - an `I*` trait impl with well-known virtual functions such as `_ready()` or `_process()`,
+/// - a `#[godot_api]` impl with `#[func]` stubs for all other methods, and `#[signal]` declarations.
+///
+/// Function bodies are `todo!()`, and types that cannot be inferred (e.g. untyped parameters) become `Variant`. The output is a starting
+/// point that needs review, not a translation of the script's logic.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{load, RustSkeleton, Script};
+///
+/// let script = load::<Script>("res://player.gd");
+/// let skeleton = RustSkeleton::from_script(&script);
+/// godot_print!("{skeleton}");
+/// ```
+#[derive(Clone, Debug)]
+pub struct RustSkeleton {
+    struct_name: String,
+    base_class: String,
+    source: String,
+    fields: Vec<Field>,
+    virtuals: Vec<&'static str>,
+    methods: Vec<Method>,
+    signals: Vec<Signal>,
+}
+
+impl RustSkeleton {
+    /// Reads the class declared by `script`.
+    ///
+    /// The struct is named after the script's file, e.g. `PlayerController` for `player_controller.gd`; see
+    /// [`with_struct_name()`](Self::with_struct_name) to change it.
+    pub fn from_script(script: &Gd<Script>) -> Self {
+        let mut script = script.clone();
+        let source = script.get_path().to_string();
+
+        let fields = script
+            .get_script_property_list()
+            .iter_shared()
+            .filter_map(|info| Field::from_info(&mut script, &info))
+            .collect();
+
+        let mut virtuals = Vec::new();
+        let mut methods = Vec::new();
+        for info in script.get_script_method_list().iter_shared() {
+            let method = Method::from_info(&info);
+            if let Some(signature) = virtual_signature(&method.name) {
+                if !virtuals.contains(&signature) {
+                    virtuals.push(signature);
+                }
+            } else if !methods.iter().any(|m: &Method| m.name == method.name) {
+                methods.push(method);
+            }
+        }
+
+        let signals = script
+            .get_script_signal_list()
+            .iter_shared()
+            .map(|info| Signal::from_info(&info))
+            .collect();
+
+        Self {
+            struct_name: struct_name_from_path(&source),
+            base_class: script.get_instance_base_type().to_string(),
+            source,
+            fields,
+            virtuals,
+            methods,
+            signals,
+        }
+    }
+
+    /// Uses `name` for the generated struct.
+    pub fn with_struct_name(mut self, name: impl Into<String>) -> Self {
+        self.struct_name = name.into();
+        self
+    }
+
+    /// Name of the generated struct.
+    pub fn struct_name(&self) -> &str {
+        &self.struct_name
+    }
+
+    /// Engine class the script extends, after resolving script inheritance.
+    pub fn base_class(&self) -> &str {
+        &self.base_class
+    }
+}
+
+/// Writes the skeleton as Rust source code.
+impl fmt::Display for RustSkeleton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            struct_name,
+            base_class,
+            source,
+            ..
+        } = self;
+
+        writeln!(f, "use godot::prelude::*;")?;
+        writeln!(f)?;
+        if !source.is_empty() {
+            writeln!(f, "/// Ported from `{source}`.")?;
+        }
+        writeln!(f, "#[derive(GodotClass)]")?;
+        writeln!(f, "#[class(init, base={base_class})]")?;
+        writeln!(f, "pub struct {struct_name} {{")?;
+        for field in &self.fields {
+            field.write(f)?;
+        }
+        writeln!(f, "    base: Base<{base_class}>,")?;
+        writeln!(f, "}}")?;
+
+        if !self.virtuals.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "#[godot_api]")?;
+            writeln!(f, "impl I{base_class} for {struct_name} {{")?;
+            for (i, signature) in self.virtuals.iter().enumerate() {
+                if i > 0 {
+                    writeln!(f)?;
+                }
+                writeln!(f, "    {signature} {{")?;
+                writeln!(f, "        todo!()")?;
+                writeln!(f, "    }}")?;
+            }
+            writeln!(f, "}}")?;
+        }
+
+        if !self.methods.is_empty() || !self.signals.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "#[godot_api]")?;
+            writeln!(f, "impl {struct_name} {{")?;
+            for signal in &self.signals {
+                signal.write(f)?;
+            }
+            for (i, method) in self.methods.iter().enumerate() {
+                if i > 0 || !self.signals.is_empty() {
+                    writeln!(f)?;
+                }
+                method.write(f)?;
+            }
+            writeln!(f, "}}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Class members
+
+#[derive(Clone, Debug)]
+struct Field {
+    name: String,
+    ty: String,
+    is_exported: bool,
+    default: Option<String>,
+}
+
+impl Field {
+    fn from_info(script: &mut Gd<Script>, info: &Dictionary) -> Option<Self> {
+        let usage = info.get("usage")?.try_to::<i64>().ok()?;
+        let script_variable = PropertyUsageFlags::SCRIPT_VARIABLE.ord() as i64;
+        if usage & script_variable == 0 {
+            // Categories, groups and the like.
+            return None;
+        }
+
+        let name = info.get("name")?.try_to::<GString>().ok()?;
+        let default = script.get_property_default_value(StringName::from(&name));
+
+        let editor = PropertyUsageFlags::EDITOR.ord() as i64;
+        let ty = TypeInfo::from_info(info).to_rust();
+        let ty = if ty.starts_with("Gd<") {
+            format!("Option<{ty}>")
+        } else {
+            ty
+        };
+
+        Some(Self {
+            name: name.to_string(),
+            ty,
+            is_exported: usage & editor != 0,
+            default: default_literal(&default),
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { name, ty, .. } = self;
+
+        if self.is_exported {
+            writeln!(f, "    #[export]")?;
+        } else {
+            writeln!(f, "    #[var]")?;
+        }
+        if let Some(default) = &self.default {
+            writeln!(f, "    #[init(default = {default})]")?;
+        }
+        writeln!(f, "    {name}: {ty},")
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Method {
+    name: String,
+    params: Vec<(String, String)>,
+    return_ty: Option<String>,
+}
+
+impl Method {
+    fn from_info(info: &Dictionary) -> Self {
+        let name = string_entry(info, "name");
+        let params = params_from_info(info);
+        let return_ty = info
+            .get_as::<_, Dictionary>("return")
+            .ok()
+            .map(|ret| TypeInfo::from_info(&ret))
+            .filter(|ret| !ret.is_void())
+            .map(|ret| ret.to_rust());
+
+        Self {
+            name,
+            params,
+            return_ty,
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { name, .. } = self;
+
+        if name.starts_with('_') {
+            writeln!(f, "    // Underscore-prefixed in GDScript: private or a virtual function not known to this generator.")?;
+        }
+        writeln!(f, "    #[func]")?;
+        write!(f, "    fn {name}(&mut self")?;
+        for (param, ty) in &self.params {
+            write!(f, ", {param}: {ty}")?;
+        }
+        write!(f, ")")?;
+        if let Some(return_ty) = &self.return_ty {
+            write!(f, " -> {return_ty}")?;
+        }
+        writeln!(f, " {{")?;
+        writeln!(f, "        todo!()")?;
+        writeln!(f, "    }}")
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Signal {
+    name: String,
+    params: Vec<(String, String)>,
+}
+
+impl Signal {
+    fn from_info(info: &Dictionary) -> Self {
+        Self {
+            name: string_entry(info, "name"),
+            params: params_from_info(info),
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params = self
+            .params
+            .iter()
+            .map(|(param, ty)| format!("{param}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(f, "    #[signal]")?;
+        writeln!(f, "    fn {name}({params});", name = self.name)
+    }
+}
+
+fn params_from_info(info: &Dictionary) -> Vec<(String, String)> {
+    let Ok(args) = info.get_as::<_, Array<Dictionary>>("args") else {
+        return Vec::new();
+    };
+
+    args.iter_shared()
+        .map(|arg| {
+            (
+                string_entry(&arg, "name"),
+                TypeInfo::from_info(&arg).to_rust(),
+            )
+        })
+        .collect()
+}
+
+fn string_entry(info: &Dictionary, key: &str) -> String {
+    info.get_as::<_, GString>(key)
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// Virtual functions commonly overridden in scripts, with their signature in the `I*` traits.
+fn virtual_signature(gdscript_name: &str) -> Option<&'static str> {
+    let signature = match gdscript_name {
+        "_ready" => "fn ready(&mut self)",
+        "_process" => "fn process(&mut self, delta: f64)",
+        "_physics_process" => "fn physics_process(&mut self, delta: f64)",
+        "_enter_tree" => "fn enter_tree(&mut self)",
+        "_exit_tree" => "fn exit_tree(&mut self)",
+        "_input" => "fn input(&mut self, event: Gd<InputEvent>)",
+        "_unhandled_input" => "fn unhandled_input(&mut self, event: Gd<InputEvent>)",
+        "_unhandled_key_input" => "fn unhandled_key_input(&mut self, event: Gd<InputEvent>)",
+        "_to_string" => "fn to_string(&self) -> GString",
+        _ => return None,
+    };
+
+    Some(signature)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Types
+
+/// Type of a property, parameter or return value, as described in a property info dictionary.
+struct TypeInfo {
+    variant_type: i64,
+    class_name: String,
+    hint: i64,
+    hint_string: String,
+    usage: i64,
+}
+
+impl TypeInfo {
+    fn from_info(info: &Dictionary) -> Self {
+        Self {
+            variant_type: info.get_as::<_, i64>("type").unwrap_or(0),
+            class_name: info
+                .get_as::<_, StringName>("class_name")
+                .map(|name| name.to_string())
+                .or_else(|_| {
+                    info.get_as::<_, GString>("class_name")
+                        .map(|name| name.to_string())
+                })
+                .unwrap_or_default(),
+            hint: info.get_as::<_, i64>("hint").unwrap_or(0),
+            hint_string: string_entry(info, "hint_string"),
+            usage: info.get_as::<_, i64>("usage").unwrap_or(0),
+        }
+    }
+
+    /// `true` for `-> void`. Functions returning `Variant` report `Nil` with the `NIL_IS_VARIANT` usage flag instead.
+    fn is_void(&self) -> bool {
+        let nil_is_variant = PropertyUsageFlags::NIL_IS_VARIANT.ord() as i64;
+        self.variant_type == VariantType::Nil as i64 && self.usage & nil_is_variant == 0
+    }
+
+    fn to_rust(&self) -> String {
+        let object = VariantType::Object as i64;
+        let array = VariantType::Array as i64;
+
+        if self.variant_type == object {
+            let class = if self.class_name.is_empty() {
+                "Object"
+            } else {
+                &self.class_name
+            };
+            return format!("Gd<{class}>");
+        }
+
+        if self.variant_type == array {
+            let array_type = PropertyHint::ARRAY_TYPE.ord() as i64;
+            if self.hint == array_type && !self.hint_string.is_empty() {
+                return format!("Array<{}>", rust_type_by_name(&self.hint_string));
+            }
+            return "VariantArray".to_string();
+        }
+
+        BUILTIN_TYPES
+            .iter()
+            .find(|(ty, _, _)| *ty as i64 == self.variant_type)
+            .map_or("Variant", |(_, _, rust)| rust)
+            .to_string()
+    }
+}
+
+/// Maps a GDScript type name, e.g. an array element type, to its Rust type.
+fn rust_type_by_name(gdscript_name: &str) -> String {
+    match BUILTIN_TYPES
+        .iter()
+        .find(|(_, name, _)| *name == gdscript_name)
+    {
+        Some((_, _, rust)) => rust.to_string(),
+        None if gdscript_name == "Array" => "VariantArray".to_string(),
+        None => format!("Gd<{gdscript_name}>"),
+    }
+}
+
+/// Variant types with a fixed Rust counterpart: `(type, GDScript name, Rust name)`.
+const BUILTIN_TYPES: &[(VariantType, &str, &str)] = &[
+    (VariantType::Bool, "bool", "bool"),
+    (VariantType::Int, "int", "i64"),
+    (VariantType::Float, "float", "f64"),
+    (VariantType::String, "String", "GString"),
+    (VariantType::Vector2, "Vector2", "Vector2"),
+    (VariantType::Vector2i, "Vector2i", "Vector2i"),
+    (VariantType::Rect2, "Rect2", "Rect2"),
+    (VariantType::Rect2i, "Rect2i", "Rect2i"),
+    (VariantType::Vector3, "Vector3", "Vector3"),
+    (VariantType::Vector3i, "Vector3i", "Vector3i"),
+    (VariantType::Transform2D, "Transform2D", "Transform2D"),
+    (VariantType::Vector4, "Vector4", "Vector4"),
+    (VariantType::Vector4i, "Vector4i", "Vector4i"),
+    (VariantType::Plane, "Plane", "Plane"),
+    (VariantType::Quaternion, "Quaternion", "Quaternion"),
+    (VariantType::Aabb, "AABB", "Aabb"),
+    (VariantType::Basis, "Basis", "Basis"),
+    (VariantType::Transform3D, "Transform3D", "Transform3D"),
+    (VariantType::Projection, "Projection", "Projection"),
+    (VariantType::Color, "Color", "Color"),
+    (VariantType::StringName, "StringName", "StringName"),
+    (VariantType::NodePath, "NodePath", "NodePath"),
+    (VariantType::Rid, "RID", "Rid"),
+    (VariantType::Callable, "Callable", "Callable"),
+    (VariantType::Signal, "Signal", "Signal"),
+    (VariantType::Dictionary, "Dictionary", "Dictionary"),
+    (
+        VariantType::PackedByteArray,
+        "PackedByteArray",
+        "PackedByteArray",
+    ),
+    (
+        VariantType::PackedInt32Array,
+        "PackedInt32Array",
+        "PackedInt32Array",
+    ),
+    (
+        VariantType::PackedInt64Array,
+        "PackedInt64Array",
+        "PackedInt64Array",
+    ),
+    (
+        VariantType::PackedFloat32Array,
+        "PackedFloat32Array",
+        "PackedFloat32Array",
+    ),
+    (
+        VariantType::PackedFloat64Array,
+        "PackedFloat64Array",
+        "PackedFloat64Array",
+    ),
+    (
+        VariantType::PackedStringArray,
+        "PackedStringArray",
+        "PackedStringArray",
+    ),
+    (
+        VariantType::PackedVector2Array,
+        "PackedVector2Array",
+        "PackedVector2Array",
+    ),
+    (
+        VariantType::PackedVector3Array,
+        "PackedVector3Array",
+        "PackedVector3Array",
+    ),
+    (
+        VariantType::PackedColorArray,
+        "PackedColorArray",
+        "PackedColorArray",
+    ),
+];
+
+/// Rust expression for a script variable's default value, if it differs from the Rust type's default and is simple to express.
+fn default_literal(value: &Variant) -> Option<String> {
+    match value.get_type() {
+        VariantType::Bool => value.to::<bool>().then(|| "true".to_string()),
+        VariantType::Int => {
+            let int = value.to::<i64>();
+            (int != 0).then(|| int.to_string())
+        }
+        VariantType::Float => {
+            let float = value.to::<f64>();
+            (float != 0.0).then(|| format!("{float:?}"))
+        }
+        VariantType::String => {
+            let string = value.to::<GString>().to_string();
+            (!string.is_empty()).then(|| format!("GString::from({string:?})"))
+        }
+        _ => None,
+    }
+}
+
+/// `res://actors/player_controller.gd` -> `PlayerController`.
+fn struct_name_from_path(path: &str) -> String {
+    let file = path.rsplit('/').next().unwrap_or(path);
+    let stem = file.split('.').next().unwrap_or(file);
+
+    let name: String = stem
+        .split(|c: char| c == '_' || c == '-' || c == ' ')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect();
+
+    if name.is_empty() {
+        "PortedClass".to_string()
+    } else {
+        name
+    }
+}
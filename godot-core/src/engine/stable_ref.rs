@@ -0,0 +1,324 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+use crate::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+use crate::builtin::GString;
+use crate::engine::{Node, ObjectMetaExt};
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId};
+use crate::property::{Export, PropertyHintInfo, Var};
+
+/// Name of the metadata entry holding a node's [`StableId`].
+pub const STABLE_ID_META: &str = "stable_id";
+
+/// Identifier of a node that stays the same across scene reloads and save/load.
+///
+/// Instance IDs and `Gd` pointers are only valid as long as the node lives; once a scene is reloaded, all of its nodes are new objects.
+/// A stable ID is instead stored in the node's metadata under [`STABLE_ID_META`], which is saved with the scene. IDs can be assigned in
+/// the editor (add a `String` metadata entry named `stable_id` in the inspector), or from code with [`assign()`](Self::assign) and
+/// [`ensure()`](Self::ensure).
+///
+/// To refer to a node by its stable ID, use [`StableRef`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StableId {
+    id: GString,
+}
+
+impl StableId {
+    /// Creates an ID from a user-chosen string, e.g. `"boss_door"`.
+    ///
+    /// # Panics
+    /// If `id` is empty.
+    pub fn new(id: impl Into<GString>) -> Self {
+        let id = id.into();
+        assert!(!id.is_empty(), "stable ID must not be empty");
+
+        Self { id }
+    }
+
+    /// Generates a new random ID, formatted as 32 hex digits.
+    pub fn generate() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        // RandomState is seeded randomly per process; counter and time make IDs unique within and across runs.
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos());
+
+        let mut halves = [0_u64; 2];
+        for (i, half) in halves.iter_mut().enumerate() {
+            let mut hasher = RandomState::new().build_hasher();
+            (i, count, time).hash(&mut hasher);
+            *half = hasher.finish();
+        }
+
+        Self::new(format!("{:016x}{:016x}", halves[0], halves[1]))
+    }
+
+    /// The ID as a string.
+    pub fn as_gstring(&self) -> &GString {
+        &self.id
+    }
+
+    /// Returns the stable ID of `node`, if it has one.
+    pub fn of<T: Inherits<Node>>(node: &Gd<T>) -> Option<Self> {
+        let node = node.clone().upcast::<Node>();
+        let id = node.get_meta_typed::<GString>(STABLE_ID_META).ok()?;
+
+        (!id.is_empty()).then_some(Self { id })
+    }
+
+    /// Returns the stable ID of `node`, assigning a newly [generated](Self::generate) one if it has none.
+    pub fn ensure<T: Inherits<Node>>(node: &Gd<T>) -> Self {
+        Self::of(node).unwrap_or_else(|| {
+            let id = Self::generate();
+            id.assign(node);
+            id
+        })
+    }
+
+    /// Stores this ID in the metadata of `node`, replacing any previous ID.
+    pub fn assign<T: Inherits<Node>>(&self, node: &Gd<T>) {
+        let mut node = node.clone().upcast::<Node>();
+        node.set_meta_typed(STABLE_ID_META, &self.id);
+    }
+
+    /// Finds the node with this ID among `root` and its descendants.
+    ///
+    /// Searches the subtree in depth-first order and returns the first match. This visits every node, so prefer [`StableRef`], which
+    /// caches the result.
+    pub fn find_in<R: Inherits<Node>>(&self, root: &Gd<R>) -> Option<Gd<Node>> {
+        let mut stack = vec![root.clone().upcast::<Node>()];
+
+        while let Some(node) = stack.pop() {
+            if Self::of(&node).as_ref() == Some(self) {
+                return Some(node);
+            }
+
+            // Push in reverse, so children are visited in tree order.
+            let children = node.get_children();
+            stack.extend((0..children.len()).rev().map(|i| children.get(i)));
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for StableId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.id, f)
+    }
+}
+
+impl GodotConvert for StableId {
+    type Via = GString;
+}
+
+impl ToGodot for StableId {
+    fn to_godot(&self) -> Self::Via {
+        self.id.clone()
+    }
+}
+
+impl FromGodot for StableId {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        if via.is_empty() {
+            return Err(ConvertError::with_cause_value(
+                "stable ID must not be empty",
+                via,
+            ));
+        }
+
+        Ok(Self { id: via })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Reference to a node of type `T` by its [`StableId`], which survives scene reloads and save/load.
+///
+/// Unlike `Gd<T>`, a stable reference does not point to a specific object. It is [resolved](Self::resolve) against a scene each time the
+/// node is needed; the result is cached and re-validated, so repeated resolution is cheap as long as the node lives. After a scene swap, the
+/// next resolution finds the node with the same ID in the new scene.
+///
+/// Stable references convert to/from their ID string, so they can be stored in save files or used as `#[export]` fields.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{StableId, StableRef};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct QuestTracker {
+///     #[export]
+///     target: StableRef<Node3D>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for QuestTracker {
+///     fn process(&mut self, _delta: f64) {
+///         let Some(root) = self.base().get_tree().and_then(|tree| tree.get_current_scene()) else {
+///             return;
+///         };
+///
+///         if let Some(target) = self.target.resolve(&root) {
+///             godot_print!("target at {}", target.get_global_position());
+///         }
+///     }
+/// }
+/// ```
+pub struct StableRef<T: GodotClass> {
+    id: Option<StableId>,
+    cached: Option<InstanceId>,
+    _class: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotClass> StableRef<T> {
+    /// Creates a reference to the node with ID `id`.
+    pub fn new(id: StableId) -> Self {
+        Self {
+            id: Some(id),
+            cached: None,
+            _class: PhantomData,
+        }
+    }
+
+    /// Creates a reference that does not point to any node. Also available as `Default`.
+    pub fn unset() -> Self {
+        Self {
+            id: None,
+            cached: None,
+            _class: PhantomData,
+        }
+    }
+
+    /// The ID of the referenced node, or `None` if [unset](Self::unset).
+    pub fn id(&self) -> Option<&StableId> {
+        self.id.as_ref()
+    }
+
+    /// Returns `true` if this reference has an ID.
+    pub fn is_set(&self) -> bool {
+        self.id.is_some()
+    }
+
+    /// Forgets the cached node, so the next [`resolve()`](Self::resolve) searches the scene again.
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+}
+
+impl<T: Inherits<Node>> StableRef<T> {
+    /// Creates a reference to `node`, assigning it a stable ID if it has none. See [`StableId::ensure()`].
+    pub fn to_node(node: &Gd<T>) -> Self {
+        let mut stable_ref = Self::new(StableId::ensure(node));
+        stable_ref.cached = Some(node.instance_id());
+        stable_ref
+    }
+
+    /// Returns the referenced node, searching among `root` and its descendants.
+    ///
+    /// Returns `None` if no node with the ID exists there, or if it is not of type `T`. If several nodes share the ID, the first one in
+    /// tree order is returned.
+    pub fn resolve<R: Inherits<Node>>(&mut self, root: &Gd<R>) -> Option<Gd<T>> {
+        let id = self.id.as_ref()?;
+        let root = root.clone().upcast::<Node>();
+
+        // The cached node may have been freed, moved out of the subtree or given another ID in the meantime.
+        if let Some(cached) = self
+            .cached
+            .and_then(|cached| Gd::<T>::try_from_instance_id(cached).ok())
+        {
+            let node = cached.clone().upcast::<Node>();
+            let is_in_subtree = node == root || root.is_ancestor_of(node.clone());
+            if is_in_subtree && StableId::of(&node).as_ref() == Some(id) {
+                return Some(cached);
+            }
+        }
+
+        let found = id.find_in(&root).and_then(|node| node.try_cast::<T>().ok());
+        self.cached = found.as_ref().map(|node| node.instance_id());
+        found
+    }
+}
+
+impl<T: GodotClass> Clone for StableRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            cached: self.cached,
+            _class: PhantomData,
+        }
+    }
+}
+
+impl<T: GodotClass> Default for StableRef<T> {
+    fn default() -> Self {
+        Self::unset()
+    }
+}
+
+/// Compares the IDs; the cached node is irrelevant.
+impl<T: GodotClass> PartialEq for StableRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T: GodotClass> fmt::Debug for StableRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StableRef")
+            .field("class", &T::class_name())
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+/// An unset reference is represented by an empty string.
+impl<T: GodotClass> GodotConvert for StableRef<T> {
+    type Via = GString;
+}
+
+impl<T: GodotClass> ToGodot for StableRef<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.id.as_ref().map(ToGodot::to_godot).unwrap_or_default()
+    }
+}
+
+impl<T: GodotClass> FromGodot for StableRef<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        if via.is_empty() {
+            Ok(Self::unset())
+        } else {
+            StableId::try_from_godot(via).map(Self::new)
+        }
+    }
+}
+
+impl<T: GodotClass> Var for StableRef<T> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value)
+    }
+}
+
+impl<T: GodotClass> Export for StableRef<T> {
+    fn default_export_info() -> PropertyHintInfo {
+        PropertyHintInfo::with_hint_none("String")
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Color, Dictionary};
+
+/// Colored regions of a single line, as returned by `ISyntaxHighlighter::get_line_syntax_highlighting()`.
+///
+/// Godot expects highlighting as a dictionary mapping the start column of each region to `{ "color": Color }`. Each region
+/// extends until the start of the next one. This builder produces that dictionary, with columns in ascending order.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{EditorSyntaxHighlighter, IEditorSyntaxHighlighter, LineHighlighting};
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorSyntaxHighlighter)]
+/// struct DialogueHighlighter {
+///     base: Base<EditorSyntaxHighlighter>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorSyntaxHighlighter for DialogueHighlighter {
+///     fn get_name(&self) -> GString {
+///         "Dialogue".into()
+///     }
+///
+///     fn get_supported_languages(&self) -> PackedStringArray {
+///         PackedStringArray::from(&["Dialogue".into()])
+///     }
+///
+///     fn get_line_syntax_highlighting(&self, line: i32) -> Dictionary {
+///         let text = self.base().get_text_edit().unwrap().get_line(line).to_string();
+///
+///         // Speaker name before ':' in yellow, rest of the line in default color.
+///         // Columns count characters, not bytes, so str::find() would be off for non-ASCII names.
+///         let mut highlighting = LineHighlighting::new();
+///         if let Some(colon) = text.chars().position(|c| c == ':') {
+///             highlighting
+///                 .color_from(0, Color::YELLOW)
+///                 .color_from(colon as i64, Color::WHITE);
+///         }
+///         highlighting.to_dictionary()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineHighlighting {
+    regions: Vec<(i64, Color)>,
+}
+
+impl LineHighlighting {
+    /// Creates highlighting without any colored regions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Colors the line from `column` until the start of the next region (or the end of the line).
+    ///
+    /// If a region already starts at `column`, its color is replaced. Like in Godot's `String`, columns count characters (Unicode code
+    /// points), not UTF-8 bytes.
+    pub fn color_from(&mut self, column: i64, color: Color) -> &mut Self {
+        match self.regions.iter_mut().find(|(start, _)| *start == column) {
+            Some(region) => region.1 = color,
+            None => self.regions.push((column, color)),
+        }
+        self
+    }
+
+    /// Returns `true` if no region has been added.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Converts to the dictionary format expected by Godot's `SyntaxHighlighter`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut regions = self.regions.clone();
+        regions.sort_by_key(|(column, _)| *column);
+
+        let mut dict = Dictionary::new();
+        for (column, color) in regions {
+            let mut region = Dictionary::new();
+            region.insert("color", color);
+            dict.insert(column, region);
+        }
+        dict
+    }
+}
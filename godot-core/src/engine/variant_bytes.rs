@@ -0,0 +1,294 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed access to Godot's binary serialization of variants.
+//!
+//! The functions in this module use the same format as GDScript's `var_to_bytes()` and `bytes_to_var()`, so data can be exchanged with
+//! GDScript, `FileAccess.store_var()` or network peers. [`VariantWriter`] and [`VariantReader`] handle sequences of values with a length
+//! prefix, like `StreamPeer.put_var()` and `StreamPeer.get_var()`.
+//!
+//! ```no_run
+//! use godot::builtin::{GString, Vector2};
+//! use godot::engine::variant_bytes;
+//!
+//! let bytes = variant_bytes::encode(&Vector2::new(1.0, 2.0));
+//! let back: Vector2 = variant_bytes::decode(bytes.as_slice()).expect("valid payload");
+//!
+//! // Type mismatches are reported as errors.
+//! assert!(variant_bytes::decode::<GString>(bytes.as_slice()).is_err());
+//! ```
+//!
+//! # Objects
+//! By default, variants holding objects are encoded as their instance ID, and decoding rejects encoded objects. The `_with_objects`
+//! functions encode objects with their class and properties instead, and decoding instantiates them. **Only decode objects from trusted
+//! sources**: instantiation can run arbitrary code, such as scripts attached to the object.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::builtin::meta::{ConvertError, FromGodot, ToGodot};
+use crate::builtin::{PackedByteArray, Variant};
+use crate::engine::utilities;
+
+/// Encodes `value` in Godot's binary format. Objects are encoded as instance IDs.
+pub fn encode<T: ToGodot>(value: &T) -> PackedByteArray {
+    utilities::var_to_bytes(value.to_variant())
+}
+
+/// Encodes `value` in Godot's binary format, including full objects.
+pub fn encode_with_objects<T: ToGodot>(value: &T) -> PackedByteArray {
+    utilities::var_to_bytes_with_objects(value.to_variant())
+}
+
+/// Decodes a single value of type `T` from `bytes`, which must contain exactly one encoded variant. Objects are rejected.
+pub fn decode<T: FromGodot>(bytes: &[u8]) -> Result<T, DeserializeError> {
+    decode_impl(bytes, false)
+}
+
+/// Decodes a single value of type `T` from `bytes`, instantiating encoded objects. See [module docs](self#objects) for risks.
+pub fn decode_with_objects<T: FromGodot>(bytes: &[u8]) -> Result<T, DeserializeError> {
+    decode_impl(bytes, true)
+}
+
+fn decode_impl<T: FromGodot>(bytes: &[u8], allow_objects: bool) -> Result<T, DeserializeError> {
+    let packed = PackedByteArray::from(bytes);
+    let variant = decode_exact(&packed, allow_objects)?;
+
+    T::try_from_variant(&variant).map_err(|err| DeserializeError {
+        kind: DeserializeErrorKind::Convert(err),
+    })
+}
+
+/// Decodes a variant that must span the whole of `packed`.
+fn decode_exact(
+    packed: &PackedByteArray,
+    allow_objects: bool,
+) -> Result<Variant, DeserializeError> {
+    // Every encoded variant has at least a 4-byte header; avoid Godot printing errors for obviously truncated input.
+    if packed.len() < 4 {
+        return Err(DeserializeError {
+            kind: DeserializeErrorKind::Invalid,
+        });
+    }
+
+    let inner = packed.as_inner();
+    let size = inner.decode_var_size(0, allow_objects);
+    if size < 0 {
+        return Err(DeserializeError {
+            kind: DeserializeErrorKind::Invalid,
+        });
+    }
+
+    let size = size as usize;
+    if size != packed.len() {
+        return Err(DeserializeError {
+            kind: DeserializeErrorKind::TrailingBytes {
+                consumed: size,
+                total: packed.len(),
+            },
+        });
+    }
+
+    Ok(inner.decode_var(0, allow_objects))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Writes length-prefixed variants to a byte stream, compatible with `StreamPeer.get_var()`.
+///
+/// Each value is written as a 32-bit little-endian length, followed by the encoded variant.
+#[derive(Debug)]
+pub struct VariantWriter<W: Write> {
+    inner: W,
+    allow_objects: bool,
+}
+
+impl<W: Write> VariantWriter<W> {
+    /// Creates a writer that encodes objects as instance IDs.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            allow_objects: false,
+        }
+    }
+
+    /// Creates a writer that encodes full objects, see [module docs](self#objects).
+    pub fn with_objects(inner: W) -> Self {
+        Self {
+            inner,
+            allow_objects: true,
+        }
+    }
+
+    /// Encodes and writes a single value.
+    pub fn write<T: ToGodot>(&mut self, value: &T) -> io::Result<()> {
+        let bytes = if self.allow_objects {
+            encode_with_objects(value)
+        } else {
+            encode(value)
+        };
+
+        let len = u32::try_from(bytes.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "variant too large"))?;
+
+        self.inner.write_all(&len.to_le_bytes())?;
+        self.inner.write_all(bytes.as_slice())
+    }
+
+    /// Returns the underlying stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads length-prefixed variants from a byte stream, as written by [`VariantWriter`] or `StreamPeer.put_var()`.
+///
+/// Length prefixes above [`max_len()`][Self::max_len] are rejected, so that untrusted streams cannot make the reader allocate
+/// arbitrary amounts of memory.
+#[derive(Debug)]
+pub struct VariantReader<R: Read> {
+    inner: R,
+    allow_objects: bool,
+    max_len: usize,
+}
+
+impl<R: Read> VariantReader<R> {
+    /// Default for [`max_len()`][Self::max_len]: 16 MiB.
+    pub const DEFAULT_MAX_LEN: usize = 16 * 1024 * 1024;
+
+    /// Creates a reader that rejects encoded objects.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            allow_objects: false,
+            max_len: Self::DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Creates a reader that instantiates encoded objects, see [module docs](self#objects).
+    pub fn with_objects(inner: R) -> Self {
+        Self {
+            inner,
+            allow_objects: true,
+            max_len: Self::DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Sets the maximum length in bytes of a single encoded value.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Maximum length in bytes of a single encoded value. Longer values make [`read()`][Self::read] fail without reading them.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Reads and decodes the next value.
+    ///
+    /// Returns `Ok(None)` if the stream ended cleanly before the next value.
+    pub fn read<T: FromGodot>(&mut self) -> Result<Option<T>, DeserializeError> {
+        let mut len_bytes = [0_u8; 4];
+        match self.inner.read(&mut len_bytes) {
+            Ok(0) => return Ok(None),
+            Ok(n) => self.inner.read_exact(&mut len_bytes[n..])?,
+            Err(err) => return Err(err.into()),
+        }
+
+        let len = u32::from_le_bytes(len_bytes) as usize;
+        if len > self.max_len {
+            return Err(DeserializeError {
+                kind: DeserializeErrorKind::TooLarge {
+                    len,
+                    max_len: self.max_len,
+                },
+            });
+        }
+
+        // Grow the buffer while reading instead of allocating `len` bytes upfront, in case the stream ends early.
+        let mut bytes = Vec::new();
+        self.inner
+            .by_ref()
+            .take(len as u64)
+            .read_to_end(&mut bytes)?;
+
+        if bytes.len() < len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+
+        decode_impl(&bytes, self.allow_objects).map(Some)
+    }
+
+    /// Returns the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when decoding variants from bytes.
+#[derive(Debug)]
+pub struct DeserializeError {
+    kind: DeserializeErrorKind,
+}
+
+impl DeserializeError {
+    /// Returns `true` if the bytes were a valid variant, but of a type not convertible to the requested one.
+    pub fn is_type_mismatch(&self) -> bool {
+        matches!(self.kind, DeserializeErrorKind::Convert(_))
+    }
+}
+
+#[derive(Debug)]
+enum DeserializeErrorKind {
+    Invalid,
+    TrailingBytes { consumed: usize, total: usize },
+    TooLarge { len: usize, max_len: usize },
+    Convert(ConvertError),
+    Io(io::Error),
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DeserializeErrorKind::Invalid => write!(f, "bytes do not encode a valid variant"),
+            DeserializeErrorKind::TrailingBytes { consumed, total } => write!(
+                f,
+                "variant occupies {consumed} bytes, but {total} bytes were given"
+            ),
+            DeserializeErrorKind::TooLarge { len, max_len } => write!(
+                f,
+                "variant occupies {len} bytes, exceeding the maximum of {max_len} bytes"
+            ),
+            DeserializeErrorKind::Convert(err) => {
+                write!(f, "decoded variant has wrong type: {err}")
+            }
+            DeserializeErrorKind::Io(err) => write!(f, "failed to read variant: {err}"),
+        }
+    }
+}
+
+impl Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            DeserializeErrorKind::Convert(err) => Some(err),
+            DeserializeErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DeserializeError {
+    fn from(err: io::Error) -> Self {
+        Self {
+            kind: DeserializeErrorKind::Io(err),
+        }
+    }
+}
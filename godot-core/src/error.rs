@@ -0,0 +1,287 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Unified error type for godot-rust.
+//!
+//! Individual APIs return specific errors such as [`ConvertError`] or [`IoError`]. When an application combines several of them, it can
+//! propagate all of them with `?` into the [`Error`] type of this module, and attach context along the way:
+//!
+//! ```no_run
+//! use godot::engine::{try_load, PackedScene};
+//! use godot::error::{Error, ErrorKind, ResultExt as _};
+//! use godot::prelude::*;
+//!
+//! fn spawn_player(level: &str) -> Result<Gd<Node3D>, Error> {
+//!     let scene = try_load::<PackedScene>(level).context("loading level")?;
+//!     let node = scene.instantiate().expect("instantiate scene");
+//!
+//!     let player = node.try_cast_err::<Node3D>()?;
+//!     Ok(player)
+//! }
+//!
+//! if let Err(err) = spawn_player("res://level.tscn") {
+//!     if err.kind() == ErrorKind::Io {
+//!         godot_error!("{err}");
+//!     }
+//! }
+//! ```
+//!
+//! [`Error`] implements [`std::error::Error`] and is `Send + Sync`, so it can itself be converted into `anyhow::Error`, or wrapped as
+//! `#[source]` in an error type derived with `thiserror`.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::builtin::meta::ConvertError;
+use crate::builtin::strings::NodePathError;
+use crate::builtin::{HtmlColorError, JsonValueError};
+use crate::engine::dynamic_call::CallError;
+use crate::engine::regex::RegexError;
+use crate::engine::variant_bytes::DeserializeError;
+use crate::engine::{IoError, MetaError};
+use crate::obj::{BindError, CastError, NotUniqueError, ObjectFreedError};
+
+type BoxedError = Box<dyn StdError + Send + Sync>;
+
+/// Error type that can hold any error produced by godot-rust, categorized by [`ErrorKind`].
+///
+/// Created from the specific error types with `From`/`?`, or from any other error with [`Error::new()`] and [`Error::other()`].
+/// Context can be attached with [`Error::context()`] or [`ResultExt::context()`].
+///
+/// `Display` shows the outermost context, or the original error if there is none. The rest is available through
+/// [`source()`][StdError::source], so that reporters like `anyhow` print the whole chain.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    context: Option<String>,
+    inner: BoxedError,
+}
+
+impl Error {
+    /// Wraps `error` with the given category.
+    pub fn new<E>(kind: ErrorKind, error: E) -> Self
+    where
+        E: Into<BoxedError>,
+    {
+        Self {
+            kind,
+            context: None,
+            inner: error.into(),
+        }
+    }
+
+    /// Wraps `error` with category [`ErrorKind::Other`]. Accepts error types as well as `String` and `&str` messages.
+    pub fn other<E>(error: E) -> Self
+    where
+        E: Into<BoxedError>,
+    {
+        Self::new(ErrorKind::Other, error)
+    }
+
+    /// Adds a context message, which becomes the error's `Display` output. The previous error is available as its source.
+    pub fn context<C>(self, context: C) -> Self
+    where
+        C: fmt::Display,
+    {
+        Self {
+            kind: self.kind,
+            context: Some(context.to_string()),
+            inner: Box::new(self),
+        }
+    }
+
+    /// Category of the original error. Not changed by adding context.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the original error, if it is of type `E`. Looks through all context layers.
+    ///
+    /// ```no_run
+    /// # use godot::error::Error;
+    /// # use godot::engine::IoError;
+    /// # fn handle(err: Error) {
+    /// if let Some(io_error) = err.downcast_ref::<IoError>() {
+    ///     // ...
+    /// }
+    /// # }
+    /// ```
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: StdError + 'static,
+    {
+        match self.inner.downcast_ref::<Error>() {
+            Some(inner) if self.context.is_some() => inner.downcast_ref(),
+            _ => self.inner.downcast_ref(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.context {
+            Some(context) => f.write_str(context),
+            None => fmt::Display::fmt(&self.inner, f),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        if self.context.is_some() {
+            Some(&*self.inner)
+        } else {
+            // Transparent: the original error is what `Display` shows, so continue with its source.
+            self.inner.source()
+        }
+    }
+}
+
+/// Category of an [`Error`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Conversion between Rust and Godot values, e.g. [`ConvertError`], [`JsonValueError`] or [`DeserializeError`].
+    Convert,
+
+    /// Dynamic method call, i.e. [`CallError`].
+    Call,
+
+    /// File system or resource loading, i.e. [`IoError`].
+    Io,
+
+    /// Downcast of an object to a derived class, i.e. [`CastError`].
+    Cast,
+
+    /// Access to an object that is freed, shared or already bound, e.g. [`ObjectFreedError`], [`NotUniqueError`] or [`BindError`].
+    Object,
+
+    /// Parsing of strings into Godot types, e.g. [`NodePathError`], [`HtmlColorError`] or [`RegexError`].
+    Parse,
+
+    /// Any other error.
+    Other,
+}
+
+macro_rules! impl_from_error {
+    ($($ErrorType:ty => $kind:ident),* $(,)?) => {
+        $(
+            impl From<$ErrorType> for Error {
+                fn from(error: $ErrorType) -> Self {
+                    Self::new(ErrorKind::$kind, error)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_error!(
+    ConvertError => Convert,
+    JsonValueError => Convert,
+    DeserializeError => Convert,
+    CallError => Call,
+    IoError => Io,
+    CastError => Cast,
+    ObjectFreedError => Object,
+    NotUniqueError => Object,
+    BindError => Object,
+    MetaError => Object,
+    NodePathError => Parse,
+    HtmlColorError => Parse,
+    RegexError => Parse,
+);
+
+/// Extension trait to attach context to the error of a `Result`, converting it into an [`Error`].
+pub trait ResultExt<T> {
+    /// Converts the error into [`Error`] and adds `context` to it.
+    fn context<C>(self, context: C) -> Result<T, Error>
+    where
+        C: fmt::Display;
+
+    /// Like [`context()`][Self::context], but computes the context only in case of an error.
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Into<Error>,
+{
+    fn context<C>(self, context: C) -> Result<T, Error>
+    where
+        C: fmt::Display,
+    {
+        self.map_err(|err| err.into().context(context))
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|err| err.into().context(f()))
+    }
+}
+
+fn __ensure_send_sync() {
+    fn check<T: Send + Sync>() {}
+    check::<Error>();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Leaf;
+
+    impl fmt::Display for Leaf {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("leaf")
+        }
+    }
+
+    impl StdError for Leaf {}
+
+    fn chain(err: &Error) -> Vec<String> {
+        let mut messages = vec![err.to_string()];
+        let mut source = err.source();
+        while let Some(err) = source {
+            messages.push(err.to_string());
+            source = err.source();
+        }
+        messages
+    }
+
+    #[test]
+    fn error_context_chain() {
+        let err = Error::new(ErrorKind::Io, Leaf);
+        assert_eq!(chain(&err), ["leaf"]);
+
+        let err = err.context("reading save").context("loading game");
+        assert_eq!(err.kind(), ErrorKind::Io);
+        assert_eq!(chain(&err), ["loading game", "reading save", "leaf"]);
+        assert!(err.downcast_ref::<Leaf>().is_some());
+        assert!(err.downcast_ref::<ConvertError>().is_none());
+    }
+
+    #[test]
+    fn error_from_specific() {
+        let result: Result<(), ConvertError> = Err(ConvertError::with_value(42));
+        let err = result.with_context(|| "converting 42").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Convert);
+        assert_eq!(err.to_string(), "converting 42");
+        assert!(err.downcast_ref::<ConvertError>().is_some());
+
+        let err = Error::other("custom message");
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(err.to_string(), "custom message");
+    }
+}
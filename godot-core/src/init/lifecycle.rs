@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Application lifecycle events (Godot 4.2+), forwarded to [`ExtensionLibrary::on_application_event()`].
+//!
+//! Godot sends the application's state changes as notifications to the main loop, which propagates them to all nodes in the scene tree.
+//! To receive them independently of user classes, a hidden node class is registered and an instance added to the tree's root.
+
+use std::ptr;
+use std::sync::Mutex;
+
+use crate::builtin::meta::ClassName;
+use crate::builtin::{Callable, Signal, Variant};
+use crate::engine::object::ConnectFlags;
+use crate::engine::{Engine, Node, RenderingServer, SceneTree};
+use crate::obj::{EngineEnum, Gd, GodotClass, InstanceId};
+use crate::sys;
+use sys::interface_fn;
+
+use super::{ApplicationEvent, ExtensionLibrary};
+
+/// Event handlers of the extension libraries in this binary, with the library pointer identifying each.
+static HANDLERS: Mutex<Vec<(usize, fn(ApplicationEvent))>> = Mutex::new(Vec::new());
+
+/// Library that registered the listener class, which must also unregister it.
+static LISTENER_LIBRARY: Mutex<usize> = Mutex::new(0);
+
+/// Listener node, once added to the scene tree.
+static LISTENER_NODE: Mutex<Option<InstanceId>> = Mutex::new(None);
+
+fn listener_class_name() -> ClassName {
+    ClassName::from_ascii_cstr(b"GdextLifecycleListener\0")
+}
+
+/// Starts forwarding application events to the library `E`. The first library registers the listener.
+pub(crate) fn attach<E: ExtensionLibrary>() {
+    let library = sys::get_library() as usize;
+
+    let is_first = {
+        let mut handlers = HANDLERS.lock().unwrap();
+        handlers.push((library, E::on_application_event));
+        handlers.len() == 1
+    };
+
+    if is_first {
+        register_listener_class(library);
+        add_listener_on_first_frame();
+    }
+}
+
+/// Stops forwarding application events to the current library. The last library removes the listener.
+pub(crate) fn detach() {
+    let library = sys::get_library() as usize;
+
+    let is_last = {
+        let mut handlers = HANDLERS.lock().unwrap();
+        handlers.retain(|&(handler_library, _)| handler_library != library);
+        handlers.is_empty()
+    };
+
+    if is_last {
+        // On shutdown, the scene tree and thus the listener are already gone. On hot reload, they are still alive.
+        if let Some(id) = LISTENER_NODE.lock().unwrap().take() {
+            if let Ok(node) = Gd::<Node>::try_from_instance_id(id) {
+                node.free();
+            }
+        }
+
+        unregister_listener_class();
+    }
+}
+
+fn register_listener_class(library: usize) {
+    let godot_params = sys::GDExtensionClassCreationInfo2 {
+        create_instance_func: Some(create_listener),
+        free_instance_func: Some(free_listener),
+        notification_func: Some(on_listener_notification),
+        // Neither instantiable nor visible from GDScript or the editor.
+        is_exposed: false as sys::GDExtensionBool,
+        ..crate::registry::default_creation_info()
+    };
+
+    *LISTENER_LIBRARY.lock().unwrap() = library;
+
+    // SAFETY: class names are valid StringNames for the duration of the call; the library is currently initializing.
+    unsafe {
+        interface_fn!(classdb_register_extension_class2)(
+            library as sys::GDExtensionClassLibraryPtr,
+            listener_class_name().string_sys(),
+            Node::class_name().string_sys(),
+            ptr::addr_of!(godot_params),
+        );
+    }
+}
+
+fn unregister_listener_class() {
+    let library = std::mem::take(&mut *LISTENER_LIBRARY.lock().unwrap());
+
+    // SAFETY: the class was registered by `library`, which is still loaded as long as any library of this binary is.
+    unsafe {
+        interface_fn!(classdb_unregister_extension_class)(
+            library as sys::GDExtensionClassLibraryPtr,
+            listener_class_name().string_sys(),
+        );
+    }
+}
+
+/// Adds the listener to the scene tree, once it exists.
+///
+/// On startup, extensions are initialized before the main loop is created. The rendering server exists already, and draws its first
+/// frame after the scene tree is set up.
+fn add_listener_on_first_frame() {
+    let callable = Callable::from_fn("add_lifecycle_listener", |_args| {
+        add_listener_to_tree();
+        Ok(Variant::nil())
+    });
+
+    Signal::from_object_signal(&RenderingServer::singleton(), "frame_post_draw")
+        .connect(callable, ConnectFlags::ONE_SHOT.ord() as i64);
+}
+
+fn add_listener_to_tree() {
+    let Some(scene_tree) = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+    else {
+        // Custom main loops do not propagate notifications to nodes.
+        return;
+    };
+    let Some(mut root) = scene_tree.get_root() else {
+        return;
+    };
+
+    // SAFETY: the listener class is registered, and constructing it returns a new, owned object.
+    let node: Gd<Node> = unsafe {
+        Gd::from_obj_sys(interface_fn!(classdb_construct_object)(
+            listener_class_name().string_sys(),
+        ))
+    };
+
+    *LISTENER_NODE.lock().unwrap() = Some(node.instance_id());
+    root.add_child(node);
+}
+
+unsafe extern "C" fn create_listener(
+    _class_userdata: *mut std::ffi::c_void,
+) -> sys::GDExtensionObjectPtr {
+    let object = interface_fn!(classdb_construct_object)(Node::class_name().string_sys());
+
+    // The listener has no state, but Godot needs a non-null instance to recognize the object as extension class.
+    let instance = ptr::NonNull::<u8>::dangling().as_ptr() as sys::GDExtensionClassInstancePtr;
+    interface_fn!(object_set_instance)(object, listener_class_name().string_sys(), instance);
+
+    object
+}
+
+unsafe extern "C" fn free_listener(
+    _class_userdata: *mut std::ffi::c_void,
+    _instance: sys::GDExtensionClassInstancePtr,
+) {
+    // Nothing to free.
+}
+
+unsafe extern "C" fn on_listener_notification(
+    _instance: sys::GDExtensionClassInstancePtr,
+    what: i32,
+    _reversed: sys::GDExtensionBool,
+) {
+    let Some(event) = ApplicationEvent::from_notification(what) else {
+        return;
+    };
+
+    // Copied, so that handlers can (un)load libraries.
+    let handlers = HANDLERS.lock().unwrap().clone();
+    for (_, handler) in handlers {
+        let ctx = || format!("ExtensionLibrary::on_application_event({event:?})");
+        let _ = crate::private::handle_panic(ctx, || handler(event));
+    }
+}
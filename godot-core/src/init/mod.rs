@@ -9,10 +9,19 @@ use godot_ffi as sys;
 
 use sys::GodotFfi;
 
+use crate::builtin::meta::ClassName;
 use crate::builtin::{GString, StringName};
 use crate::out;
+use std::sync::Mutex;
 
-pub use sys::GdextBuild;
+#[cfg(since_api = "4.2")]
+mod lifecycle;
+pub(crate) mod reload;
+pub(crate) mod startup_report;
+
+use startup_report::Category;
+
+pub use sys::{GdextBuild, GodotAllocator};
 
 #[doc(hidden)]
 // TODO consider body safe despite unsafe function, and explicitly mark unsafe {} locations
@@ -22,14 +31,25 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     init: *mut sys::GDExtensionInitialization,
 ) -> sys::GDExtensionBool {
     let init_code = || {
-        let tool_only_in_editor = match E::editor_run_behavior() {
-            EditorRunBehavior::ToolClassesOnly => true,
-            EditorRunBehavior::AllClasses => false,
-        };
-
-        let config = sys::GdextConfig::new(tool_only_in_editor);
-
-        sys::initialize(interface_or_get_proc_address, library, config);
+        // Further extension libraries in the same binary share the binding of the first one, including its settings.
+        if sys::is_initialized() {
+            out!("Binding already initialized by another extension library in this binary.");
+        } else {
+            let tool_only_in_editor = match E::editor_run_behavior() {
+                EditorRunBehavior::ToolClassesOnly => true,
+                EditorRunBehavior::AllClasses => false,
+            };
+
+            let config = sys::GdextConfig::new(tool_only_in_editor);
+            crate::private::set_panic_config(E::panic_reporting(), E::func_panic_behavior());
+
+            startup_report::measure(
+                None,
+                Category::Binding,
+                || "initialize GDExtension interface".to_string(),
+                || sys::initialize(interface_or_get_proc_address, library, config),
+            );
+        }
 
         // Currently no way to express failure; could be exposed to E if necessary.
         // No early exit, unclear if Godot still requires output parameters to be set.
@@ -37,7 +57,7 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 
         let godot_init_params = sys::GDExtensionInitialization {
             minimum_initialization_level: E::min_level().to_sys(),
-            userdata: std::ptr::null_mut(),
+            userdata: library as *mut std::ffi::c_void, // identifies the library in level callbacks
             initialize: Some(ffi_initialize_layer::<E>),
             deinitialize: Some(ffi_deinitialize_layer::<E>),
         };
@@ -54,7 +74,7 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 }
 
 unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
-    _userdata: *mut std::ffi::c_void,
+    userdata: *mut std::ffi::c_void,
     init_level: sys::GDExtensionInitializationLevel,
 ) {
     let level = InitLevel::from_sys(init_level);
@@ -62,13 +82,38 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
 
     // Swallow panics. TODO consider crashing if gdext init fails.
     let _ = crate::private::handle_panic(ctx, || {
-        gdext_on_level_init(level);
-        E::on_level_init(level);
+        sys::set_library(userdata as sys::GDExtensionClassLibraryPtr);
+        if level == E::min_level() {
+            reload::end_unload();
+        }
+
+        gdext_on_level_init(level, E::registers_class);
+
+        startup_report::measure(
+            Some(level),
+            Category::Callback,
+            || "ExtensionLibrary::on_level_init()".to_string(),
+            || E::on_level_init(level),
+        );
+
+        // Also for libraries with a higher min_level: application events matter at runtime, where the editor level is never loaded.
+        #[cfg(since_api = "4.2")]
+        if level == InitLevel::Scene {
+            lifecycle::attach::<E>();
+        }
+
+        // Instances are recreated by Godot after all levels are initialized, so the hook is deferred to the next frame.
+        #[cfg(since_api = "4.2")]
+        if level == E::min_level() && reload::is_editor_running() {
+            reload::schedule_after_reload(E::on_after_reload);
+        }
     });
+
+    startup_report::finish_level(level);
 }
 
 unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
-    _userdata: *mut std::ffi::c_void,
+    userdata: *mut std::ffi::c_void,
     init_level: sys::GDExtensionInitializationLevel,
 ) {
     let level = InitLevel::from_sys(init_level);
@@ -76,40 +121,123 @@ unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
 
     // Swallow panics.
     let _ = crate::private::handle_panic(ctx, || {
+        sys::set_library(userdata as sys::GDExtensionClassLibraryPtr);
+
+        // The highest level is deinitialized first, while all instances are still alive.
+        if reload::begin_unload() {
+            E::on_before_reload();
+        }
+
+        #[cfg(since_api = "4.2")]
+        if level == InitLevel::Scene {
+            lifecycle::detach();
+        }
+
         E::on_level_deinit(level);
         gdext_on_level_deinit(level);
     });
+
+    // Lowest level is deinitialized last, before the library is unloaded. Other libraries in the binary may still need the hook.
+    if level == E::min_level() && !is_any_library_loaded() {
+        crate::private::uninstall_panic_hook();
+    }
+}
+
+/// Number of extension libraries in this binary which currently have each level initialized, indexed by `InitLevel::to_sys()`.
+static LIBRARIES_PER_LEVEL: Mutex<[usize; 4]> = Mutex::new([0; 4]);
+
+/// Records that a library initialized `level`. Returns whether it is the first one.
+fn add_library_at_level(level: InitLevel) -> bool {
+    let mut counts = LIBRARIES_PER_LEVEL.lock().unwrap();
+    let count = &mut counts[level.to_sys() as usize];
+    *count += 1;
+    *count == 1
+}
+
+/// Records that a library deinitialized `level`. Returns whether it was the last one.
+fn remove_library_at_level(level: InitLevel) -> bool {
+    let mut counts = LIBRARIES_PER_LEVEL.lock().unwrap();
+    let count = &mut counts[level.to_sys() as usize];
+    *count = count.saturating_sub(1);
+    *count == 0
+}
+
+fn is_any_library_loaded() -> bool {
+    LIBRARIES_PER_LEVEL
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|&count| count > 0)
 }
 
 /// Tasks needed to be done by gdext internally upon loading an initialization level. Called before user code.
-fn gdext_on_level_init(level: InitLevel) {
+///
+/// If several extension libraries share this binary, engine-wide setup only happens for the first one to reach `level`.
+fn gdext_on_level_init(level: InitLevel, registers_class: fn(ClassName, &str) -> bool) {
     // SAFETY: we are in the main thread, during initialization, no other logic is happening.
     // TODO: in theory, a user could start a thread in one of the early levels, and run concurrent code that messes with the global state
     // (e.g. class registration). This would break the assumption that the load_class_method_table() calls are exclusive.
     // We could maybe protect globals with a mutex until initialization is complete, and then move it to a directly-accessible, read-only static.
     unsafe {
+        let load_table = |api_level: sys::ClassApiLevel| {
+            startup_report::measure(
+                Some(level),
+                Category::Binding,
+                || format!("load class method table ({api_level:?})"),
+                || sys::load_class_method_table(api_level),
+            );
+        };
+
+        let is_first_library = add_library_at_level(level);
+
         match level {
+            _ if !is_first_library => {}
             InitLevel::Core => {}
             InitLevel::Servers => {
-                sys::load_class_method_table(sys::ClassApiLevel::Server);
+                load_table(sys::ClassApiLevel::Server);
             }
             InitLevel::Scene => {
-                sys::load_class_method_table(sys::ClassApiLevel::Scene);
+                load_table(sys::ClassApiLevel::Scene);
                 ensure_godot_features_compatible();
+                crate::tools::on_scene_init();
             }
             InitLevel::Editor => {
-                sys::load_class_method_table(sys::ClassApiLevel::Editor);
+                load_table(sys::ClassApiLevel::Editor);
             }
         }
-        crate::auto_register_classes(level);
+        crate::auto_register_classes(level, registers_class);
     }
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    let is_last_library = remove_library_at_level(level);
+
+    if level == InitLevel::Scene && is_last_library {
+        crate::tools::on_scene_deinit();
+    }
+
     crate::unregister_classes(level);
 }
 
+/// Loads all method bindings of engine class `T` upfront, with the `lazy-function-tables` feature.
+///
+/// With lazy function tables, a method binding is only fetched from Godot when the method is first called. This speeds up startup,
+/// but moves the cost to the first call. Preloading classes used in hot paths brings that cost back to initialization.
+/// Only the metadata of preloaded classes and called methods is kept in the binary; the rest can be stripped by the linker.
+///
+/// Must be called once the init level of `T` has been loaded, e.g. in [`ExtensionLibrary::on_level_init()`] for
+/// [`InitLevel::Scene`] if `T` is a scene class. Does nothing without `lazy-function-tables`, as all bindings are then loaded
+/// upfront anyway.
+pub fn preload_methods<T: crate::obj::EngineClass>() {
+    startup_report::measure(
+        None,
+        Category::Binding,
+        || format!("preload methods of {}", T::class_name()),
+        T::__preload_methods,
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Defines the entry point for a GDExtension Rust library.
@@ -131,6 +259,37 @@ fn gdext_on_level_deinit(level: InitLevel) {
 /// unsafe impl ExtensionLibrary for MyExtension {}
 /// ```
 ///
+/// # Multiple libraries in one binary
+/// A single cdylib can contain several implementations of this trait, which Godot loads as separate extensions. Give each one its own
+/// entry symbol with `#[gdextension(entry_symbol = "...")]`, reference that symbol from its own `.gdextension` file, and decide with
+/// [`registers_class()`][Self::registers_class] which classes belong to which library.
+///
+/// The libraries share one binding to Godot: [`editor_run_behavior()`][Self::editor_run_behavior],
+/// [`panic_reporting()`][Self::panic_reporting] and [`func_panic_behavior()`][Self::func_panic_behavior] are taken from the library that
+/// Godot loads first.
+///
+/// ```
+/// # use godot::init::*;
+/// # use godot::builtin::meta::ClassName;
+/// struct GameExtension;
+///
+/// #[gdextension(entry_symbol = "game_init")]
+/// unsafe impl ExtensionLibrary for GameExtension {
+///     fn registers_class(_class_name: ClassName, module_path: &str) -> bool {
+///         !module_path.contains("::editor")
+///     }
+/// }
+///
+/// struct EditorToolsExtension;
+///
+/// #[gdextension(entry_symbol = "editor_tools_init")]
+/// unsafe impl ExtensionLibrary for EditorToolsExtension {
+///     fn registers_class(_class_name: ClassName, module_path: &str) -> bool {
+///         module_path.contains("::editor")
+///     }
+/// }
+/// ```
+///
 /// # Safety
 /// By using godot-rust, you accept the safety considerations [as outlined in the book][safety].
 /// Please make sure you fully understand the implications.
@@ -155,6 +314,29 @@ pub unsafe trait ExtensionLibrary {
         InitLevel::Scene
     }
 
+    /// Determines how panics in Rust code are reported to Godot (terse by default).
+    ///
+    /// See [`PanicReporting`] for details.
+    fn panic_reporting() -> PanicReporting {
+        PanicReporting::Terse
+    }
+
+    /// Determines what a `#[func]` returns to the caller after a panic (a failed call by default).
+    ///
+    /// See [`FuncPanicBehavior`] for details.
+    fn func_panic_behavior() -> FuncPanicBehavior {
+        FuncPanicBehavior::FailCall
+    }
+
+    /// Determines whether a `#[derive(GodotClass)]` class is registered by this library (all classes by default).
+    ///
+    /// `module_path` is the Rust module declaring the class, as returned by `module_path!()`. This is only needed if the binary contains
+    /// multiple extension libraries; a class accepted by several of them is only registered by the first, and silently skipped by the
+    /// others. With the default, the first library loaded thus registers all classes.
+    fn registers_class(_class_name: ClassName, _module_path: &str) -> bool {
+        true
+    }
+
     /// Custom logic when a certain init-level of Godot is loaded.
     ///
     /// This will only be invoked for levels >= [`Self::min_level()`], in ascending order. Use `if` or `match` to hook to specific levels.
@@ -168,6 +350,28 @@ pub unsafe trait ExtensionLibrary {
     fn on_level_deinit(_level: InitLevel) {
         // Nothing by default.
     }
+
+    /// Custom logic when the application changes its state, e.g. is paused or resumed on a mobile device (Godot 4.2+).
+    ///
+    /// Events are delivered while a scene tree is running, starting with its first frame. See [`ApplicationEvent`] for details.
+    fn on_application_event(_event: ApplicationEvent) {
+        // Nothing by default.
+    }
+
+    /// Custom logic before the library is unloaded for a hot reload in the editor (Godot 4.2+).
+    ///
+    /// Invoked before [`Self::on_level_deinit()`], while all instances of the library's classes are still alive. Per-instance state can
+    /// be kept with `save_reload_state()` and `restore_reload_state()` in the class's `I*` interface trait.
+    fn on_before_reload() {
+        // Nothing by default.
+    }
+
+    /// Custom logic after the library has been reloaded in the editor (Godot 4.2+).
+    ///
+    /// Invoked on the first frame after the reload, once Godot has recreated all instances and restored their state.
+    fn on_after_reload() {
+        // Nothing by default.
+    }
 }
 
 /// Determines if and how an extension's code is run in the editor.
@@ -196,6 +400,96 @@ pub enum EditorRunBehavior {
     AllClasses,
 }
 
+/// Determines how panics in Rust code are reported to Godot.
+///
+/// Panics that unwind to the FFI boundary (e.g. in `#[func]` methods, virtual callbacks or init functions) are caught by godot-rust and
+/// printed as errors, which appear in Godot's output and debugger. They are never propagated into the engine.
+///
+/// See also [`ExtensionLibrary::panic_reporting()`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum PanicReporting {
+    /// Prints the source location, the class and method being executed, and the panic message.
+    ///
+    /// The panic hook is only replaced while a panic is caught.
+    Terse,
+
+    /// Additionally captures a backtrace, and prints everything as a single, indented error.
+    ///
+    /// This installs a process-wide panic hook when the library is loaded, and reinstalls the previous hook on unload.
+    /// Panics outside godot-rust's handling (e.g. in threads you spawned) are forwarded to the previous hook. Backtraces are captured
+    /// regardless of the `RUST_BACKTRACE` environment variable, which makes panics more expensive. Symbol names require debug info.
+    Detailed,
+}
+
+/// Determines what a `#[func]` returns to its caller after it panicked.
+///
+/// In either case, the panic is reported according to [`PanicReporting`].
+///
+/// See also [`ExtensionLibrary::func_panic_behavior()`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum FuncPanicBehavior {
+    /// Signals a failed call to Godot. A calling GDScript function is aborted at this point.
+    FailCall,
+
+    /// Returns `null` to the caller, which continues running, as if the method had reported a script error.
+    ///
+    /// Only applies to dynamic calls (varcalls), which is how GDScript usually invokes methods. Ptrcalls from statically typed code
+    /// cannot signal errors and leave the return value untouched.
+    ReturnNil,
+}
+
+/// Change in the state of the application, as reported by the operating system.
+///
+/// On Android and iOS, Godot translates the activity or app delegate callbacks into these events, so they are the way to react to
+/// the app being sent to the background, e.g. to save progress or stop audio. Desktop platforms only report focus changes. Godot does not
+/// expose the JNI environment or the iOS application delegate to GDExtension; platform services are reached through the singletons that
+/// native Godot plugins register, see [`Engine::get_singleton()`](crate::engine::Engine::get_singleton).
+///
+/// See also [`ExtensionLibrary::on_application_event()`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ApplicationEvent {
+    /// The application is paused, e.g. sent to the background on mobile. It may be terminated by the OS without further notice.
+    Paused,
+
+    /// The application is resumed after being paused.
+    Resumed,
+
+    /// The application gained focus.
+    FocusIn,
+
+    /// The application lost focus.
+    FocusOut,
+
+    /// The OS is running low on memory; caches should be freed.
+    MemoryWarning,
+
+    /// The user requested to go back, e.g. with the Android back button.
+    GoBackRequested,
+}
+
+impl ApplicationEvent {
+    /// Maps the `Node` notification propagated by the scene tree, if it is one of the application events.
+    #[cfg_attr(before_api = "4.2", allow(dead_code))]
+    fn from_notification(what: i32) -> Option<Self> {
+        use crate::engine::notify::NodeNotification;
+
+        let event = match NodeNotification::from(what) {
+            NodeNotification::WmGoBackRequest => Self::GoBackRequested,
+            NodeNotification::OsMemoryWarning => Self::MemoryWarning,
+            NodeNotification::ApplicationResumed => Self::Resumed,
+            NodeNotification::ApplicationPaused => Self::Paused,
+            NodeNotification::ApplicationFocusIn => Self::FocusIn,
+            NodeNotification::ApplicationFocusOut => Self::FocusOut,
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Stage of the Godot initialization process.
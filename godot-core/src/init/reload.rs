@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Hot reloading in the editor (Godot 4.2+): detection, and keeping state of user instances across the reload.
+//!
+//! During a hot reload, Godot keeps all objects alive but drops their Rust instances, unloads and reloads the library, and then
+//! recreates the instances. Instance state returned by `save_reload_state()` is stored as metadata of the Godot object in between.
+
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::builtin::{StringName, Variant};
+use crate::engine::{Engine, Object};
+use crate::obj::Gd;
+use crate::sys;
+
+/// Metadata entry holding the saved state of a Rust instance. Names starting with `_` are hidden in the inspector.
+const STATE_META: &str = "_gdext_reload_state";
+
+/// Set while the library is being unloaded for a hot reload.
+static IS_UNLOADING_FOR_RELOAD: AtomicBool = AtomicBool::new(false);
+
+/// Whether the editor is up and running, i.e. the library is (un)loaded as part of a hot reload.
+///
+/// On regular startup and shutdown of the editor, extensions are (de)initialized while no main loop exists.
+pub(crate) fn is_editor_running() -> bool {
+    if cfg!(before_api = "4.2") {
+        return false;
+    }
+
+    let engine = Engine::singleton();
+    engine.is_editor_hint() && engine.get_main_loop().is_some()
+}
+
+/// Starts unloading for a hot reload, if that is the case. Returns `true` only on the first call per unload.
+pub(crate) fn begin_unload() -> bool {
+    if IS_UNLOADING_FOR_RELOAD.load(Ordering::Relaxed) || !is_editor_running() {
+        return false;
+    }
+
+    IS_UNLOADING_FOR_RELOAD.store(true, Ordering::Relaxed);
+    true
+}
+
+/// Ends unloading, once the library is initialized again.
+///
+/// Godot may free the instances only after all levels are deinitialized, so the flag cannot be reset earlier.
+pub(crate) fn end_unload() {
+    IS_UNLOADING_FOR_RELOAD.store(false, Ordering::Relaxed);
+}
+
+pub(crate) fn is_unloading_for_reload() -> bool {
+    IS_UNLOADING_FOR_RELOAD.load(Ordering::Relaxed)
+}
+
+/// Runs `callback` once on the next frame, after Godot has recreated all instances of the reloaded library.
+#[cfg(since_api = "4.2")]
+pub(crate) fn schedule_after_reload(callback: fn()) {
+    use crate::builtin::{Callable, Signal};
+    use crate::engine::object::ConnectFlags;
+    use crate::engine::SceneTree;
+    use crate::obj::EngineEnum;
+
+    let Some(scene_tree) = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+    else {
+        return;
+    };
+
+    let callable = Callable::from_fn("on_after_reload", move |_args| {
+        callback();
+        Ok(Variant::nil())
+    });
+
+    Signal::from_object_signal(&scene_tree, "process_frame")
+        .connect(callable, ConnectFlags::ONE_SHOT.ord() as i64);
+}
+
+/// Keeps `state` in the Godot object until its Rust instance is recreated.
+///
+/// # Safety
+/// `object` must point to a live Godot object.
+pub(crate) unsafe fn store_state(object: sys::GDExtensionObjectPtr, state: Variant) {
+    // Weak pointer: the object is not owned here, and may be in the middle of being unregistered.
+    let mut object = ManuallyDrop::new(Gd::<Object>::from_obj_sys_weak(object));
+    object.set_meta(StringName::from(STATE_META), state);
+}
+
+/// Removes and returns the state stored by [`store_state()`], if any.
+///
+/// # Safety
+/// `object` must point to a live Godot object.
+pub(crate) unsafe fn take_state(object: sys::GDExtensionObjectPtr) -> Option<Variant> {
+    let mut object = ManuallyDrop::new(Gd::<Object>::from_obj_sys_weak(object));
+    let name = StringName::from(STATE_META);
+
+    if !object.has_meta(name.clone()) {
+        return None;
+    }
+
+    let state = object.get_meta(name.clone());
+    object.remove_meta(name);
+    Some(state)
+}
@@ -0,0 +1,256 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Opt-in report of the time spent while loading the extension.
+//!
+//! Enabled through the environment variable [`REPORT_ENV`]. With `1`, a summary is printed after each init level; any other value is
+//! used as path of a JSON file, which is rewritten after each level with all measurements so far.
+
+use std::fmt::Write as _;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+use super::InitLevel;
+
+/// Name of the environment variable enabling the report.
+pub(crate) const REPORT_ENV: &str = "GDEXT_STARTUP_REPORT";
+
+/// What a measurement refers to.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Category {
+    /// Loading the GDExtension interface and engine method tables.
+    Binding,
+
+    /// Registering one class, including its `#[godot_api]` blocks.
+    Class,
+
+    /// Other gdext setup and user callbacks, such as `ExtensionLibrary::on_level_init()`.
+    Callback,
+}
+
+impl Category {
+    fn as_str(self) -> &'static str {
+        match self {
+            Category::Binding => "binding",
+            Category::Class => "class",
+            Category::Callback => "callback",
+        }
+    }
+}
+
+/// Runs `f`, recording its duration under `name` if the report is enabled.
+pub(crate) fn measure<R>(
+    level: Option<InitLevel>,
+    category: Category,
+    name: impl FnOnce() -> String,
+    f: impl FnOnce() -> R,
+) -> R {
+    if !is_enabled() {
+        return f();
+    }
+
+    let start = Instant::now();
+    let result = f();
+    let duration = start.elapsed();
+
+    lock_report().entries.push(Entry {
+        level,
+        category,
+        name: name(),
+        duration,
+    });
+
+    result
+}
+
+/// Outputs the measurements taken since the previous level, once `level` has been initialized.
+pub(crate) fn finish_level(level: InitLevel) {
+    let Some(output) = output() else {
+        return;
+    };
+
+    let mut report = lock_report();
+    let new_entries = &report.entries[report.reported..];
+
+    match output {
+        Output::Print => print_level(new_entries, level),
+        Output::JsonFile(path) => {
+            if let Err(e) = std::fs::write(path, to_json(&report.entries)) {
+                crate::godot_warn!("Failed to write startup report to {path}: {e}");
+            }
+        }
+    }
+
+    report.reported = report.entries.len();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Default)]
+struct Report {
+    entries: Vec<Entry>,
+
+    /// Number of entries already output by [`finish_level()`].
+    reported: usize,
+}
+
+struct Entry {
+    level: Option<InitLevel>,
+    category: Category,
+    name: String,
+    duration: Duration,
+}
+
+enum Output {
+    Print,
+    JsonFile(String),
+}
+
+fn output() -> Option<&'static Output> {
+    static OUTPUT: OnceLock<Option<Output>> = OnceLock::new();
+
+    OUTPUT
+        .get_or_init(|| {
+            let value = std::env::var(REPORT_ENV).ok()?;
+            match value.as_str() {
+                "" | "0" => None,
+                "1" => Some(Output::Print),
+                path => Some(Output::JsonFile(path.to_string())),
+            }
+        })
+        .as_ref()
+}
+
+fn is_enabled() -> bool {
+    output().is_some()
+}
+
+fn lock_report() -> MutexGuard<'static, Report> {
+    static REPORT: OnceLock<Mutex<Report>> = OnceLock::new();
+
+    REPORT
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Goes through Godot's print functions, so the report also shows up in the editor's _Output_ panel.
+fn print_level(entries: &[Entry], level: InitLevel) {
+    let total: Duration = entries.iter().map(|entry| entry.duration).sum();
+    let mut text = format!(
+        "Startup report for level `{level:?}` ({:.3} ms):",
+        as_millis(total)
+    );
+
+    for entry in entries {
+        let _ = write!(
+            text,
+            "\n  {:<9} {:<50} {:>9.3} ms",
+            entry.category.as_str(),
+            entry.name,
+            as_millis(entry.duration)
+        );
+    }
+
+    crate::godot_print!("{text}");
+}
+
+fn to_json(entries: &[Entry]) -> String {
+    let mut json = String::from("[\n");
+
+    for (i, entry) in entries.iter().enumerate() {
+        let level = match entry.level {
+            Some(level) => json_string(&format!("{level:?}")),
+            None => "null".to_string(),
+        };
+        let separator = if i + 1 < entries.len() { "," } else { "" };
+
+        let _ = writeln!(
+            json,
+            "  {{\"level\": {level}, \"category\": {}, \"name\": {}, \"ms\": {:.3}}}{separator}",
+            json_string(entry.category.as_str()),
+            json_string(&entry.name),
+            as_millis(entry.duration),
+        );
+    }
+
+    json.push_str("]\n");
+    json
+}
+
+/// Quotes `s` as JSON string, escaping it according to RFC 8259.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            // All other control characters must be escaped; U+2028 and U+2029 for consumers that embed the JSON in JavaScript.
+            c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json_output() {
+        let entries = [
+            Entry {
+                level: None,
+                category: Category::Binding,
+                name: "initialize interface".to_string(),
+                duration: Duration::from_micros(1500),
+            },
+            Entry {
+                level: Some(InitLevel::Scene),
+                category: Category::Class,
+                name: "My\"Class".to_string(),
+                duration: Duration::from_micros(250),
+            },
+        ];
+
+        let expected = r#"[
+  {"level": null, "category": "binding", "name": "initialize interface", "ms": 1.500},
+  {"level": "Scene", "category": "class", "name": "My\"Class", "ms": 0.250}
+]
+"#;
+        assert_eq!(to_json(&entries), expected);
+    }
+
+    #[test]
+    fn json_string_escaping() {
+        assert_eq!(json_string("Node"), r#""Node""#);
+        assert_eq!(json_string(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(json_string("line\nbreak\ttab"), r#""line\nbreak\ttab""#);
+        assert_eq!(
+            json_string("\u{1}\u{7f}\u{2028}"),
+            r#""\u0001\u007f\u2028""#
+        );
+        assert_eq!(json_string("Ünïcödé"), "\"Ünïcödé\"");
+    }
+}
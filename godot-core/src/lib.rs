@@ -10,10 +10,13 @@ mod storage;
 
 pub mod builder;
 pub mod builtin;
+pub mod error;
 pub mod init;
 pub mod log;
 pub mod obj;
 pub mod property;
+pub mod test_util;
+pub mod tools;
 
 #[doc(hidden)]
 #[path = "deprecated.rs"]
@@ -38,6 +41,7 @@ pub use registry::*;
 /// * [`global`][crate::engine::global]: global enums not belonging to a specific class.
 /// * [`utilities`][crate::engine::utilities]: utility methods that are global in Godot.
 /// * [`translate`][crate::engine::translate]: convenience macros for translation.
+/// * [`dynamic_call`][crate::engine::dynamic_call]: type-checked dynamic method calls.
 pub mod engine;
 
 // Output of generated code. Mimics the file structure, symbols are re-exported.
@@ -18,10 +18,11 @@ macro_rules! inner_godot_msg {
             // assert!(msg.is_ascii(), "godot_error: message must be ASCII");
 
             // Check whether engine is loaded, otherwise fall back to stderr.
+            // Location is that of the user's macro invocation, so the debugger shows the Rust call site.
             if $crate::sys::is_initialized() {
                 $crate::sys::interface_fn!($godot_fn)(
                     $crate::sys::c_str_from_str(&msg),
-                    $crate::sys::c_str(b"<function unset>\0"),
+                    $crate::sys::c_str_from_str(concat!(module_path!(), "\0")),
                     $crate::sys::c_str_from_str(concat!(file!(), "\0")),
                     line!() as i32,
                     false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
@@ -35,6 +36,8 @@ macro_rules! inner_godot_msg {
 
 /// Pushes a warning message to Godot's built-in debugger and to the OS terminal.
 ///
+/// The debugger shows the module, file and line of the macro invocation as the message's source.
+///
 /// _Godot equivalent: [`@GlobalScope.push_warning()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-push-warning)_.
 #[macro_export]
 macro_rules! godot_warn {
@@ -45,6 +48,9 @@ macro_rules! godot_warn {
 
 /// Pushes an error message to Godot's built-in debugger and to the OS terminal.
 ///
+/// The debugger shows the module, file and line of the macro invocation as the message's source. For errors that may occur every frame,
+/// consider [`godot_error_once!`].
+///
 /// _Godot equivalent: [`@GlobalScope.push_error()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-push-error)_.
 #[macro_export]
 macro_rules! godot_error {
@@ -53,6 +59,32 @@ macro_rules! godot_error {
     };
 }
 
+/// Like [`godot_error!`], but only reports the first error from each invocation site.
+///
+/// Useful for errors in code that runs every frame, which would otherwise flood the debugger. Format arguments are only evaluated for the
+/// error that is reported.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn find_target() -> Option<Vector2> { None }
+/// fn process(delta: f64) {
+///     let Some(target) = find_target() else {
+///         godot_error_once!("no target found (delta {delta})");
+///         return;
+///     };
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! godot_error_once {
+    ($fmt:literal $(, $args:expr)* $(,)?) => {{
+        static REPORTED: ::std::sync::atomic::AtomicBool = ::std::sync::atomic::AtomicBool::new(false);
+        if !REPORTED.swap(true, ::std::sync::atomic::Ordering::Relaxed) {
+            $crate::inner_godot_msg!(print_error; $fmt $(, $args)*);
+        }
+    }};
+}
+
 /// Logs a script error to Godot's built-in debugger and to the OS terminal.
 #[macro_export]
 macro_rules! godot_script_error {
@@ -69,15 +101,25 @@ macro_rules! godot_print {
     ($fmt:literal $(, $args:expr)* $(,)?) => {
         $crate::log::print(&[
             $crate::builtin::Variant::from(
-                $crate::builtin::GString::from(
-                    format!($fmt $(, $args)*)
+                $crate::builtin::GString::from_fmt(
+                    format_args!($fmt $(, $args)*)
                 )
             )
         ])
     };
 }
 
-pub use crate::{godot_error, godot_print, godot_script_error, godot_warn};
+pub use crate::{godot_error, godot_error_once, godot_print, godot_script_error, godot_warn};
+
+#[cfg(feature = "log")]
+mod godot_logger;
+#[cfg(feature = "tracing")]
+mod tracing_layer;
+
+#[cfg(feature = "log")]
+pub use godot_logger::GodotLogger;
+#[cfg(feature = "tracing")]
+pub use tracing_layer::GodotTracingLayer;
 
 use crate::builtin::{StringName, Variant};
 use crate::sys::{self, GodotFfi};
@@ -104,3 +146,56 @@ pub fn print(varargs: &[Variant]) {
     // TODO use generated method, but figure out how print() with zero args can be called
     // crate::engine::utilities::print(head, rest);
 }
+
+/// Source location reported along with an error or warning from a logging backend.
+#[cfg(any(feature = "log", feature = "tracing"))]
+#[derive(Copy, Clone)]
+struct MessageLocation<'a> {
+    function: Option<&'a str>,
+    file: Option<&'a str>,
+    line: Option<u32>,
+}
+
+/// Like `godot_error!`/`godot_warn!`, but reports the location of the log statement instead of the backend's code.
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn push_message(message: &str, location: MessageLocation<'_>, is_error: bool) {
+    let message = format!("{message}\0");
+    let function = format!("{}\0", location.function.unwrap_or("<function unset>"));
+    let file = format!("{}\0", location.file.unwrap_or("<file unset>"));
+    let line = location.line.unwrap_or(0) as i32;
+
+    let print_fn = if is_error {
+        sys::interface_fn!(print_error)
+    } else {
+        sys::interface_fn!(print_warning)
+    };
+
+    // SAFETY: all strings are null-terminated and outlive the call.
+    unsafe {
+        print_fn(
+            sys::c_str_from_str(&message),
+            sys::c_str_from_str(&function),
+            sys::c_str_from_str(&file),
+            line,
+            false as sys::GDExtensionBool, // whether to create a toast notification in editor
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn error_once_reports_first_only() {
+        let mut evaluated = 0;
+        for i in 0..3 {
+            crate::godot_error_once!("iteration {}", {
+                evaluated += 1;
+                i
+            });
+        }
+
+        assert_eq!(evaluated, 1);
+    }
+}
@@ -0,0 +1,260 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::str::FromStr;
+
+use ::log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::builtin::GString;
+use crate::engine::ProjectSettings;
+use crate::log::{push_message, MessageLocation};
+use crate::sys;
+
+/// Backend for the [`log`](https://docs.rs/log) crate, writing to Godot's output.
+///
+/// Records are mapped as follows:
+/// * `error!` → `push_error()`, shown in the editor's debugger with the source location of the log statement.
+/// * `warn!` → `push_warning()`, likewise.
+/// * `info!`, `debug!`, `trace!` → `print()`, prefixed with level and target.
+///
+/// Which records are logged is controlled by a filter, using the syntax of
+/// [`env_logger`](https://docs.rs/env_logger/latest/env_logger/#enabling-logging): a comma-separated list of `target=level` entries,
+/// plus an optional default level without target. For example, `warn,my_game=debug,my_game::net=trace` logs warnings and errors from
+/// all crates, more from `my_game` and everything from its `net` module. A target matches its own records and those of its submodules;
+/// the longest matching target wins.
+///
+/// The filter can be set in code, or in the project setting `godot_rust/log/filter` (see [`from_project_settings()`][Self::from_project_settings]):
+/// ```text
+/// [godot_rust]
+/// log/filter="info,noisy_dependency=warn"
+/// ```
+///
+/// Install the logger once, in [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init]:
+/// ```no_run
+/// use godot::init::*;
+/// use godot::log::GodotLogger;
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             GodotLogger::from_project_settings().init().expect("logger installed only once");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GodotLogger {
+    default_level: LevelFilter,
+
+    /// Sorted by descending length, so that the first match is the most specific one.
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl GodotLogger {
+    /// Name of the project setting holding the filter.
+    pub const FILTER_SETTING: &'static str = "godot_rust/log/filter";
+
+    /// Creates a logger that logs `info` and more severe records from all targets.
+    pub fn new() -> Self {
+        Self {
+            default_level: LevelFilter::Info,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Creates a logger with the filter from the project setting [`FILTER_SETTING`][Self::FILTER_SETTING].
+    ///
+    /// Falls back to [`new()`][Self::new] if the setting is absent. Invalid entries are reported as warnings and skipped.
+    ///
+    /// Requires the engine to be initialized, i.e. must be called in or after [`InitLevel::Scene`][crate::init::InitLevel::Scene].
+    pub fn from_project_settings() -> Self {
+        let settings = ProjectSettings::singleton();
+        let key = GString::from(Self::FILTER_SETTING);
+
+        if !settings.has_setting(key.clone()) {
+            return Self::new();
+        }
+
+        let filter = settings.get_setting(key).stringify().to_string();
+        Self::new().with_filter(&filter)
+    }
+
+    /// Sets the level for targets that have no more specific entry.
+    pub fn with_default_level(mut self, level: LevelFilter) -> Self {
+        self.default_level = level;
+        self
+    }
+
+    /// Sets the level for `target` and its submodules, e.g. `"my_game::net"`.
+    pub fn with_target(mut self, target: impl Into<String>, level: LevelFilter) -> Self {
+        let target = target.into();
+        self.targets.retain(|(existing, _)| *existing != target);
+        self.targets.push((target, level));
+        self.targets.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+        self
+    }
+
+    /// Adds all entries of a filter string, in the syntax described in the [type-level docs](Self).
+    ///
+    /// Invalid entries are reported as warnings and skipped.
+    pub fn with_filter(mut self, filter: &str) -> Self {
+        for entry in filter.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            self = match parse_entry(entry) {
+                Some((None, level)) => self.with_default_level(level),
+                Some((Some(target), level)) => self.with_target(target, level),
+                None => {
+                    crate::godot_warn!("GodotLogger: invalid filter entry `{entry}`, ignored");
+                    self
+                }
+            };
+        }
+        self
+    }
+
+    /// Installs this logger as the global logger of the `log` crate.
+    ///
+    /// Fails if a logger has already been installed.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let max_level = self.max_level();
+        ::log::set_boxed_logger(Box::new(self))?;
+        ::log::set_max_level(max_level);
+        Ok(())
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .find(|(prefix, _)| target_matches(target, prefix))
+            .map_or(self.default_level, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default_level, Ord::max)
+    }
+}
+
+impl Default for GodotLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Log for GodotLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let target = record.target();
+        let args = record.args();
+
+        // Before the engine is loaded (or after it's unloaded), there is nowhere to write to.
+        if !sys::is_initialized() {
+            eprintln!("[{} {target}] {args}", record.level());
+            return;
+        }
+
+        let location = MessageLocation {
+            function: record.module_path(),
+            file: record.file(),
+            line: record.line(),
+        };
+
+        match record.level() {
+            ::log::Level::Error => push_message(&format!("{target}: {args}"), location, true),
+            ::log::Level::Warn => push_message(&format!("{target}: {args}"), location, false),
+            level => {
+                let line = GString::from_fmt(format_args!("[{level} {target}] {args}"));
+                crate::log::print(&[line.into()]);
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Parses `level` or `target=level`. Returns `None` for invalid entries.
+fn parse_entry(entry: &str) -> Option<(Option<&str>, LevelFilter)> {
+    match entry.split_once('=') {
+        Some((target, level)) => {
+            let target = target.trim();
+            let level = LevelFilter::from_str(level.trim()).ok()?;
+            (!target.is_empty()).then_some((Some(target), level))
+        }
+        None => LevelFilter::from_str(entry)
+            .ok()
+            .map(|level| (None, level))
+            // A bare target without level enables everything for it, like env_logger.
+            .or_else(|| is_target(entry).then_some((Some(entry), LevelFilter::Trace))),
+    }
+}
+
+fn is_target(s: &str) -> bool {
+    s.chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == ':')
+}
+
+/// Whether `target` is `prefix` itself or one of its submodules.
+fn target_matches(target: &str, prefix: &str) -> bool {
+    match target.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with("::"),
+        None => false,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn logger_filter_levels() {
+        let logger =
+            GodotLogger::new().with_filter("warn, my_game=debug,my_game::net=trace, other");
+
+        assert_eq!(logger.level_for("some_crate"), LevelFilter::Warn);
+        assert_eq!(logger.level_for("my_game"), LevelFilter::Debug);
+        assert_eq!(logger.level_for("my_game::ai"), LevelFilter::Debug);
+        assert_eq!(logger.level_for("my_game::net::tcp"), LevelFilter::Trace);
+        assert_eq!(logger.level_for("my_gamepad"), LevelFilter::Warn);
+        assert_eq!(logger.level_for("other"), LevelFilter::Trace);
+        assert_eq!(logger.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn logger_filter_entries() {
+        assert_eq!(parse_entry("off"), Some((None, LevelFilter::Off)));
+        assert_eq!(
+            parse_entry("a::b = ERROR"),
+            Some((Some("a::b"), LevelFilter::Error))
+        );
+        assert_eq!(parse_entry("a=loud"), None);
+        assert_eq!(parse_entry("=info"), None);
+        assert_eq!(parse_entry("not a target"), None);
+    }
+
+    #[test]
+    fn logger_filter_override() {
+        let logger = GodotLogger::new()
+            .with_target("dep", LevelFilter::Error)
+            .with_target("dep", LevelFilter::Off);
+
+        assert_eq!(logger.level_for("dep::inner"), LevelFilter::Off);
+        assert_eq!(logger.max_level(), LevelFilter::Info);
+    }
+}
@@ -0,0 +1,177 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{self, Write as _};
+use std::time::Instant;
+
+use tracing::field::{Field, Visit};
+use tracing::span::Id;
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::builtin::GString;
+use crate::engine::profiling;
+use crate::log::{push_message, MessageLocation};
+use crate::sys;
+
+/// Layer for [`tracing`](https://docs.rs/tracing) subscribers, writing events to Godot's output and measuring spans in the profiler.
+///
+/// Events are written like records of [`GodotLogger`][super::GodotLogger]: `ERROR` with `push_error()`, `WARN` with `push_warning()`,
+/// everything else with `print()`. Fields other than the message are appended as `key=value`.
+///
+/// Time spent inside spans is reported like [`profiling::scope()`], i.e. summed per span name and shown as custom monitor
+/// `Rust/<span name>` in the debugger (Godot 4.2+). As with scopes, [`profiling::frame_begin()`] and [`profiling::frame_end()`] must
+/// be called every frame. A span's time only counts while it is entered, so awaiting in an instrumented future is not measured.
+///
+/// The layer does not filter; combine it with a filter from `tracing_subscriber`, for example:
+/// ```no_run
+/// use godot::log::GodotTracingLayer;
+/// use tracing_subscriber::filter::{LevelFilter, Targets};
+/// use tracing_subscriber::prelude::*;
+///
+/// let filter = Targets::new()
+///     .with_default(LevelFilter::INFO)
+///     .with_target("my_game::ai", LevelFilter::DEBUG);
+///
+/// tracing_subscriber::registry()
+///     .with(GodotTracingLayer::new().with_filter(filter))
+///     .init();
+/// ```
+#[derive(Clone, Debug)]
+pub struct GodotTracingLayer {
+    events: bool,
+    spans: bool,
+}
+
+impl GodotTracingLayer {
+    /// Creates a layer that writes events and measures spans.
+    pub fn new() -> Self {
+        Self {
+            events: true,
+            spans: true,
+        }
+    }
+
+    /// Whether events are written to Godot's output (default `true`).
+    pub fn with_events(self, events: bool) -> Self {
+        Self { events, ..self }
+    }
+
+    /// Whether spans are measured in the profiler (default `true`).
+    pub fn with_spans(self, spans: bool) -> Self {
+        Self { spans, ..self }
+    }
+}
+
+impl Default for GodotTracingLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for GodotTracingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if !self.events {
+            return;
+        }
+
+        let metadata = event.metadata();
+        let target = metadata.target();
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let text = visitor.finish();
+
+        // Before the engine is loaded (or after it's unloaded), there is nowhere to write to.
+        if !sys::is_initialized() {
+            eprintln!("[{} {target}] {text}", metadata.level());
+            return;
+        }
+
+        let location = MessageLocation {
+            function: metadata.module_path(),
+            file: metadata.file(),
+            line: metadata.line(),
+        };
+
+        match *metadata.level() {
+            Level::ERROR => push_message(&format!("{target}: {text}"), location, true),
+            Level::WARN => push_message(&format!("{target}: {text}"), location, false),
+            level => {
+                let line = GString::from_fmt(format_args!("[{level} {target}] {text}"));
+                crate::log::print(&[line.into()]);
+            }
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if !self.spans {
+            return;
+        }
+
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().replace(EnteredAt(Instant::now()));
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if !self.spans {
+            return;
+        }
+
+        if let Some(span) = ctx.span(id) {
+            if let Some(EnteredAt(start)) = span.extensions_mut().remove::<EnteredAt>() {
+                profiling::record_scope_time(span.name(), start.elapsed());
+            }
+        }
+    }
+}
+
+/// Stored in a span's extensions while it is entered.
+struct EnteredAt(Instant);
+
+/// Formats the `message` field first, followed by all other fields as `key=value`.
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: String,
+}
+
+impl FieldVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            if !self.fields.is_empty() {
+                self.fields.push(' ');
+            }
+            let _ = write!(self.fields, "{}={value:?}", field.name());
+        }
+    }
+}
@@ -13,12 +13,13 @@ use godot_ffi as sys;
 use sys::{static_assert_eq_size, VariantType};
 
 use crate::builtin::meta::{
-    ConvertError, FromFfiError, FromGodot, GodotConvert, GodotType, ToGodot,
+    ClassName, ConvertError, FromFfiError, FromGodot, GodotConvert, GodotType, ToGodot,
 };
 use crate::builtin::{Callable, NodePath, StringName, Variant};
 use crate::obj::raw::RawGd;
 use crate::obj::{
-    bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    bounds, cap, BindError, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits,
+    InstanceId,
 };
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use crate::{callbacks, engine, out};
@@ -80,6 +81,45 @@ use crate::{callbacks, engine, out};
 /// on the owning `Gd<T>` is performed. This is important to keep in mind, as you can get into situations that violate dynamic borrow rules; for
 /// example if you are inside a `&mut self` method, make a call to GDScript and indirectly call another method on the same object (re-entrancy).
 ///
+/// # Re-entrancy
+///
+/// Within a `&mut self` method, use [`base_mut()`][crate::obj::WithBaseField::base_mut] rather than `to_gd()` to call engine methods.
+/// This temporarily gives up the exclusive borrow, so that engine callbacks into the same object (e.g. a signal emitted by
+/// `base_mut().emit_signal(...)` whose handler is a `#[func]` on the same class) can bind the instance again.
+///
+/// If a callback can still run while the instance is bound, there are two ways to avoid the double-borrow panic:
+/// * **Queue the callback.** Connect signals with [`ConnectFlags::DEFERRED`][crate::engine::object::ConnectFlags::DEFERRED], or use
+///   [`Object::call_deferred()`][crate::engine::Object::call_deferred] instead of `call()`. The engine then invokes the handler at the end
+///   of the frame, when the current borrow has been released.
+/// * **Detect the conflict.** [`try_bind()`][Self::try_bind] and [`try_bind_mut()`][Self::try_bind_mut] return a [`BindError`] instead
+///   of panicking, allowing to skip or postpone the work:
+///   ```no_run
+///   # use godot::prelude::*;
+///   #[derive(GodotClass)]
+///   #[class(init, base=Node)]
+///   struct Tool {
+///       dirty: bool,
+///   }
+///
+///   #[godot_api]
+///   impl Tool {
+///       #[func]
+///       fn mark_dirty(&mut self) {
+///           self.dirty = true;
+///       }
+///   }
+///
+///   fn notify(tool: &mut Gd<Tool>) {
+///       if let Ok(mut guard) = tool.try_bind_mut() {
+///           guard.mark_dirty();
+///           return;
+///       }
+///
+///       // Already bound further up the call stack; run once the current borrow is released.
+///       tool.call_deferred("mark_dirty".into(), &[]);
+///   }
+///   ```
+///
 /// [book]: https://godot-rust.github.io/book/intro/objects.html
 /// [`Object`]: engine::Object
 /// [`RefCounted`]: engine::RefCounted
@@ -194,6 +234,20 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Fallible version of [`bind()`][Self::bind]: returns an error instead of panicking if the instance is exclusively bound.
+    ///
+    /// See [Re-entrancy](#re-entrancy) for when this is useful.
+    pub fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.raw.try_bind()
+    }
+
+    /// Fallible version of [`bind_mut()`][Self::bind_mut]: returns an error instead of panicking if the instance is already bound.
+    ///
+    /// See [Re-entrancy](#re-entrancy) for when this is useful.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.raw.try_bind_mut()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -278,6 +332,47 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Runs `f` on this object if it is still alive, otherwise returns an error.
+    ///
+    /// Accessing a destroyed object normally panics. For manually-managed objects that may be freed elsewhere (e.g. a node that
+    /// is removed by the scene tree or by GDScript), this method offers a recoverable alternative: liveness is checked right before
+    /// `f` is invoked, and an [`ObjectFreedError`] is returned if the object is gone.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn get_target() -> Gd<Node3D> { unimplemented!() }
+    /// let target: Gd<Node3D> = get_target();
+    /// match target.if_alive(|node| node.get_position()) {
+    ///     Ok(pos) => godot_print!("Target at {pos}"),
+    ///     Err(err) => godot_print!("Lost target: {err}"),
+    /// }
+    /// ```
+    pub fn if_alive<R>(&self, f: impl FnOnce(&Self) -> R) -> Result<R, ObjectFreedError> {
+        self.check_alive()?;
+        Ok(f(self))
+    }
+
+    /// Runs `f` on this object if it is still alive, otherwise returns an error (exclusive access).
+    ///
+    /// See [`if_alive()`][Self::if_alive] for details.
+    pub fn if_alive_mut<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> Result<R, ObjectFreedError> {
+        self.check_alive()?;
+        Ok(f(self))
+    }
+
+    fn check_alive(&self) -> Result<(), ObjectFreedError> {
+        if self.is_instance_valid() {
+            Ok(())
+        } else {
+            Err(ObjectFreedError {
+                instance_id: self.instance_id_unchecked(),
+            })
+        }
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -357,6 +452,24 @@ impl<T: GodotClass> Gd<T> {
         self.owned_cast()
     }
 
+    /// **Downcast:** like [`Self::try_cast()`], but returns a [`CastError`] describing the failure instead of the original object.
+    ///
+    /// Useful with `?` in functions returning [`godot::error::Error`][crate::error::Error] or other error types.
+    pub fn try_cast_err<Derived>(self) -> Result<Gd<Derived>, CastError>
+    where
+        Derived: GodotClass + Inherits<T>,
+        T: Inherits<engine::Object>,
+    {
+        self.owned_cast().map_err(|from_obj| CastError {
+            from_class: from_obj
+                .upcast_ref::<engine::Object>()
+                .get_class()
+                .to_string(),
+            to_class: Derived::class_name(),
+            instance_id: from_obj.instance_id(),
+        })
+    }
+
     /// ⚠️ **Downcast:** convert into a smart pointer to a derived class. Panics on error.
     ///
     /// # Panics
@@ -579,6 +692,22 @@ impl<T: GodotClass> ToGodot for Gd<T> {
         self.raw.check_rtti("Gd<T>::into_godot");
         self
     }
+
+    fn validate_engine_arg(&self) -> Result<(), String> {
+        if self.raw.is_null() {
+            return Err(format!("Gd<{}> is null", T::class_name()));
+        }
+
+        if !self.raw.is_instance_valid() {
+            return Err(format!(
+                "Gd<{}> with instance ID {} has been freed",
+                T::class_name(),
+                self.instance_id_unchecked()
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl<T: GodotClass> FromGodot for Gd<T> {
@@ -823,3 +952,67 @@ impl std::fmt::Display for NotUniqueError {
         )
     }
 }
+
+/// Error returned by [`Gd::try_cast_err()`] when the object is not an instance of the target class.
+#[derive(Debug)]
+pub struct CastError {
+    from_class: String,
+    to_class: ClassName,
+    instance_id: InstanceId,
+}
+
+impl CastError {
+    /// Dynamic class of the object that could not be cast.
+    pub fn from_class(&self) -> &str {
+        &self.from_class
+    }
+
+    /// Class that the object was cast to.
+    pub fn to_class(&self) -> ClassName {
+        self.to_class
+    }
+
+    /// Instance ID of the object that could not be cast.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+impl std::error::Error for CastError {}
+
+impl std::fmt::Display for CastError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot cast instance {} of class `{}` to `{}`",
+            self.instance_id, self.from_class, self.to_class
+        )
+    }
+}
+
+/// Error returned when accessing a [`Gd`] whose object has already been destroyed.
+///
+/// Returned by [`Gd::if_alive()`] and [`Gd::if_alive_mut()`].
+#[derive(Debug)]
+pub struct ObjectFreedError {
+    instance_id: InstanceId,
+}
+
+impl ObjectFreedError {
+    /// The last known instance ID of the freed object.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+impl std::error::Error for ObjectFreedError {}
+
+impl std::fmt::Display for ObjectFreedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "access to instance with ID {} after it has been freed",
+            self.instance_id
+        )
+    }
+}
@@ -81,6 +81,42 @@ impl<T: GodotClass> Drop for GdMut<'_, T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Error returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+///
+/// This occurs when the requested borrow would violate Rust's aliasing rules, typically because of re-entrancy: a method holding
+/// `&mut self` calls into the engine, which in turn calls back into the same object.
+#[derive(Debug)]
+pub struct BindError {
+    class: &'static str,
+    is_mut: bool,
+    details: String,
+}
+
+impl BindError {
+    pub(crate) fn new<T: GodotClass>(is_mut: bool, cause: Box<dyn std::error::Error>) -> Self {
+        Self {
+            class: std::any::type_name::<T>(),
+            is_mut,
+            details: cause.to_string(),
+        }
+    }
+}
+
+impl std::error::Error for BindError {}
+
+impl std::fmt::Display for BindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let method = if self.is_mut { "bind_mut" } else { "bind" };
+        write!(
+            f,
+            "{method}() failed, already bound; T = {}: {}",
+            self.class, self.details
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Shared reference guard for a [`Base`](crate::obj::Base) pointer.
 ///
 /// This can be used to call methods on the base object of a rust object that take `&self` as the receiver.
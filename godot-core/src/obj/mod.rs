@@ -17,6 +17,7 @@ mod guards;
 mod instance_id;
 mod onready;
 mod raw;
+mod smoothed;
 mod traits;
 
 pub(crate) mod rtti;
@@ -27,6 +28,7 @@ pub use guards::*;
 pub use instance_id::*;
 pub use onready::*;
 pub use raw::*;
+pub use smoothed::*;
 pub use traits::*;
 
 pub mod bounds;
@@ -17,7 +17,7 @@ use crate::builtin::meta::{
 use crate::builtin::Variant;
 use crate::obj::bounds::DynMemory as _;
 use crate::obj::rtti::ObjectRtti;
-use crate::obj::{bounds, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
+use crate::obj::{bounds, BindError, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
 use crate::storage::{InstanceStorage, Storage};
 use crate::{engine, out};
 
@@ -370,6 +370,26 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind()`](Self::bind), but returns an error instead of panicking if the instance is already bound.
+    pub(crate) fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        self.check_rtti("try_bind");
+        self.storage()
+            .unwrap()
+            .try_get()
+            .map(GdRef::from_guard)
+            .map_err(|err| BindError::new::<T>(false, err))
+    }
+
+    /// Like [`bind_mut()`](Self::bind_mut), but returns an error instead of panicking if the instance is already bound.
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        self.check_rtti("try_bind_mut");
+        self.storage()
+            .unwrap()
+            .try_get_mut()
+            .map(GdMut::from_guard)
+            .map_err(|err| BindError::new::<T>(true, err))
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -414,7 +434,7 @@ where
         }
 
         let callbacks = crate::storage::nop_instance_callbacks();
-        let token = sys::get_library() as *mut std::ffi::c_void;
+        let token = sys::get_instance_binding_token();
         let binding = interface_fn!(object_get_instance_binding)(self.obj_sys(), token, &callbacks);
 
         debug_assert!(
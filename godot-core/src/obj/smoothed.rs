@@ -0,0 +1,198 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::math::FloatExt;
+use crate::builtin::meta::GodotConvert;
+use crate::builtin::{real, Color, Quaternion, RealConv, Vector2, Vector3, Vector4};
+use crate::property::{Export, PropertyHintInfo, Var};
+
+/// Property wrapper that smoothly converges towards the last assigned value.
+///
+/// `Smoothed<T>` stores a _target_ and a _current_ value. Assignments from Godot -- through `#[var]`/`#[export]`, an RPC, an
+/// `AnimationPlayer` track or GDScript -- only change the target. The current value is moved towards the target each time
+/// [`advance()`](Self::advance) is called, which is typically done in `process()` or `physics_process()`.
+///
+/// Interpolation is exponential: every second, the remaining distance shrinks by a factor determined by the [`rate`](Self::rate).
+/// This is independent of the frame rate and never overshoots. Higher rates converge faster.
+///
+/// Reading the property from Godot returns the target, so that setting and getting a property round-trips. In Rust, use
+/// [`current()`](Self::current) to obtain the interpolated value.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::Smoothed;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct RemotePlayer {
+///     // Updated via RPC with the authoritative position.
+///     #[var]
+///     #[init(default = Smoothed::new(Vector2::ZERO, 15.0))]
+///     synced_position: Smoothed<Vector2>,
+///
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for RemotePlayer {
+///     fn process(&mut self, delta: f64) {
+///         let position = self.synced_position.advance(delta);
+///         self.base_mut().set_position(position);
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Smoothed<T: Interpolate> {
+    current: T,
+    target: T,
+    rate: real,
+}
+
+impl<T: Interpolate> Smoothed<T> {
+    /// Rate used by [`Default`]: the remaining distance shrinks to about 0.005% after one second.
+    pub const DEFAULT_RATE: real = 10.0;
+
+    /// Creates a new container with both current and target value set to `value`.
+    ///
+    /// `rate` must be non-negative. A rate of 0 never moves the current value.
+    pub fn new(value: T, rate: real) -> Self {
+        assert!(
+            rate >= 0.0,
+            "Smoothed rate must be non-negative, got {rate}"
+        );
+
+        Self {
+            current: value,
+            target: value,
+            rate,
+        }
+    }
+
+    /// The interpolated value, as of the last call to [`advance()`](Self::advance).
+    pub fn current(&self) -> T {
+        self.current
+    }
+
+    /// The value that is being converged to.
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    /// Sets a new target; the current value will converge towards it in subsequent [`advance()`](Self::advance) calls.
+    pub fn set_target(&mut self, target: T) {
+        self.target = target;
+    }
+
+    /// Immediately sets both current and target value, without interpolation.
+    ///
+    /// Useful for teleports or initial placement.
+    pub fn snap_to(&mut self, value: T) {
+        self.current = value;
+        self.target = value;
+    }
+
+    /// Convergence rate, see [`new()`](Self::new).
+    pub fn rate(&self) -> real {
+        self.rate
+    }
+
+    /// Changes the convergence rate, see [`new()`](Self::new).
+    pub fn set_rate(&mut self, rate: real) {
+        assert!(
+            rate >= 0.0,
+            "Smoothed rate must be non-negative, got {rate}"
+        );
+        self.rate = rate;
+    }
+
+    /// Moves the current value towards the target, given the elapsed time `delta` in seconds. Returns the new current value.
+    pub fn advance(&mut self, delta: f64) -> T {
+        let weight = 1.0 - (-self.rate.as_f64() * delta).exp();
+        self.current = self
+            .current
+            .interpolate(self.target, real::from_f64(weight));
+        self.current
+    }
+}
+
+impl<T: Interpolate + Default> Default for Smoothed<T> {
+    fn default() -> Self {
+        Self::new(T::default(), Self::DEFAULT_RATE)
+    }
+}
+
+impl<T: Interpolate + GodotConvert> GodotConvert for Smoothed<T> {
+    type Via = T::Via;
+}
+
+impl<T: Interpolate + Var> Var for Smoothed<T> {
+    fn get_property(&self) -> Self::Via {
+        self.target.get_property()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        self.target.set_property(value);
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        T::property_hint()
+    }
+}
+
+impl<T: Interpolate + Export> Export for Smoothed<T> {
+    fn default_export_info() -> PropertyHintInfo {
+        T::default_export_info()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Types that can be interpolated by [`Smoothed<T>`].
+pub trait Interpolate: Copy {
+    /// Interpolates between `self` (at `weight` 0) and `to` (at `weight` 1).
+    fn interpolate(self, to: Self, weight: real) -> Self;
+}
+
+macro_rules! impl_interpolate_lerp {
+    ($($Ty:ty),*) => {
+        $(
+            impl Interpolate for $Ty {
+                fn interpolate(self, to: Self, weight: real) -> Self {
+                    self.lerp(to, weight)
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_lerp!(Vector2, Vector3, Vector4);
+
+impl Interpolate for f32 {
+    fn interpolate(self, to: Self, weight: real) -> Self {
+        FloatExt::lerp(self, to, weight.as_f32())
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(self, to: Self, weight: real) -> Self {
+        FloatExt::lerp(self, to, weight.as_f64())
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, to: Self, weight: real) -> Self {
+        self.lerp(to, weight.as_f64())
+    }
+}
+
+/// Rotations use spherical interpolation along the shortest arc.
+impl Interpolate for Quaternion {
+    fn interpolate(self, to: Self, weight: real) -> Self {
+        self.slerp(to, weight)
+    }
+}
@@ -156,6 +156,10 @@ pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
 pub trait EngineClass: GodotClass {
     fn as_object_ptr(&self) -> sys::GDExtensionObjectPtr;
     fn as_type_ptr(&self) -> sys::GDExtensionTypePtr;
+
+    /// Loads all method binds of the class, with lazy function tables. See [`init::preload_methods()`][crate::init::preload_methods].
+    #[doc(hidden)]
+    fn __preload_methods() {}
 }
 
 /// Auto-implemented for all engine-provided enums.
@@ -494,6 +498,15 @@ pub mod cap {
         fn __godot_set_property(&mut self, property: StringName, value: Variant) -> bool;
     }
 
+    #[doc(hidden)]
+    pub trait GodotReloadState: GodotClass {
+        #[doc(hidden)]
+        fn __godot_save_reload_state(&self) -> Variant;
+
+        #[doc(hidden)]
+        fn __godot_restore_reload_state(&mut self, state: Variant);
+    }
+
     /// Auto-implemented for `#[godot_api] impl MyClass` blocks
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
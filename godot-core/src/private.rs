@@ -5,13 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 pub use crate::gen::classes::class_macros;
-pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginItem};
+pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginItem, ReloadStateFns};
 pub use crate::storage::{as_storage, Storage};
 pub use sys::out;
 
+use crate::init::{FuncPanicBehavior, PanicReporting};
 use crate::{log, sys};
 
 // If someone forgets #[godot_api], this causes a compile error, rather than virtual functions not being called at runtime.
@@ -49,7 +53,7 @@ pub fn print_panic(err: Box<dyn std::any::Any + Send>) {
     } else if let Some(s) = err.downcast_ref::<String>() {
         print_panic_message(s.as_str());
     } else {
-        log::godot_error!("Rust panic of type ID {:?}", err.type_id());
+        log::godot_error!("{}", panic_message(&*err));
     }
 }
 
@@ -57,6 +61,24 @@ pub fn auto_init<T>(l: &mut crate::obj::OnReady<T>) {
     l.init_auto();
 }
 
+/// Applies `#[class(process_priority, physics_process_priority)]` before `ready()`.
+#[allow(unused_variables)] // physics_process on Godot 4.0
+pub fn set_process_priorities<T>(this: &T, process: Option<i32>, physics_process: Option<i32>)
+where
+    T: crate::obj::WithBaseField + crate::obj::Inherits<crate::engine::Node>,
+{
+    let mut node = this.to_gd().upcast::<crate::engine::Node>();
+
+    if let Some(priority) = process {
+        node.set_process_priority(priority);
+    }
+
+    #[cfg(since_api = "4.1")]
+    if let Some(priority) = physics_process {
+        node.set_physics_process_priority(priority);
+    }
+}
+
 fn print_panic_message(msg: &str) {
     // If the message contains newlines, print all of the lines after a line break, and indent them.
     let lbegin = "\n  ";
@@ -69,12 +91,135 @@ fn print_panic_message(msg: &str) {
     }
 }
 
+fn panic_message(err: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = err.downcast_ref::<&'static str>() {
+        s.to_string()
+    } else if let Some(s) = err.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        format!("Rust panic of type ID {:?}", err.type_id())
+    }
+}
+
 struct GodotPanicInfo {
     line: u32,
     file: String,
-    //backtrace: Backtrace, // for future use
+    backtrace: Option<Backtrace>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Panic configuration, set by ExtensionLibrary
+
+static DETAILED_PANICS: AtomicBool = AtomicBool::new(false);
+static FUNC_PANICS_RETURN_NIL: AtomicBool = AtomicBool::new(false);
+
+static PANIC_HOOK_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+// `PanicInfo` is deprecated in favor of `PanicHookInfo`, which requires Rust 1.81.
+#[allow(deprecated)]
+type PanicHook = Box<dyn Fn(&std::panic::PanicInfo<'_>) + Sync + Send + 'static>;
+
+/// Hook that was active before [`install_panic_hook()`]; reinstalled by [`uninstall_panic_hook()`].
+///
+/// Kept outside the installed hook, so that it can be handed back to the process without code from this library wrapping it.
+static PREVIOUS_PANIC_HOOK: Mutex<Option<PanicHook>> = Mutex::new(None);
+
+thread_local! {
+    /// Number of `handle_panic()` calls on the current thread's stack; the detailed hook only captures panics inside them.
+    static HANDLE_PANIC_DEPTH: Cell<usize> = Cell::new(0);
+
+    /// Information about the last panic on this thread, captured by the detailed hook.
+    static LAST_PANIC: RefCell<Option<GodotPanicInfo>> = RefCell::new(None);
+}
+
+pub fn set_panic_config(reporting: PanicReporting, func_behavior: FuncPanicBehavior) {
+    let detailed = reporting == PanicReporting::Detailed;
+    let return_nil = func_behavior == FuncPanicBehavior::ReturnNil;
+
+    DETAILED_PANICS.store(detailed, Ordering::Relaxed);
+    FUNC_PANICS_RETURN_NIL.store(return_nil, Ordering::Relaxed);
+
+    if detailed {
+        install_panic_hook();
+    } else {
+        uninstall_panic_hook();
+    }
+}
+
+/// Whether a panic in a `#[func]` varcall should be signaled as a failed call (as opposed to returning `null`).
+pub fn func_panic_fails_call() -> bool {
+    !FUNC_PANICS_RETURN_NIL.load(Ordering::Relaxed)
+}
+
+fn install_panic_hook() {
+    if PANIC_HOOK_INSTALLED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+
+    *PREVIOUS_PANIC_HOOK.lock().unwrap() = Some(std::panic::take_hook());
+    std::panic::set_hook(Box::new(|panic_info| {
+        if HANDLE_PANIC_DEPTH.with(Cell::get) == 0 {
+            // Not inside gdext's panic handling (e.g. a user-spawned thread): behave like before.
+            if let Ok(previous_hook) = PREVIOUS_PANIC_HOOK.lock() {
+                if let Some(previous_hook) = previous_hook.as_ref() {
+                    previous_hook(panic_info);
+                }
+            }
+            return;
+        }
+
+        let info = panic_info.location().map(|location| GodotPanicInfo {
+            file: location.file().to_string(),
+            line: location.line(),
+            backtrace: Some(Backtrace::force_capture()),
+        });
+
+        LAST_PANIC.with(|last| *last.borrow_mut() = info);
+    }));
+}
+
+/// Reinstalls the hook that was active before [`install_panic_hook()`]. Must be called before unloading the library, as the hook's code
+/// lives in it.
+pub(crate) fn uninstall_panic_hook() {
+    if !PANIC_HOOK_INSTALLED.swap(false, Ordering::Relaxed) {
+        return;
+    }
+
+    match PREVIOUS_PANIC_HOOK.lock().unwrap().take() {
+        // Replaces (and drops) this library's hook.
+        Some(previous_hook) => std::panic::set_hook(previous_hook),
+        None => drop(std::panic::take_hook()),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Method profiling
+
+/// Measures a call from Godot into a `#[func]` or virtual method until dropped, see [`crate::tools::method_profiler`].
+pub struct MethodTimer {
+    name: &'static str,
+    start: Option<std::time::Instant>,
 }
 
+impl MethodTimer {
+    /// Starts measuring if the profiler is enabled; otherwise, this is only an atomic load.
+    pub fn start(name: &'static str) -> Self {
+        let start = crate::tools::method_profiler::is_enabled().then(std::time::Instant::now);
+        Self { name, start }
+    }
+}
+
+impl Drop for MethodTimer {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            crate::tools::method_profiler::record_call(self.name, start.elapsed());
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Panic handling
+
 /// Executes `code`. If a panic is thrown, it is caught and an error message is printed to Godot.
 ///
 /// Returns `None` if a panic occurred, and `Some(result)` with the result of `code` otherwise.
@@ -85,6 +230,10 @@ where
     F: FnOnce() -> R + std::panic::UnwindSafe,
     S: std::fmt::Display,
 {
+    if DETAILED_PANICS.load(Ordering::Relaxed) {
+        return handle_panic_detailed(error_context, code);
+    }
+
     let info: Arc<Mutex<Option<GodotPanicInfo>>> = Arc::new(Mutex::new(None));
 
     // Back up previous hook, set new one
@@ -96,7 +245,7 @@ where
                 *info.lock().unwrap() = Some(GodotPanicInfo {
                     file: location.file().to_string(),
                     line: location.line(),
-                    //backtrace: Backtrace::capture(),
+                    backtrace: None,
                 });
             } else {
                 println!("panic occurred but can't get location information...");
@@ -112,7 +261,6 @@ where
         Ok(result) => Some(result),
         Err(err) => {
             // Flush, to make sure previous Rust output (e.g. test announcement, or debug prints during app) have been printed
-            // TODO write custom panic handler and move this there, before panic backtrace printing
             flush_stdout();
 
             let guard = info.lock().unwrap();
@@ -123,13 +271,53 @@ where
                 info.line,
                 error_context()
             );
-            //eprintln!("Backtrace:\n{}", info.backtrace);
             print_panic(err);
             None
         }
     }
 }
 
+/// Like [`handle_panic()`], but relies on the global hook from [`install_panic_hook()`] and prints a single error including the backtrace.
+fn handle_panic_detailed<E, F, R, S>(error_context: E, code: F) -> Option<R>
+where
+    E: FnOnce() -> S,
+    F: FnOnce() -> R + std::panic::UnwindSafe,
+    S: std::fmt::Display,
+{
+    HANDLE_PANIC_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let panic = std::panic::catch_unwind(code);
+    HANDLE_PANIC_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+    let err = match panic {
+        Ok(result) => return Some(result),
+        Err(err) => err,
+    };
+
+    flush_stdout();
+
+    let info = LAST_PANIC.with(|last| last.borrow_mut().take());
+    let message = panic_message(&*err);
+
+    // Indent continuation lines, so that Godot's output groups them under the first line.
+    let mut report =
+        format!("Rust panic in {}: {}", error_context(), message).replace('\n', "\n  ");
+    if let Some(info) = info {
+        report.push_str(&format!("\n  at {}:{}", info.file, info.line));
+
+        if let Some(backtrace) = info.backtrace {
+            let backtrace = backtrace.to_string();
+            report.push_str("\n  Backtrace:");
+            for line in backtrace.lines() {
+                report.push_str("\n    ");
+                report.push_str(line);
+            }
+        }
+    }
+
+    log::godot_error!("{report}");
+    None
+}
+
 pub fn flush_stdout() {
     use std::io::Write;
     std::io::stdout().flush().expect("flush stdout");
@@ -13,6 +13,7 @@
 use crate::builder::ClassBuilder;
 use crate::builtin::{StringName, Variant};
 use crate::obj::{cap, Base, GodotClass, UserClass};
+use crate::registry::ReloadStateFns;
 use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
 use godot_ffi as sys;
 use std::any::Any;
@@ -26,10 +27,20 @@ pub unsafe extern "C" fn create<T: cap::GodotDefault>(
 
 #[cfg(since_api = "4.2")]
 pub unsafe extern "C" fn recreate<T: cap::GodotDefault>(
-    _class_userdata: *mut std::ffi::c_void,
+    class_userdata: *mut std::ffi::c_void,
     object: sys::GDExtensionObjectPtr,
 ) -> sys::GDExtensionClassInstancePtr {
-    create_rust_part_for_existing_godot_part(T::__godot_user_init, object)
+    let instance = create_rust_part_for_existing_godot_part(T::__godot_user_init, object);
+
+    // Non-null userdata means the class implements restore_reload_state().
+    if !class_userdata.is_null() {
+        if let Some(state) = crate::init::reload::take_state(object) {
+            let reload_state_fns = &*(class_userdata as *const ReloadStateFns);
+            (reload_state_fns.restore)(instance, state);
+        }
+    }
+
+    instance
 }
 
 pub(crate) fn create_custom<T, F>(make_user_instance: F) -> sys::GDExtensionObjectPtr
@@ -76,7 +87,7 @@ where
         interface_fn!(object_set_instance)(base_ptr, class_name.string_sys(), instance_ptr);
         interface_fn!(object_set_instance_binding)(
             base_ptr,
-            sys::get_library() as *mut std::ffi::c_void,
+            sys::get_instance_binding_token(),
             instance_ptr as *mut std::ffi::c_void,
             &binding_data_callbacks,
         );
@@ -87,17 +98,42 @@ where
 }
 
 pub unsafe extern "C" fn free<T: GodotClass>(
-    _class_user_data: *mut std::ffi::c_void,
+    class_user_data: *mut std::ffi::c_void,
     instance: sys::GDExtensionClassInstancePtr,
 ) {
     {
         let storage = as_storage::<T>(instance);
+
+        // The Godot object outlives its Rust instance during a hot reload, so it can hold the state until recreate().
+        if !class_user_data.is_null() && crate::init::reload::is_unloading_for_reload() {
+            let reload_state_fns = &*(class_user_data as *const ReloadStateFns);
+            let state = (reload_state_fns.save)(instance);
+            crate::init::reload::store_state(storage.base().as_gd().obj_sys(), state);
+        }
+
         storage.mark_destroyed_by_godot();
     } // Ref no longer valid once next statement is executed.
 
     crate::storage::destroy_storage::<T>(instance);
 }
 
+pub unsafe fn save_reload_state<T: cap::GodotReloadState>(
+    instance: sys::GDExtensionClassInstancePtr,
+) -> Variant {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+    T::__godot_save_reload_state(&*instance)
+}
+
+pub unsafe fn restore_reload_state<T: cap::GodotReloadState>(
+    instance: sys::GDExtensionClassInstancePtr,
+    state: Variant,
+) {
+    let storage = as_storage::<T>(instance);
+    let mut instance = storage.get_mut();
+    T::__godot_restore_reload_state(&mut *instance, state);
+}
+
 pub unsafe extern "C" fn get_virtual<T: cap::ImplementsGodotVirtual>(
     _class_user_data: *mut std::ffi::c_void,
     name: sys::GDExtensionConstStringNamePtr,
@@ -6,6 +6,7 @@
  */
 
 use crate::builtin::meta::ClassName;
+use crate::init::startup_report::{self, Category};
 use crate::init::InitLevel;
 use crate::obj::{cap, GodotClass};
 use crate::out;
@@ -21,7 +22,13 @@ pub mod callbacks;
 // calls register/unregister in the main thread. Mutex is just casual way to ensure safety in this non-performance-critical path.
 // Note that we panic on concurrent access instead of blocking (fail-fast approach). If that happens, most likely something changed on Godot
 // side and analysis required to adopt these changes.
-static LOADED_CLASSES: Global<HashMap<InitLevel, Vec<ClassName>>> = Global::default();
+static LOADED_CLASSES: Global<HashMap<InitLevel, Vec<LoadedClass>>> = Global::default();
+
+/// Class registered with Godot, along with the extension library that registered it (there can be multiple per binary).
+struct LoadedClass {
+    class_name: ClassName,
+    library: usize,
+}
 
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginItem, while others is directly
 // translated to code. Consider moving more code to the PluginItem, which allows for more dynamic registration and will
@@ -53,6 +60,15 @@ impl fmt::Debug for ErasedRegisterFn {
     }
 }
 
+/// Type-erased `save_reload_state()` and `restore_reload_state()` of a class, passed to Godot as class userdata.
+///
+/// Godot hands the userdata to the `free` and `recreate` callbacks, which thus know whether a class preserves state across hot reloads.
+#[derive(Debug)]
+pub struct ReloadStateFns {
+    pub save: unsafe fn(sys::GDExtensionClassInstancePtr) -> crate::builtin::Variant,
+    pub restore: unsafe fn(sys::GDExtensionClassInstancePtr, crate::builtin::Variant),
+}
+
 /// Represents the data part of a [`ClassPlugin`] instance.
 ///
 /// Each enumerator represents a different item in Rust code, which is processed by an independent proc macro (for example,
@@ -102,6 +118,9 @@ pub enum PluginItem {
 
         /// Whether the class has a default constructor.
         is_instantiable: bool,
+
+        /// Rust module declaring the class, as per `module_path!()`.
+        module_path: &'static str,
     },
 
     /// Collected from `#[godot_api] impl MyClass`.
@@ -173,6 +192,9 @@ pub enum PluginItem {
             ) -> sys::GDExtensionBool,
         >,
 
+        /// User-defined `save_reload_state` and `restore_reload_state` functions.
+        user_reload_state_fns: Option<&'static ReloadStateFns>,
+
         /// Callback for other virtuals.
         get_virtual_fn: unsafe extern "C" fn(
             p_userdata: *mut std::os::raw::c_void,
@@ -202,6 +224,7 @@ struct ClassRegistrationInfo {
     #[allow(dead_code)] // Currently unused; may be useful for diagnostics in the future.
     init_level: InitLevel,
     is_editor_plugin: bool,
+    module_path: &'static str,
 
     /// Used to ensure that each component is only filled once.
     component_already_filled: [bool; 3],
@@ -288,12 +311,15 @@ pub fn register_class<
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        module_path: "",
         component_already_filled: Default::default(), // [false; N]
     });
 }
 
 /// Lets Godot know about all classes that have self-registered through the plugin system.
-pub fn auto_register_classes(init_level: InitLevel) {
+///
+/// Only classes accepted by `registers_class` are registered, with the library currently set in [`sys::set_library()`].
+pub fn auto_register_classes(init_level: InitLevel, registers_class: fn(ClassName, &str) -> bool) {
     out!("Auto-register classes at level `{init_level:?}`...");
 
     // Note: many errors are already caught by the compiler, before this runtime validation even takes place:
@@ -302,24 +328,60 @@ pub fn auto_register_classes(init_level: InitLevel) {
     //
     let mut map = HashMap::<ClassName, ClassRegistrationInfo>::new();
 
-    crate::private::iterate_plugins(|elem: &ClassPlugin| {
-        // Filter per ClassPlugin and not PluginItem, because all components of all classes are mixed together in one huge list.
-        if elem.init_level != init_level {
-            return;
-        }
+    let collect_plugins = || {
+        crate::private::iterate_plugins(|elem: &ClassPlugin| {
+            // Filter per ClassPlugin and not PluginItem, because all components of all classes are mixed together in one huge list.
+            if elem.init_level != init_level {
+                return;
+            }
 
-        //out!("* Plugin: {elem:#?}");
+            //out!("* Plugin: {elem:#?}");
 
-        let name = elem.class_name;
-        let class_info = map
-            .entry(name)
-            .or_insert_with(|| default_registration_info(name));
+            let name = elem.class_name;
+            let class_info = map
+                .entry(name)
+                .or_insert_with(|| default_registration_info(name));
 
-        fill_class_info(elem.item.clone(), class_info);
-    });
+            fill_class_info(elem.item.clone(), class_info);
+        })
+    };
+
+    startup_report::measure(
+        Some(init_level),
+        Category::Callback,
+        || "collect class plugins".to_string(),
+        collect_plugins,
+    );
 
+    let library = unsafe { sys::get_library() } as usize;
     let mut loaded_classes_by_level = global_loaded_classes();
     for info in map.into_values() {
+        if !registers_class(info.class_name, info.module_path) {
+            continue;
+        }
+
+        let is_already_loaded = loaded_classes_by_level
+            .values()
+            .flatten()
+            .any(|loaded| loaded.class_name == info.class_name);
+        // Expected with the default registers_class(), which accepts every class in every library of the binary.
+        if is_already_loaded {
+            out!(
+                "Skip class:       {} (already registered by another library in this binary)",
+                info.class_name
+            );
+            continue;
+        }
+
+        if let Some(parent_class_name) = missing_parent_class(&info) {
+            crate::godot_warn!(
+                "class `{}` is not registered: its base class `{parent_class_name}` does not exist in Godot {}",
+                info.class_name,
+                sys::GdextBuild::godot_runtime_version_string(),
+            );
+            continue;
+        }
+
         out!(
             "Register class:   {} at level `{init_level:?}`",
             info.class_name
@@ -328,27 +390,41 @@ pub fn auto_register_classes(init_level: InitLevel) {
         loaded_classes_by_level
             .entry(init_level)
             .or_default()
-            .push(info.class_name);
-
-        register_class_raw(info);
+            .push(LoadedClass {
+                class_name,
+                library,
+            });
+
+        startup_report::measure(
+            Some(init_level),
+            Category::Class,
+            || class_name.to_string(),
+            || register_class_raw(info),
+        );
         out!("Class {} loaded", class_name);
     }
 
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
+/// Unregisters the classes of `init_level` that were registered by the library currently set in [`sys::set_library()`].
 pub fn unregister_classes(init_level: InitLevel) {
+    let library = unsafe { sys::get_library() } as usize;
     let mut loaded_classes_by_level = global_loaded_classes();
-    let loaded_classes_current_level = loaded_classes_by_level
-        .remove(&init_level)
-        .unwrap_or_default();
+    let loaded_classes_current_level = loaded_classes_by_level.entry(init_level).or_default();
+
+    let (unloaded, kept) = std::mem::take(loaded_classes_current_level)
+        .into_iter()
+        .partition::<Vec<_>, _>(|loaded| loaded.library == library);
+    *loaded_classes_current_level = kept;
+
     out!("Unregistering classes of level {init_level:?}...");
-    for class_name in loaded_classes_current_level.iter().rev() {
-        unregister_class_raw(*class_name);
+    for loaded in unloaded.iter().rev() {
+        unregister_class_raw(loaded.class_name);
     }
 }
 
-fn global_loaded_classes() -> GlobalGuard<'static, HashMap<InitLevel, Vec<ClassName>>> {
+fn global_loaded_classes() -> GlobalGuard<'static, HashMap<InitLevel, Vec<LoadedClass>>> {
     match LOADED_CLASSES.try_lock() {
         Ok(it) => it,
         Err(err) => match err {
@@ -378,8 +454,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             is_editor_plugin,
             is_hidden,
             is_instantiable,
+            module_path,
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.module_path = module_path;
 
             // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
             // "Abstract" is a misnomer -- it's not an abstract base class, but rather a "utility/static class" (although it can have instance
@@ -432,6 +510,7 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             user_on_notification_fn,
             user_set_fn,
             user_get_fn,
+            user_reload_state_fns,
             get_virtual_fn,
         } => {
             c.user_register_fn = user_register_fn;
@@ -454,6 +533,11 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.godot_params.set_func = user_set_fn;
             c.godot_params.get_func = user_get_fn;
             c.user_virtual_fn = Some(get_virtual_fn);
+
+            if let Some(reload_state_fns) = user_reload_state_fns {
+                c.godot_params.class_userdata =
+                    reload_state_fns as *const ReloadStateFns as *mut std::ffi::c_void;
+            }
         }
     }
     // out!("|   reg (after):     {c:?}");
@@ -470,6 +554,19 @@ fn fill_into<T>(dst: &mut Option<T>, src: Option<T>) -> Result<(), ()> {
     Ok(())
 }
 
+/// Returns the engine base class of a user class, if it does not exist at runtime.
+///
+/// The base can be missing if the running Godot version is older than the API gdext was compiled against, or if the engine was built
+/// without the module providing it. Registering the class would then fail.
+fn missing_parent_class(info: &ClassRegistrationInfo) -> Option<ClassName> {
+    let parent_class_name = info.parent_class_name?;
+
+    // SAFETY: class name is a valid StringName for the duration of the call.
+    let tag = unsafe { interface_fn!(classdb_get_class_tag)(parent_class_name.string_sys()) };
+
+    tag.is_null().then_some(parent_class_name)
+}
+
 /// Registers a class with given the dynamic type information `info`.
 fn register_class_raw(mut info: ClassRegistrationInfo) {
     // First register class...
@@ -575,6 +672,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        module_path: "",
         component_already_filled: Default::default(), // [false; N]
     }
 }
@@ -603,7 +701,7 @@ fn default_creation_info() -> sys::GDExtensionClassCreationInfo {
 }
 
 #[cfg(since_api = "4.2")]
-fn default_creation_info() -> sys::GDExtensionClassCreationInfo2 {
+pub(crate) fn default_creation_info() -> sys::GDExtensionClassCreationInfo2 {
     sys::GDExtensionClassCreationInfo2 {
         is_abstract: false as u8,
         is_virtual: false as u8,
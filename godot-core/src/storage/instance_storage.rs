@@ -5,6 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::error::Error;
+
 use crate::obj::{Base, Gd, GodotClass, Inherits};
 use crate::{godot_error, out};
 use godot_ffi as sys;
@@ -85,6 +87,12 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> godot_cell::MutGuard<'_, Self::Instance>;
 
+    /// Returns a shared reference to this storage's instance, or an error if it is currently bound exclusively.
+    fn try_get(&self) -> Result<godot_cell::RefGuard<'_, Self::Instance>, Box<dyn Error>>;
+
+    /// Returns a mutable/exclusive reference to this storage's instance, or an error if it is currently bound.
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, Self::Instance>, Box<dyn Error>>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
@@ -6,6 +6,7 @@
  */
 
 use std::any::type_name;
+use std::error::Error;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicU32, Ordering};
 
@@ -55,7 +56,7 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn get(&self) -> godot_cell::RefGuard<'_, T> {
-        self.user_instance.as_ref().borrow().unwrap_or_else(|err| {
+        self.try_get().unwrap_or_else(|err| {
             panic!(
                 "\
                     Gd<T>::bind() failed, already bound; T = {}.\n  \
@@ -68,19 +69,24 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn get_mut(&self) -> godot_cell::MutGuard<'_, T> {
-        self.user_instance
-            .as_ref()
-            .borrow_mut()
-            .unwrap_or_else(|err| {
-                panic!(
-                    "\
+        self.try_get_mut().unwrap_or_else(|err| {
+            panic!(
+                "\
                     Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
                     Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
                     Details: {err}.\
                 ",
-                    type_name::<T>()
-                )
-            })
+                type_name::<T>()
+            )
+        })
+    }
+
+    fn try_get(&self) -> Result<godot_cell::RefGuard<'_, T>, Box<dyn Error>> {
+        self.user_instance.as_ref().borrow()
+    }
+
+    fn try_get_mut(&self) -> Result<godot_cell::MutGuard<'_, T>, Box<dyn Error>> {
+        self.user_instance.as_ref().borrow_mut()
     }
 
     fn get_inaccessible<'a: 'b, 'b>(
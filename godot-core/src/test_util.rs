@@ -0,0 +1,217 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Assertions for tests of code using Godot's geometric types.
+//!
+//! Floating-point results rarely match exactly, so comparing vectors or transforms with `assert_eq!` is fragile. [`assert_eq_approx!`]
+//! and [`assert_ne_approx!`] compare approximately instead:
+//!
+//! ```
+//! use godot::builtin::{Transform2D, Vector2};
+//! use godot::test_util::assert_eq_approx;
+//!
+//! let rotated = Vector2::RIGHT.rotated(std::f32::consts::FRAC_PI_2 as _);
+//!
+//! // Default precision of ApproxEq, relative to the magnitude of the values.
+//! assert_eq_approx!(rotated, Vector2::DOWN);
+//!
+//! // Explicit epsilon, as absolute difference per component.
+//! assert_eq_approx!(rotated, Vector2::DOWN, epsilon = 1e-4);
+//!
+//! // Custom message.
+//! let t = Transform2D::IDENTITY.translated(Vector2::new(1.0, 2.0));
+//! assert_eq_approx!(t.origin, Vector2::new(1.0, 2.0), epsilon = 1e-6, "translation of {t:?}");
+//! ```
+//!
+//! With an epsilon, a failing assertion lists each differing component, e.g. `origin.y: 2 vs 2.5 (diff 0.5)`. This form works for all
+//! types implementing [`ApproxComponents`]: floats, vectors, quaternions, bases, transforms, colors, planes, rects, AABBs and projections.
+
+pub use crate::builtin::math::ApproxEq;
+pub use crate::{assert_eq_approx, assert_ne_approx};
+
+use crate::builtin::{
+    Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Transform2D, Transform3D, Vector2,
+    Vector3, Vector4,
+};
+
+/// Types that can be compared component-wise with an explicit epsilon, see [`assert_eq_approx!`].
+pub trait ApproxComponents {
+    /// Appends all scalar components to `out`, named by their path below `prefix`, e.g. `("origin.x", 1.0)`.
+    fn push_components(&self, prefix: &str, out: &mut Vec<(String, f64)>);
+}
+
+/// Describes all components in which `actual` and `expected` differ by more than `epsilon`.
+///
+/// Returns `None` if all components are within `epsilon`. Used by [`assert_eq_approx!`] and [`assert_ne_approx!`] with `epsilon = ...`.
+pub fn component_diff<T>(actual: &T, expected: &T, epsilon: f64) -> Option<String>
+where
+    T: ApproxComponents + ?Sized,
+{
+    let actual = components(actual);
+    let expected = components(expected);
+
+    let lines: Vec<String> = actual
+        .iter()
+        .zip(expected.iter())
+        .filter(|((_, a), (_, e))| !is_within(*a, *e, epsilon))
+        .map(|((name, a), (_, e))| format!("  {name}: {a} vs {e} (diff {})", (a - e).abs()))
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn components<T: ApproxComponents + ?Sized>(value: &T) -> Vec<(String, f64)> {
+    let mut out = Vec::new();
+    value.push_components("", &mut out);
+    out
+}
+
+fn is_within(actual: f64, expected: f64, epsilon: f64) -> bool {
+    // Equality check first, so that equal infinities match. NaN never matches.
+    actual == expected || (actual - expected).abs() <= epsilon
+}
+
+fn join_path(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}.{name}")
+    }
+}
+
+impl ApproxComponents for f32 {
+    fn push_components(&self, prefix: &str, out: &mut Vec<(String, f64)>) {
+        (*self as f64).push_components(prefix, out);
+    }
+}
+
+impl ApproxComponents for f64 {
+    fn push_components(&self, prefix: &str, out: &mut Vec<(String, f64)>) {
+        let name = if prefix.is_empty() { "value" } else { prefix };
+        out.push((name.to_string(), *self));
+    }
+}
+
+macro_rules! impl_approx_components {
+    ($T:ty { $($field:ident),+ }) => {
+        impl ApproxComponents for $T {
+            fn push_components(&self, prefix: &str, out: &mut Vec<(String, f64)>) {
+                $(
+                    self.$field.push_components(&join_path(prefix, stringify!($field)), out);
+                )+
+            }
+        }
+    };
+}
+
+impl_approx_components!(Vector2 { x, y });
+impl_approx_components!(Vector3 { x, y, z });
+impl_approx_components!(Vector4 { x, y, z, w });
+impl_approx_components!(Quaternion { x, y, z, w });
+impl_approx_components!(Color { r, g, b, a });
+impl_approx_components!(Transform2D { a, b, origin });
+impl_approx_components!(Transform3D { basis, origin });
+impl_approx_components!(Plane { normal, d });
+impl_approx_components!(Rect2 { position, size });
+impl_approx_components!(Aabb { position, size });
+
+impl ApproxComponents for Basis {
+    fn push_components(&self, prefix: &str, out: &mut Vec<(String, f64)>) {
+        for (i, row) in self.rows.iter().enumerate() {
+            row.push_components(&join_path(prefix, &format!("rows[{i}]")), out);
+        }
+    }
+}
+
+impl ApproxComponents for Projection {
+    fn push_components(&self, prefix: &str, out: &mut Vec<(String, f64)>) {
+        for (i, col) in self.cols.iter().enumerate() {
+            col.push_components(&join_path(prefix, &format!("cols[{i}]")), out);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn component_diff_lists_differences() {
+        let actual = Transform2D::from_cols(Vector2::RIGHT, Vector2::DOWN, Vector2::new(1.0, 2.5));
+        let expected =
+            Transform2D::from_cols(Vector2::RIGHT, Vector2::DOWN, Vector2::new(1.0, 2.0));
+
+        let diff = component_diff(&actual, &expected, 0.1).expect("origin.y differs");
+        assert_eq!(diff, "  origin.y: 2.5 vs 2 (diff 0.5)");
+
+        assert_eq!(component_diff(&actual, &expected, 0.5), None);
+    }
+
+    #[test]
+    fn component_diff_non_finite() {
+        assert!(component_diff(&f64::INFINITY, &f64::INFINITY, 0.0).is_none());
+        assert!(component_diff(&f64::NAN, &f64::NAN, 1.0).is_some());
+    }
+
+    #[test]
+    fn assert_approx_epsilon() {
+        let basis = Basis::from_rows(Vector3::RIGHT, Vector3::UP, Vector3::new(0.0, 0.0, 1.001));
+
+        assert_eq_approx!(basis, Basis::IDENTITY, epsilon = 0.01);
+        assert_ne_approx!(basis, Basis::IDENTITY, epsilon = 0.0001);
+        assert_eq_approx!(
+            Color::from_rgb(0.5, 0.5, 0.5),
+            Color::from_rgb(0.5, 0.505, 0.5),
+            epsilon = 0.01,
+            "gray"
+        );
+    }
+
+    #[test]
+    fn component_diff_names() {
+        let plane = Plane::new(Vector3::UP, 1.0);
+        let diff = component_diff(&plane, &Plane::new(Vector3::UP, 2.0), 0.1);
+        assert_eq!(diff.as_deref(), Some("  d: 1 vs 2 (diff 1)"));
+
+        let aabb = Aabb::new(Vector3::ZERO, Vector3::ONE);
+        let diff = component_diff(
+            &aabb,
+            &Aabb::new(Vector3::ZERO, Vector3::new(1.0, 3.0, 1.0)),
+            0.1,
+        );
+        assert_eq!(diff.as_deref(), Some("  size.y: 1 vs 3 (diff 2)"));
+
+        let rect = Rect2::new(Vector2::ZERO, Vector2::ONE);
+        let diff = component_diff(
+            &rect,
+            &Rect2::new(Vector2::new(0.5, 0.0), Vector2::ONE),
+            0.1,
+        );
+        assert_eq!(diff.as_deref(), Some("  position.x: 0 vs 0.5 (diff 0.5)"));
+
+        let mut projection = Projection::IDENTITY;
+        projection.cols[3].z = 2.0;
+        let diff = component_diff(&projection, &Projection::IDENTITY, 0.1);
+        assert_eq!(diff.as_deref(), Some("  cols[3].z: 2 vs 0 (diff 2)"));
+    }
+
+    #[test]
+    #[should_panic(expected = "rows[2].z: ")]
+    fn assert_approx_epsilon_fails() {
+        let basis = Basis::from_rows(Vector3::RIGHT, Vector3::UP, Vector3::new(0.0, 0.0, 1.5));
+        assert_eq_approx!(basis, Basis::IDENTITY, epsilon = 0.01);
+    }
+}
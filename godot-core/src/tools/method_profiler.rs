@@ -0,0 +1,181 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Built-in profiler measuring the time Godot spends in Rust methods.
+//!
+//! While enabled, every call from Godot into a `#[func]` or virtual method (`ready()`, `process()`, ...) of a Rust class is timed.
+//! Times are aggregated per method, named `Class::method`, and published once per frame by [`frame_end()`]:
+//! - to the **editor debugger**, as message [`FRAME_MESSAGE`] which an `EditorDebuggerPlugin` can capture. Its data holds one
+//!   array `[name, calls, total_usec, max_usec]` per method that was called during the frame.
+//! - to Rust, via [`last_frame()`].
+//!
+//! Measured times are inclusive: if a Rust method calls into the engine which calls another Rust method, the time of the inner call
+//! counts towards both.
+//!
+//! # Integration with the engine debugger
+//! The easiest way to drive the profiler is an `EngineProfiler` implemented in Rust, registered under [`PROFILER_NAME`]. Godot then
+//! turns it on and off along with the editor's profiler and ticks it every frame:
+//!
+//! ```no_run
+//! # use godot::prelude::*;
+//! use godot::engine::{EngineDebugger, EngineProfiler, IEngineProfiler};
+//! use godot::tools::method_profiler;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=EngineProfiler)]
+//! struct RustMethodProfiler {
+//!     base: Base<EngineProfiler>,
+//! }
+//!
+//! #[godot_api]
+//! impl IEngineProfiler for RustMethodProfiler {
+//!     fn toggle(&mut self, enable: bool, _options: VariantArray) {
+//!         method_profiler::set_enabled(enable);
+//!     }
+//!
+//!     fn tick(&mut self, _frame_time: f64, _process_time: f64, _physics_time: f64, _physics_frame_time: f64) {
+//!         method_profiler::frame_end();
+//!     }
+//! }
+//!
+//! // In ExtensionLibrary::on_level_init(), for InitLevel::Scene:
+//! let profiler = RustMethodProfiler::new_gd();
+//! EngineDebugger::singleton().register_profiler(method_profiler::PROFILER_NAME.into(), profiler.upcast());
+//! ```
+//!
+//! Without the engine debugger (e.g. in exported games), call [`set_enabled()`] and [`frame_end()`] manually, for example from the
+//! `process()` method of an autoload.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{GString, VariantArray};
+use crate::engine::EngineDebugger;
+
+/// Name under which an `EngineProfiler` driving this profiler should be registered.
+pub const PROFILER_NAME: &str = "rust_methods";
+
+/// Message sent to the editor debugger by [`frame_end()`].
+pub const FRAME_MESSAGE: &str = "rust_methods:frame";
+
+/// Aggregated time spent in one method during a frame.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MethodTime {
+    /// Method name in the form `Class::method`.
+    pub name: &'static str,
+
+    /// How often the method was called.
+    pub calls: u32,
+
+    /// Sum of all call durations.
+    pub total: Duration,
+
+    /// Longest single call.
+    pub max: Duration,
+}
+
+/// Turns recording on or off.
+///
+/// Enabling discards measurements of an unfinished frame; disabling also clears [`last_frame()`].
+pub fn set_enabled(enabled: bool) {
+    let mut stats = lock_stats();
+    stats.current.clear();
+    if !enabled {
+        stats.last_frame.clear();
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether method calls are currently recorded.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Ends the current frame, publishing its measurements.
+///
+/// If the engine debugger is active, they are sent as [`FRAME_MESSAGE`]. Does nothing while the profiler is disabled.
+///
+/// Must be called on the main thread.
+pub fn frame_end() {
+    if !is_enabled() {
+        return;
+    }
+
+    let frame = {
+        let mut stats = lock_stats();
+        let mut frame: Vec<MethodTime> = stats.current.drain().map(|(_, time)| time).collect();
+        frame.sort_by(|a, b| b.total.cmp(&a.total).then(a.name.cmp(b.name)));
+
+        stats.last_frame = frame.clone();
+        frame
+    };
+
+    let mut debugger = EngineDebugger::singleton();
+    if debugger.is_active() {
+        let data: VariantArray = frame
+            .iter()
+            .map(|time| to_message_entry(time).to_variant())
+            .collect();
+        debugger.send_message(FRAME_MESSAGE.into(), data);
+    }
+}
+
+/// Methods called during the last completed frame, the most expensive first.
+pub fn last_frame() -> Vec<MethodTime> {
+    lock_stats().last_frame.clone()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Adds a call of `name` to the current frame. Invoked by `private::MethodTimer`.
+pub(crate) fn record_call(name: &'static str, elapsed: Duration) {
+    let mut stats = lock_stats();
+    let time = stats.current.entry(name).or_insert_with(|| MethodTime {
+        name,
+        calls: 0,
+        total: Duration::ZERO,
+        max: Duration::ZERO,
+    });
+
+    time.calls += 1;
+    time.total += elapsed;
+    time.max = time.max.max(elapsed);
+}
+
+#[derive(Default)]
+struct Stats {
+    current: HashMap<&'static str, MethodTime>,
+    last_frame: Vec<MethodTime>,
+}
+
+fn lock_stats() -> MutexGuard<'static, Stats> {
+    static STATS: OnceLock<Mutex<Stats>> = OnceLock::new();
+
+    // A panic while holding the lock cannot leave the stats inconsistent, so ignore poisoning.
+    STATS
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn to_message_entry(time: &MethodTime) -> VariantArray {
+    let micros = |duration: Duration| i64::try_from(duration.as_micros()).unwrap_or(i64::MAX);
+
+    let mut entry = VariantArray::new();
+    entry.push(GString::from(time.name).to_variant());
+    entry.push(time.calls.to_variant());
+    entry.push(micros(time.total).to_variant());
+    entry.push(micros(time.max).to_variant());
+    entry
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Tooling and diagnostics: surfacing information about Rust systems in the editor.
+
+pub mod method_profiler;
+#[cfg(since_api = "4.2")]
+mod monitors;
+
+#[cfg(since_api = "4.2")]
+pub use monitors::{monitor, remove_monitor, MonitorSource};
+
+/// Called by gdext after the `Scene` level has been initialized.
+pub(crate) fn on_scene_init() {
+    #[cfg(since_api = "4.2")]
+    monitors::register_pending();
+}
+
+/// Called by gdext before the `Scene` level is deinitialized.
+pub(crate) fn on_scene_deinit() {
+    #[cfg(since_api = "4.2")]
+    monitors::unregister_all();
+}
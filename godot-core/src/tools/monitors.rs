@@ -0,0 +1,178 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicI32, AtomicI64, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Callable, StringName, Variant};
+use crate::engine::Performance;
+
+/// Registers a custom monitor, shown in the debugger's _Monitors_ tab.
+///
+/// `id` has the form `"category/name"`, e.g. `"game/enemies_alive"`; without a `/`, the monitor appears in the `Custom` category.
+/// `source` is sampled by the editor while the monitor is visible, and is either:
+/// * a closure returning an integer or float, e.g. `|| world.lock().unwrap().enemies.len() as i64`, or
+/// * a reference to a static atomic integer, such as `&ENEMY_COUNT` for a `static ENEMY_COUNT: AtomicI64`.
+///
+/// ```no_run
+/// use std::sync::atomic::{AtomicI64, Ordering};
+/// use godot::tools;
+///
+/// static ENEMIES_ALIVE: AtomicI64 = AtomicI64::new(0);
+/// static BULLETS_FIRED: AtomicI64 = AtomicI64::new(0);
+///
+/// tools::monitor("game/enemies_alive", || ENEMIES_ALIVE.load(Ordering::Relaxed));
+/// tools::monitor("game/bullets_fired", &BULLETS_FIRED);
+/// ```
+///
+/// Registering an `id` again replaces the previous monitor.
+///
+/// Monitors can be registered at any time, also before the engine is fully loaded (e.g. in
+/// [`ExtensionLibrary::on_level_init()`](crate::init::ExtensionLibrary::on_level_init) for `InitLevel::Core`). They are added to Godot once
+/// the `Scene` level is initialized, and removed automatically when it is deinitialized, before the library is unloaded.
+///
+/// Must be called on the main thread once the `Scene` level is initialized.
+///
+/// _Godot equivalent: [`Performance.add_custom_monitor()`](https://docs.godotengine.org/en/stable/classes/class_performance.html#class-performance-method-add-custom-monitor)_
+pub fn monitor(id: impl Into<String>, source: impl MonitorSource) {
+    let id = id.into();
+    let source: Arc<dyn MonitorSource> = Arc::new(source);
+
+    let mut registry = lock_registry();
+    if let Some(pos) = registry.position(&id) {
+        registry.monitors.remove(pos);
+        if registry.is_scene_loaded {
+            remove_from_godot(&id);
+        }
+    }
+
+    if registry.is_scene_loaded {
+        add_to_godot(&id, &source);
+    }
+    registry.monitors.push((id, source));
+}
+
+/// Removes a monitor previously registered with [`monitor()`].
+///
+/// Returns `false` if no monitor with this `id` was registered.
+pub fn remove_monitor(id: &str) -> bool {
+    let mut registry = lock_registry();
+    let Some(pos) = registry.position(id) else {
+        return false;
+    };
+
+    registry.monitors.remove(pos);
+    if registry.is_scene_loaded {
+        remove_from_godot(id);
+    }
+    true
+}
+
+/// Value source of a custom [`monitor()`].
+///
+/// Implemented for closures `Fn() -> T` where `T` is an integer or float, and for `&'static` atomic integers.
+pub trait MonitorSource: Send + Sync + 'static {
+    /// Current value, which must be an `int` or `float` variant.
+    fn sample(&self) -> Variant;
+}
+
+impl<F, T> MonitorSource for F
+where
+    F: Fn() -> T + Send + Sync + 'static,
+    T: ToGodot,
+{
+    fn sample(&self) -> Variant {
+        self().to_variant()
+    }
+}
+
+macro_rules! impl_monitor_source_for_atomic {
+    ($($Atomic:ty),* $(,)?) => {
+        $(
+            impl MonitorSource for &'static $Atomic {
+                fn sample(&self) -> Variant {
+                    // Godot monitors are i64 at most; saturate larger unsigned values.
+                    let value = self.load(Ordering::Relaxed);
+                    i64::try_from(value).unwrap_or(i64::MAX).to_variant()
+                }
+            }
+        )*
+    };
+}
+
+impl_monitor_source_for_atomic!(AtomicI32, AtomicI64, AtomicU32, AtomicU64, AtomicUsize);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct Registry {
+    is_scene_loaded: bool,
+    monitors: Vec<(String, Arc<dyn MonitorSource>)>,
+}
+
+impl Registry {
+    fn position(&self, id: &str) -> Option<usize> {
+        self.monitors
+            .iter()
+            .position(|(existing, _)| existing == id)
+    }
+}
+
+fn lock_registry() -> MutexGuard<'static, Registry> {
+    static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+        is_scene_loaded: false,
+        monitors: Vec::new(),
+    });
+
+    // Sources are only sampled outside the lock, so poisoning cannot leave the registry inconsistent.
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub(super) fn register_pending() {
+    let mut registry = lock_registry();
+    registry.is_scene_loaded = true;
+
+    for (id, source) in registry.monitors.iter() {
+        add_to_godot(id, source);
+    }
+}
+
+pub(super) fn unregister_all() {
+    let mut registry = lock_registry();
+    registry.is_scene_loaded = false;
+
+    // The callables point into this library, so they must not outlive it.
+    for (id, _) in std::mem::take(&mut registry.monitors) {
+        remove_from_godot(&id);
+    }
+}
+
+fn add_to_godot(id: &str, source: &Arc<dyn MonitorSource>) {
+    let mut performance = Performance::singleton();
+    let id = StringName::from(id);
+
+    // Could be registered by GDScript or another extension; Godot does not allow duplicates.
+    if performance.has_custom_monitor(id.clone()) {
+        performance.remove_custom_monitor(id.clone());
+    }
+
+    let source = Arc::clone(source);
+    let getter = Callable::from_fn(id.to_string(), move |_args| Ok(source.sample()));
+    performance.add_custom_monitor(id, getter);
+}
+
+fn remove_from_godot(id: &str) {
+    let mut performance = Performance::singleton();
+    let id = StringName::from(id);
+
+    if performance.has_custom_monitor(id.clone()) {
+        performance.remove_custom_monitor(id);
+    }
+}
@@ -21,10 +21,13 @@ fn main() {
     let h_path = gen_path.join("gdextension_interface.h");
     let rs_path = gen_path.join("gdextension_interface.rs");
 
-    godot_bindings::clear_dir(gen_path, &mut watch);
-    godot_bindings::write_gdextension_headers(&h_path, &rs_path, &mut watch);
+    // The directory is not cleared; unchanged files are kept as-is, so that incremental builds only see actually modified code.
+    std::fs::create_dir_all(gen_path).unwrap_or_else(|e| panic!("failed to create dir: {e}"));
 
-    godot_codegen::generate_sys_files(gen_path, &h_path, &mut watch);
+    godot_codegen::generate_cached("godot-ffi", gen_path, &mut watch, |watch| {
+        godot_bindings::write_gdextension_headers(&h_path, &rs_path, watch);
+        godot_codegen::generate_sys_files(gen_path, &h_path, watch);
+    });
 
     watch.write_stats_to(&gen_path.join("ffi-stats.txt"));
     println!("cargo:rerun-if-changed=build.rs");
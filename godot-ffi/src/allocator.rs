@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Global allocator forwarding to Godot's memory functions.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::ptr;
+use std::sync::OnceLock;
+
+use crate as sys;
+
+/// Godot's `mem_alloc`, `mem_realloc` and `mem_free`, once the interface is loaded.
+///
+/// Stored outside the binding, as allocations happen on any thread and the binding may only be accessed from the main thread.
+static MEMORY_FUNCTIONS: OnceLock<MemoryFunctions> = OnceLock::new();
+
+struct MemoryFunctions {
+    alloc: unsafe extern "C" fn(usize) -> *mut c_void,
+    realloc: unsafe extern "C" fn(*mut c_void, usize) -> *mut c_void,
+    free: unsafe extern "C" fn(*mut c_void),
+}
+
+/// Makes Godot's memory functions available to [`GodotAllocator`]. Called once the interface is loaded.
+pub(crate) fn set_memory_functions(interface: &sys::GDExtensionInterface) {
+    let (Some(alloc), Some(realloc), Some(free)) = (
+        interface.mem_alloc,
+        interface.mem_realloc,
+        interface.mem_free,
+    ) else {
+        return;
+    };
+
+    // Several extension libraries in one binary share the functions of the first.
+    let _ = MEMORY_FUNCTIONS.set(MemoryFunctions {
+        alloc,
+        realloc,
+        free,
+    });
+}
+
+/// Global allocator which forwards Rust heap allocations to Godot's memory functions.
+///
+/// Allocations then count towards Godot's static memory, as shown by the engine's memory monitors (`Performance.MEMORY_STATIC`,
+/// debug builds only), and are subject to its allocation tracking. Register it in the crate that builds the extension library:
+/// ```ignore
+/// use godot::init::GodotAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: GodotAllocator = GodotAllocator;
+/// ```
+///
+/// Memory allocated before Godot loads the library, e.g. during class self-registration, comes from the system allocator, as does
+/// memory when running outside Godot (unit tests). Each allocation remembers its origin, so it is always freed by the right allocator.
+/// Every allocation carries a small header, plus padding for alignments that Godot does not guarantee.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct GodotAllocator;
+
+/// Stored directly before each allocation's data.
+#[derive(Copy, Clone)]
+struct Header {
+    /// Start of the underlying allocation.
+    raw: *mut u8,
+    is_from_godot: bool,
+}
+
+const HEADER_SIZE: usize = size_of::<Header>();
+
+/// Size of the underlying allocation, with room for the header and for aligning the data.
+fn raw_size(layout: &Layout) -> Option<usize> {
+    layout
+        .size()
+        .checked_add(HEADER_SIZE)?
+        .checked_add(layout.align() - 1)
+}
+
+/// Places the header into a new underlying allocation, and returns the pointer to the data.
+///
+/// # Safety
+/// `raw` must point to an allocation of `raw_size(layout)` bytes.
+unsafe fn init_header(raw: *mut u8, layout: &Layout, is_from_godot: bool) -> *mut u8 {
+    let offset = data_offset(raw, layout);
+    let data = raw.add(offset);
+
+    let header = Header { raw, is_from_godot };
+    ptr::write_unaligned(data.sub(HEADER_SIZE) as *mut Header, header);
+    data
+}
+
+fn data_offset(raw: *mut u8, layout: &Layout) -> usize {
+    let align = layout.align();
+    let unaligned = raw as usize + HEADER_SIZE;
+    let aligned = (unaligned + align - 1) & !(align - 1);
+
+    aligned - raw as usize
+}
+
+/// # Safety
+/// `data` must have been returned by [`GodotAllocator`].
+unsafe fn read_header(data: *mut u8) -> Header {
+    ptr::read_unaligned(data.sub(HEADER_SIZE) as *const Header)
+}
+
+fn system_layout(raw_size: usize) -> Layout {
+    // Alignment is handled by the header offset, so the raw allocation needs none.
+    Layout::from_size_align(raw_size, 1).expect("valid layout")
+}
+
+unsafe impl GlobalAlloc for GodotAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let Some(raw_size) = raw_size(&layout) else {
+            return ptr::null_mut();
+        };
+
+        let (raw, is_from_godot) = match MEMORY_FUNCTIONS.get() {
+            Some(functions) => ((functions.alloc)(raw_size) as *mut u8, true),
+            None => (System.alloc(system_layout(raw_size)), false),
+        };
+
+        if raw.is_null() {
+            return ptr::null_mut();
+        }
+
+        init_header(raw, &layout, is_from_godot)
+    }
+
+    unsafe fn dealloc(&self, data: *mut u8, layout: Layout) {
+        let header = read_header(data);
+
+        if header.is_from_godot {
+            let functions = MEMORY_FUNCTIONS.get().expect("Godot memory functions");
+            (functions.free)(header.raw as *mut c_void);
+        } else {
+            let raw_size = raw_size(&layout).expect("size of existing allocation");
+            System.dealloc(header.raw, system_layout(raw_size));
+        }
+    }
+
+    unsafe fn realloc(&self, data: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let header = read_header(data);
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+
+        // System allocations are moved into Godot's memory on the way, once available.
+        if !header.is_from_godot {
+            let new_data = self.alloc(new_layout);
+            if !new_data.is_null() {
+                ptr::copy_nonoverlapping(data, new_data, layout.size().min(new_size));
+                self.dealloc(data, layout);
+            }
+            return new_data;
+        }
+
+        let Some(new_raw_size) = raw_size(&new_layout) else {
+            return ptr::null_mut();
+        };
+
+        let functions = MEMORY_FUNCTIONS.get().expect("Godot memory functions");
+        let old_offset = data.offset_from(header.raw) as usize;
+        let new_raw = (functions.realloc)(header.raw as *mut c_void, new_raw_size) as *mut u8;
+        if new_raw.is_null() {
+            return ptr::null_mut();
+        }
+
+        // The new allocation may be aligned differently, in which case the data must move within it.
+        let new_offset = data_offset(new_raw, &new_layout);
+        if new_offset != old_offset {
+            let len = layout.size().min(new_size);
+            ptr::copy(new_raw.add(old_offset), new_raw.add(new_offset), len);
+        }
+
+        init_header(new_raw, &new_layout, true)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Without Godot, allocations take the system fallback; this checks the header and alignment handling.
+    #[test]
+    fn test_allocator_alignment() {
+        for align in [1, 2, 8, 16, 64, 4096] {
+            let layout = Layout::from_size_align(24, align).unwrap();
+
+            unsafe {
+                let data = GodotAllocator.alloc(layout);
+                assert!(!data.is_null());
+                assert_eq!(data as usize % align, 0, "align {align}");
+
+                ptr::write_bytes(data, 0xAB, layout.size());
+                assert!(!read_header(data).is_from_godot);
+
+                GodotAllocator.dealloc(data, layout);
+            }
+        }
+    }
+
+    #[test]
+    fn test_allocator_realloc() {
+        let layout = Layout::from_size_align(8, 32).unwrap();
+
+        unsafe {
+            let data = GodotAllocator.alloc(layout);
+            for i in 0..8 {
+                *data.add(i) = i as u8;
+            }
+
+            let grown = GodotAllocator.realloc(data, layout, 1000);
+            assert_eq!(grown as usize % 32, 0);
+            for i in 0..8 {
+                assert_eq!(*grown.add(i), i as u8);
+            }
+
+            GodotAllocator.dealloc(grown, Layout::from_size_align(1000, 32).unwrap());
+        }
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     ClassServersMethodTable, GDExtensionClassLibraryPtr, GDExtensionInterface,
     GdextRuntimeMetadata, UnsafeOnceCell, UtilityFunctionTable,
 };
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 #[cfg(feature = "experimental-threads")]
 mod multi_threaded;
@@ -32,6 +33,7 @@ pub use single_threaded::GdextConfig;
 pub(crate) struct GodotBinding {
     interface: GDExtensionInterface,
     library: ClassLibraryPtr,
+    current_library: AtomicPtr<std::ffi::c_void>,
     global_method_table: BuiltinLifecycleTable,
     class_server_method_table: UnsafeOnceCell<ClassServersMethodTable>,
     class_scene_method_table: UnsafeOnceCell<ClassSceneMethodTable>,
@@ -54,6 +56,7 @@ impl GodotBinding {
         Self {
             interface,
             library: ClassLibraryPtr(library),
+            current_library: AtomicPtr::new(library),
             global_method_table,
             class_server_method_table: UnsafeOnceCell::new(),
             class_scene_method_table: UnsafeOnceCell::new(),
@@ -198,6 +201,66 @@ pub unsafe fn get_interface() -> &'static GDExtensionInterface {
 /// If "experimental-threads" is not enabled, then this must be called from the same thread that the bindings were initialized from.
 #[inline(always)]
 pub unsafe fn get_library() -> crate::GDExtensionClassLibraryPtr {
+    get_binding().current_library.load(Ordering::Relaxed)
+}
+
+/// Selects the library returned by [`get_library`], when one binary contains multiple extension libraries.
+///
+/// Godot associates registered classes, methods and properties with the library passed to it, so this must be set to the library whose
+/// init level is being (de)initialized.
+///
+/// # Safety
+///
+/// The Godot binding must have been initialized before calling this function.
+///
+/// If "experimental-threads" is not enabled, then this must be called from the same thread that the bindings were initialized from.
+pub unsafe fn set_library(library: crate::GDExtensionClassLibraryPtr) {
+    get_binding()
+        .current_library
+        .store(library, Ordering::Relaxed);
+}
+
+/// Godot's `get_proc_address`, kept to load interface functions which are not part of [`GDExtensionInterface`].
+#[cfg(since_api = "4.1")]
+static GET_PROC_ADDRESS: std::sync::OnceLock<crate::GDExtensionInterfaceGetProcAddress> =
+    std::sync::OnceLock::new();
+
+#[cfg(since_api = "4.1")]
+pub(crate) fn set_get_proc_address(get_proc_address: crate::GDExtensionInterfaceGetProcAddress) {
+    // Several extension libraries in one binary receive the same function.
+    let _ = GET_PROC_ADDRESS.set(get_proc_address);
+}
+
+/// Looks up a GDExtension interface function by its name in `gdextension_interface.h`.
+///
+/// Returns `None` if the running Godot version does not provide the function. This also works for functions introduced after the Godot
+/// version gdext was compiled against, and for any thread.
+///
+/// # Panics
+/// If the Godot binding has not been initialized.
+#[cfg(since_api = "4.1")]
+pub fn get_proc_address(name: &std::ffi::CStr) -> crate::GDExtensionInterfaceFunctionPtr {
+    let get_proc_address = GET_PROC_ADDRESS
+        .get()
+        .copied()
+        .flatten()
+        .expect("Godot binding not initialized");
+
+    // SAFETY: `get_proc_address` is provided by Godot, and `name` is a valid C string.
+    unsafe { get_proc_address(name.as_ptr()) }
+}
+
+/// Token identifying gdext's instance bindings on Godot objects.
+///
+/// This is the library loaded first, and stays the same if one binary contains multiple extension libraries.
+///
+/// # Safety
+///
+/// The Godot binding must have been initialized before calling this function.
+///
+/// If "experimental-threads" is not enabled, then this must be called from the same thread that the bindings were initialized from.
+#[inline(always)]
+pub unsafe fn get_instance_binding_token() -> *mut std::ffi::c_void {
     get_binding().library.0
 }
 
@@ -79,9 +79,9 @@ impl BindingCompat for sys::GDExtensionInterfaceGetProcAddress {
             }
         }
 
-        // From here we can assume Godot 4.1+. We need to make sure that the runtime version is >= static version.
-        // Lexicographical tuple comparison does that.
-        let static_version = crate::GdextBuild::godot_static_version_triple();
+        // From here we can assume Godot 4.1+. We need to make sure that the runtime version is >= minimum version, which is the static
+        // version unless an older one was declared through GDEXT_MIN_GODOT_API. Lexicographical tuple comparison does that.
+        let min_version = crate::GdextBuild::godot_min_runtime_version();
         let runtime_version_raw = self.runtime_version();
 
         // SAFETY: Godot provides this version struct.
@@ -91,12 +91,14 @@ impl BindingCompat for sys::GDExtensionInterfaceGetProcAddress {
             runtime_version_raw.patch as u8,
         );
 
-        if runtime_version < static_version {
+        if runtime_version < min_version {
             let runtime_version_str = read_version_string(&runtime_version_raw);
+            let (min_major, min_minor, _) = min_version;
 
             panic!(
                 "gdext was compiled against newer Godot version: {static_version_str}\n\
                 but loaded by older Godot binary, with version: {runtime_version_str}\n\
+                (oldest supported version: {min_major}.{min_minor})\n\
                 \n\
                 Update your Godot engine version, or compile gdext against an older version.\n\
                 For more information, read https://godot-rust.github.io/book/toolchain/compatibility.html.\n\
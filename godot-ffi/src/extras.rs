@@ -76,6 +76,16 @@ pub fn panic_call_error(
     function_name: &str,
     vararg_types: &[VariantType],
 ) -> ! {
+    let reason = call_error_reason(err, vararg_types);
+
+    // Note: Godot also outputs thread ID
+    // In Godot source: variant.cpp:3043 or core_bind.cpp:2742
+    panic!("Function call failed:  {function_name} -- {reason}.");
+}
+
+/// Describes why a call failed, in the same words as [`panic_call_error()`], but without panicking.
+#[doc(hidden)]
+pub fn call_error_reason(err: &GDExtensionCallError, vararg_types: &[VariantType]) -> String {
     // This specializes on reflection-style calls, e.g. call(), rpc() etc.
     // In these cases, varargs are the _actual_ arguments, with required args being metadata such as method name.
 
@@ -88,7 +98,7 @@ pub fn panic_call_error(
     } = *err;
 
     let argc = vararg_types.len();
-    let reason = match error {
+    match error {
         GDEXTENSION_CALL_ERROR_INVALID_METHOD => "method not found".to_string(),
         GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT => {
             let from = vararg_types[argument as usize];
@@ -106,11 +116,7 @@ pub fn panic_call_error(
         GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL => "instance is null".to_string(),
         GDEXTENSION_CALL_ERROR_METHOD_NOT_CONST => "method is not const".to_string(), // not handled in Godot
         _ => format!("unknown reason (error code {error})"),
-    };
-
-    // Note: Godot also outputs thread ID
-    // In Godot source: variant.cpp:3043 or core_bind.cpp:2742
-    panic!("Function call failed:  {function_name} -- {reason}.");
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -134,3 +140,28 @@ pub mod lazy_keys {
         pub hash: i64,
     }
 }
+
+/// Method bind that is loaded on first call, stored in a `static` inside the generated method that uses it.
+///
+/// The method's key (class, name and hash) is only referenced by that method, so methods that are never called are removed together
+/// with their metadata by the linker. After the first call, the bind is read without going through the method table.
+#[cfg(feature = "codegen-lazy-fptrs")]
+pub struct LazyMethodBind<T> {
+    bind: std::sync::OnceLock<T>,
+}
+
+#[cfg(feature = "codegen-lazy-fptrs")]
+impl<T: Copy> LazyMethodBind<T> {
+    #[allow(clippy::new_without_default)] // only used in statics
+    pub const fn new() -> Self {
+        Self {
+            bind: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the bind, calling `load` (usually the method table's `fptr_by_key()`) if this is the first call.
+    #[inline(always)]
+    pub fn get_or_load(&self, load: impl FnOnce() -> T) -> T {
+        *self.bind.get_or_init(load)
+    }
+}
@@ -31,6 +31,7 @@ pub(crate) mod gen {
     pub mod interface;
 }
 
+mod allocator;
 mod compat;
 mod extras;
 mod global;
@@ -65,6 +66,7 @@ pub use gen::table_servers_classes::*;
 pub use gen::table_utilities::*;
 
 // Other
+pub use allocator::GodotAllocator;
 pub use extras::*;
 pub use gen::central::*;
 pub use gen::gdextension_interface::*;
@@ -146,9 +148,22 @@ pub unsafe fn initialize(
     let version = compat.runtime_version();
     out!("Godot version of GDExtension API at runtime: {version:?}");
 
+    // Allowed by the check above if GDEXT_MIN_GODOT_API is older; engine functions missing at runtime are then not loaded.
+    let runtime_triple = (
+        version.major as u8,
+        version.minor as u8,
+        version.patch as u8,
+    );
+    set_runtime_older_than_api(runtime_triple < GdextBuild::godot_static_version_triple());
+
     let interface = compat.load_interface();
     out!("Loaded interface.");
 
+    allocator::set_memory_functions(&interface);
+
+    #[cfg(since_api = "4.1")]
+    binding::set_get_proc_address(compat);
+
     let global_method_table = BuiltinLifecycleTable::load(&interface);
     out!("Loaded global method table.");
 
@@ -188,6 +203,7 @@ pub unsafe fn initialize(
     }
 
     out!("Assigned binding.");
+    report_unavailable_functions("builtin methods and utility functions");
 
     // Lazy case: load afterwards because table's internal StringCache stores &'static references to the interface.
     #[cfg(feature = "codegen-lazy-fptrs")]
@@ -269,6 +285,8 @@ pub unsafe fn load_class_method_table(api_level: ClassApiLevel) {
         }
     }
 
+    report_unavailable_functions("engine class methods");
+
     let _elapsed = std::time::Instant::now() - begin;
     out!(
         "{:?} level: loaded {} classes and {} methods in {}s.",
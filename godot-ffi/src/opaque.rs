@@ -26,3 +26,8 @@ pub struct Opaque<const N: usize> {
     storage: [u8; N],
     marker: std::marker::PhantomData<*const u8>, // disable Send/Sync
 }
+
+impl<const N: usize> Opaque<N> {
+    /// Size of the Godot type in bytes, as reported by the GDExtension API. May be smaller than `size_of::<Self>()`.
+    pub const SIZE: usize = N;
+}
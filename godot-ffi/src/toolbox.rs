@@ -8,6 +8,8 @@
 //! Functions and macros that are not very specific to gdext, but come in handy.
 
 use crate as sys;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Macros
@@ -176,6 +178,16 @@ pub(crate) type GetClassMethod = unsafe extern "C" fn(
 #[derive(Clone, Copy)]
 pub struct ClassMethodBind(pub sys::GDExtensionMethodBindPtr);
 
+impl ClassMethodBind {
+    /// Whether the method was loaded.
+    ///
+    /// This is only `false` for methods of classes that do not exist in the running engine build, and for methods that are newer than
+    /// the running Godot version (see [`is_runtime_older_than_api()`]).
+    pub fn is_loaded(&self) -> bool {
+        !self.0.is_null()
+    }
+}
+
 // SAFETY: `sys::GDExtensionMethodBindPtr` is effectively the same as a `unsafe extern "C" fn`. So sharing it between
 // threads is fine, as using it in any way requires `unsafe` and it is up to the caller to ensure it is thread safe
 // to do so.
@@ -234,6 +246,20 @@ pub(crate) fn load_class_method(
         unsafe { get_method_bind(class_sname_ptr, method_sname_ptr, hash) };
 
     if method.is_null() {
+        // Classes from modules disabled in this engine build (e.g. navigation or XR) do not exist at all. Their methods stay unloaded;
+        // calling one panics, see `ClassMethodBind::is_loaded()`. A missing method on an existing class is a version mismatch.
+        // SAFETY: class name pointer is valid for the duration of the call.
+        let class_tag = unsafe { sys::interface_fn!(classdb_get_class_tag)(class_sname_ptr as _) };
+        if class_tag.is_null() {
+            return ClassMethodBind(std::ptr::null_mut());
+        }
+
+        // Godot older than the API: the method was added in a later version, or its signature (hash) changed since.
+        if is_runtime_older_than_api() {
+            UNAVAILABLE_FUNCTION_COUNT.fetch_add(1, Ordering::Relaxed);
+            return ClassMethodBind(std::ptr::null_mut());
+        }
+
         panic!(
             "Failed to load class method {}::{} (hash {}).\n\
             Make sure gdext and Godot are compatible: https://godot-rust.github.io/book/gdext/advanced/compatibility.html",
@@ -265,6 +291,11 @@ pub(crate) fn load_builtin_method(
     // SAFETY: function pointers provided by Godot. We have no way to validate them.
     let method = unsafe { get_builtin_method(variant_type, method_sname, hash) };
 
+    if method.is_none() && is_runtime_older_than_api() {
+        UNAVAILABLE_FUNCTION_COUNT.fetch_add(1, Ordering::Relaxed);
+        return unavailable_builtin_method;
+    }
+
     method.unwrap_or_else(|| {
         panic!(
             "Failed to load builtin method {variant_type_str}::{method_name} (hash {hash}).{INFO}"
@@ -287,6 +318,11 @@ pub(crate) fn load_utility_function(
     // SAFETY: function pointers provided by Godot. We have no way to validate them.
     let utility_fn = unsafe { get_utility_fn(string_names.fetch(fn_name_str), hash) };
 
+    if utility_fn.is_none() && is_runtime_older_than_api() {
+        UNAVAILABLE_FUNCTION_COUNT.fetch_add(1, Ordering::Relaxed);
+        return unavailable_utility_function;
+    }
+
     utility_fn.unwrap_or_else(|| {
         panic!("Failed to load utility function {fn_name_str} (hash {hash}).{INFO}")
     })
@@ -308,6 +344,92 @@ pub(crate) fn read_version_string(version_ptr: &sys::GDExtensionGodotVersion) ->
 
 const INFO: &str = "\nMake sure gdext and Godot are compatible: https://godot-rust.github.io/book/gdext/advanced/compatibility.html";
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Older Godot versions at runtime
+
+/// Whether the running Godot version is older than the API gdext was compiled against. Set once during initialization.
+static IS_RUNTIME_OLDER_THAN_API: AtomicBool = AtomicBool::new(false);
+
+/// Number of engine functions that could not be loaded because the running Godot version is too old, since the last report.
+static UNAVAILABLE_FUNCTION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the running Godot version is older than the API gdext was compiled against.
+///
+/// This is only possible if an older minimum version was declared through `GDEXT_MIN_GODOT_API`. Engine functions missing at runtime are
+/// then not loaded, instead of failing initialization.
+pub fn is_runtime_older_than_api() -> bool {
+    IS_RUNTIME_OLDER_THAN_API.load(Ordering::Relaxed)
+}
+
+pub(crate) fn set_runtime_older_than_api(is_older: bool) {
+    IS_RUNTIME_OLDER_THAN_API.store(is_older, Ordering::Relaxed);
+}
+
+/// Prints a warning if engine functions could not be loaded since the last call, because the running Godot version is too old.
+pub(crate) fn report_unavailable_functions(what: &str) {
+    let count = UNAVAILABLE_FUNCTION_COUNT.swap(0, Ordering::Relaxed);
+    if count == 0 {
+        return;
+    }
+
+    let message = format!(
+        "{count} {what} are not available in Godot {runtime}, which is older than the API gdext was compiled against ({api}).\n\
+        Calling them fails; check `Engine::api_version()` before using newer APIs.\0",
+        runtime = crate::GdextBuild::godot_runtime_version_string(),
+        api = crate::GdextBuild::godot_static_version_string(),
+    );
+
+    // SAFETY: binding is initialized; all strings are null-terminated and outlive the call.
+    unsafe {
+        sys::interface_fn!(print_warning)(
+            c_str_from_str(&message),
+            c_str(b"\0"),
+            c_str(b"\0"),
+            0,
+            false as sys::GDExtensionBool,
+        );
+    }
+}
+
+thread_local! {
+    /// Set when a stand-in for an unavailable engine function has been called on this thread, see [`check_available_call()`].
+    static UNAVAILABLE_CALLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Panics if the engine function `name`, which was just called, is not available in the running Godot version.
+///
+/// Stand-ins for such functions cannot panic themselves, as unwinding out of an `extern "C"` function is not possible. They only record
+/// the call, leaving the default-initialized return value untouched; the caller then reports it with this function.
+pub fn check_available_call(what: &str, name: &str) {
+    if is_runtime_older_than_api() && UNAVAILABLE_CALLED.with(|called| called.replace(false)) {
+        panic!(
+            "{what} `{name}` is not available in the running Godot version {runtime}; it requires a newer version (gdext was compiled against {api}).\n\
+            Check `Engine::api_version()` before calling APIs newer than the oldest supported Godot version.",
+            runtime = crate::GdextBuild::godot_runtime_version_string(),
+            api = crate::GdextBuild::godot_static_version_string(),
+        );
+    }
+}
+
+/// Stands in for builtin methods missing from the running Godot version. See [`check_available_call()`].
+unsafe extern "C" fn unavailable_builtin_method(
+    _base: sys::GDExtensionTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+    _ret: sys::GDExtensionTypePtr,
+    _arg_count: std::os::raw::c_int,
+) {
+    UNAVAILABLE_CALLED.with(|called| called.set(true));
+}
+
+/// Stands in for utility functions missing from the running Godot version. See [`check_available_call()`].
+unsafe extern "C" fn unavailable_utility_function(
+    _ret: sys::GDExtensionTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+    _arg_count: std::os::raw::c_int,
+) {
+    UNAVAILABLE_CALLED.with(|called| called.set(true));
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Private abstractions
 // Dont use abstractions made here outside this crate, if needed then we should discuss making it more of a first-class
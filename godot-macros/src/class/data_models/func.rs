@@ -37,6 +37,7 @@ pub fn make_virtual_callback(
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
     let invocation = make_ptrcall_invocation(method_name, &sig_tuple, &wrapped_method, true);
+    let profiler_name = format!("{class_name}::{method_name}");
 
     quote! {
         {
@@ -47,6 +48,7 @@ pub fn make_virtual_callback(
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
             ) {
+                let _timer = ::godot::private::MethodTimer::start(#profiler_name);
                 #invocation;
             }
             Some(function)
@@ -73,8 +75,8 @@ pub fn make_method_registration(
         make_forwarding_closure(class_name, &signature_info, BeforeKind::Without);
 
     let method_name = &signature_info.method_name;
-    let varcall_func = make_varcall_func(method_name, &sig_tuple, &forwarding_closure);
-    let ptrcall_func = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
+    let varcall_func = make_varcall_func(class_name, method_name, &sig_tuple, &forwarding_closure);
+    let ptrcall_func = make_ptrcall_func(class_name, method_name, &sig_tuple, &forwarding_closure);
 
     // String literals
     let class_name_str = class_name.to_string();
@@ -357,12 +359,13 @@ fn make_method_flags(method_type: ReceiverType) -> TokenStream {
 
 /// Generate code for a C FFI function that performs a varcall.
 fn make_varcall_func(
+    class_name: &Ident,
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
 ) -> TokenStream {
     let invocation = make_varcall_invocation(method_name, sig_tuple, wrapped_method);
-    let method_name_str = method_name.to_string();
+    let error_context = format!("{class_name}::{method_name}");
 
     quote! {
         {
@@ -374,14 +377,17 @@ fn make_varcall_func(
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
+                let _timer = ::godot::private::MethodTimer::start(#error_context);
                 let success = ::godot::private::handle_panic(
-                    || #method_name_str,
+                    || #error_context,
                     || #invocation
                 );
 
                 if success.is_none() {
-                    // Signal error and set return type to Nil
-                    (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD; // no better fitting enum?
+                    // Signal error (unless configured otherwise) and set return type to Nil
+                    if ::godot::private::func_panic_fails_call() {
+                        (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD; // no better fitting enum?
+                    }
 
                     // TODO(uninit)
                     sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
@@ -395,12 +401,13 @@ fn make_varcall_func(
 
 /// Generate code for a C FFI function that performs a ptrcall.
 fn make_ptrcall_func(
+    class_name: &Ident,
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
 ) -> TokenStream {
     let invocation = make_ptrcall_invocation(method_name, sig_tuple, wrapped_method, false);
-    let method_name_str = method_name.to_string();
+    let error_context = format!("{class_name}::{method_name}");
 
     quote! {
         {
@@ -410,8 +417,9 @@ fn make_ptrcall_func(
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
             ) {
+                let _timer = ::godot::private::MethodTimer::start(#error_context);
                 let success = ::godot::private::handle_panic(
-                    || #method_name_str,
+                    || #error_context,
                     || #invocation
                 );
 
@@ -64,8 +64,20 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         TokenStream::new()
     };
 
-    let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+    let priority_init = make_priority_init(&struct_cfg);
+    if !priority_init.is_empty() && fields.base_field.is_none() {
+        return bail!(
+            class_name,
+            "#[class(process_priority, physics_process_priority)] require a `Base<T>` field"
+        );
+    }
+
+    let (user_class_impl, has_default_virtual) = make_user_class_impl(
+        class_name,
+        struct_cfg.is_tool,
+        &fields.all_fields,
+        priority_init,
+    );
 
     let mut init_expecter = TokenStream::new();
     let mut godot_init_impl = TokenStream::new();
@@ -139,6 +151,7 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
                 is_editor_plugin: #is_editor_plugin,
                 is_hidden: #is_hidden,
                 is_instantiable: #is_instantiable,
+                module_path: ::std::module_path!(),
             },
             init_level: {
                 let level = <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL;
@@ -188,6 +201,26 @@ struct ClassAttributes {
     is_editor_plugin: bool,
     is_hidden: bool,
     rename: Option<Ident>,
+    process_priority: Option<TokenStream>,
+    physics_process_priority: Option<TokenStream>,
+}
+
+/// Sets process priorities before `ready()`, if any of them were specified.
+fn make_priority_init(struct_cfg: &ClassAttributes) -> TokenStream {
+    if struct_cfg.process_priority.is_none() && struct_cfg.physics_process_priority.is_none() {
+        return TokenStream::new();
+    }
+
+    let to_option = |priority: &Option<TokenStream>| match priority {
+        Some(expr) => quote! { Some(#expr) },
+        None => quote! { None },
+    };
+    let process = to_option(&struct_cfg.process_priority);
+    let physics_process = to_option(&struct_cfg.physics_process_priority);
+
+    quote! {
+        ::godot::private::set_process_priorities(self, #process, #physics_process);
+    }
 }
 
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
@@ -222,6 +255,7 @@ fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
     all_fields: &[Field],
+    priority_init: TokenStream,
 ) -> (TokenStream, bool) {
     let onready_field_inits = all_fields
         .iter()
@@ -233,7 +267,11 @@ fn make_user_class_impl(
             }
         });
 
-    let default_virtual_fn = if all_fields.iter().any(|field| field.is_onready) {
+    // ready() needs to be hooked if there is anything to do in __before_ready().
+    let needs_before_ready =
+        all_fields.iter().any(|field| field.is_onready) || !priority_init.is_empty();
+
+    let default_virtual_fn = if needs_before_ready {
         let tool_check = util::make_virtual_tool_check();
         let signature_info = SignatureInfo::fn_ready();
 
@@ -265,6 +303,7 @@ fn make_user_class_impl(
 
             fn __before_ready(&mut self) {
                 #( #onready_field_inits )*
+                #priority_init
             }
 
             #default_virtual_fn
@@ -282,6 +321,8 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
     let mut is_editor_plugin = false;
     let mut is_hidden = false;
     let mut rename: Option<Ident> = None;
+    let mut process_priority = None;
+    let mut physics_process_priority = None;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
@@ -336,6 +377,18 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             is_hidden = true;
         }
 
+        // #[class(process_priority = expr)]
+        process_priority = parser.handle_expr("process_priority")?;
+
+        // #[class(physics_process_priority = expr)]
+        physics_process_priority = parser.handle_expr("physics_process_priority")?;
+        if physics_process_priority.is_some() && cfg!(before_api = "4.1") {
+            return bail!(
+                &class.name,
+                "#[class(physics_process_priority)] is not supported in Godot 4.0"
+            );
+        }
+
         parser.finish()?;
     }
 
@@ -346,6 +399,8 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
         is_editor_plugin,
         is_hidden,
         rename,
+        process_priority,
+        physics_process_priority,
     })
 }
 
@@ -469,6 +469,8 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let mut on_notification_fn = None;
     let mut get_property_fn = None;
     let mut set_property_fn = None;
+    let mut save_reload_state = None;
+    let mut restore_reload_state = None;
 
     let mut virtual_methods = vec![];
     let mut virtual_method_cfg_attrs = vec![];
@@ -637,6 +639,10 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                 });
             }
 
+            // Only used together; the trait impl is generated after the loop.
+            "save_reload_state" => save_reload_state = Some((method.name.clone(), cfg_attrs)),
+            "restore_reload_state" => restore_reload_state = Some((method.name.clone(), cfg_attrs)),
+
             // Other virtual methods, like ready, process etc.
             _ => {
                 let method = util::reduce_to_signature(method);
@@ -687,6 +693,40 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
         virtual_methods.push((signature_info, BeforeKind::OnlyBefore));
     }
 
+    let (reload_state_impl, reload_state_fns) = match (save_reload_state, restore_reload_state) {
+        (Some((_, cfg_attrs)), Some(_)) => {
+            let reload_state_impl = quote! {
+                #(#cfg_attrs)*
+                impl ::godot::obj::cap::GodotReloadState for #class_name {
+                    fn __godot_save_reload_state(&self) -> ::godot::builtin::Variant {
+                        <Self as #trait_path>::save_reload_state(self)
+                    }
+
+                    fn __godot_restore_reload_state(&mut self, state: ::godot::builtin::Variant) {
+                        <Self as #trait_path>::restore_reload_state(self, state)
+                    }
+                }
+            };
+
+            let reload_state_fns = Some(quote! {
+                #(#cfg_attrs)*
+                () => Some(&#prv::ReloadStateFns {
+                    save: #prv::callbacks::save_reload_state::<#class_name>,
+                    restore: #prv::callbacks::restore_reload_state::<#class_name>,
+                }),
+            });
+
+            (reload_state_impl, reload_state_fns)
+        }
+        (None, None) => (TokenStream::new(), None),
+        (Some((name, _)), None) | (None, Some((name, _))) => {
+            return bail!(
+                name,
+                "save_reload_state() and restore_reload_state() must be implemented together"
+            );
+        }
+    };
+
     let tool_check = util::make_virtual_tool_check();
     let virtual_method_callbacks: Vec<TokenStream> = virtual_methods
         .into_iter()
@@ -707,6 +747,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let on_notification_fn = convert_to_match_expression_or_none(on_notification_fn);
     let get_property_fn = convert_to_match_expression_or_none(get_property_fn);
     let set_property_fn = convert_to_match_expression_or_none(set_property_fn);
+    let reload_state_fns = convert_to_match_expression_or_none(reload_state_fns);
 
     let result = quote! {
         #original_impl
@@ -716,6 +757,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
         #register_class_impl
         #get_property_impl
         #set_property_impl
+        #reload_state_impl
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
 
@@ -745,6 +787,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                 user_on_notification_fn: #on_notification_fn,
                 user_set_fn: #set_property_fn,
                 user_get_fn: #get_property_fn,
+                user_reload_state_fns: #reload_state_fns,
                 get_virtual_fn: #prv::callbacks::get_virtual::<#class_name>,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
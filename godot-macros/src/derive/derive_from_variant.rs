@@ -9,6 +9,10 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, NamedStructFields, StructFields, TupleField, TupleStructFields};
 
+use crate::derive::godot_attr::GodotAttr;
+use crate::derive::int_enum::IntRepr;
+use crate::derive::tagged_enum::TaggedRepr;
+use crate::derive::transparent::TransparentField;
 use crate::util::{decl_get_info, has_attr, DeclInfo};
 use crate::ParseResult;
 
@@ -17,6 +21,27 @@ fn has_attr_skip(attributes: &[venial::Attribute]) -> bool {
 }
 
 pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
+    if let Some(field) = TransparentField::parse(&decl)? {
+        return Ok(field.derive_from_godot(&decl));
+    }
+
+    if let Some(repr) = IntRepr::parse(&decl)? {
+        return Ok(repr.derive_from_godot(&decl));
+    }
+
+    // Conversion method for field values; `#[godot(lenient)]` accepts implicit conversions.
+    let try_to = if GodotAttr::is_lenient(&decl)? {
+        quote! { try_to_lenient }
+    } else {
+        quote! { try_to }
+    };
+
+    if let Declaration::Enum(enum_) = &decl {
+        if let Some(repr) = TaggedRepr::parse(enum_)? {
+            return Ok(repr.derive_from_godot(enum_, decl_get_info(&decl), &try_to));
+        }
+    }
+
     let DeclInfo {
         where_,
         generic_params,
@@ -37,10 +62,10 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
         Declaration::Struct(s) => match s.fields {
             StructFields::Unit => make_unit_struct(&mut body),
             StructFields::Tuple(fields) if fields.fields.len() == 1 => {
-                make_new_type_struct(&mut body, fields)
+                make_new_type_struct(&mut body, fields, &try_to)
             }
-            StructFields::Tuple(fields) => make_tuple_struct(fields, &mut body, &name),
-            StructFields::Named(fields) => make_named_struct(fields, &mut body, &name),
+            StructFields::Tuple(fields) => make_tuple_struct(fields, &mut body, &name, &try_to),
+            StructFields::Named(fields) => make_named_struct(fields, &mut body, &name, &try_to),
         },
         Declaration::Enum(enum_) => {
             if enum_.variants.is_empty() {
@@ -81,14 +106,19 @@ pub fn derive_from_godot(decl: Declaration) -> ParseResult<TokenStream> {
                                     &variant_name_string,
                                 )
                             } else {
-                                make_enum_new_type(field, &variant_name, &variant_name_string)
+                                make_enum_new_type(
+                                    field,
+                                    &variant_name,
+                                    &variant_name_string,
+                                    &try_to,
+                                )
                             }
                         }
                         StructFields::Tuple(fields) => {
-                            make_enum_tuple(fields, &variant_name, &variant_name_string)
+                            make_enum_tuple(fields, &variant_name, &variant_name_string, &try_to)
                         }
                         StructFields::Named(fields) => {
-                            make_enum_named(fields, &variant_name, &variant_name_string)
+                            make_enum_named(fields, &variant_name, &variant_name_string, &try_to)
                         }
                     };
                     matches = quote! {
@@ -126,6 +156,7 @@ fn make_named_struct(
     fields: venial::NamedStructFields,
     body: &mut TokenStream,
     name: &impl ToTokens,
+    try_to: &TokenStream,
 ) {
     let fields = fields.fields.iter().map(|(field, _)| {
         let ident = &field.name;
@@ -142,7 +173,7 @@ fn make_named_struct(
                         None => return Err(ConvertError::with_cause_value(#err, root)),
                     };
                 },
-                quote! { #ident: #ident.try_to()? },
+                quote! { #ident: #ident.#try_to()? },
             )
         }
     });
@@ -161,6 +192,7 @@ fn make_tuple_struct(
     fields: venial::TupleStructFields,
     body: &mut TokenStream,
     name: &impl ToTokens,
+    try_to: &TokenStream,
 ) {
     let ident_and_set = fields.fields.iter().enumerate().map(|(k, (f, _))| {
         let ident = format_ident!("__{}", k);
@@ -174,7 +206,7 @@ fn make_tuple_struct(
             } else {
                 quote! {
                     let #ident = match root.pop_front() {
-                        Some(value) => value.try_to::<#field_type>()?,
+                        Some(value) => value.#try_to::<#field_type>()?,
                         None => return Err(ConvertError::with_cause_value("missing expected value", root)),
                     };
                 }
@@ -194,13 +226,17 @@ fn make_tuple_struct(
     };
 }
 
-fn make_new_type_struct(body: &mut TokenStream, fields: venial::TupleStructFields) {
+fn make_new_type_struct(
+    body: &mut TokenStream,
+    fields: venial::TupleStructFields,
+    try_to: &TokenStream,
+) {
     *body = if has_attr_skip(&fields.fields.first().unwrap().0.attributes) {
         quote! { Ok(Self::default()) }
     } else {
         quote! {
             #body
-            let root = root.try_to()?;
+            let root = root.#try_to()?;
             Ok(Self(root))
         }
     }
@@ -217,12 +253,13 @@ fn make_enum_new_type(
     field: &TupleField,
     variant_name: &impl ToTokens,
     variant_name_string: &impl ToTokens,
+    try_to: &TokenStream,
 ) -> TokenStream {
     let field_type = &field.ty;
     quote! {
         if let Ok(child) = root.try_to::<::godot::builtin::Dictionary>() {
             if let Some(variant) = child.get(#variant_name_string) {
-                return Ok(Self::#variant_name(variant.try_to::<#field_type>()?));
+                return Ok(Self::#variant_name(variant.#try_to::<#field_type>()?));
             }
         }
     }
@@ -251,6 +288,7 @@ fn make_enum_tuple(
     fields: &TupleStructFields,
     variant_name: &impl ToTokens,
     variant_name_string: &impl ToTokens,
+    try_to: &TokenStream,
 ) -> TokenStream {
     let fields = fields.fields.iter().enumerate().map(|(k, (field, _))| {
         let ident = format_ident!("__{k}");
@@ -263,7 +301,7 @@ fn make_enum_tuple(
             quote! {
                 let #ident = variant.pop_front()
                     .ok_or(ConvertError::with_cause_value("missing expected value", &variant))?
-                    .try_to::<#field_type>()?;
+                    .#try_to::<#field_type>()?;
             }
         };
         (ident.to_token_stream(), set_ident)
@@ -285,6 +323,7 @@ fn make_enum_named(
     fields: &NamedStructFields,
     variant_name: &impl ToTokens,
     variant_name_string: &impl ToTokens,
+    try_to: &TokenStream,
 ) -> TokenStream {
     let fields = fields.fields.iter().map(|(field, _)| {
         let field_name = &field.name;
@@ -299,7 +338,7 @@ fn make_enum_named(
             quote! {
                 let #field_name = variant.get(#field_name_string)
                     .ok_or(ConvertError::with_cause_value(#err, &variant))?
-                    .try_to::<#field_type>()?;
+                    .#try_to::<#field_type>()?;
             }
         };
         (field_name.to_token_stream(), set_field)
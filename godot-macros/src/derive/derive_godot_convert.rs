@@ -9,10 +9,15 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use venial::Declaration;
 
+use crate::derive::transparent::TransparentField;
 use crate::util::{decl_get_info, via_type, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_godot_convert(decl: Declaration) -> ParseResult<TokenStream> {
+    if let Some(field) = TransparentField::parse(&decl)? {
+        return Ok(field.derive_godot_convert(&decl));
+    }
+
     let DeclInfo {
         where_,
         generic_params,
@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, decl_get_info, DeclInfo, KvParser};
+use crate::ParseResult;
+
+pub fn derive_property_view(decl: Declaration) -> ParseResult<TokenStream> {
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        ..
+    } = decl_get_info(&decl);
+
+    let fields = match &decl {
+        Declaration::Struct(struct_) => match &struct_.fields {
+            StructFields::Named(fields) => fields,
+            _ => {
+                return bail!(
+                    &struct_.name,
+                    "PropertyView can only be derived on structs with named fields"
+                )
+            }
+        },
+        Declaration::Enum(enum_) => {
+            return bail!(&enum_.name, "PropertyView can only be derived on structs")
+        }
+        _ => unreachable!(),
+    };
+
+    let mut field_idents = Vec::new();
+    let mut property_names = Vec::new();
+    for (field, _) in fields.fields.iter() {
+        let mut property_name = field.name.to_string();
+        if let Some(mut parser) = KvParser::parse(&field.attributes, "property")? {
+            if let Some(rename) = parser.handle_string("rename")? {
+                property_name = rename;
+            }
+            parser.finish()?;
+        }
+
+        field_idents.push(field.name.clone());
+        property_names.push(property_name);
+    }
+
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+
+    Ok(quote! {
+        impl #generic_params ::godot::engine::PropertyView for #name #gen #where_ {
+            fn property_names() -> &'static [::godot::builtin::StringName] {
+                static NAMES: ::std::sync::OnceLock<::std::vec::Vec<::godot::builtin::StringName>> =
+                    ::std::sync::OnceLock::new();
+
+                NAMES.get_or_init(|| {
+                    ::std::vec![ #( ::godot::builtin::StringName::from(#property_names), )* ]
+                })
+            }
+
+            fn from_property_values(
+                values: ::std::vec::Vec<::godot::builtin::Variant>
+            ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                let mut values = values.into_iter();
+
+                Ok(Self {
+                    #(
+                        #field_idents: ::godot::builtin::meta::FromGodot::try_from_variant(
+                            &values.next().unwrap_or_default()
+                        )?,
+                    )*
+                })
+            }
+
+            fn to_property_values(&self) -> ::std::vec::Vec<::godot::builtin::Variant> {
+                ::std::vec![ #( ::godot::builtin::meta::ToGodot::to_variant(&self.#field_idents), )* ]
+            }
+        }
+    })
+}
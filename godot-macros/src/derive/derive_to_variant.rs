@@ -9,10 +9,27 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, StructFields};
 
+use crate::derive::int_enum::IntRepr;
+use crate::derive::tagged_enum::TaggedRepr;
+use crate::derive::transparent::TransparentField;
 use crate::util::{decl_get_info, has_attr, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_to_godot(decl: Declaration) -> ParseResult<TokenStream> {
+    if let Some(field) = TransparentField::parse(&decl)? {
+        return Ok(field.derive_to_godot(&decl));
+    }
+
+    if let Some(repr) = IntRepr::parse(&decl)? {
+        return Ok(repr.derive_to_godot(&decl));
+    }
+
+    if let Declaration::Enum(enum_) = &decl {
+        if let Some(repr) = TaggedRepr::parse(enum_)? {
+            return Ok(repr.derive_to_godot(enum_, decl_get_info(&decl)));
+        }
+    }
+
     let mut body = quote! {
         let mut root = ::godot::builtin::Dictionary::new();
     };
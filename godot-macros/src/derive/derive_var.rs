@@ -9,10 +9,15 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use venial::{Declaration, StructFields};
 
+use crate::derive::transparent::TransparentField;
 use crate::util::{bail, decl_get_info, via_type, DeclInfo};
 use crate::ParseResult;
 
 pub fn derive_var(decl: Declaration) -> ParseResult<TokenStream2> {
+    if let Some(field) = TransparentField::parse(&decl)? {
+        return Ok(field.derive_var(&decl));
+    }
+
     let DeclInfo {
         name, name_string, ..
     } = decl_get_info(&decl);
@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::Span;
+use venial::Declaration;
+
+use crate::util::KvParser;
+use crate::ParseResult;
+
+/// Type-level `#[godot(...)]` attribute, shared by the conversion and property derives.
+pub(crate) struct GodotAttr {
+    pub span: Span,
+
+    /// `#[godot(transparent)]`: convert via the single field of a newtype.
+    pub transparent: bool,
+
+    /// `#[godot(lenient)]`: `FromGodot` accepts GDScript's implicit conversions for all fields.
+    pub lenient: bool,
+}
+
+impl GodotAttr {
+    /// Returns `None` if the declaration has no `#[godot]` attribute.
+    pub fn parse(decl: &Declaration) -> ParseResult<Option<Self>> {
+        let attributes = match decl {
+            Declaration::Struct(struct_) => &struct_.attributes,
+            Declaration::Enum(enum_) => &enum_.attributes,
+            _ => return Ok(None),
+        };
+
+        let Some(mut parser) = KvParser::parse(attributes, "godot")? else {
+            return Ok(None);
+        };
+
+        let attr = Self {
+            span: parser.span(),
+            transparent: parser.handle_alone("transparent")?,
+            lenient: parser.handle_alone("lenient")?,
+        };
+        parser.finish()?;
+
+        Ok(Some(attr))
+    }
+
+    /// Whether `#[godot(lenient)]` is present.
+    pub fn is_lenient(decl: &Declaration) -> ParseResult<bool> {
+        Ok(Self::parse(decl)?.map_or(false, |attr| attr.lenient))
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Integer representation for C-style enums with `#[repr(i*/u*)]`, whose `GodotConvert::Via` is the repr type.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use venial::{Declaration, StructFields};
+
+use crate::derive::godot_attr::GodotAttr;
+use crate::util::{bail, decl_get_info, enum_repr, DeclInfo, ViaType};
+use crate::ParseResult;
+
+/// C-style enum converted to and from its discriminant.
+pub(crate) struct IntRepr {
+    int_ty: Ident,
+    /// Variant names and their discriminant expressions.
+    variants: Vec<(Ident, TokenStream)>,
+    is_lenient: bool,
+}
+
+impl IntRepr {
+    /// Returns `None` if the declaration is not an enum with an integer `#[repr]`.
+    pub fn parse(decl: &Declaration) -> ParseResult<Option<Self>> {
+        let Declaration::Enum(enum_) = decl else {
+            return Ok(None);
+        };
+
+        let ViaType::EnumWithRepr { int_ty } = enum_repr(enum_)? else {
+            return Ok(None);
+        };
+
+        if enum_.variants.is_empty() {
+            return bail!(
+                &enum_.name,
+                "enums with #[repr] must have at least one variant to be converted"
+            );
+        }
+
+        let mut variants = Vec::new();
+        for (enum_v, _) in enum_.variants.iter() {
+            if !matches!(enum_v.contents, StructFields::Unit) {
+                return bail!(
+                    &enum_v.name,
+                    "enums with #[repr] can only be converted if all variants are unit variants"
+                );
+            }
+
+            let Some(value) = &enum_v.value else {
+                return bail!(
+                    &enum_v.name,
+                    "enums with #[repr] can only be converted if all variants have explicit discriminants"
+                );
+            };
+
+            let disc = &value.value;
+            variants.push((enum_v.name.clone(), quote! { #disc }));
+        }
+
+        Ok(Some(Self {
+            int_ty,
+            variants,
+            is_lenient: GodotAttr::is_lenient(decl)?,
+        }))
+    }
+
+    pub fn derive_to_godot(&self, decl: &Declaration) -> TokenStream {
+        let DeclInfo { name, .. } = decl_get_info(decl);
+        let arms = self
+            .variants
+            .iter()
+            .map(|(v_name, disc)| quote! { Self::#v_name => #disc, });
+
+        quote! {
+            impl ::godot::builtin::meta::ToGodot for #name {
+                fn to_godot(&self) -> Self::Via {
+                    match self {
+                        #( #arms )*
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn derive_from_godot(&self, decl: &Declaration) -> TokenStream {
+        let DeclInfo {
+            name, name_string, ..
+        } = decl_get_info(decl);
+        let int_ty = &self.int_ty;
+
+        let arms = self
+            .variants
+            .iter()
+            .map(|(v_name, disc)| quote! { #disc => Ok(Self::#v_name), });
+        let discs = self.variants.iter().map(|(_, disc)| disc);
+
+        let err = format!("value is not a discriminant of enum {name_string}");
+
+        // Like `#[godot(transparent)]`, the attribute makes the type lenient even outside of `Lenient<T>`.
+        let lenient_attr_override = if self.is_lenient {
+            quote! {
+                fn try_from_variant(
+                    variant: &::godot::builtin::Variant
+                ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                    Self::try_from_variant_lenient(variant)
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            impl ::godot::builtin::meta::FromGodot for #name {
+                fn try_from_godot(
+                    via: Self::Via
+                ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                    match via {
+                        #( #arms )*
+                        _ => Err(::godot::builtin::meta::ConvertError::with_cause_value(#err, via)),
+                    }
+                }
+
+                #lenient_attr_override
+
+                // Out-of-range integers are clamped to the lowest/highest discriminant. Values in gaps between discriminants
+                // are still rejected, as there is no unambiguous nearest variant.
+                fn try_from_variant_lenient(
+                    variant: &::godot::builtin::Variant
+                ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                    let value = variant.try_to_lenient::<i64>()?;
+
+                    let discriminants = [ #( (#discs) as i64 ),* ];
+                    let min = discriminants.iter().copied().min().unwrap();
+                    let max = discriminants.iter().copied().max().unwrap();
+
+                    Self::try_from_godot(value.clamp(min, max) as #int_ty)
+                }
+            }
+        }
+    }
+}
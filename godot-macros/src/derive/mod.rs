@@ -10,11 +10,17 @@
 mod derive_export;
 mod derive_from_variant;
 mod derive_godot_convert;
+mod derive_property_view;
 mod derive_to_variant;
 mod derive_var;
+mod godot_attr;
+mod int_enum;
+mod tagged_enum;
+mod transparent;
 
 pub(crate) use derive_export::*;
 pub(crate) use derive_from_variant::*;
 pub(crate) use derive_godot_convert::*;
+pub(crate) use derive_property_view::*;
 pub(crate) use derive_to_variant::*;
 pub(crate) use derive_var::*;
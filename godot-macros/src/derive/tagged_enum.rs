@@ -0,0 +1,352 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Tagged dictionary representation for enums, selected with `#[variant(tag = "...")]` on the enum.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use venial::{EnumVariant, StructFields};
+
+use crate::util::{bail, has_attr, DeclInfo, KvParser};
+use crate::ParseResult;
+
+fn has_attr_skip(attributes: &[venial::Attribute]) -> bool {
+    has_attr(attributes, "variant", "skip")
+}
+
+/// Enum-level `#[variant(tag = "...", content = "...")]` attribute.
+///
+/// Every enum value is converted to a single `Dictionary`, in which the `tag` key holds the variant name:
+/// * Without `content`, fields of struct-like variants are stored next to the tag (_internally tagged_).
+///   Tuple variants cannot be represented this way.
+/// * With `content`, the variant's data is stored under the `content` key (_adjacently tagged_): a single value for
+///   1-element tuple variants, an array for other tuple variants and a dictionary for struct-like variants.
+///   Unit variants have no `content` entry.
+pub(crate) struct TaggedRepr {
+    tag: TokenStream,
+    content: Option<TokenStream>,
+}
+
+impl TaggedRepr {
+    /// Returns `None` if the enum uses the default (externally tagged) representation.
+    pub fn parse(enum_: &venial::Enum) -> ParseResult<Option<Self>> {
+        let Some(mut parser) = KvParser::parse(&enum_.attributes, "variant")? else {
+            return Ok(None);
+        };
+
+        let tag = parser.handle_expr_required("tag")?;
+        let content = parser.handle_expr("content")?;
+        parser.finish()?;
+
+        let repr = Self { tag, content };
+        if repr.content.is_none() {
+            for (enum_v, _) in enum_.variants.iter() {
+                if matches!(enum_v.contents, StructFields::Tuple(_))
+                    && !has_attr_skip(&enum_v.attributes)
+                {
+                    return bail!(
+                        &enum_v.name,
+                        "tuple variants require `#[variant(tag = ..., content = ...)]` on the enum"
+                    );
+                }
+            }
+        }
+
+        Ok(Some(repr))
+    }
+
+    pub fn derive_to_godot(&self, enum_: &venial::Enum, info: DeclInfo) -> TokenStream {
+        let DeclInfo {
+            where_,
+            generic_params,
+            name,
+            ..
+        } = info;
+
+        let arms = enum_
+            .variants
+            .iter()
+            .map(|(enum_v, _)| self.make_to_godot_arm(enum_v));
+
+        let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+
+        quote! {
+            impl #generic_params ::godot::builtin::meta::ToGodot for #name #gen #where_ {
+                fn to_godot(&self) -> ::godot::builtin::Variant {
+                    match *self {
+                        #( #arms )*
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn derive_from_godot(
+        &self,
+        enum_: &venial::Enum,
+        info: DeclInfo,
+        try_to: &TokenStream,
+    ) -> TokenStream {
+        let DeclInfo {
+            where_,
+            generic_params,
+            name,
+            name_string,
+        } = info;
+
+        let tag = &self.tag;
+        let missing_tag = format!("missing tag in dictionary for enum {name_string}");
+        let unknown_variant = format!("unknown variant for enum {name_string}");
+
+        // Skipped variants are converted to nil; reading them back requires `Default`.
+        let skipped_check = if enum_
+            .variants
+            .iter()
+            .any(|(enum_v, _)| has_attr_skip(&enum_v.attributes))
+        {
+            quote! {
+                if variant.is_nil() {
+                    return Ok(<Self as Default>::default());
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        let arms = enum_
+            .variants
+            .iter()
+            .filter(|(enum_v, _)| !has_attr_skip(&enum_v.attributes))
+            .map(|(enum_v, _)| self.make_from_godot_arm(enum_v, try_to));
+
+        let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+
+        quote! {
+            impl #generic_params ::godot::builtin::meta::FromGodot for #name #gen #where_ {
+                fn try_from_godot(
+                    variant: ::godot::builtin::Variant
+                ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                    use ::godot::builtin::meta::ConvertError;
+
+                    #skipped_check
+                    let dict = variant.try_to::<::godot::builtin::Dictionary>()?;
+                    let tag = match dict.get(#tag) {
+                        Some(tag) => tag.try_to::<String>()?,
+                        None => return Err(ConvertError::with_cause_value(#missing_tag, dict)),
+                    };
+
+                    match tag.as_str() {
+                        #( #arms )*
+                        _ => Err(ConvertError::with_cause_value(#unknown_variant, &tag)),
+                    }
+                }
+            }
+        }
+    }
+
+    fn make_to_godot_arm(&self, enum_v: &EnumVariant) -> TokenStream {
+        let variant_name = &enum_v.name;
+        let variant_name_string = enum_v.name.to_string();
+        let tag = &self.tag;
+
+        if has_attr_skip(&enum_v.attributes) {
+            return quote! {
+                Self::#variant_name { .. } => ::godot::builtin::Variant::nil(),
+            };
+        }
+
+        let (pattern, inserts) = match &enum_v.contents {
+            StructFields::Unit => (quote! {}, TokenStream::new()),
+
+            StructFields::Tuple(fields) => {
+                // Presence of `content` was validated in parse().
+                let content = self.content.as_ref().unwrap();
+
+                let idents: Vec<_> = (0..fields.fields.len())
+                    .map(|k| format_ident!("__{k}"))
+                    .collect();
+                let patterns = fields.fields.iter().zip(&idents).map(|((f, _), ident)| {
+                    if has_attr_skip(&f.attributes) {
+                        quote! { _ }
+                    } else {
+                        quote! { ref #ident }
+                    }
+                });
+                let values = fields
+                    .fields
+                    .iter()
+                    .zip(&idents)
+                    .filter(|((f, _), _)| !has_attr_skip(&f.attributes))
+                    .map(
+                        |(_, ident)| quote! { ::godot::builtin::meta::ToGodot::to_variant(#ident) },
+                    )
+                    .collect::<Vec<_>>();
+
+                let inserts = if fields.fields.len() == 1 {
+                    let value = values
+                        .first()
+                        .cloned()
+                        .unwrap_or_else(|| quote! { ::godot::builtin::Variant::nil() });
+                    quote! { dict.insert(#content, #value); }
+                } else {
+                    quote! {
+                        #[allow(unused_mut)]
+                        let mut array = ::godot::builtin::VariantArray::new();
+                        #( array.push(#values); )*
+                        dict.insert(#content, array);
+                    }
+                };
+
+                (quote! { ( #(#patterns,)* ) }, inserts)
+            }
+
+            StructFields::Named(fields) => {
+                let names: Vec<_> = fields
+                    .fields
+                    .iter()
+                    .filter(|(f, _)| !has_attr_skip(&f.attributes))
+                    .map(|(f, _)| (f.name.clone(), f.name.to_string()))
+                    .collect();
+
+                // Without `content`, fields are stored directly in the tagged dictionary.
+                let target = if self.content.is_some() {
+                    quote! { fields }
+                } else {
+                    quote! { dict }
+                };
+                let field_inserts = names.iter().map(|(ident, ident_string)| {
+                    quote! {
+                        #target.insert(#ident_string, ::godot::builtin::meta::ToGodot::to_variant(#ident));
+                    }
+                });
+
+                let inserts = match &self.content {
+                    Some(content) => quote! {
+                        #[allow(unused_mut)]
+                        let mut fields = ::godot::builtin::Dictionary::new();
+                        #( #field_inserts )*
+                        dict.insert(#content, fields);
+                    },
+                    None => quote! {
+                        #( #field_inserts )*
+                    },
+                };
+
+                let idents = names.iter().map(|(ident, _)| ident);
+                (quote! { { #(ref #idents,)* .. } }, inserts)
+            }
+        };
+
+        quote! {
+            Self::#variant_name #pattern => {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                dict.insert(#tag, #variant_name_string);
+                #inserts
+                ::godot::builtin::meta::ToGodot::to_variant(&dict)
+            }
+        }
+    }
+
+    fn make_from_godot_arm(&self, enum_v: &EnumVariant, try_to: &TokenStream) -> TokenStream {
+        let variant_name = &enum_v.name;
+        let variant_name_string = enum_v.name.to_string();
+
+        let missing_content = self.content.as_ref().map(|content| {
+            let err = format!("missing content for variant {variant_name_string}");
+            quote! {
+                let content = match dict.get(#content) {
+                    Some(content) => content,
+                    None => return Err(ConvertError::with_cause_value(#err, dict)),
+                };
+            }
+        });
+
+        let construct = match &enum_v.contents {
+            StructFields::Unit => quote! { Ok(Self::#variant_name) },
+
+            StructFields::Tuple(fields) if fields.fields.len() == 1 => {
+                let (field, _) = fields.fields.first().unwrap();
+                let field_type = &field.ty;
+                if has_attr_skip(&field.attributes) {
+                    quote! { Ok(Self::#variant_name(<#field_type as Default>::default())) }
+                } else {
+                    quote! {
+                        #missing_content
+                        Ok(Self::#variant_name(content.#try_to::<#field_type>()?))
+                    }
+                }
+            }
+
+            StructFields::Tuple(fields) => {
+                let mut index = 0_usize;
+                let values = fields.fields.iter().map(|(field, _)| {
+                    let field_type = &field.ty;
+                    if has_attr_skip(&field.attributes) {
+                        quote! { <#field_type as Default>::default() }
+                    } else {
+                        let i = index;
+                        index += 1;
+                        quote! {
+                            match array.try_get(#i) {
+                                Some(value) => value.#try_to::<#field_type>()?,
+                                None => return Err(ConvertError::with_cause_value("missing expected value", array)),
+                            }
+                        }
+                    }
+                });
+                let values: Vec<_> = values.collect();
+
+                quote! {
+                    #missing_content
+                    let array = content.try_to::<::godot::builtin::VariantArray>()?;
+                    Ok(Self::#variant_name( #(#values,)* ))
+                }
+            }
+
+            StructFields::Named(fields) => {
+                let values = fields.fields.iter().map(|(field, _)| {
+                    let field_name = &field.name;
+                    let field_name_string = field.name.to_string();
+                    let field_type = &field.ty;
+
+                    if has_attr_skip(&field.attributes) {
+                        quote! { #field_name: <#field_type as Default>::default() }
+                    } else {
+                        let err = format!("missing expected value {field_name_string}");
+                        quote! {
+                            #field_name: match fields.get(#field_name_string) {
+                                Some(value) => value.#try_to::<#field_type>()?,
+                                None => return Err(ConvertError::with_cause_value(#err, fields)),
+                            }
+                        }
+                    }
+                });
+
+                let fields_source = match &missing_content {
+                    Some(missing_content) => quote! {
+                        #missing_content
+                        let fields = content.try_to::<::godot::builtin::Dictionary>()?;
+                    },
+                    None => quote! {
+                        let fields = dict;
+                    },
+                };
+
+                quote! {
+                    #fields_source
+                    Ok(Self::#variant_name { #(#values,)* })
+                }
+            }
+        };
+
+        quote! {
+            #variant_name_string => {
+                #construct
+            }
+        }
+    }
+}
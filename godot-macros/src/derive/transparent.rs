@@ -0,0 +1,187 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Support for `#[godot(transparent)]` newtypes, which convert via their single field.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+use venial::{Declaration, StructFields, TyExpr};
+
+use crate::derive::godot_attr::GodotAttr;
+use crate::util::{bail, decl_get_info, DeclInfo};
+use crate::ParseResult;
+
+/// The single field of a struct annotated with `#[godot(transparent)]`.
+pub(crate) struct TransparentField {
+    ty: TyExpr,
+    /// Field name or tuple index, as used in `self.<member>`.
+    member: TokenStream,
+    is_named: bool,
+    is_lenient: bool,
+}
+
+impl TransparentField {
+    /// Returns `None` if the declaration is not marked `#[godot(transparent)]`.
+    pub fn parse(decl: &Declaration) -> ParseResult<Option<Self>> {
+        let attr = match GodotAttr::parse(decl)? {
+            Some(attr) if attr.transparent => attr,
+            _ => return Ok(None),
+        };
+
+        let Declaration::Struct(struct_) = decl else {
+            return bail!(
+                attr.span,
+                "#[godot(transparent)] can only be used on structs"
+            );
+        };
+
+        let field = match &struct_.fields {
+            StructFields::Tuple(fields) if fields.fields.len() == 1 => {
+                let (field, _) = fields.fields.first().unwrap();
+                Self {
+                    ty: field.ty.clone(),
+                    member: Literal::usize_unsuffixed(0).to_token_stream(),
+                    is_named: false,
+                    is_lenient: attr.lenient,
+                }
+            }
+            StructFields::Named(fields) if fields.fields.len() == 1 => {
+                let (field, _) = fields.fields.first().unwrap();
+                Self {
+                    ty: field.ty.clone(),
+                    member: field.name.to_token_stream(),
+                    is_named: true,
+                    is_lenient: attr.lenient,
+                }
+            }
+            _ => {
+                return bail!(
+                    &struct_.name,
+                    "#[godot(transparent)] requires a struct with exactly one field"
+                )
+            }
+        };
+
+        Ok(Some(field))
+    }
+
+    /// `impl` header `impl<...> #trait_ for Name<...> where ...`, including generics of the declaration.
+    fn impl_header(decl: &Declaration, trait_: TokenStream) -> TokenStream {
+        let DeclInfo {
+            where_,
+            generic_params,
+            name,
+            ..
+        } = decl_get_info(decl);
+
+        let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+        quote! { impl #generic_params #trait_ for #name #gen #where_ }
+    }
+
+    /// Expression constructing `Self` from a value of the inner type.
+    fn construct(&self, value: TokenStream) -> TokenStream {
+        let member = &self.member;
+        if self.is_named {
+            quote! { Self { #member: #value } }
+        } else {
+            quote! { Self(#value) }
+        }
+    }
+
+    pub fn derive_godot_convert(&self, decl: &Declaration) -> TokenStream {
+        let header = Self::impl_header(decl, quote! { ::godot::builtin::meta::GodotConvert });
+        let ty = &self.ty;
+
+        quote! {
+            #header {
+                type Via = <#ty as ::godot::builtin::meta::GodotConvert>::Via;
+            }
+        }
+    }
+
+    pub fn derive_to_godot(&self, decl: &Declaration) -> TokenStream {
+        let header = Self::impl_header(decl, quote! { ::godot::builtin::meta::ToGodot });
+        let member = &self.member;
+
+        quote! {
+            #header {
+                fn to_godot(&self) -> Self::Via {
+                    ::godot::builtin::meta::ToGodot::to_godot(&self.#member)
+                }
+
+                fn into_godot(self) -> Self::Via {
+                    ::godot::builtin::meta::ToGodot::into_godot(self.#member)
+                }
+            }
+        }
+    }
+
+    pub fn derive_from_godot(&self, decl: &Declaration) -> TokenStream {
+        let header = Self::impl_header(decl, quote! { ::godot::builtin::meta::FromGodot });
+        let construct = self.construct(quote! { inner });
+
+        // Strict conversion goes through Via; lenient conversion needs to see the original variant.
+        let lenient_override = if self.is_lenient {
+            quote! {
+                fn try_from_variant(
+                    variant: &::godot::builtin::Variant
+                ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                    ::godot::builtin::meta::FromGodot::try_from_variant_lenient(variant).map(|inner| #construct)
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            #header {
+                fn try_from_godot(
+                    via: Self::Via
+                ) -> Result<Self, ::godot::builtin::meta::ConvertError> {
+                    ::godot::builtin::meta::FromGodot::try_from_godot(via).map(|inner| #construct)
+                }
+
+                #lenient_override
+            }
+        }
+    }
+
+    pub fn derive_var(&self, decl: &Declaration) -> TokenStream {
+        let header = Self::impl_header(decl, quote! { ::godot::register::property::Var });
+        let ty = &self.ty;
+        let member = &self.member;
+
+        quote! {
+            #header {
+                fn get_property(&self) -> Self::Via {
+                    ::godot::register::property::Var::get_property(&self.#member)
+                }
+
+                fn set_property(&mut self, value: Self::Via) {
+                    ::godot::register::property::Var::set_property(&mut self.#member, value)
+                }
+
+                fn property_hint() -> ::godot::register::property::PropertyHintInfo {
+                    <#ty as ::godot::register::property::Var>::property_hint()
+                }
+            }
+        }
+    }
+
+    pub fn derive_export(&self, decl: &Declaration) -> TokenStream {
+        let header = Self::impl_header(decl, quote! { ::godot::register::property::Export });
+        let ty = &self.ty;
+
+        quote! {
+            #header {
+                fn default_export_info() -> ::godot::register::property::PropertyHintInfo {
+                    <#ty as ::godot::register::property::Export>::default_export_info()
+                }
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use venial::Declaration;
 
@@ -29,77 +29,120 @@ pub fn attribute_gdextension(decl: Declaration) -> ParseResult<TokenStream> {
     let drained_attributes = std::mem::take(&mut impl_decl.attributes);
     let mut parser = KvParser::parse_required(&drained_attributes, "gdextension", &impl_decl)?;
     let entry_point = parser.handle_ident("entry_point")?;
+    let entry_symbol = parser.handle_string("entry_symbol")?;
     parser.finish()?;
 
-    let entry_point = entry_point.unwrap_or_else(|| ident("gdext_rust_init"));
+    let entry_point = match (entry_point, entry_symbol) {
+        (Some(entry_point), None) => entry_point,
+        (None, Some(entry_symbol)) => parse_entry_symbol(&entry_symbol, &impl_decl)?,
+        (None, None) => ident("gdext_rust_init"),
+        (Some(entry_point), Some(_)) => {
+            return bail!(
+                entry_point,
+                "#[gdextension] accepts either `entry_point` or `entry_symbol`, not both"
+            )
+        }
+    };
+    let entry_symbol = entry_point.to_string();
     let impl_ty = &impl_decl.self_ty;
 
     Ok(quote! {
         #impl_decl
 
-        // This cfg cannot be checked from the outer proc-macro since its 'target' is the build
-        // host. See: https://github.com/rust-lang/rust/issues/42587
-        #[cfg(target_os = "emscripten")]
-        fn emscripten_preregistration() {
-            // Module is documented here[1] by emscripten so perhaps we can consider it a part
-            // of its public API? In any case for now we mutate global state directly in order
-            // to get things working.
-            // [1] https://emscripten.org/docs/api_reference/module.html
-            //
-            // Warning: It may be possible that in the process of executing the code leading up
-            // to `emscripten_run_script` that we might trigger usage of one of the symbols we
-            // wish to monkey patch? It seems fairly unlikely, especially as long as no i64 are
-            // involved, but I don't know what guarantees we have here.
-            //
-            // We should keep an eye out for these sorts of failures!
-            let script = std::ffi::CString::new(concat!(
-                "var pkgName = '", env!("CARGO_PKG_NAME"), "';", r#"
-                var libName = pkgName.replaceAll('-', '_') + '.wasm';
-                var dso = LDSO.loadedLibsByName[libName];
-                // This property was renamed as of emscripten 3.1.34
-                var dso_exports = "module" in dso ? dso["module"] : dso["exports"];
-                var registrants = [];
-                for (sym in dso_exports) {
-                    if (sym.startsWith("dynCall_")) {
-                        if (!(sym in Module)) {
-                            console.log(`Patching Module with ${sym}`);
-                            Module[sym] = dso_exports[sym];
+        // Scoped, so that multiple #[gdextension] impls can coexist in one module.
+        const _: () = {
+            // This cfg cannot be checked from the outer proc-macro since its 'target' is the build
+            // host. See: https://github.com/rust-lang/rust/issues/42587
+            #[cfg(target_os = "emscripten")]
+            fn emscripten_preregistration() {
+                // Module is documented here[1] by emscripten so perhaps we can consider it a part
+                // of its public API? In any case for now we mutate global state directly in order
+                // to get things working.
+                // [1] https://emscripten.org/docs/api_reference/module.html
+                //
+                // Warning: It may be possible that in the process of executing the code leading up
+                // to `emscripten_run_script` that we might trigger usage of one of the symbols we
+                // wish to monkey patch? It seems fairly unlikely, especially as long as no i64 are
+                // involved, but I don't know what guarantees we have here.
+                //
+                // We should keep an eye out for these sorts of failures!
+                let script = std::ffi::CString::new(concat!(
+                    "var entrySymbol = '", #entry_symbol, "';", r#"
+                    // Identify the module by its entry symbol rather than its file name, which may be changed by the user or the export.
+                    var dso = Object.values(LDSO.loadedLibsByName).find(function (lib) {
+                        // This property was renamed as of emscripten 3.1.34
+                        var libExports = "module" in lib ? lib["module"] : lib["exports"];
+                        return libExports && entrySymbol in libExports;
+                    });
+                    if (!dso) {
+                        throw new Error(`godot-rust: no loaded WebAssembly module exports ${entrySymbol}`);
+                    }
+                    var dso_exports = "module" in dso ? dso["module"] : dso["exports"];
+                    var registrants = [];
+                    for (sym in dso_exports) {
+                        if (sym.startsWith("dynCall_")) {
+                            if (!(sym in Module)) {
+                                console.log(`Patching Module with ${sym}`);
+                                Module[sym] = dso_exports[sym];
+                            }
+                        } else if (sym.startsWith("rust_gdext_registrant_")) {
+                            registrants.push(sym);
                         }
-                    } else if (sym.startsWith("rust_gdext_registrant_")) {
-                        registrants.push(sym);
                     }
-                }
-                for (sym of registrants) {
-                    console.log(`Running registrant ${sym}`);
-                    dso_exports[sym]();
-                }
-                console.log("Added",  registrants.length, "plugins to registry!");
-            "#)).expect("Unable to create CString from script");
+                    // Several extension libraries in one binary share the registry, so it must only be filled once.
+                    if (!dso_exports.__gdext_registrants_run) {
+                        dso_exports.__gdext_registrants_run = true;
+                        for (sym of registrants) {
+                            console.log(`Running registrant ${sym}`);
+                            dso_exports[sym]();
+                        }
+                        console.log("Added",  registrants.length, "plugins to registry!");
+                    }
+                "#)).expect("Unable to create CString from script");
 
-            extern "C" { fn emscripten_run_script(script: *const std::ffi::c_char); }
-            unsafe { emscripten_run_script(script.as_ptr()); }
-        }
+                extern "C" { fn emscripten_run_script(script: *const std::ffi::c_char); }
+                unsafe { emscripten_run_script(script.as_ptr()); }
+            }
 
-        #[no_mangle]
-        unsafe extern "C" fn #entry_point(
-            interface_or_get_proc_address: ::godot::sys::InitCompat,
-            library: ::godot::sys::GDExtensionClassLibraryPtr,
-            init: *mut ::godot::sys::GDExtensionInitialization,
-        ) -> ::godot::sys::GDExtensionBool {
-            // Required due to the lack of a constructor facility such as .init_array in rust wasm
-            #[cfg(target_os = "emscripten")]
-            emscripten_preregistration();
+            #[no_mangle]
+            unsafe extern "C" fn #entry_point(
+                interface_or_get_proc_address: ::godot::sys::InitCompat,
+                library: ::godot::sys::GDExtensionClassLibraryPtr,
+                init: *mut ::godot::sys::GDExtensionInitialization,
+            ) -> ::godot::sys::GDExtensionBool {
+                // Required due to the lack of a constructor facility such as .init_array in rust wasm
+                #[cfg(target_os = "emscripten")]
+                emscripten_preregistration();
 
-            ::godot::init::__gdext_load_library::<#impl_ty>(
-                interface_or_get_proc_address,
-                library,
-                init
-            )
-        }
+                ::godot::init::__gdext_load_library::<#impl_ty>(
+                    interface_or_get_proc_address,
+                    library,
+                    init
+                )
+            }
 
-        fn __static_type_check() {
-            // Ensures that the init function matches the signature advertised in FFI header
-            let _unused: ::godot::sys::GDExtensionInitializationFunction = Some(#entry_point);
-        }
+            fn __static_type_check() {
+                // Ensures that the init function matches the signature advertised in FFI header
+                let _unused: ::godot::sys::GDExtensionInitializationFunction = Some(#entry_point);
+            }
+        };
     })
 }
+
+/// Validates that the `entry_symbol` string can be used as the name of the exported function.
+fn parse_entry_symbol(entry_symbol: &str, impl_decl: &venial::Impl) -> ParseResult<Ident> {
+    let mut chars = entry_symbol.chars();
+    let is_valid = chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !is_valid {
+        return bail!(
+            &impl_decl.self_ty,
+            "entry_symbol \"{entry_symbol}\" is not a valid symbol name (ASCII letters, digits and `_`)"
+        );
+    }
+
+    Ok(ident(entry_symbol))
+}
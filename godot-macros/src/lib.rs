@@ -365,6 +365,40 @@ use crate::util::ident;
 /// This should usually be combined with `#[class(tool)]` so that the code you write will actually run in the
 /// editor.
 ///
+/// Editor plugins can register further Rust classes with the editor. For example, resource thumbnails in the FileSystem dock and
+/// inspector pickers are provided by a class inheriting `EditorResourcePreviewGenerator`, which overrides `handles()` and `generate()`.
+/// An instance is registered in the plugin's `enter_tree()`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::{EditorPlugin, IEditorPlugin, EditorInterface, EditorResourcePreviewGenerator};
+/// # #[derive(GodotClass)]
+/// # #[class(init, base=EditorResourcePreviewGenerator, tool)]
+/// # struct ItemPreview;
+/// #[derive(GodotClass)]
+/// #[class(tool, init, editor_plugin, base=EditorPlugin)]
+/// struct ItemEditorPlugin {
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorPlugin for ItemEditorPlugin {
+///     fn enter_tree(&mut self) {
+///         let previewer = EditorInterface::singleton().get_resource_previewer();
+///         if let Some(mut previewer) = previewer {
+///             previewer.add_preview_generator(ItemPreview::new_gd().upcast());
+///         }
+///     }
+/// }
+/// ```
+///
+/// In the same way, a class inheriting `EditorSyntaxHighlighter` provides highlighting for custom languages in the script editor.
+/// It overrides `get_name()`, `get_supported_languages()` and `get_line_syntax_highlighting()` -- the latter can be built with
+/// [`LineHighlighting`](../engine/struct.LineHighlighting.html) -- and is registered with
+/// `EditorInterface::singleton().get_script_editor()` through `register_syntax_highlighter()`. Use `unregister_syntax_highlighter()`
+/// in `exit_tree()`. Highlighters used outside the editor, e.g. for a `CodeEdit` in a game, can inherit `SyntaxHighlighter` or
+/// configure a `CodeHighlighter` instead.
+///
 /// ## Class renaming
 ///
 /// You may want to have structs with the same name. With Rust, this is allowed using `mod`. However in GDScript,
@@ -403,6 +437,34 @@ use crate::util::ident;
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hide` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Process priorities
+///
+/// Godot calls `process()` and `physics_process()` of all nodes in order of their priority, lower values first; nodes with equal priority
+/// are processed in tree order. The keys `process_priority` and `physics_process_priority` set these priorities for every instance of a
+/// class, right before `ready()`. They accept any `i32` expression and require a `Base<T>` field, where `T` inherits `Node`.
+/// `physics_process_priority` is available from Godot 4.1 on.
+///
+/// To change priorities at runtime, call `set_process_priority()` or `set_physics_process_priority()` on the node.
+///
+/// A common pattern is a "system" node that runs before or after all other nodes each frame, e.g. to gather input or to apply
+/// simulation results. Give it the lowest or highest possible priority and add it to the scene tree, for example as an autoload:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, process_priority = i32::MIN, physics_process_priority = i32::MIN)]
+/// struct EarlySystem {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for EarlySystem {
+///     fn process(&mut self, _delta: f64) {
+///         // Runs before process() of any node with higher priority.
+///     }
+/// }
+/// ```
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -472,6 +534,9 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// Neither `#[godot_api]` attribute is required. For small data bundles inheriting `RefCounted`, you may be fine with
 /// accessing properties directly from GDScript.
 ///
+/// Each `#[func]` is registered with two entry points: a _varcall_ one for dynamic calls (`Object.call()`, untyped GDScript),
+/// and a _ptrcall_ one, which statically typed GDScript and C# use to pass arguments and return values without `Variant` conversions.
+///
 /// # Examples
 ///
 /// ## `RefCounted` as a base, overridden `init`
@@ -527,7 +592,29 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 }
 
 /// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs (required by [`ToGodot`] and [`FromGodot`]).
-#[proc_macro_derive(GodotConvert)]
+///
+/// # Transparent newtypes
+///
+/// Structs with exactly one field can be annotated with `#[godot(transparent)]`. Instead of being wrapped in a dictionary, they are then
+/// converted exactly like the inner type. The attribute is respected by the `GodotConvert`, `ToGodot`, `FromGodot`, `Var` and `Export`
+/// derives, so the newtype can be used in signatures and as `#[var]`/`#[export]` field, appearing as the inner type to Godot.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotConvert, ToGodot, FromGodot, Var, Export, Default, Copy, Clone, PartialEq, Debug)]
+/// #[godot(transparent)]
+/// struct Meters(f32);
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Runner {
+///     #[export]
+///     distance: Meters, // Shows up as float in the inspector.
+/// }
+///
+/// assert_eq!(Meters(1.5).to_variant(), 1.5.to_variant());
+/// ```
+#[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
@@ -560,7 +647,37 @@ pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// You can use the `#[skip]` attribute to ignore a field from being converted to `ToGodot`.
-#[proc_macro_derive(ToGodot, attributes(variant))]
+///
+/// # Enum representation
+///
+/// By default, enums are converted to a dictionary with the enum name as key, and the variant as value (see above). This can be changed
+/// with `#[variant(tag = "...")]` on the enum, which produces a flat dictionary that is easier to consume from GDScript: the `tag` key
+/// holds the variant name, and fields of struct-like variants are stored alongside it. With an additional `content = "..."` key,
+/// the variant's data is instead stored under that key -- this is required for tuple variants.
+///
+/// C-style enums with an integer `#[repr]` (e.g. `#[repr(i32)]`) and explicit discriminants are instead converted to their discriminant,
+/// like enums in GDScript.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(FromGodot, ToGodot, GodotConvert, Clone, PartialEq, Debug)]
+/// #[variant(tag = "type", content = "data")]
+/// enum Action {
+///     Idle,
+///     Move(Vector2),
+///     Attack { target: i64 },
+/// }
+///
+/// let attack = Action::Attack { target: 7 };
+/// let dict = dict! {
+///     "type": "Attack",
+///     "data": dict! { "target": 7 },
+/// };
+///
+/// assert_eq!(attack.to_variant(), dict.to_variant());
+/// assert_eq!(Action::Idle.to_variant(), dict! { "type": "Idle" }.to_variant());
+/// ```
+#[proc_macro_derive(ToGodot, attributes(variant, godot))]
 pub fn derive_to_godot(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_to_godot)
 }
@@ -594,7 +711,18 @@ pub fn derive_to_godot(input: TokenStream) -> TokenStream {
 ///
 /// You can use the skip attribute to ignore a field from the provided variant and use `Default::default()`
 /// to get it instead.
-#[proc_macro_derive(FromGodot, attributes(variant))]
+///
+/// Enums can use a tagged representation with `#[variant(tag = "...")]` or `#[variant(tag = "...", content = "...")]`;
+/// see [`ToGodot`](derive.ToGodot.html) for details.
+///
+/// By default, each field must be stored with exactly its own type. With `#[godot(lenient)]` on the type, fields additionally accept
+/// GDScript's implicit conversions (e.g. an `int` for an `f32` field), see
+/// [`FromGodot::try_from_variant_lenient()`](../builtin/meta/trait.FromGodot.html#method.try_from_variant_lenient).
+///
+/// For C-style enums with an integer `#[repr]`, only the discriminants are accepted. In lenient mode (with `#[godot(lenient)]` or inside
+/// [`Lenient<T>`](../builtin/meta/struct.Lenient.html)), integers below or above the range of discriminants are clamped to the lowest or
+/// highest one.
+#[proc_macro_derive(FromGodot, attributes(variant, godot))]
 pub fn derive_from_godot(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_from_godot)
 }
@@ -605,6 +733,7 @@ pub fn derive_from_godot(input: TokenStream) -> TokenStream {
 ///
 /// Currently has some tight requirements which are expected to be softened as implementation expands:
 /// - Only works for enums, structs aren't supported by this derive macro at the moment.
+///   The exception are `#[godot(transparent)]` newtypes, see [`GodotConvert`].
 /// - The enum must have an explicit `#[repr(u*/i*)]` type.
 ///     - This will likely stay this way, since `isize`, the default repr type, is not a concept in Godot.
 /// - The enum variants must not have any fields - currently only unit variants are supported.
@@ -637,7 +766,7 @@ pub fn derive_from_godot(input: TokenStream) -> TokenStream {
 ///     assert_eq!(class.foo, MyEnum::A);
 /// }
 /// ```
-#[proc_macro_derive(Var)]
+#[proc_macro_derive(Var, attributes(godot))]
 pub fn derive_property(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_var)
 }
@@ -645,11 +774,37 @@ pub fn derive_property(input: TokenStream) -> TokenStream {
 /// Derive macro for [`Export`](../register/property/trait.Export.html) on enums.
 ///
 /// Currently has some tight requirements which are expected to be softened as implementation expands, see requirements for [`Var`].
-#[proc_macro_derive(Export)]
+#[proc_macro_derive(Export, attributes(godot))]
 pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`PropertyView`](../engine/trait.PropertyView.html) on structs with named fields.
+///
+/// Each field maps to the property of the same name; use `#[property(rename = "...")]` to map to a different property.
+/// Field types must implement both `FromGodot` and `ToGodot`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::register::PropertyView;
+///
+/// #[derive(PropertyView)]
+/// struct Transform {
+///     position: Vector2,
+///     #[property(rename = "rotation")]
+///     angle: f32,
+/// }
+///
+/// # let node: Gd<Node2D> = unimplemented!();
+/// let view: Transform = node.read_properties().unwrap();
+/// ```
+#[proc_macro_derive(PropertyView, attributes(property))]
+pub fn derive_property_view(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_property_view)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -668,6 +823,10 @@ pub fn bench(meta: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Proc-macro attribute to be used in combination with the [`ExtensionLibrary`] trait.
 ///
+/// The `entry_symbol` in the `.gdextension` file must match the exported init function, which is `gdext_rust_init` by default. It can
+/// be renamed with `#[gdextension(entry_symbol = "my_init")]` (or `entry_point = my_init`), which also allows several `ExtensionLibrary`
+/// impls in one binary. To keep the `.gdextension` file up to date, see `GdextensionFile` in the `godot-bindings` crate.
+///
 /// [`ExtensionLibrary`]: ../init/trait.ExtensionLibrary.html
 #[proc_macro_attribute]
 pub fn gdextension(meta: TokenStream, input: TokenStream) -> TokenStream {
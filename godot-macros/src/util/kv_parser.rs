@@ -162,6 +162,27 @@ impl KvParser {
         Ok(Some(int))
     }
 
+    /// Handles an optional key that can only occur with a string literal as the value. Returns the string value, with escape
+    /// sequences resolved.
+    pub fn handle_string(&mut self, key: &str) -> ParseResult<Option<String>> {
+        let Some(expr) = self.handle_expr(key)? else {
+            return Ok(None);
+        };
+
+        let mut tokens = expr.into_iter();
+        let (Some(TokenTree::Literal(lit)), None) = (tokens.next(), tokens.next()) else {
+            return bail!(key, "value for '{key}' must be a string literal");
+        };
+
+        match string_literal_value(&lit.to_string()) {
+            Some(value) => Ok(Some(value)),
+            None => bail!(
+                lit,
+                "value for '{key}' must be a string literal; found {lit}"
+            ),
+        }
+    }
+
     /// Handles a key that must be provided and must have an identifier as the value.
     pub fn handle_ident_required(&mut self, key: &str) -> ParseResult<Ident> {
         self.handle_ident(key)?
@@ -395,6 +416,64 @@ pub(crate) fn has_attr(attributes: &[Attribute], expected: &str, key: &str) -> b
     }
 }
 
+/// Value of a (raw) string literal, given its token representation. Returns `None` for other literals and invalid escape sequences.
+fn string_literal_value(repr: &str) -> Option<String> {
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = &raw[..raw.len() - raw.trim_start_matches('#').len()];
+        let content = raw
+            .strip_prefix(hashes)?
+            .strip_prefix('"')?
+            .strip_suffix(hashes)?
+            .strip_suffix('"')?;
+
+        return Some(content.to_string());
+    }
+
+    let content = repr.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+
+        let escaped = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            c @ ('\\' | '\'' | '"') => c,
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                let byte = u8::from_str_radix(&hex, 16).ok().filter(u8::is_ascii)?;
+                char::from(byte)
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+
+                let hex: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                char::from_u32(u32::from_str_radix(&hex.replace('_', ""), 16).ok()?)?
+            }
+            '\n' => {
+                // Line continuation: skip the line break and leading whitespace of the next line.
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                continue;
+            }
+            _ => return None,
+        };
+
+        value.push(escaped);
+    }
+
+    Some(value)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -560,4 +639,29 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn test_string_literal_value() {
+        let value = |tokens: TokenStream| {
+            let lit = tokens.into_iter().next().expect("literal");
+            string_literal_value(&lit.to_string())
+        };
+
+        assert_eq!(value(quote! { "plain" }).as_deref(), Some("plain"));
+        assert_eq!(
+            value(quote! { "quote \" tab \t newline \n backslash \\" }).as_deref(),
+            Some("quote \" tab \t newline \n backslash \\")
+        );
+        assert_eq!(
+            value(quote! { "\x41\u{1F600}" }).as_deref(),
+            Some("A\u{1F600}")
+        );
+        assert_eq!(
+            value(quote! { r#"raw \n "quoted""# }).as_deref(),
+            Some("raw \\n \"quoted\"")
+        );
+
+        assert_eq!(value(quote! { 42 }), None);
+        assert_eq!(value(quote! { b"bytes" }), None);
+    }
 }
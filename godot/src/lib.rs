@@ -110,7 +110,17 @@
 //! * **`double-precision`**
 //!
 //!   Use `f64` instead of `f32` for the floating-point type [`real`][type@builtin::real]. Requires Godot to be compiled with the
-//!   scons flag `precision=double`.<br><br>
+//!   scons flag `precision=double`, and currently also the `custom-godot` feature, so that the bindings are generated from that build.
+//!
+//!   This affects the layout of vectors, matrices, `Color` and other math types passed to Godot. A mismatch between this feature and the
+//!   Godot build is detected when the extension is loaded, which then fails. Additionally, code generation fails if the API JSON states
+//!   a different precision (Godot 4.2+ JSONs include it).<br><br>
+//!
+//! * **`simd`**
+//!
+//!   Perform arithmetic on `Vector3`, `Vector4` and `Basis`, as well as `Transform3D` multiplication with transforms and vectors, with SIMD
+//!   instructions where available, using glam's SIMD types internally. The public types keep their layout, so they are still passed to
+//!   Godot as-is. Has no effect together with `double-precision`, as there are no SIMD types for `f64`.<br><br>
 //!
 //! * **`custom-godot`**
 //!
@@ -125,19 +135,129 @@
 //!   ```
 //!   <br>
 //!
+//! * **`min-godot-api`**
+//!
+//!   Downloads the prebuilt APIs of older Godot versions, so that `GDEXT_MIN_GODOT_API` can omit APIs newer than the declared version.
+//!   See [Supporting multiple Godot versions](#supporting-multiple-godot-versions).<br><br>
+//!
 //! * **`serde`**
 //!
-//!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
+//!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for `Variant` and built-in types such as vectors,
+//!   strings, `Color`, `Array`, `Dictionary` and packed arrays. Variants that hold objects, callables, signals or RIDs cannot be serialized.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!   <br><br>
 //!
+//! * **`serde_json`**
+//!
+//!   Conversions between `Variant` and [`serde_json::Value`](https://docs.rs/serde_json/latest/serde_json/enum.Value.html), through
+//!   `Variant::from_json_value()` and `Variant::to_json_value()`.
+//!   <br><br>
+//!
+//! * **`futures`**
+//!
+//!   Adds `Signal::to_stream()`, which returns a [`Stream`](https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html)
+//!   of signal emissions for use in async code. Requires Godot 4.2 or later.
+//!   <br><br>
+//!
+//! * **`tracy`**, **`puffin`**
+//!
+//!   Report the scopes and frame markers of [`engine::profiling`] to the [Tracy](https://github.com/wolfpld/tracy) or
+//!   [puffin](https://github.com/EmbarkStudios/puffin) profiler, in addition to Godot's debugger monitors.
+//!   <br><br>
+//!
+//! * **`rayon`**
+//!
+//!   Adds `par_iter()` and `par_iter_mut()` to packed arrays (except `PackedStringArray`) and `par_map()` to `Array`, for processing
+//!   elements in parallel with [rayon](https://docs.rs/rayon).
+//!   <br><br>
+//!
+//! * **`glam`**
+//!
+//!   Implements `From` in both directions between vectors, `Quaternion`, `Basis`, `Transform2D`, `Transform3D` and `Projection` and
+//!   their [glam](https://docs.rs/glam) counterparts. The matching glam version is re-exported as `builtin::math::glam`; floating-point
+//!   types map to glam types with the precision of `real`.
+//!   <br><br>
+//!
+//! * **`mint`**
+//!
+//!   Implements `From` in both directions between vectors, `Quaternion`, `Basis`, `Transform2D`, `Transform3D` and `Projection` and
+//!   the types of [mint](https://docs.rs/mint), as well as `mint::IntoMint`. This makes them usable with math crates that accept mint
+//!   types, such as cgmath, nalgebra or ultraviolet.
+//!   <br><br>
+//!
+//! * **`nalgebra`**
+//!
+//!   Conversions between vectors, `Quaternion`, `Basis`, `Transform3D` and `Projection` and [nalgebra](https://nalgebra.org) types such
+//!   as `Vector3`, `Point3`, `UnitQuaternion`, `Matrix3`, `Isometry3` and `Matrix4`. Converting a `Transform3D` to an `Isometry3` drops
+//!   scale and shear.
+//!   <br><br>
+//!
+//! * **`rand`**
+//!
+//!   Sample vectors, `Color`s, unit vectors (`builtin::math::UnitVector`) and points in `Rect2`/`Aabb` with [rand](https://docs.rs/rand),
+//!   e.g. `rng.gen::<Vector2>()`. `engine::GodotRng` exposes Godot's `RandomNumberGenerator` as a `rand::RngCore`, so the same code can
+//!   be driven by Godot's seeded generator.
+//!   <br><br>
+//!
+//! * **`log`**
+//!
+//!   Adds `log::GodotLogger`, a backend for the [log](https://docs.rs/log) crate that writes errors and warnings with `push_error()`
+//!   and `push_warning()`, and other records with `print()`. Output of dependencies that use `log` then shows up in Godot.
+//!   <br><br>
+//!
+//! * **`tracing`**
+//!
+//!   Adds `log::GodotTracingLayer`, a [tracing-subscriber](https://docs.rs/tracing-subscriber) layer that writes
+//!   [tracing](https://docs.rs/tracing) events to Godot's output like `log::GodotLogger`, and reports the time spent in spans as
+//!   [`engine::profiling`] scopes, so they appear in the debugger next to engine timings.
+//!   <br><br>
+//!
 //! * **`lazy-function-tables`**
 //!
 //!   Instead of loading all engine function pointers at startup, load them lazily on first use. This reduces startup time and RAM usage, but
-//!   incurs additional overhead in each FFI call. Also, you lose the guarantee that once the library has booted, all function pointers are
-//!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
+//!   incurs overhead on the first call of each method, and a small check on later calls. Also, you lose the guarantee that once the library
+//!   has booted, all function pointers are truly available. Function calls may thus panic only at runtime, possibly in deeply nested code
+//!   paths. Each method caches its own function pointer, so the metadata of methods that are never called (class and method names, hashes)
+//!   is not referenced and can be stripped by the linker, which makes binaries smaller -- relevant for Web and mobile exports. To avoid the
+//!   first-call cost for classes used in hot paths, call `init::preload_methods::<T>()`.
 //!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.<br><br>
 //!
+//! * **`try-methods`**
+//!
+//!   For each engine method, additionally generate a `try_*` method which returns `Result<R, CallError>` instead of panicking, e.g.
+//!   when Godot rejects a varcall due to wrong arguments, the return value cannot be converted, or the method's class is not part of
+//!   the engine build. Useful for servers and networked code that must recover from such errors. Dereferencing a freed `Gd` still
+//!   panics, so check `is_instance_valid()` first.<br>
+//!   This roughly doubles the number of generated methods and thus increases compile times. Methods for which Godot itself has a `try_*`
+//!   counterpart (e.g. `Mutex::try_lock()`) are skipped.<br><br>
+//!
+//! * **`codegen-nodes-2d`**, **`codegen-nodes-3d`**, **`codegen-editor`**, **`codegen-servers`**
+//!
+//!   By default, the whole engine API is generated. With `default-features = false`, only a small core of classes (`Node`, `Resource`,
+//!   `Engine`, ...) is generated, and these features add modules on top:
+//!   * `codegen-nodes-2d`: `Node2D` and its subclasses, as well as other classes ending in `2D` (e.g. `Shape2D`).
+//!   * `codegen-nodes-3d`: the same for `Node3D` and `3D`.
+//!   * `codegen-editor`: all editor classes.
+//!   * `codegen-servers`: servers such as `PhysicsServer3D` and related classes.
+//!
+//!   Methods that use a class which is not generated are omitted. Generating only the needed modules cuts compile times and
+//!   binary size considerably.
+//!
+//!   To generate fewer classes, name the classes to generate at build time, in addition to any modules:
+//!   * `GDEXT_CODEGEN_CLASSES`: environment variable with class names separated by commas or whitespace, e.g. `Sprite2D,Timer`.
+//!   * `GDEXT_CODEGEN_CLASSES_FILE`: path to a file with one class name per line; `#` starts a comment. Use an absolute path, e.g.
+//!     via `[env]` with `relative = true` in `.cargo/config.toml`.
+//!
+//!   Base classes of listed classes are included automatically, but classes used as argument or return types are not: methods of listed
+//!   classes that use them are omitted, and reported as a build warning. Unknown class names fail the build. Both variables are ignored
+//!   while the whole API is generated.<br><br>
+//!
+//! * **`debug-validation`**
+//!
+//!   In debug builds, check arguments before every engine call and panic at the Rust call site if they are invalid: freed or null
+//!   objects, transforms, bases and quaternions containing NaN or infinity, and enum values that are not valid enumerators.
+//!   Such arguments would otherwise cause errors or crashes deep inside engine code. Has no effect in release builds.<br><br>
+//!
 //! * **`formatted`**
 //!
 //!   Format the generated binding code with a custom-built formatter, which aims to strike a balance between runtime and human readability.
@@ -158,7 +278,84 @@
 //!
 //!   Support for WebAssembly exports is still a work-in-progress and is not yet well tested. This feature is in place for users
 //!   to explicitly opt-in to any instabilities or rough edges that may result. Due to a limitation in Godot, it might currently not
-//!   work Firefox browser.<br><br>
+//!   work Firefox browser. See [WebAssembly](#webassembly) for the required build setup.<br><br>
+//!
+//! # Supporting multiple Godot versions
+//!
+//! The bindings are generated for one Godot version, but an extension may be loaded by older engines, as long as it only calls methods
+//! that exist there. To catch calls to newer methods at compile time, set the environment variable `GDEXT_MIN_GODOT_API` to the oldest
+//! Godot version you support, e.g. `GDEXT_MIN_GODOT_API=4.1`. Classes, methods and utility functions introduced in a later version are
+//! then omitted, so using them fails to compile. They are determined by comparing the API against the one of the declared version, which
+//! requires the `min-godot-api` feature (it downloads the prebuilt APIs of Godot 4.0 and 4.1). The docs of each affected class list the
+//! omitted methods along with the Godot version they require. Methods of builtin types like `GString` are not omitted, but panic when
+//! called in an engine that lacks them.
+//!
+//! The same variable lets older engines load the library: without it, gdext refuses to start in a Godot version older than the one it
+//! was compiled against. With it, initialization succeeds down to the declared version (but not below 4.2 when compiling against 4.2 or
+//! later, as gdext itself relies on that GDExtension interface). Engine methods missing at runtime are then reported as a warning and
+//! fail when called, and classes whose engine base class does not exist are not registered. Query the running version with
+//! `Engine::api_version()` from `engine::EngineExt` to pick code paths.
+//!
+//! GDExtension is forward-compatible, so to support a range like 4.1 to 4.3 with one binary, compile against the oldest version.
+//!
+//! # Caching generated bindings
+//!
+//! Generating the engine API is a large part of a clean build. Set the environment variable `GDEXT_BINDINGS_CACHE` to a directory, and
+//! the generated files are stored there and reused by later builds instead of running codegen again. Entries are keyed by the Godot API,
+//! the codegen-relevant Cargo features, the target and the codegen environment variables (such as `GDEXT_MIN_GODOT_API`), so a change in
+//! any of them creates a new entry rather than reusing a stale one. The directory can be persisted as CI cache, or checked into version
+//! control to share it within a team. Old entries are not removed automatically.
+//!
+//! Without a cache hit, class files and method tables are generated on several threads (up to 8 by default). The environment variable
+//! `GDEXT_CODEGEN_THREADS` sets their number, e.g. `1` on machines with little memory; it does not change the generated code.
+//!
+//! Generated files are only rewritten if their content changes, and every engine class is generated into its own module. When codegen
+//! runs again, e.g. after changing the class allowlist, incremental compilation thus only redoes the modules that actually differ.
+//!
+//! # Startup report
+//!
+//! To find out why an extension loads slowly, set the environment variable `GDEXT_STARTUP_REPORT` when launching Godot. With the value
+//! `1`, the time spent on loading the bindings, registering each class and running `ExtensionLibrary` callbacks is printed after each
+//! init level, to the terminal as well as the editor's _Output_ panel. Any other value is interpreted as a file path, to which the measurements are written as JSON.
+//!
+//! # Generating the `.gdextension` file
+//!
+//! Godot finds the library through a `.gdextension` file, whose entry symbol, minimum version and library paths must match the Rust
+//! build. Instead of maintaining it by hand, add `godot-bindings` as a build dependency and call `GdextensionFile` from `build.rs`:
+//! ```no_run
+//! # mod godot_bindings { pub struct GdextensionFile; impl GdextensionFile { pub fn new(_: &str) -> Self { Self } pub fn write(self) {} } }
+//! godot_bindings::GdextensionFile::new("../godot/rust.gdextension").write();
+//! ```
+//! This sets the entry symbol of `#[gdextension]`, `compatibility_minimum` according to `GDEXT_MIN_GODOT_API`, and the debug and release
+//! library paths of the platform being compiled for. Entries for other platforms and other sections are kept.
+//!
+//! # WebAssembly
+//!
+//! Godot's web export loads extensions as emscripten side modules into its own main module. With the `experimental-wasm` feature, a
+//! library can be built for the `wasm32-unknown-emscripten` target, which requires nightly Rust and an emscripten version matching the
+//! one of the Godot export templates (3.1.39 for Godot 4.2). The module must be linked the way Godot expects, so configure the target
+//! in `.cargo/config.toml`:
+//! ```toml
+//! [target.wasm32-unknown-emscripten]
+//! rustflags = [
+//!     "-C", "link-args=-sSIDE_MODULE=2",
+//!     "-C", "link-args=-pthread",
+//!     "-C", "target-feature=+atomics,+bulk-memory,+mutable-globals",
+//!     "-C", "llvm-args=-enable-emscripten-cxx-exceptions=0",
+//!     "-Zlink-native-libraries=no",
+//! ]
+//! ```
+//! and build with `cargo +nightly build --target wasm32-unknown-emscripten -Zbuild-std`.
+//!
+//! * `SIDE_MODULE=2` only exports the symbols marked `#[no_mangle]`, i.e. the entry symbol and the class registration functions. As
+//!   side modules have no static constructors, `#[gdextension]` calls the registration functions itself on load.
+//! * Godot's web builds use threads, so memory is shared with the main module. This requires the `atomics` target feature, and thus
+//!   the standard library to be rebuilt with `-Zbuild-std`; gdext fails to compile without it. Only the main thread may call into Godot,
+//!   unless `experimental-threads` is enabled.
+//! * The Godot main module is compiled without C++ exception support, so exceptions must be disabled in the side module as well. Panics
+//!   then abort instead of unwinding, and are not caught by gdext.
+//!
+//! `GdextensionFile` writes the `web.debug.wasm32` and `web.release.wasm32` library entries when building for this target.
 //!
 //! # Public API
 //!
@@ -175,9 +372,12 @@
 //! Problems arising from using such APIs are not considered bugs, and anything relying on them may stop working without announcement.
 //! Please refrain from using undocumented and private features; if you are missing certain functionality, bring it up for discussion instead.
 //! This allows us to decide whether it fits the scope of the library and to design proper APIs for it.
+//!
+//! Engine functionality that godot-rust does not wrap yet can be reached through the GDExtension C interface with
+//! [`engine::raw_interface`], rather than the private `sys` module.
 
 #[doc(inline)]
-pub use godot_core::{builtin, engine, log, obj};
+pub use godot_core::{builtin, engine, error, log, obj, test_util, tools};
 
 #[doc(hidden)]
 pub use godot_core::sys;
@@ -188,6 +388,10 @@ compile_error!("Thread safety for lazy function pointers is not yet implemented.
 #[cfg(all(target_family = "wasm", not(feature = "experimental-wasm")))]
 compile_error!("Must opt-in using `experimental-wasm` Cargo feature; keep in mind that this is work in progress");
 
+// Godot's web export runs with threads, so the side module must share its memory.
+#[cfg(all(target_os = "emscripten", not(target_feature = "atomics")))]
+compile_error!("WebAssembly builds for Godot need `-C target-feature=+atomics,+bulk-memory,+mutable-globals` and `-Zbuild-std`");
+
 // See also https://github.com/godotengine/godot/issues/86346.
 #[cfg(all(feature = "double-precision", not(feature = "custom-godot")))]
 compile_error!("The feature `double-precision` currently requires `custom-godot` due to incompatibilities in the GDExtension API JSON.");
@@ -203,7 +407,9 @@ pub mod init {
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
     pub use godot_core::property;
-    pub use godot_macros::{godot_api, Export, FromGodot, GodotClass, GodotConvert, ToGodot, Var};
+    pub use godot_macros::{
+        godot_api, Export, FromGodot, GodotClass, GodotConvert, PropertyView, ToGodot, Var,
+    };
 }
 
 /// Testing facilities (unstable).
@@ -26,6 +26,8 @@ pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, O
 
 // Make trait methods available.
 pub use super::engine::NodeExt as _;
+pub use super::engine::ObjectMetaExt as _;
+pub use super::engine::ObjectPropertiesExt as _;
 pub use super::obj::EngineBitfield as _;
 pub use super::obj::EngineEnum as _;
 pub use super::obj::NewAlloc as _;
@@ -0,0 +1,20 @@
+# Copyright (c) godot-rust; Bromeon and contributors.
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+# Input for RustSkeleton tests; not run as a test suite.
+extends Node2D
+
+signal died(cause: String)
+
+@export var speed: float = 200.0
+@export var target: Node
+var health: int = 3
+
+func _ready():
+	pass
+
+func take_damage(amount: int) -> bool:
+	health -= amount
+	return health <= 0
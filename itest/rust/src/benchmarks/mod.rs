@@ -10,7 +10,8 @@
 use std::hint::black_box;
 
 use godot::builtin::inner::InnerRect2i;
-use godot::builtin::{GString, Rect2i, StringName, Vector2i};
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{GString, Rect2i, StringName, Vector2i, Vector3};
 use godot::engine::{Node3D, Os, RefCounted};
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
 use godot::register::GodotClass;
@@ -46,6 +47,24 @@ fn builtin_ffi_call() -> bool {
     rect.has_point(point)
 }
 
+#[bench]
+fn variant_int_roundtrip() -> i64 {
+    let variant = black_box(123_456_i64).to_variant();
+    variant.to::<i64>()
+}
+
+#[bench]
+fn variant_vector3_roundtrip() -> Vector3 {
+    let variant = black_box(Vector3::new(1.0, 2.0, 3.0)).to_variant();
+    variant.to::<Vector3>()
+}
+
+#[bench]
+fn variant_string_roundtrip() -> GString {
+    let variant = black_box(GString::from("some test string")).to_variant();
+    variant.to::<GString>()
+}
+
 #[bench(repeat = 25)]
 fn class_node_life() -> InstanceId {
     let node = Node3D::new_alloc();
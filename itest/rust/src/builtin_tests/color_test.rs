@@ -6,7 +6,9 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::{Color, ColorChannelOrder};
+use godot::builtin::inner::InnerColor;
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Color, ColorChannelOrder, GString};
 
 #[itest]
 fn color_from_rgba8() {
@@ -60,6 +62,106 @@ fn color_from_html() {
     assert_eq!(Color::from_string("#abcg"), None);
 }
 
+#[itest]
+fn color_from_html_native() {
+    for html in ["#abcd", "abc", "#a1b2c3", "A1B2C3D4"] {
+        assert_eq!(
+            Color::from_html(html),
+            Ok(InnerColor::html(html.into())),
+            "html: {html}"
+        );
+    }
+
+    let err = Color::from_html("#abcde").unwrap_err();
+    assert_eq!(err.html(), "#abcde");
+    assert_eq!(
+        err.to_string(),
+        "invalid HTML color \"#abcde\": expected 3, 4, 6 or 8 hex digits, found 5"
+    );
+
+    assert_eq!(
+        Color::from_html(GString::from("#abcd")),
+        Ok(Color::from_rgba8(0xaa, 0xbb, 0xcc, 0xdd))
+    );
+
+    let err = Color::from_html("#abcg").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid HTML color \"#abcg\": 'g' is not a hex digit"
+    );
+}
+
+#[itest]
+fn color_hsv() {
+    let colors = [
+        Color::from_rgb(1.0, 0.0, 0.0),
+        Color::from_rgb(0.2, 0.6, 0.9),
+        Color::from_rgb(0.9, 0.1, 0.5),
+        Color::from_rgb(0.5, 0.5, 0.5),
+    ];
+
+    for color in colors {
+        let (h, s, v) = color.to_hsv();
+        let inner = InnerColor::from_outer(&color);
+
+        assert_eq_approx!(h, inner.get_h());
+        assert_eq_approx!(s, inner.get_s());
+        assert_eq_approx!(v, inner.get_v());
+        assert_eq_approx!(Color::from_hsv(h, s, v), color);
+        assert_eq_approx!(Color::from_hsv(h, s, v), InnerColor::from_hsv(h, s, v, 1.0));
+    }
+}
+
+#[itest]
+fn color_lerp_hsv() {
+    let red = Color::from_rgb(1.0, 0.0, 0.0);
+    let green = Color::from_rgb(0.0, 1.0, 0.0);
+    let magenta = Color::from_rgb(1.0, 0.0, 1.0);
+
+    assert_eq_approx!(red.lerp_hsv(green, 0.5), Color::from_rgb(1.0, 1.0, 0.0));
+
+    // Shorter way around the hue circle: from red over 0.0 to magenta.
+    assert_eq_approx!(red.lerp_hsv(magenta, 0.5), Color::from_rgb(1.0, 0.0, 0.5));
+
+    let translucent = Color::from_rgba(0.0, 0.0, 1.0, 0.0);
+    assert_eq_approx!(red.lerp_hsv(translucent, 0.5).a, 0.5);
+}
+
+#[itest]
+fn color_ok_hsl() {
+    for (h, s, l) in [(0.0, 1.0, 0.5), (0.3, 0.5, 0.7), (0.75, 0.9, 0.2)] {
+        let color = Color::from_ok_hsl(h, s, l);
+        assert_eq_approx!(color, InnerColor::from_ok_hsl(h, s, l, 1.0));
+
+        let (h2, s2, l2) = color.to_ok_hsl();
+        assert_eq_approx!(Color::from_ok_hsl(h2, s2, l2), color);
+    }
+}
+
+#[itest]
+fn color_oklab() {
+    let (l, a, b) = Color::from_rgb(1.0, 0.0, 0.0).to_oklab();
+
+    // Reference values from https://bottosson.github.io/posts/oklab/.
+    assert_eq_approx!(l, 0.627955);
+    assert_eq_approx!(a, 0.224863);
+    assert_eq_approx!(b, 0.125846);
+
+    let color = Color::from_rgb(0.2, 0.6, 0.9);
+    let (l, a, b) = color.to_oklab();
+    assert_eq_approx!(Color::from_oklab(l, a, b), color);
+}
+
+#[itest]
+fn color_srgb_linear() {
+    let color = Color::from_rgba(0.02, 0.5, 0.9, 0.3);
+    let inner = InnerColor::from_outer(&color);
+
+    assert_eq_approx!(color.srgb_to_linear(), inner.srgb_to_linear());
+    assert_eq_approx!(color.linear_to_srgb(), inner.linear_to_srgb());
+    assert_eq_approx!(color.srgb_to_linear().linear_to_srgb(), color);
+}
+
 #[itest]
 fn color_from_string() {
     // We don't test all possibilities because internally the string is just passed to the engine.
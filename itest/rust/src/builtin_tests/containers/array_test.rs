@@ -297,6 +297,32 @@ fn array_extend() {
     assert_eq!(array, array![1, 2, 3, 4]);
 }
 
+#[itest]
+fn array_from_iterator() {
+    let array: Array<i64> = (1..=3).collect();
+    assert_eq!(array, array![1, 2, 3]);
+
+    // No lower bound in the size hint.
+    let array: Array<i64> = (1..=6).filter(|i| i % 2 == 0).collect();
+    assert_eq!(array, array![2, 4, 6]);
+
+    let strings: Array<GString> = ["a", "b"].into_iter().map(GString::from).collect();
+    assert_eq!(strings.len(), 2);
+    assert_eq!(strings.get(1), GString::from("b"));
+}
+
+#[itest]
+fn array_extend_iter() {
+    let mut array = array![1, 2];
+    array.extend(vec![3, 4]);
+    array.extend(&[5, 6]);
+    assert_eq!(array, array![1, 2, 3, 4, 5, 6]);
+
+    let mut packed = PackedInt32Array::from(&[1]);
+    packed.extend(&[2, 3]);
+    assert_eq!(packed.as_slice(), &[1, 2, 3]);
+}
+
 #[itest]
 fn array_sort() {
     let mut array = array![2, 1];
@@ -318,6 +344,106 @@ fn array_shuffle() {
     assert_eq!(array, array![1]);
 }
 
+#[itest]
+fn array_sort_unstable_by() {
+    let mut array = array![3, 1, 4, 1, 5];
+    array.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(array, array![5, 4, 3, 1, 1]);
+
+    let mut strings = array![
+        GString::from("ccc"),
+        GString::from("a"),
+        GString::from("bb")
+    ];
+    let shared = strings.clone();
+    strings.sort_unstable_by_key(|s| s.len());
+    assert_eq!(
+        shared,
+        array![
+            GString::from("a"),
+            GString::from("bb"),
+            GString::from("ccc")
+        ]
+    );
+}
+
+#[itest]
+fn array_binary_search_by() {
+    let array = array![1, 3, 5, 7];
+
+    assert_eq!(array.binary_search_by(|x| x.cmp(&5)), Ok(2));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&4)), Err(2));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&0)), Err(0));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&9)), Err(4));
+    assert_eq!(Array::<i64>::new().binary_search_by(|x| x.cmp(&1)), Err(0));
+}
+
+#[itest]
+fn array_truncate() {
+    let mut array = array![1, 2, 3];
+    array.truncate(5);
+    assert_eq!(array, array![1, 2, 3]);
+
+    array.truncate(1);
+    assert_eq!(array, array![1]);
+}
+
+#[itest]
+fn array_dedup() {
+    let mut array = array![1, 1, 2, 3, 3, 3, 1];
+    array.dedup();
+    assert_eq!(array, array![1, 2, 3, 1]);
+
+    let mut empty = Array::<i64>::new();
+    empty.dedup();
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn array_swap_remove() {
+    let mut array = array![1, 2, 3, 4];
+    assert_eq!(array.swap_remove(1), 2);
+    assert_eq!(array, array![1, 4, 3]);
+
+    assert_eq!(array.swap_remove(2), 3);
+    assert_eq!(array, array![1, 4]);
+
+    expect_panic("swap_remove() out of bounds", || {
+        array.swap_remove(2);
+    });
+}
+
+#[itest]
+fn array_retain() {
+    let mut array = array![1, 2, 3, 4, 5, 6];
+    array.retain(|&value| value % 2 == 0);
+    assert_eq!(array, array![2, 4, 6]);
+
+    array.retain(|_| false);
+    assert!(array.is_empty());
+}
+
+#[itest]
+fn array_drain() {
+    let mut array = array![0, 1, 2, 3, 4, 5];
+
+    let drained: Vec<i64> = array.drain(1..3).collect();
+    assert_eq!(drained, vec![1, 2]);
+    assert_eq!(array, array![0, 3, 4, 5]);
+
+    let drained: Vec<i64> = array.drain(2..).rev().collect();
+    assert_eq!(drained, vec![5, 4]);
+    assert_eq!(array, array![0, 3]);
+
+    // Not consuming the iterator still removes the elements.
+    let _ = array.drain(..);
+    assert!(array.is_empty());
+
+    expect_panic("drain() out of bounds", || {
+        array.drain(0..1);
+    });
+}
+
 #[itest]
 fn array_mixed_values() {
     let int = 1;
@@ -7,8 +7,9 @@
 
 use std::collections::{HashMap, HashSet};
 
+use godot::builtin::dictionary::Entry;
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, TypedDict, Variant};
 use godot::sys::GdextBuild;
 
 use crate::framework::{expect_panic, itest};
@@ -667,3 +668,158 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn dictionary_entry() {
+    let mut dict = dict! { "a": 1 };
+
+    assert_eq!(dict.entry("a").or_insert(10), 1.to_variant());
+    assert_eq!(dict.entry("b").or_insert(10), 10.to_variant());
+    assert_eq!(
+        dict.entry("c").or_insert_with(|| "lazy"),
+        "lazy".to_variant()
+    );
+
+    dict.entry("a")
+        .and_modify(|value| *value = (value.to::<i64>() + 1).to_variant())
+        .or_insert(0);
+    dict.entry("d")
+        .and_modify(|value| *value = Variant::nil())
+        .or_insert(0);
+
+    assert_eq!(dict, dict! { "a": 2, "b": 10, "c": "lazy", "d": 0 });
+}
+
+#[itest]
+fn dictionary_entry_occupied_vacant() {
+    let mut dict = dict! { "key": 1 };
+
+    match dict.entry("key") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"key".to_variant());
+            assert_eq!(entry.get_as::<i64>().unwrap(), 1);
+            assert_eq!(entry.insert(2), 1.to_variant());
+            assert_eq!(entry.remove(), 2.to_variant());
+        }
+        Entry::Vacant(_) => panic!("expected occupied entry"),
+    }
+    assert!(dict.is_empty());
+
+    match dict.entry("key") {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.insert(3), 3.to_variant());
+        }
+        Entry::Occupied(_) => panic!("expected vacant entry"),
+    }
+    assert_eq!(dict.get("key"), Some(3.to_variant()));
+}
+
+#[itest]
+fn dictionary_get_as() {
+    let dict = dict! { "int": 5, "text": "five" };
+
+    assert_eq!(dict.get_as::<_, i64>("int").unwrap(), 5);
+    assert!(dict.get_as::<_, i64>("text").is_err());
+    assert!(dict.get_as::<_, i64>("missing").is_err());
+}
+
+#[itest]
+fn dictionary_merge() {
+    let mut dict = dict! { "a": 1, "b": 2 };
+    let other = dict! { "b": 20, "c": 30 };
+
+    dict.merge(&other, false);
+    assert_eq!(dict, dict! { "a": 1, "b": 2, "c": 30 });
+
+    dict.merge(&other, true);
+    assert_eq!(dict, dict! { "a": 1, "b": 20, "c": 30 });
+    assert_eq!(other, dict! { "b": 20, "c": 30 });
+}
+
+#[itest]
+fn dictionary_retain() {
+    let mut dict = dict! { "a": 1, "b": 2, "c": 3, "d": 4 };
+    dict.retain(|_key, value| value.to::<i64>() % 2 == 0);
+
+    assert_eq!(dict, dict! { "b": 2, "d": 4 });
+
+    dict.retain(|_key, _value| false);
+    assert!(dict.is_empty());
+}
+
+#[itest]
+fn dictionary_drain() {
+    let mut dict = dict! { "a": 1, "b": 2 };
+    let shared = dict.clone();
+
+    let drained: Vec<(GString, i64)> = dict
+        .drain()
+        .map(|(key, value)| (key.to::<GString>(), value.to::<i64>()))
+        .collect();
+
+    assert_eq!(drained, vec![("a".into(), 1), ("b".into(), 2)]);
+    assert!(dict.is_empty());
+    assert!(shared.is_empty());
+
+    // Not consuming the iterator still empties the dictionary.
+    let mut dict = dict! { "x": 0 };
+    let _ = dict.drain();
+    assert!(dict.is_empty());
+}
+
+#[itest]
+fn typed_dict_insert_get() {
+    let mut dict = TypedDict::<GString, i64>::new();
+    assert!(dict.is_empty());
+
+    assert_eq!(dict.insert("a".into(), 1), None);
+    assert_eq!(dict.insert("b".into(), 2), None);
+    assert_eq!(dict.insert("a".into(), 3), Some(1));
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.get(&"a".into()), Some(3));
+    assert_eq!(dict.get(&"c".into()), None);
+    assert!(dict.contains_key(&"b".into()));
+
+    assert_eq!(dict.remove(&"b".into()), Some(2));
+    assert_eq!(dict.remove(&"b".into()), None);
+
+    let entries: HashMap<GString, i64> = dict.iter_shared().collect();
+    assert_eq!(entries, HashMap::from([("a".into(), 3)]));
+    assert_eq!(dict.as_dictionary(), &dict! { "a": 3 });
+}
+
+#[itest]
+fn typed_dict_conversion() {
+    let untyped = dict! { 1: "one", 2: "two" };
+
+    let typed = TypedDict::<i64, GString>::try_from_dictionary(untyped.clone()).unwrap();
+    assert_eq!(typed.get(&2), Some("two".into()));
+
+    let from_variant = untyped.to_variant().to::<TypedDict<i64, GString>>();
+    assert_eq!(from_variant, typed);
+
+    assert!(TypedDict::<GString, GString>::try_from_dictionary(untyped.clone()).is_err());
+    assert!(untyped
+        .to_variant()
+        .try_to::<TypedDict<i64, i64>>()
+        .is_err());
+
+    let collected: TypedDict<i64, i64> = (0..3).map(|i| (i, i * i)).collect();
+    assert_eq!(collected.into_dictionary(), dict! { 0: 0, 1: 1, 2: 4 });
+}
+
+#[itest]
+fn typed_dict_foreign_entries() {
+    let typed = TypedDict::<i64, i64>::new();
+
+    // Modification through an untyped reference bypasses the checks.
+    let mut untyped = typed.as_dictionary().clone();
+    untyped.insert(1, "not an int");
+
+    assert!(typed.try_get(&1).is_err());
+    assert_eq!(typed.try_get(&2).ok(), Some(None));
+    expect_panic("get() with wrong value type", || {
+        typed.get(&1);
+    });
+}
@@ -6,7 +6,8 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::{GString, PackedByteArray, PackedFloat32Array, PackedStringArray};
+use std::io::{Read, Seek, SeekFrom, Write};
 
 #[itest]
 fn packed_array_default() {
@@ -36,6 +37,29 @@ fn packed_array_from() {
     assert_eq!(array.get(1), 2);
 }
 
+#[itest]
+fn packed_array_from_vec() {
+    let floats = PackedFloat32Array::from_vec(vec![1.5, -2.0, 3.25]);
+    assert_eq!(floats.as_slice(), &[1.5, -2.0, 3.25]);
+    assert_eq!(PackedFloat32Array::from_vec(Vec::new()).len(), 0);
+
+    let strings = PackedStringArray::from(vec![GString::from("a"), GString::from("b")]);
+    assert_eq!(
+        strings.to_vec(),
+        vec![GString::from("a"), GString::from("b")]
+    );
+}
+
+#[itest]
+fn packed_array_into_vec() {
+    let array = PackedFloat32Array::from(&[1.0, 2.0]);
+    let copy = array.clone();
+
+    let vec: Vec<f32> = array.into();
+    assert_eq!(vec, vec![1.0, 2.0]);
+    assert_eq!(copy.into_vec(), vec![1.0, 2.0]);
+}
+
 #[itest]
 fn packed_array_to_vec() {
     let array = PackedByteArray::new();
@@ -211,6 +235,41 @@ fn packed_array_extend() {
     assert_eq!(array.to_vec(), vec![1, 2, 3, 4]);
 }
 
+#[itest]
+fn packed_array_extend_iter() {
+    let mut array = PackedFloat32Array::from(&[1.0]);
+    let shared = array.clone();
+
+    // Exact size hint: written through a slice.
+    array.extend([2.0, 3.0]);
+    assert_eq!(array.as_slice(), &[1.0, 2.0, 3.0]);
+
+    // Unknown size: pushed one by one.
+    array.extend((4..8).map(|i| i as f32).filter(|x| x % 2.0 == 0.0));
+    assert_eq!(array.as_slice(), &[1.0, 2.0, 3.0, 4.0, 6.0]);
+    assert_eq!(shared.as_slice(), &[1.0]);
+
+    let mut strings = PackedStringArray::new();
+    strings.extend(["a", "b"].map(GString::from));
+    assert_eq!(strings.as_ref(), &[GString::from("a"), GString::from("b")]);
+}
+
+#[itest]
+fn packed_array_as_mut() {
+    fn double_all(samples: &mut impl AsMut<[f32]>) {
+        for sample in samples.as_mut() {
+            *sample *= 2.0;
+        }
+    }
+
+    let mut array = PackedFloat32Array::from(&[0.5, -1.0]);
+    let shared = array.clone();
+    double_all(&mut array);
+
+    assert_eq!(array.as_slice(), &[1.0, -2.0]);
+    assert_eq!(shared.as_slice(), &[0.5, -1.0]);
+}
+
 #[itest]
 fn packed_array_sort() {
     let mut array = PackedByteArray::from(&[2, 1]);
@@ -233,3 +292,65 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_byte_array_reader() {
+    let array = PackedByteArray::from(&[1, 2, 3, 4, 5]);
+    let mut reader = array.reader();
+
+    let mut buf = [0; 2];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(reader.remaining_slice(), &[3, 4, 5]);
+
+    reader.seek(SeekFrom::End(-1)).unwrap();
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, vec![5]);
+    assert_eq!(reader.position(), 5);
+}
+
+#[itest]
+fn packed_byte_array_writer() {
+    let mut array = PackedByteArray::from(&[9, 9, 9]);
+
+    let mut writer = array.writer();
+    writer.write_all(&[1, 2]).unwrap();
+    writer.seek(SeekFrom::Current(2)).unwrap();
+    writer.write_all(&[5]).unwrap();
+    assert_eq!(writer.position(), 5);
+    assert!(writer.seek(SeekFrom::Current(-6)).is_err());
+
+    assert_eq!(array.to_vec(), vec![1, 2, 9, 0, 5]);
+
+    let mut writer = array.writer();
+    writer.seek(SeekFrom::End(0)).unwrap();
+    write!(writer, "ok").unwrap();
+    assert_eq!(array.as_slice(), &[1, 2, 9, 0, 5, b'o', b'k']);
+}
+
+#[itest]
+fn packed_string_array_from_str() {
+    let words: PackedStringArray = "alpha beta gamma".split(' ').collect();
+    assert_eq!(words.len(), 3);
+    assert_eq!(words.get(1), GString::from("beta"));
+
+    let owned: PackedStringArray = vec![String::from("x"), String::from("y")]
+        .into_iter()
+        .collect();
+    assert_eq!(owned.to_vec_string(), vec!["x", "y"]);
+}
+
+#[itest]
+fn packed_string_array_extend_str() {
+    let mut array = PackedStringArray::new();
+    array.extend(["a", "b"]);
+    array.extend(vec![String::from("c")]);
+    array.extend(["d", "e", "f"].into_iter().filter(|s| *s != "e"));
+
+    assert_eq!(array.to_vec_string(), vec!["a", "b", "c", "d", "f"]);
+    assert_eq!(
+        array.to_vec_gstring(),
+        ["a", "b", "c", "d", "f"].map(GString::from).to_vec()
+    );
+}
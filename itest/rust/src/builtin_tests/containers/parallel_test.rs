@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{array, real, Array, PackedFloat32Array, PackedInt64Array, Vector2};
+use rayon::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn packed_array_par_iter() {
+    let array = PackedInt64Array::from_vec((1..=1000).collect());
+
+    let sum: i64 = array.par_iter().sum();
+    assert_eq!(sum, 500_500);
+}
+
+#[itest]
+fn packed_array_par_iter_mut() {
+    let mut array = PackedFloat32Array::from(&[1.0, 2.0, 3.0]);
+
+    array.par_iter_mut().for_each(|value| *value *= 2.0);
+    assert_eq!(array.as_slice(), &[2.0, 4.0, 6.0]);
+}
+
+#[itest]
+fn array_par_map() {
+    let array: Array<i64> = array![1, 2, 3];
+
+    let squares = array.par_map(|value| value * value);
+    assert_eq!(squares, array![1, 4, 9]);
+
+    let points: Array<Vector2> = array.par_map(|value| Vector2::new(value as real, 0.0));
+    assert_eq!(points.get(2), Vector2::new(3.0, 0.0));
+}
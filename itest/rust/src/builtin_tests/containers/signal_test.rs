@@ -149,3 +149,62 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+#[cfg(all(feature = "futures", since_api = "4.2"))]
+#[itest]
+fn signal_stream() {
+    use futures_core::Stream;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Arc::new(NoopWaker).into();
+    let mut cx = Context::from_waker(&waker);
+
+    let mut object = Object::new_alloc();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let mut stream = signal.to_stream::<(i64, GString)>();
+    assert_eq!(signal.connections().len(), 1);
+
+    assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+
+    signal.emit(&[1.to_variant(), "one".to_variant()]);
+    signal.emit(&[2.to_variant(), "two".to_variant()]);
+
+    assert_eq!(
+        Pin::new(&mut stream).poll_next(&mut cx),
+        Poll::Ready(Some((1, GString::from("one"))))
+    );
+    assert_eq!(
+        Pin::new(&mut stream).poll_next(&mut cx),
+        Poll::Ready(Some((2, GString::from("two"))))
+    );
+    assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+
+    // Stream ends once the object is gone.
+    object.free();
+    assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+}
+
+#[cfg(all(feature = "futures", since_api = "4.2"))]
+#[itest]
+fn signal_stream_drop_disconnects() {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let stream = signal.to_stream::<()>();
+    assert_eq!(signal.connections().len(), 1);
+
+    drop(stream);
+    assert_eq!(signal.connections().len(), 0);
+
+    object.free();
+}
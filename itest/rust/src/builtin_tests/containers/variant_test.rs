@@ -10,9 +10,12 @@ use std::fmt::Display;
 
 use godot::builtin::meta::{FromGodot, ToGodot};
 use godot::builtin::{
-    dict, varray, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
+    array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector2i,
+    Vector3, Vector4, Vector4i,
+};
+use godot::builtin::{
+    Basis, Dictionary, VariantArray, VariantDispatch, VariantOperator, VariantType,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
 use godot::engine::{Node, Node2D};
 use godot::obj::{Gd, InstanceId, NewAlloc};
 use godot::sys::GodotFfi;
@@ -378,6 +381,55 @@ fn variant_stringify_correct() {
     );
 }
 
+#[itest]
+fn variant_inline_conversions_agree_with_godot() {
+    // Written without FFI, read by Godot.
+    assert_eq!(false.to_variant().stringify(), gstr("false"));
+    assert_eq!((-7).to_variant().stringify(), gstr("-7"));
+    assert_eq!(2.5.to_variant().stringify(), gstr("2.5"));
+    assert_eq!(
+        Vector2i::new(3, -4).to_variant().stringify(),
+        gstr("(3, -4)")
+    );
+    assert_eq!(
+        Vector3::new(1.0, 2.0, 3.5).to_variant().stringify(),
+        gstr("(1, 2, 3.5)")
+    );
+    assert_eq!(
+        Vector4i::new(1, 2, 3, 4).to_variant().stringify(),
+        gstr("(1, 2, 3, 4)")
+    );
+
+    // Written by Godot, read without FFI.
+    let add = |lhs: Variant, rhs: Variant| lhs.evaluate(&rhs, VariantOperator::Add).unwrap();
+    assert_eq!(add(40.to_variant(), 2.to_variant()).to::<i64>(), 42);
+    assert_eq!(add(0.5.to_variant(), 2.to_variant()).to::<f64>(), 2.5);
+    assert_eq!(
+        add(
+            Vector2::new(1.0, 2.0).to_variant(),
+            Vector2::ONE.to_variant()
+        )
+        .to::<Vector2>(),
+        Vector2::new(2.0, 3.0)
+    );
+    assert_eq!(
+        add(Vector4::ONE.to_variant(), Vector4::ONE.to_variant()).to::<Vector4>(),
+        Vector4::new(2.0, 2.0, 2.0, 2.0)
+    );
+    assert!(!1
+        .to_variant()
+        .evaluate(&2.to_variant(), VariantOperator::Equal)
+        .unwrap()
+        .to::<bool>());
+
+    // Type tags are checked, also across inline types.
+    assert!(Vector2i::new(1, 2)
+        .to_variant()
+        .try_to::<Vector2>()
+        .is_err());
+    assert!(1.to_variant().try_to::<bool>().is_err());
+}
+
 #[itest]
 fn variant_booleanize_correct() {
     assert!(gstr("string").to_variant().booleanize());
@@ -420,6 +472,94 @@ fn variant_hash_correct() {
     assert_ne!(dict! { 0: dict!{ 0: 0 } }, dict! { 0: dict!{ 0: 1 } });
 }
 
+#[itest]
+fn variant_dispatch() {
+    assert!(matches!(Variant::nil().dispatch(), VariantDispatch::Nil));
+    assert!(matches!(7.to_variant().dispatch(), VariantDispatch::Int(7)));
+    assert!(matches!(
+        true.to_variant().dispatch(),
+        VariantDispatch::Bool(true)
+    ));
+
+    match "text".to_variant().dispatch() {
+        VariantDispatch::String(string) => assert_eq!(string, gstr("text")),
+        other => panic!("expected String, got {other:?}"),
+    }
+
+    match Vector2::new(1.0, 2.0).to_variant().dispatch() {
+        VariantDispatch::Vector2(vector) => assert_eq!(vector, Vector2::new(1.0, 2.0)),
+        other => panic!("expected Vector2, got {other:?}"),
+    }
+
+    let node = Node::new_alloc();
+    let node_variant = node.to_variant();
+    match node_variant.dispatch() {
+        VariantDispatch::Object(object) => assert_eq!(object.instance_id(), node.instance_id()),
+        other => panic!("expected Object, got {other:?}"),
+    }
+    node.free();
+
+    // Freed objects dispatch to Nil.
+    assert!(matches!(node_variant.dispatch(), VariantDispatch::Nil));
+
+    let values = [
+        Variant::nil(),
+        3.5.to_variant(),
+        gname("name").to_variant(),
+        varray![1, "two"].to_variant(),
+        dict! { "key": 1 }.to_variant(),
+    ];
+    for value in values {
+        let dispatch = value.dispatch();
+        assert_eq!(dispatch.variant_type(), value.get_type());
+
+        let back: Variant = dispatch.into();
+        assert_eq!(back, value);
+    }
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn variant_try_to_ref() {
+    let variant = gstr("borrowed").to_variant();
+    let string: &GString = variant.try_to_ref().expect("GString ref");
+    assert_eq!(string, &gstr("borrowed"));
+
+    let array = varray![1, 2, 3];
+    let variant = array.to_variant();
+    let borrowed: &VariantArray = variant.try_to_ref().expect("VariantArray ref");
+    assert_eq!(borrowed.len(), 3);
+
+    // Reference points to the same array, no copy.
+    array.clone().push(4.to_variant());
+    assert_eq!(borrowed.len(), 4);
+
+    let dict = dict! { "key": 10 };
+    let variant = dict.to_variant();
+    let borrowed: &Dictionary = variant.try_to_ref().expect("Dictionary ref");
+    assert_eq!(borrowed.get("key"), Some(10.to_variant()));
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn variant_try_to_ref_fails() {
+    let variant = gname("name").to_variant();
+    assert!(variant.try_to_ref::<GString>().is_err());
+    assert!(Variant::nil().try_to_ref::<Dictionary>().is_err());
+
+    // Untyped array cannot be borrowed as typed one.
+    let variant = varray![1, 2].to_variant();
+    assert!(variant.try_to_ref::<Array<i64>>().is_err());
+
+    let typed: Array<i64> = array![1, 2];
+    let variant = typed.to_variant();
+    assert_eq!(
+        variant.try_to_ref::<Array<i64>>().map(Array::len).ok(),
+        Some(2)
+    );
+    assert!(variant.try_to_ref::<VariantArray>().is_err());
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -5,9 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+use godot::builtin::meta::{ConvertError, FromGodot, GodotConvert, Lenient, ToGodot};
 use godot::builtin::{
-    dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    dict, Array, Dictionary, GString, StringName, Variant, VariantArray, Vector2, Vector2Axis,
 };
 use godot::engine::{Node, Resource};
 use godot::obj::{Gd, NewAlloc};
@@ -229,3 +229,104 @@ fn custom_convert_error_from_variant() {
     assert!(err.cause().is_none());
     assert_eq!(err.value_str().unwrap(), format!("{:?}", i64::MAX));
 }
+
+#[itest]
+fn lenient_conversions() {
+    let int = 7.to_variant();
+
+    assert!(int.try_to::<f64>().is_err());
+    assert_eq!(int.try_to_lenient::<f64>().unwrap(), 7.0);
+    assert_eq!(int.try_to_lenient::<i64>().unwrap(), 7);
+    assert_eq!(2.9.to_variant().try_to_lenient::<i64>().unwrap(), 2);
+    assert_eq!(
+        GString::from("name")
+            .to_variant()
+            .try_to_lenient::<StringName>()
+            .unwrap(),
+        StringName::from("name")
+    );
+
+    // No implicit conversion exists.
+    assert!(Vector2::ZERO.to_variant().try_to_lenient::<i64>().is_err());
+    assert!(Variant::nil().try_to_lenient::<f64>().is_err());
+}
+
+#[itest]
+fn lenient_wrapper() {
+    let value = Lenient::<f32>::from_variant(&3.to_variant());
+    assert_eq!(*value, 3.0);
+    assert_eq!(value.to_variant(), 3.0.to_variant());
+
+    assert!(Lenient::<f32>::try_from_variant(&"three".to_variant()).is_err());
+}
+
+#[itest]
+fn std_collections() {
+    use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+    let map = HashMap::from([(GString::from("a"), 1_i64), (GString::from("b"), 2)]);
+    let dict = map.to_godot();
+    assert_eq!(dict, dict! { "a": 1, "b": 2 });
+    assert_eq!(HashMap::<GString, i64>::from_godot(dict), map);
+
+    let tree = BTreeMap::from([(1_i32, Vector2::new(1.0, 2.0)), (2, Vector2::ZERO)]);
+    let variant = tree.to_variant();
+    assert_eq!(variant.to::<BTreeMap<i32, Vector2>>(), tree);
+
+    let set = HashSet::from([3_u8, 5, 7]);
+    let array = set.to_godot();
+    assert_eq!(array.len(), 3);
+    assert_eq!(HashSet::<u8>::from_godot(array), set);
+
+    let deque = VecDeque::from([1.5_f64, -2.0, 0.0]);
+    let array = deque.to_godot();
+    assert_eq!(
+        array,
+        Array::from(&[1.5.to_variant(), (-2.0).to_variant(), 0.0.to_variant()])
+    );
+    assert_eq!(VecDeque::<f64>::from_godot(array), deque);
+
+    // Element conversion failures are reported.
+    let mixed = dict! { "a": 1, "b": "text" };
+    assert!(HashMap::<GString, i64>::try_from_godot(mixed).is_err());
+}
+
+#[itest]
+fn time_conversions() {
+    use godot::builtin::meta::Millis;
+    use std::time::{Duration, SystemTime};
+
+    let duration = Duration::from_millis(1500);
+    assert_eq!(duration.to_godot(), 1.5);
+    assert_eq!(Duration::from_godot(0.25), Duration::from_millis(250));
+    assert!(Duration::try_from_godot(-1.0).is_err());
+    assert!(Duration::try_from_godot(f64::NAN).is_err());
+
+    assert_eq!(Millis(duration).to_godot(), 1500);
+    assert_eq!(Millis::from_godot(20).0, Duration::from_millis(20));
+    assert!(Millis::try_from_godot(-20).is_err());
+
+    let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    assert_eq!(time.to_godot(), 1_000_000.0);
+    assert_eq!(SystemTime::from_godot(1_000_000.0), time);
+
+    let before = SystemTime::UNIX_EPOCH - Duration::from_secs(10);
+    assert_eq!(before.to_godot(), -10.0);
+    assert_eq!(SystemTime::from_godot(-10.0), before);
+}
+
+#[itest]
+fn path_conversions() {
+    use std::path::{Path, PathBuf};
+
+    let path = PathBuf::from("user://saves/slot1.json");
+    assert_eq!(path.to_godot(), GString::from("user://saves/slot1.json"));
+    assert_eq!(
+        Path::new("res://icon.svg").to_variant(),
+        "res://icon.svg".to_variant()
+    );
+    assert_eq!(
+        PathBuf::from_godot(GString::from("res://a/b")),
+        PathBuf::from("res://a/b")
+    );
+}
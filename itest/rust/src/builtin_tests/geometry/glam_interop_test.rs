@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::math::glam::{Affine2, Affine3A, IVec3, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use godot::builtin::{
+    Basis, Projection, Quaternion, Transform2D, Transform3D, Vector2, Vector3, Vector3i, Vector4,
+};
+
+use crate::framework::itest;
+
+#[itest]
+fn glam_vector_roundtrip() {
+    let v = Vector3::new(1.0, -2.0, 3.5);
+    let g: Vec3 = v.into();
+    assert_eq!(g, Vec3::new(1.0, -2.0, 3.5));
+    assert_eq!(Vector3::from(g), v);
+
+    assert_eq!(Vec2::from(Vector2::new(1.0, 2.0)), Vec2::new(1.0, 2.0));
+    assert_eq!(
+        Vector4::from(Vec4::new(1.0, 2.0, 3.0, 4.0)),
+        Vector4::new(1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!(IVec3::from(Vector3i::new(1, -2, 3)), IVec3::new(1, -2, 3));
+}
+
+#[itest]
+fn glam_basis_keeps_columns() {
+    let basis = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7)
+        .scaled(Vector3::new(1.0, 2.0, 0.5));
+    let mat = Mat3::from(basis);
+
+    assert_eq!(Vector3::from(mat.x_axis), basis.col_a());
+    assert_eq!(Vector3::from(mat.y_axis), basis.col_b());
+    assert_eq!(Vector3::from(mat.z_axis), basis.col_c());
+
+    let v = Vector3::new(0.3, -1.2, 4.0);
+    assert_eq_approx!(Vector3::from(mat * Vec3::from(v)), basis * v);
+    assert_eq_approx!(Basis::from(mat), basis);
+}
+
+#[itest]
+fn glam_quaternion_rotates_like_basis() {
+    let quat = Quaternion::from_axis_angle(Vector3::UP, 1.2);
+    let glam_quat = Quat::from(quat);
+    let v = Vector3::new(1.0, 0.0, 0.0);
+
+    let expected = Basis::from_quat(quat) * v;
+    assert_eq_approx!(Vector3::from(glam_quat * Vec3::from(v)), expected);
+    assert_eq!(Quaternion::from(glam_quat), quat);
+}
+
+#[itest]
+fn glam_transform_roundtrip() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::RIGHT, 0.4),
+        Vector3::new(5.0, -1.0, 2.0),
+    );
+    let affine = Affine3A::from(transform);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq_approx!(
+        Vector3::from(affine.transform_point3(Vec3::from(v))),
+        transform * v
+    );
+    assert_eq_approx!(
+        Vector3::from(Mat4::from(transform).transform_point3(Vec3::from(v))),
+        transform * v
+    );
+    assert_eq_approx!(Transform3D::from(affine), transform);
+
+    let transform = Transform2D::from_angle_origin(0.8, Vector2::new(3.0, 4.0));
+    let affine = Affine2::from(transform);
+    let v = Vector2::new(-1.0, 2.0);
+
+    assert_eq_approx!(
+        Vector2::from(affine.transform_point2(Vec2::from(v))),
+        transform * v
+    );
+    assert_eq_approx!(Transform2D::from(affine), transform);
+}
+
+#[itest]
+fn glam_projection_matches_gl_constructor() {
+    let projection = Projection::create_perspective(60.0, 16.0 / 9.0, 0.1, 100.0, false);
+    let mat = Mat4::perspective_rh_gl(60.0_f32.to_radians(), 16.0 / 9.0, 0.1, 100.0);
+
+    assert_eq_approx!(Projection::from(mat), projection);
+    assert_eq!(Mat4::from(projection), mat);
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{
+    real, Basis, Projection, Quaternion, Transform2D, Transform3D, Vector2, Vector3, Vector4i,
+};
+
+use crate::framework::itest;
+
+// Stands in for a math crate that accepts any mint-compatible type.
+fn to_mint<T: mint::IntoMint>(value: T) -> T::MintType {
+    value.into()
+}
+
+#[itest]
+fn mint_vector_roundtrip() {
+    let v = Vector3::new(1.0, -2.0, 3.5);
+    let m: mint::Vector3<real> = to_mint(v);
+    assert_eq!((m.x, m.y, m.z), (1.0, -2.0, 3.5));
+    assert_eq!(Vector3::from(m), v);
+
+    let m: mint::Vector4<i32> = to_mint(Vector4i::new(1, 2, 3, 4));
+    assert_eq!(Vector4i::from(m), Vector4i::new(1, 2, 3, 4));
+}
+
+#[itest]
+fn mint_quaternion_scalar_last() {
+    let q = Quaternion::new(0.1, 0.2, 0.3, 0.9);
+    let m: mint::Quaternion<real> = to_mint(q);
+
+    assert_eq!((m.v.x, m.v.y, m.v.z, m.s), (0.1, 0.2, 0.3, 0.9));
+    assert_eq!(Quaternion::from(m), q);
+}
+
+#[itest]
+fn mint_basis_columns_and_rows() {
+    let basis = Basis::from_rows(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(4.0, 5.0, 6.0),
+        Vector3::new(7.0, 8.0, 9.0),
+    );
+
+    let cols: mint::ColumnMatrix3<real> = to_mint(basis);
+    assert_eq!(Vector3::from(cols.x), Vector3::new(1.0, 4.0, 7.0));
+    assert_eq!(Basis::from(cols), basis);
+
+    let rows = mint::RowMatrix3::from(basis);
+    assert_eq!(Vector3::from(rows.x), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Basis::from(rows), basis);
+}
+
+#[itest]
+fn mint_transform_origin_last() {
+    let transform = Transform2D::from_cols(
+        Vector2::new(1.0, 2.0),
+        Vector2::new(3.0, 4.0),
+        Vector2::new(5.0, 6.0),
+    );
+    let m: mint::ColumnMatrix2x3<real> = to_mint(transform);
+    assert_eq!(Vector2::from(m.z), transform.origin);
+    assert_eq!(Transform2D::from(m), transform);
+
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, 0.5),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+    let m: mint::ColumnMatrix3x4<real> = to_mint(transform);
+    assert_eq!(Vector3::from(m.x), transform.basis.col_a());
+    assert_eq!(Vector3::from(m.w), transform.origin);
+    assert_eq!(Transform3D::from(m), transform);
+}
+
+#[itest]
+fn mint_projection_roundtrip() {
+    let projection = Projection::create_perspective(60.0, 16.0 / 9.0, 0.1, 100.0, false);
+    let m: mint::ColumnMatrix4<real> = to_mint(projection);
+
+    assert_eq!(m.z.w, -1.0);
+    assert_eq!(Projection::from(m), projection);
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{real, Basis, Projection, Quaternion, Transform3D, Vector3};
+use nalgebra as na;
+
+use crate::framework::itest;
+
+#[itest]
+fn nalgebra_vector_roundtrip() {
+    let v = Vector3::new(1.0, -2.0, 3.5);
+
+    assert_eq!(na::Vector3::from(v), na::Vector3::new(1.0, -2.0, 3.5));
+    assert_eq!(na::Point3::from(v), na::Point3::new(1.0, -2.0, 3.5));
+    assert_eq!(Vector3::from(na::Vector3::from(v)), v);
+}
+
+#[itest]
+fn nalgebra_basis_keeps_columns() {
+    let basis = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7)
+        .scaled(Vector3::new(1.0, 2.0, 0.5));
+    let m = na::Matrix3::from(basis);
+    let v = Vector3::new(0.3, -1.2, 4.0);
+
+    assert_eq_approx!(Vector3::from(m * na::Vector3::from(v)), basis * v);
+    assert_eq!(Basis::from(m), basis);
+}
+
+#[itest]
+fn nalgebra_quaternion_rotates_like_basis() {
+    let quat = Quaternion::from_axis_angle(Vector3::UP, 1.2);
+    let unit = na::UnitQuaternion::from(quat);
+    let v = Vector3::new(1.0, 0.0, 0.0);
+
+    assert_eq_approx!(
+        Vector3::from(unit * na::Vector3::from(v)),
+        Basis::from_quat(quat) * v
+    );
+    assert_eq_approx!(Quaternion::from(unit), quat);
+}
+
+#[itest]
+fn nalgebra_isometry_roundtrip() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::RIGHT, 0.4),
+        Vector3::new(5.0, -1.0, 2.0),
+    );
+    let isometry = na::Isometry3::from(transform);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq_approx!(Vector3::from(isometry * na::Point3::from(v)), transform * v);
+    assert_eq_approx!(Transform3D::from(isometry), transform);
+}
+
+#[itest]
+fn nalgebra_isometry_drops_scale() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, 0.4).scaled(Vector3::new(2.0, 2.0, 2.0)),
+        Vector3::new(5.0, -1.0, 2.0),
+    );
+    let isometry = na::Isometry3::from(transform);
+
+    assert_eq_approx!(
+        Transform3D::from(isometry),
+        Transform3D::new(
+            Basis::from_axis_angle(Vector3::UP, 0.4),
+            Vector3::new(5.0, -1.0, 2.0)
+        )
+    );
+}
+
+#[itest]
+fn nalgebra_matrix4() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::RIGHT, 0.4),
+        Vector3::new(5.0, -1.0, 2.0),
+    );
+    let m = na::Matrix4::from(transform);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq_approx!(
+        Vector3::from(m.transform_point(&na::Point3::from(v))),
+        transform * v
+    );
+
+    let projection = Projection::create_perspective(60.0, 16.0 / 9.0, 0.1, 100.0, false);
+    let m = na::Matrix4::<real>::from(projection);
+    assert_eq!(m[(3, 2)], -1.0);
+    assert_eq!(Projection::from(m), projection);
+}
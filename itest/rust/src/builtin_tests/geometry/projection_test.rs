@@ -11,7 +11,9 @@ use crate::framework::itest;
 
 use godot::builtin::inner::InnerProjection;
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{real, Aabb, Projection, RealConv, Rect2, Vector2, Vector3};
+use godot::builtin::{
+    real, Aabb, Projection, ProjectionPlane, RealConv, Rect2, Vector2, Vector3, Vector4,
+};
 
 #[itest]
 fn test_create_orthogonal() {
@@ -376,3 +378,64 @@ fn test_projection_combined() {
         }
     }
 }
+
+#[itest]
+fn test_frustum_planes() {
+    let proj = Projection::create_perspective(90.0, 1.0, 0.5, 10.0, false);
+    let planes = proj.frustum_planes();
+
+    for plane in [
+        ProjectionPlane::Near,
+        ProjectionPlane::Left,
+        ProjectionPlane::Bottom,
+    ] {
+        assert_eq_approx!(planes[plane as usize], proj.get_projection_plane(plane));
+    }
+
+    let inside = Vector3::new(0.5, -0.5, -2.0);
+    assert!(planes.iter().all(|plane| !plane.is_point_over(inside)));
+
+    let behind = Vector3::new(0.0, 0.0, 1.0);
+    assert!(planes[ProjectionPlane::Near as usize].is_point_over(behind));
+}
+
+#[itest]
+fn test_unproject() {
+    let proj = Projection::create_perspective(90.0, 2.0, 0.5, 10.0, false);
+    let rect = Rect2::new(Vector2::new(100.0, 100.0), Vector2::new(200.0, 100.0));
+
+    let center = proj.unproject(Vector3::new(200.0, 150.0, -1.0), rect);
+    assert_eq_approx!(center, Vector3::new(0.0, 0.0, -0.5));
+
+    let top_left = proj.unproject(Vector3::new(100.0, 100.0, -1.0), rect);
+    assert_eq_approx!(top_left, Vector3::new(-1.0, 0.5, -0.5));
+
+    let far = proj.unproject(Vector3::new(200.0, 150.0, 1.0), rect);
+    assert_eq_approx!(far, Vector3::new(0.0, 0.0, -10.0));
+}
+
+#[itest]
+fn test_jitter_offset() {
+    let proj = Projection::create_perspective(60.0, 1.5, 0.1, 100.0, false);
+    let offset = Vector2::new(0.25, -0.5);
+
+    assert_eq_approx!(
+        proj.jitter_offset(offset),
+        InnerProjection::from_outer(&proj).jitter_offseted(offset)
+    );
+}
+
+#[itest]
+fn test_create_perspective_infinite_reverse_z() {
+    let near = 0.25;
+    let proj = Projection::create_perspective_infinite_reverse_z(70.0, 16.0 / 9.0, near);
+
+    let depth = |distance: real| {
+        let clip = proj * Vector4::new(0.0, 0.0, -distance, 1.0);
+        clip.z / clip.w
+    };
+
+    assert_eq_approx!(depth(near), 1.0);
+    assert!(depth(1000.0) > 0.0);
+    assert!(depth(1.0e6) < depth(1000.0));
+}
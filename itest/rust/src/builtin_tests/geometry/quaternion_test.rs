@@ -7,7 +7,7 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{Quaternion, Vector3};
+use godot::builtin::{real, Basis, Quaternion, Vector3};
 
 #[itest]
 fn quaternion_default() {
@@ -179,3 +179,74 @@ fn quaternion_spherical_cubic_interpolate_in_time() {
     assert_eq!(outcome, Quaternion::default())
 }
 // TODO more tests
+
+fn rotate(quat: Quaternion, v: Vector3) -> Vector3 {
+    Basis::from_quat(quat) * v
+}
+
+#[itest]
+fn quaternion_from_arc() {
+    let from = Vector3::new(1.0, 2.0, 0.0);
+    let to = Vector3::new(0.0, -1.0, 3.0);
+    let quat = Quaternion::from_arc(from, to);
+
+    assert!(quat.is_normalized());
+    assert_eq_approx!(rotate(quat, from.normalized()), to.normalized());
+
+    let opposite = Quaternion::from_arc(Vector3::UP, Vector3::DOWN);
+    assert_eq_approx!(rotate(opposite, Vector3::UP), Vector3::DOWN);
+
+    expect_panic("zero vector", || {
+        Quaternion::from_arc(Vector3::ZERO, Vector3::UP);
+    });
+}
+
+#[itest]
+fn quaternion_new_looking_at() {
+    let direction = Vector3::new(1.0, 0.5, -2.0);
+    let quat = Quaternion::new_looking_at(direction, Vector3::UP);
+
+    assert_eq_approx!(rotate(quat, Vector3::FORWARD), direction.normalized());
+
+    #[cfg(since_api = "4.1")]
+    assert_eq_approx!(
+        Basis::from_quat(quat),
+        Basis::new_looking_at(direction, Vector3::UP, false)
+    );
+
+    expect_panic("direction parallel to up", || {
+        Quaternion::new_looking_at(Vector3::UP, Vector3::UP);
+    });
+}
+
+#[itest]
+fn quaternion_swing_twist() {
+    let twist_in = Quaternion::from_axis_angle(Vector3::UP, 0.9);
+    let swing_in = Quaternion::from_axis_angle(Vector3::RIGHT, 0.4);
+    let quat = swing_in * twist_in;
+
+    let (swing, twist) = quat.swing_twist(Vector3::UP);
+    assert_eq_approx!(swing * twist, quat);
+    assert_eq_approx!(twist, twist_in);
+    assert_eq_approx!(swing, swing_in);
+
+    let (swing, twist) = quat.swing_twist(Vector3::FORWARD);
+    assert_eq_approx!(swing * twist, quat);
+}
+
+#[itest]
+fn quaternion_integrate_angular_velocity() {
+    let start = Quaternion::from_axis_angle(Vector3::RIGHT, 0.3);
+    let velocity = Vector3::new(0.0, 2.0, 0.0);
+
+    let end = start.integrate(velocity, 0.25);
+    assert_eq_approx!(end, Quaternion::from_axis_angle(Vector3::UP, 0.5) * start);
+    assert_eq_approx!(start.angular_velocity_to(end, 0.25), velocity);
+
+    assert_eq!(start.integrate(Vector3::ZERO, 1.0), start);
+
+    // Non-positive durations have no meaningful velocity.
+    assert_eq!(start.angular_velocity_to(end, 0.0), Vector3::ZERO);
+    assert_eq!(start.angular_velocity_to(end, -1.0), Vector3::ZERO);
+    assert_eq!(start.angular_velocity_to(end, real::NAN), Vector3::ZERO);
+}
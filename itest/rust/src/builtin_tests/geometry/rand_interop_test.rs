@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::{assert_eq_approx, UnitVector};
+use godot::builtin::{Aabb, Color, Rect2, Vector2, Vector2i, Vector3};
+use godot::engine::{GodotRng, RandomNumberGenerator};
+use godot::obj::NewGd;
+use rand::distributions::{Distribution, Uniform};
+use rand::{Rng, RngCore, SeedableRng};
+
+use crate::framework::itest;
+
+const SAMPLES: usize = 200;
+
+#[itest]
+fn rand_godot_rng_matches_gdscript_sequence() {
+    let mut rng = GodotRng::seed_from_u64(12345);
+
+    let mut reference = RandomNumberGenerator::new_gd();
+    reference.set_seed(12345);
+
+    for _ in 0..10 {
+        assert_eq!(rng.next_u32(), reference.randi());
+    }
+
+    // Shared objects advance the same state.
+    let mut shared = GodotRng::from_gd(reference.clone());
+    shared.next_u32();
+    let expected = {
+        let mut copy = GodotRng::seed_from_u64(12345);
+        (0..12).map(|_| copy.next_u32()).last().unwrap()
+    };
+    assert_eq!(reference.randi(), expected);
+}
+
+#[itest]
+fn rand_standard_vectors_and_colors() {
+    let mut rng = GodotRng::seed_from_u64(1);
+
+    for _ in 0..SAMPLES {
+        let v: Vector3 = rng.gen();
+        assert!([v.x, v.y, v.z].iter().all(|c| (0.0..1.0).contains(c)));
+
+        let color: Color = rng.gen();
+        assert_eq!(color.a, 1.0);
+        assert!([color.r, color.g, color.b]
+            .iter()
+            .all(|c| (0.0..1.0).contains(c)));
+    }
+}
+
+#[itest]
+fn rand_uniform_vectors() {
+    let mut rng = GodotRng::seed_from_u64(2);
+
+    let low = Vector2::new(-1.0, 10.0);
+    let high = Vector2::new(1.0, 20.0);
+    let uniform = Uniform::new(low, high);
+    for v in uniform.sample_iter(&mut rng).take(SAMPLES) {
+        assert!((low.x..high.x).contains(&v.x), "{v:?}");
+        assert!((low.y..high.y).contains(&v.y), "{v:?}");
+    }
+
+    for _ in 0..SAMPLES {
+        let v = rng.gen_range(Vector2i::new(0, 0)..=Vector2i::new(3, 3));
+        assert!((0..=3).contains(&v.x) && (0..=3).contains(&v.y), "{v:?}");
+    }
+}
+
+#[itest]
+fn rand_unit_vectors() {
+    let mut rng = GodotRng::seed_from_u64(3);
+
+    for _ in 0..SAMPLES {
+        let v2: Vector2 = rng.sample(UnitVector);
+        assert_eq_approx!(v2.length(), 1.0);
+
+        let v3: Vector3 = rng.sample(UnitVector);
+        assert_eq_approx!(v3.length(), 1.0);
+    }
+}
+
+#[itest]
+fn rand_points_in_shapes() {
+    let mut rng = GodotRng::seed_from_u64(4);
+
+    let rect = Rect2::new(Vector2::new(-5.0, 2.0), Vector2::new(3.0, 4.0));
+    let aabb = Aabb::new(Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 0.5, 8.0));
+
+    for _ in 0..SAMPLES {
+        assert!(rect.has_point(rng.sample(rect)));
+        assert!(aabb.has_point(rng.sample(aabb)));
+    }
+}
@@ -96,3 +96,41 @@ fn rect2_inner_equivalence() {
         }
     }
 }
+
+#[itest]
+fn rect2_corners_edges() {
+    let rect = Rect2::from_components(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(
+        rect.corners(),
+        [
+            Vector2::new(1.0, 2.0),
+            Vector2::new(4.0, 2.0),
+            Vector2::new(4.0, 6.0),
+            Vector2::new(1.0, 6.0),
+        ]
+    );
+
+    let edges = rect.edges();
+    assert_eq!(
+        edges[RectSide::Top as usize],
+        (Vector2::new(1.0, 2.0), Vector2::new(4.0, 2.0))
+    );
+    assert_eq!(
+        edges[RectSide::Left as usize],
+        (Vector2::new(1.0, 6.0), Vector2::new(1.0, 2.0))
+    );
+
+    // Each edge moves along the side it names when that side is grown.
+    for side in [
+        RectSide::Left,
+        RectSide::Top,
+        RectSide::Right,
+        RectSide::Bottom,
+    ] {
+        let (from, to) = rect.edges()[side as usize];
+        let (grown_from, grown_to) = rect.grow_side(side, 1.0).edges()[side as usize];
+        assert_eq!((grown_from - from).length(), 1.0, "{side:?}");
+        assert_eq!((grown_to - to).length(), 1.0, "{side:?}");
+    }
+}
@@ -93,3 +93,23 @@ fn rect2i_equiv_unary() {
         }
     }
 }
+
+#[itest]
+fn rect2i_corners_edges() {
+    let rect = Rect2i::from_components(-1, 2, 3, 4);
+
+    assert_eq!(
+        rect.corners(),
+        [
+            Vector2i::new(-1, 2),
+            Vector2i::new(2, 2),
+            Vector2i::new(2, 6),
+            Vector2i::new(-1, 6),
+        ]
+    );
+    assert_eq!(
+        rect.edges()[RectSide::Bottom as usize],
+        (Vector2i::new(2, 6), Vector2i::new(-1, 6))
+    );
+    assert!(rect.corners().iter().all(|&c| rect.expand(c) == rect));
+}
@@ -94,3 +94,31 @@ fn transform2d_xform_equiv() {
         "operator: Transform2D * Rect2 (2)"
     );
 }
+
+#[itest]
+fn transform2d_xform_inv_equiv() {
+    let transform = Transform2D::from_angle_origin(0.8, Vector2::new(3.0, -4.0));
+    let vec = Vector2::new(1.0, 2.0);
+
+    assert_eq_approx!(
+        vec * transform,
+        vec.to_variant()
+            .evaluate(&transform.to_variant(), VariantOperator::Multiply)
+            .unwrap()
+            .to::<Vector2>(),
+        "operator: Vector2 * Transform2D"
+    );
+    assert_eq_approx!(transform * vec * transform, vec);
+
+    let rect_2 = Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+
+    assert_eq_approx!(
+        rect_2 * transform,
+        rect_2
+            .to_variant()
+            .evaluate(&transform.to_variant(), VariantOperator::Multiply)
+            .unwrap()
+            .to::<Rect2>(),
+        "operator: Rect2 * Transform2D"
+    );
+}
@@ -82,3 +82,47 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_xform_inv_equiv() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7),
+        Vector3::new(5.0, -1.0, 2.0),
+    );
+
+    let vec = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq_approx!(
+        vec * transform,
+        vec.to_variant()
+            .evaluate(&transform.to_variant(), VariantOperator::Multiply)
+            .unwrap()
+            .to::<Vector3>(),
+        "operator: Vector3 * Transform3D"
+    );
+    assert_eq_approx!(transform * vec * transform, vec);
+
+    let aabb = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+
+    assert_eq_approx!(
+        aabb * transform,
+        aabb.to_variant()
+            .evaluate(&transform.to_variant(), VariantOperator::Multiply)
+            .unwrap()
+            .to::<Aabb>(),
+        "operator: Aabb * Transform3D"
+    );
+
+    let plane = Plane::new(Vector3::new(1.0, 2.0, 3.0).normalized(), 5.0);
+
+    assert_eq_approx!(
+        plane * transform,
+        plane
+            .to_variant()
+            .evaluate(&transform.to_variant(), VariantOperator::Multiply)
+            .unwrap()
+            .to::<Plane>(),
+        "operator: Plane * Transform3D"
+    );
+    assert_eq_approx!((transform * plane) * transform, plane);
+}
@@ -7,9 +7,17 @@
 
 mod geometry {
     mod basis_test;
+    #[cfg(feature = "glam")]
+    mod glam_interop_test;
+    #[cfg(feature = "mint")]
+    mod mint_interop_test;
+    #[cfg(feature = "nalgebra")]
+    mod nalgebra_interop_test;
     mod plane_test;
     mod projection_test;
     mod quaternion_test;
+    #[cfg(feature = "rand")]
+    mod rand_interop_test;
     mod rect2_test;
     mod rect2i_test;
     mod transform2d_test;
@@ -22,6 +30,8 @@ mod containers {
     mod callable_test;
     mod dictionary_test;
     mod packed_array_test;
+    #[cfg(feature = "rayon")]
+    mod parallel_test;
     mod rid_test;
     mod signal_test;
     mod variant_test;
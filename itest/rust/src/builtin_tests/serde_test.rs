@@ -6,7 +6,13 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::{array, Array, GString, NodePath, StringName, Vector2i};
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{
+    array, dict, varray, Array, Dictionary, GString, NodePath, PackedInt32Array, PackedStringArray,
+    StringName, Variant, VariantType, Vector2i,
+};
+use godot::engine::Node;
+use godot::obj::NewAlloc;
 use serde::{Deserialize, Serialize};
 
 fn serde_roundtrip<T>(value: &T, expected_json: &str)
@@ -78,3 +84,89 @@ fn serde_array_godot_type() {
 
     serde_roundtrip(&value, expected_json)
 }
+
+#[itest]
+fn serde_packed_array() {
+    let value = PackedInt32Array::from(&[1, -2, 3]);
+    serde_roundtrip(&value, "[1,-2,3]");
+
+    let value = PackedStringArray::from(&["a".into(), "b".into()]);
+    serde_roundtrip(&value, r#"["a","b"]"#);
+}
+
+#[itest]
+fn serde_variant() {
+    serde_roundtrip(&Variant::nil(), r#""Nil""#);
+    serde_roundtrip(&3.to_variant(), r#"{"Int":3}"#);
+    serde_roundtrip(&"text".to_variant(), r#"{"String":"text"}"#);
+    serde_roundtrip(
+        &Vector2i::new(1, 2).to_variant(),
+        r#"{"Vector2i":{"x":1,"y":2}}"#,
+    );
+    serde_roundtrip(
+        &varray![true, StringName::from("name")].to_variant(),
+        r#"{"Array":[{"Bool":true},{"StringName":"name"}]}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_unsupported() {
+    let node = Node::new_alloc();
+    assert!(serde_json::to_string(&node.to_variant()).is_err());
+    node.free();
+}
+
+#[itest]
+fn serde_dictionary() {
+    let value = dict! { "key": 1, 2: Vector2i::new(3, 4) };
+
+    let expected_json = r#"[[{"String":"key"},{"Int":1}],[{"Int":2},{"Vector2i":{"x":3,"y":4}}]]"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn json_value_to_variant() {
+    let json = serde_json::json!({
+        "name": "Godot",
+        "version": 4,
+        "ratio": 1.5,
+        "tags": ["engine", null, true],
+    });
+
+    let variant = Variant::from_json_value(&json);
+    let dict = variant.to::<Dictionary>();
+    assert_eq!(dict.get("name"), Some("Godot".to_variant()));
+    assert_eq!(dict.get("version"), Some(4.to_variant()));
+    assert_eq!(dict.get("ratio"), Some(1.5.to_variant()));
+    assert_eq!(
+        dict.get("tags"),
+        Some(varray!["engine", Variant::nil(), true].to_variant())
+    );
+
+    // Integers that don't fit in i64 become floats; floats stay floats.
+    let big = Variant::from_json_value(&serde_json::json!(u64::MAX));
+    assert_eq!(big.get_type(), VariantType::Float);
+    let float = Variant::from_json_value(&serde_json::json!(2.0));
+    assert_eq!(float.get_type(), VariantType::Float);
+
+    assert_eq!(variant.to_json_value().unwrap(), json);
+}
+
+#[itest]
+fn json_value_from_variant() {
+    let dict = dict! {
+        1: PackedInt32Array::from(&[1, 2]),
+        true: StringName::from("name"),
+    };
+    assert_eq!(
+        dict.to_variant().to_json_value().unwrap(),
+        serde_json::json!({ "1": [1, 2], "true": "name" })
+    );
+
+    assert!(Vector2i::new(1, 2).to_variant().to_json_value().is_err());
+    assert!(f64::NAN.to_variant().to_json_value().is_err());
+
+    let bad_key = dict! { (Vector2i::ZERO): 1 };
+    assert!(bad_key.to_variant().to_json_value().is_err());
+}
@@ -5,10 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
-use crate::framework::itest;
-use godot::builtin::GString;
+use crate::framework::{expect_panic, itest};
+use godot::builtin::{gformat, GString, NodePath, StringName};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -96,3 +98,242 @@ fn string_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn string_validate_filename() {
+    assert_eq!(
+        GString::from("../save:slot*1?").validate_filename(),
+        GString::from(".._save_slot_1_")
+    );
+    assert_eq!(
+        GString::from("screenshot.png").validate_filename(),
+        GString::from("screenshot.png")
+    );
+
+    // Special directory names.
+    assert_eq!(GString::from(".").validate_filename(), GString::from("_"));
+    assert_eq!(GString::from("..").validate_filename(), GString::from("__"));
+    assert_eq!(
+        GString::from("...").validate_filename(),
+        GString::from("...")
+    );
+}
+
+#[itest]
+fn string_validate_node_name() {
+    assert_eq!(
+        GString::from("enemy/1:boss.@").validate_node_name(),
+        GString::from("enemy_1_boss__")
+    );
+    assert_eq!(
+        GString::from("Player").validate_node_name(),
+        GString::from("Player")
+    );
+}
+
+#[itest]
+fn string_escape_bbcode() {
+    assert_eq!(
+        GString::from("[b]bold[/b] name").escape_bbcode(),
+        GString::from("[lb]b[rb]bold[lb]/b[rb] name")
+    );
+    assert_eq!(
+        GString::from("plain").escape_bbcode(),
+        GString::from("plain")
+    );
+}
+
+#[itest]
+fn string_to_translation_key() {
+    assert_eq!(
+        GString::from("Start game!").to_translation_key(),
+        GString::from("START_GAME")
+    );
+    assert_eq!(
+        GString::from("  menu -- options/Audio 2 ").to_translation_key(),
+        GString::from("MENU_OPTIONS_AUDIO_2")
+    );
+    assert_eq!(GString::from("!?").to_translation_key(), GString::new());
+}
+
+#[itest]
+fn string_gformat() {
+    let hp = 42;
+    let name = "Ada";
+    assert_eq!(gformat!("{name}: {hp} hp"), GString::from("Ada: 42 hp"));
+    assert_eq!(
+        gformat!("no placeholders"),
+        GString::from("no placeholders")
+    );
+    assert_eq!(gformat!("{:>4}|{:.1}", 7, 2.26), GString::from("   7|2.3"));
+
+    let long = "x".repeat(10_000);
+    assert_eq!(gformat!("{long}").len(), 10_000);
+}
+
+#[itest]
+fn string_gformat_nested() {
+    struct Inner;
+
+    impl std::fmt::Display for Inner {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", gformat!("<{}>", 1))
+        }
+    }
+
+    assert_eq!(gformat!("a{}b", Inner), GString::from("a<1>b"));
+}
+
+#[itest]
+fn string_chars_lossy() {
+    let string = GString::from("héllo ✓");
+    assert_eq!(string.chars().collect::<String>(), "héllo ✓");
+    assert_eq!(string.chars().rev().next(), Some('✓'));
+    assert_eq!(string.char_len(), 7);
+    assert_eq!(string.utf8_len(), "héllo ✓".len());
+
+    assert_eq!(string.as_str_lossy(), "héllo ✓");
+    assert!(matches!(GString::new().as_str_lossy(), Cow::Borrowed("")));
+}
+
+#[itest]
+fn string_starts_ends_with() {
+    let string = GString::from("res://icon.png");
+    assert!(string.starts_with("res://"));
+    assert!(string.starts_with(""));
+    assert!(!string.starts_with("user://"));
+    assert!(string.ends_with(".png"));
+    assert!(!string.ends_with("res://icon.png.import"));
+}
+
+#[itest]
+fn string_eq_str() {
+    let string = GString::from("abc");
+    assert_eq!(string, "abc");
+    assert_eq!("abc", string);
+    assert_ne!(string, "ab");
+    assert_ne!(string, "abcd");
+
+    assert_eq!(StringName::from("name"), "name");
+    assert_ne!(StringName::from("name"), "other");
+    assert_eq!(NodePath::from("a/b"), "a/b");
+    assert_eq!("a/b", NodePath::from("a/b"));
+}
+
+#[itest]
+fn string_push() {
+    let mut string = GString::from("hp");
+    string.push(':');
+    string.push_str(" 42");
+    string += " / ";
+    string += &GString::from("100");
+    string += '!';
+    assert_eq!(string, "hp: 42 / 100!");
+
+    // Copy-on-write: a clone is not affected.
+    let copy = string.clone();
+    string.push('?');
+    assert_eq!(copy, "hp: 42 / 100!");
+}
+
+#[itest]
+fn string_insert_replace_range() {
+    let mut string = GString::from("hello world");
+    string.insert(5, ",");
+    assert_eq!(string, "hello, world");
+    string.insert(string.len(), "!");
+    assert_eq!(string, "hello, world!");
+
+    string.replace_range(7..12, "Godot");
+    assert_eq!(string, "hello, Godot!");
+    string.replace_range(..5, "Hi");
+    assert_eq!(string, "Hi, Godot!");
+    string.replace_range(2.., "");
+    assert_eq!(string, "Hi");
+
+    expect_panic("insert out of bounds", || {
+        GString::from("ab").insert(3, "c");
+    });
+}
+
+#[itest]
+fn string_godot_helpers() {
+    let s = |text: &str| GString::from(text);
+
+    assert_eq!(s("abc").similarity(&s("abc")), 1.0);
+    assert_eq!(s("abc").similarity(&s("xyz")), 0.0);
+
+    assert_eq!(s("file2").natural_nocase_cmp(&s("File10")), Ordering::Less);
+    assert_eq!(s("ABC").nocase_cmp(&s("abc")), Ordering::Equal);
+    assert_eq!(s("ABC").case_cmp(&s("abc")), Ordering::Less);
+
+    assert_eq!(s("-42").parse_int(), Some(-42));
+    assert_eq!(s("4x").parse_int(), None);
+    assert_eq!(s("1.5").parse_float(), Some(1.5));
+    assert_eq!(s("abc").parse_float(), None);
+
+    assert!(s("move_speed").is_valid_identifier());
+    assert!(!s("2fast").is_valid_identifier());
+    assert_eq!(s("move_speed").capitalize(), "Move Speed");
+    assert_eq!(s("move_speed").to_pascal_case(), "MoveSpeed");
+    assert_eq!(s("MoveSpeed").to_snake_case(), "move_speed");
+}
+
+#[itest]
+fn string_godot_path_helpers() {
+    let path = GString::from("res://levels/./one.tscn");
+
+    assert_eq!(path.simplify_path(), "res://levels/one.tscn");
+    assert_eq!(path.simplify_path().base_dir(), "res://levels");
+    assert_eq!(path.file_name(), "one.tscn");
+    assert_eq!(path.extension(), Some(GString::from("tscn")));
+    assert_eq!(GString::from("res://LICENSE").extension(), None);
+    assert_eq!(
+        GString::from("res://levels").path_join(&GString::from("two.tscn")),
+        "res://levels/two.tscn"
+    );
+}
+
+#[itest]
+fn string_godot_escape_helpers() {
+    let text = GString::from("a\n\"b\"");
+    assert_eq!(text.c_escape(), "a\\n\\\"b\\\"");
+    assert_eq!(text.c_escape().c_unescape(), text);
+
+    assert_eq!(
+        GString::from("<a & b>").xml_escape(false),
+        "&lt;a &amp; b&gt;"
+    );
+    assert_eq!(GString::from("a b").uri_encode(), "a%20b");
+    assert_eq!(GString::from("a%20b").uri_decode(), "a b");
+    assert_eq!(GString::from("").md5_text().len(), 32);
+}
+
+#[itest]
+fn string_substr_char_at() {
+    let string = GString::from("añb✓c");
+    assert_eq!(string.substr(1..4), "ñb✓");
+    assert_eq!(string.substr(3..), "✓c");
+    assert_eq!(string.substr(..0), "");
+    assert_eq!(string.char_at(3), Some('✓'));
+    assert_eq!(string.char_at(5), None);
+
+    expect_panic("substr out of bounds", || {
+        GString::from("ab").substr(1..3);
+    });
+}
+
+#[itest]
+fn string_find_split() {
+    let string = GString::from("ä-b-ä-b");
+    assert_eq!(string.find("b"), Some(2));
+    assert_eq!(string.rfind("b"), Some(6));
+    assert_eq!(string.find("ä-b"), Some(0));
+    assert_eq!(string.rfind("ä-b"), Some(4));
+    assert_eq!(string.find("x"), None);
+    assert_eq!(string.find(""), Some(0));
+    assert_eq!(string.rfind(""), Some(7));
+
+    let parts = GString::from("a,,b").split(",");
+    assert_eq!(parts.to_vec_string(), vec!["a", "", "b"]);
+}
@@ -7,8 +7,8 @@
 
 use std::collections::HashSet;
 
-use crate::framework::itest;
-use godot::builtin::{GString, NodePath};
+use crate::framework::{expect_panic, itest};
+use godot::builtin::{npath, GString, NodePath, StringName};
 
 #[itest]
 fn node_path_default() {
@@ -64,3 +64,46 @@ fn node_path_hash() {
     .collect();
     assert_eq!(set.len(), 5);
 }
+
+#[itest]
+fn node_path_parse() {
+    let path = NodePath::parse("Player/Sprite:texture:size").unwrap();
+    assert!(!path.is_absolute());
+    assert_eq!(
+        path.names(),
+        vec![StringName::from("Player"), StringName::from("Sprite")]
+    );
+    assert_eq!(path.subname_count(), 2);
+    assert_eq!(path.subname(1), StringName::from("size"));
+
+    assert!(NodePath::parse("/root/Main").unwrap().is_absolute());
+    assert!(NodePath::parse("").is_ok());
+    assert!(NodePath::parse(":position:x").is_ok());
+    assert!(NodePath::parse("../%Unique").is_ok());
+
+    for invalid in ["A//B", "A/", "//A", "A::b", "A:", "A:b:"] {
+        let err = NodePath::parse(invalid).expect_err(invalid);
+        assert_eq!(err.path(), invalid);
+    }
+}
+
+#[itest]
+fn node_path_join_parent() {
+    let base = npath!("/root/Level");
+    assert_eq!(
+        base.join(&npath!("Player:position")),
+        "/root/Level/Player:position"
+    );
+    assert_eq!(base.join(&npath!("/root/Other")), "/root/Other");
+
+    assert_eq!(
+        npath!("Player/Sprite:texture").parent(),
+        Some(npath!("Player"))
+    );
+    assert_eq!(npath!("Player").parent(), Some(NodePath::default()));
+    assert_eq!(NodePath::default().parent(), None);
+
+    expect_panic("join onto subnames", || {
+        npath!("Player:position").join(&npath!("Child"));
+    });
+}
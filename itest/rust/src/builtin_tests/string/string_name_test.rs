@@ -5,10 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::framework::{assert_eq_self, itest};
-use godot::builtin::{GString, NodePath, StringName};
+use godot::builtin::strings::LazyStringName;
+use godot::builtin::{sname, GString, NodePath, StringName};
 
 #[itest]
 fn string_name_default() {
@@ -83,6 +84,34 @@ fn string_name_transient_ord() {
     assert!(low != high);
 }
 
+#[itest]
+fn string_name_ord_consistent_with_transient_ord() {
+    let a = StringName::from("Alpha");
+    let b = StringName::from("Beta");
+
+    assert_eq!(a.cmp(&b), a.transient_ord().cmp(&b.transient_ord()));
+    assert_eq!(a.cmp(&StringName::from("Alpha")), std::cmp::Ordering::Equal);
+    assert_eq!(
+        StringName::default().cmp(&StringName::from("")),
+        std::cmp::Ordering::Equal
+    );
+}
+
+#[itest]
+fn string_name_map_keys() {
+    let mut hash_map = HashMap::new();
+    hash_map.insert(StringName::from("jump"), 1);
+    hash_map.insert(StringName::from("crouch"), 2);
+    assert_eq!(hash_map.get(&StringName::from("jump")), Some(&1));
+
+    let mut btree_map = BTreeMap::new();
+    btree_map.insert(StringName::from("jump"), 1);
+    btree_map.insert(StringName::from("crouch"), 2);
+    btree_map.insert(StringName::from("jump"), 3);
+    assert_eq!(btree_map.len(), 2);
+    assert_eq!(btree_map.get(&StringName::from("jump")), Some(&3));
+}
+
 #[itest]
 fn string_name_clone() {
     let first = StringName::from("some string");
@@ -141,3 +170,20 @@ fn string_name_from_latin1_with_nul() {
         assert_eq!(a, b);
     }
 }
+
+#[itest]
+fn string_name_lazy() {
+    static NAME: LazyStringName = LazyStringName::new("lazy_name");
+
+    assert_eq!(NAME.as_str(), "lazy_name");
+    assert_eq!(*NAME.get(), StringName::from("lazy_name"));
+    assert!(std::ptr::eq(NAME.get(), NAME.get()));
+}
+
+#[itest]
+fn string_name_sname_macro() {
+    let names: Vec<StringName> = (0..3).map(|_| sname!("jump")).collect();
+
+    assert_eq!(names[0], StringName::from("jump"));
+    assert_eq!(names[0], names[2]);
+}
@@ -0,0 +1,140 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Rect2i, Vector2, Vector2i, Vector3};
+use godot::engine::a_star_grid_2d::DiagonalMode;
+use godot::engine::{AStarGraph2D, AStarGraph3D, AStarGrid};
+
+use crate::framework::itest;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+enum Place {
+    Town,
+    Bridge,
+    Ford,
+    Castle,
+}
+
+fn map() -> AStarGraph2D<Place> {
+    let mut graph = AStarGraph2D::new();
+    graph.add_point(Place::Town, Vector2::new(0.0, 0.0));
+    graph.add_point(Place::Bridge, Vector2::new(5.0, 0.0));
+    graph.add_point(Place::Ford, Vector2::new(0.0, 8.0));
+    graph.add_point(Place::Castle, Vector2::new(5.0, 5.0));
+
+    graph.connect(&Place::Town, &Place::Bridge, true);
+    graph.connect(&Place::Bridge, &Place::Castle, true);
+    graph.connect(&Place::Town, &Place::Ford, true);
+    graph.connect(&Place::Ford, &Place::Castle, true);
+    graph
+}
+
+#[itest]
+fn astar_graph_paths() {
+    let mut graph = map();
+    assert_eq!(graph.len(), 4);
+
+    assert_eq!(
+        graph.find_path(&Place::Town, &Place::Castle),
+        Some(vec![Place::Town, Place::Bridge, Place::Castle])
+    );
+    assert_eq!(
+        graph.find_point_path(&Place::Town, &Place::Castle),
+        Some(vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(5.0, 0.0),
+            Vector2::new(5.0, 5.0),
+        ])
+    );
+
+    graph.set_disabled(&Place::Bridge, true);
+    assert_eq!(
+        graph.find_path(&Place::Town, &Place::Castle),
+        Some(vec![Place::Town, Place::Ford, Place::Castle])
+    );
+
+    graph.disconnect(&Place::Ford, &Place::Castle, true);
+    assert_eq!(graph.find_path(&Place::Town, &Place::Castle), None);
+}
+
+#[itest]
+fn astar_graph_points() {
+    let mut graph = map();
+
+    assert_eq!(
+        graph.closest_point(Vector2::new(4.0, 4.0)),
+        Some(&Place::Castle)
+    );
+    assert_eq!(graph.position(&Place::Ford), Some(Vector2::new(0.0, 8.0)));
+    assert!(graph.are_connected(&Place::Town, &Place::Bridge));
+    assert!(!graph.are_connected(&Place::Town, &Place::Castle));
+
+    assert!(graph.remove_point(&Place::Bridge));
+    assert!(!graph.remove_point(&Place::Bridge));
+    assert!(!graph.contains(&Place::Bridge));
+    assert_eq!(graph.find_path(&Place::Town, &Place::Bridge), None);
+    assert_eq!(graph.len(), 3);
+
+    // Re-adding a key reuses the mapping without creating duplicates.
+    graph.add_point(Place::Town, Vector2::new(1.0, 1.0));
+    assert_eq!(graph.len(), 3);
+    assert_eq!(graph.position(&Place::Town), Some(Vector2::new(1.0, 1.0)));
+}
+
+#[itest]
+fn astar_graph_one_way() {
+    let mut graph = AStarGraph3D::new();
+    graph.add_point("top", Vector3::new(0.0, 10.0, 0.0));
+    graph.add_point("bottom", Vector3::ZERO);
+    graph.connect(&"top", &"bottom", false);
+
+    assert_eq!(
+        graph.find_path(&"top", &"bottom"),
+        Some(vec!["top", "bottom"])
+    );
+    assert_eq!(graph.find_path(&"bottom", &"top"), None);
+    assert!(graph.are_connected(&"top", &"bottom"));
+    assert!(!graph.are_connected(&"bottom", &"top"));
+}
+
+#[itest]
+fn astar_grid_paths() {
+    let mut grid = AStarGrid::new(
+        Rect2i::new(Vector2i::ZERO, Vector2i::new(4, 3)),
+        Vector2::new(16.0, 16.0),
+    );
+    grid.set_diagonal_mode(DiagonalMode::NEVER);
+
+    // Wall in column 1, leaving a gap in the bottom row.
+    grid.set_solid(Vector2i::new(1, 0), true);
+    grid.set_solid(Vector2i::new(1, 1), true);
+    assert!(grid.is_solid(Vector2i::new(1, 0)));
+    assert!(grid.is_solid(Vector2i::new(10, 10)));
+
+    let path = grid
+        .find_path(Vector2i::new(0, 0), Vector2i::new(2, 0))
+        .expect("path around the wall");
+    assert_eq!(path.first(), Some(&Vector2i::new(0, 0)));
+    assert_eq!(path.last(), Some(&Vector2i::new(2, 0)));
+    assert!(path.contains(&Vector2i::new(1, 2)));
+    assert_eq!(path.len(), 7);
+
+    let points = grid
+        .find_point_path(Vector2i::new(0, 0), Vector2i::new(2, 0))
+        .unwrap();
+    assert_eq!(points[2], Vector2::new(0.0, 32.0));
+
+    grid.set_solid(Vector2i::new(1, 2), true);
+    assert_eq!(
+        grid.find_path(Vector2i::new(0, 0), Vector2i::new(2, 0)),
+        None
+    );
+    assert_eq!(
+        grid.find_path(Vector2i::new(0, 0), Vector2i::new(9, 0)),
+        None
+    );
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector2;
+use godot::engine::global::MouseButton;
+use godot::engine::{
+    CanvasHandles, HandleEvent, InputEvent, InputEventMouseButton, InputEventMouseMotion,
+};
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::itest;
+
+fn mouse_button(button: MouseButton, pressed: bool, position: Vector2) -> Gd<InputEvent> {
+    let mut event = InputEventMouseButton::new_gd();
+    event.set_button_index(button);
+    event.set_pressed(pressed);
+    event.set_position(position);
+    event.upcast()
+}
+
+fn mouse_motion(position: Vector2) -> Gd<InputEvent> {
+    let mut event = InputEventMouseMotion::new_gd();
+    event.set_position(position);
+    event.upcast()
+}
+
+#[itest]
+fn canvas_handles_drag() {
+    let mut handles = CanvasHandles::default();
+    handles.set_positions([Vector2::new(10.0, 10.0), Vector2::new(100.0, 10.0)]);
+
+    // Motion without a drag, and clicks away from handles, are not consumed.
+    assert_eq!(
+        handles.handle_input(&mouse_motion(Vector2::new(10.0, 10.0))),
+        None
+    );
+    let miss = mouse_button(MouseButton::LEFT, true, Vector2::new(50.0, 50.0));
+    assert_eq!(handles.handle_input(&miss), None);
+
+    let right = mouse_button(MouseButton::RIGHT, true, Vector2::new(100.0, 10.0));
+    assert_eq!(handles.handle_input(&right), None);
+
+    let press = mouse_button(MouseButton::LEFT, true, Vector2::new(102.0, 12.0));
+    assert_eq!(
+        handles.handle_input(&press),
+        Some(HandleEvent::Pressed { index: 1 })
+    );
+    assert_eq!(handles.dragged(), Some(1));
+
+    let target = Vector2::new(120.0, 40.0);
+    assert_eq!(
+        handles.handle_input(&mouse_motion(target)),
+        Some(HandleEvent::Dragged {
+            index: 1,
+            position: target
+        })
+    );
+    assert_eq!(handles.positions()[1], target);
+
+    let release = mouse_button(MouseButton::LEFT, false, target);
+    assert_eq!(
+        handles.handle_input(&release),
+        Some(HandleEvent::Released {
+            index: 1,
+            position: target
+        })
+    );
+    assert_eq!(handles.dragged(), None);
+}
+
+#[itest]
+fn canvas_handles_topmost() {
+    let mut handles = CanvasHandles::default();
+    handles.set_positions([Vector2::ZERO, Vector2::new(2.0, 0.0)]);
+
+    assert_eq!(handles.handle_at(Vector2::new(1.0, 0.0)), Some(1));
+    assert_eq!(handles.handle_at(Vector2::new(-5.0, 0.0)), Some(0));
+    assert_eq!(handles.handle_at(Vector2::new(50.0, 0.0)), None);
+}
@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{real, Color, Vector2, Vector3};
+use godot::engine::gradient::InterpolationMode;
+use godot::engine::{BakedCurve, BakedCurve2D, BakedCurve3D, BakedGradient};
+
+use crate::framework::itest;
+
+#[itest]
+fn baked_curve_matches_engine() {
+    let baked = BakedCurve::from_points([
+        Vector2::new(0.0, 0.0),
+        Vector2::new(0.5, 2.0),
+        Vector2::new(1.0, -1.0),
+    ]);
+    let curve = baked.as_gd().clone();
+
+    assert_eq!(baked.values().len(), curve.get_bake_resolution() as usize);
+    assert_eq_approx!(baked.sample(0.25), 1.0);
+    assert_eq_approx!(baked.sample(1.5), -1.0);
+
+    for i in 0..=20 {
+        let offset = i as real / 20.0;
+        assert_eq_approx!(
+            baked.sample(offset),
+            curve.sample_baked(offset),
+            "offset: {offset}"
+        );
+    }
+}
+
+#[itest]
+fn baked_curve2d_by_distance() {
+    let baked = BakedCurve2D::from_points([
+        Vector2::new(0.0, 0.0),
+        Vector2::new(10.0, 0.0),
+        Vector2::new(10.0, 5.0),
+    ]);
+
+    assert_eq_approx!(baked.length(), 15.0);
+    assert_eq!(baked.points(), baked.as_gd().get_baked_points().as_slice());
+
+    assert_eq_approx!(baked.sample(4.0), Vector2::new(4.0, 0.0));
+    assert_eq_approx!(baked.sample(12.0), Vector2::new(10.0, 2.0));
+    assert_eq_approx!(baked.sample(100.0), Vector2::new(10.0, 5.0));
+    assert_eq_approx!(baked.sample_ratio(0.0), Vector2::ZERO);
+}
+
+#[itest]
+fn baked_curve3d_by_distance() {
+    let baked = BakedCurve3D::from_points([Vector3::ZERO, Vector3::new(0.0, 0.0, -8.0)]);
+
+    assert_eq_approx!(baked.length(), 8.0);
+    assert_eq_approx!(baked.sample_ratio(0.25), Vector3::new(0.0, 0.0, -2.0));
+}
+
+#[itest]
+fn baked_curve_empty() {
+    let baked = BakedCurve2D::from_points([]);
+
+    assert!(baked.points().is_empty());
+    assert_eq!(baked.length(), 0.0);
+    assert_eq!(baked.sample(1.0), Vector2::ZERO);
+}
+
+#[itest]
+fn baked_gradient_matches_engine() {
+    let stops = [
+        (0.8, Color::from_rgb(0.0, 0.0, 1.0)),
+        (0.0, Color::from_rgb(1.0, 0.0, 0.0)),
+        (0.3, Color::from_rgba(0.2, 0.9, 0.1, 0.5)),
+    ];
+    let mut baked = BakedGradient::from_stops(stops);
+    assert_eq!(baked.offsets(), &[0.0, 0.3, 0.8]);
+    assert_eq!(baked.colors()[0], Color::from_rgb(1.0, 0.0, 0.0));
+
+    for mode in [
+        InterpolationMode::LINEAR,
+        InterpolationMode::CONSTANT,
+        InterpolationMode::CUBIC,
+    ] {
+        let mut gradient = baked.as_gd().clone();
+        gradient.set_interpolation_mode(mode);
+        baked.rebake();
+
+        for i in 0..=20 {
+            let offset = i as f32 / 20.0;
+            assert_eq_approx!(
+                baked.sample(offset),
+                gradient.sample(offset),
+                "mode: {mode:?}, offset: {offset}"
+            );
+        }
+    }
+}
+
+#[cfg(since_min_api = "4.2")]
+#[itest]
+fn baked_gradient_color_spaces() {
+    use godot::engine::gradient::ColorSpace;
+
+    let mut baked = BakedGradient::from_stops([
+        (0.0, Color::from_rgb(1.0, 0.2, 0.0)),
+        (1.0, Color::from_rgb(0.1, 0.4, 0.9)),
+    ]);
+
+    for space in [ColorSpace::LINEAR_SRGB, ColorSpace::OKLAB] {
+        let mut gradient = baked.as_gd().clone();
+        gradient.set_interpolation_color_space(space);
+        baked.rebake();
+
+        for offset in [0.25, 0.5, 0.75] {
+            assert_eq_approx!(
+                baked.sample(offset),
+                gradient.sample(offset),
+                "space: {space:?}, offset: {offset}"
+            );
+        }
+    }
+}
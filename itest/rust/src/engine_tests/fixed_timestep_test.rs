@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::FixedTimestep;
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn fixed_timestep_accumulates() {
+    let mut timestep = FixedTimestep::new(0.25);
+    let mut ticks = 0;
+
+    let alpha = timestep.advance(0.125, |_| ticks += 1);
+    assert_eq!(ticks, 0);
+    assert_eq!(alpha, 0.5);
+
+    let alpha = timestep.advance(0.5, |step| {
+        assert_eq!(step, 0.25);
+        ticks += 1;
+    });
+    assert_eq!(ticks, 2);
+    assert_eq!(alpha, 0.5);
+
+    timestep.reset();
+    assert_eq!(timestep.alpha(), 0.0);
+}
+
+#[itest]
+fn fixed_timestep_max_steps() {
+    let mut timestep = FixedTimestep::from_rate(4.0).with_max_steps_per_frame(3);
+    assert_eq!(timestep.step(), 0.25);
+
+    let mut ticks = 0;
+    let alpha = timestep.advance(10.125, |_| ticks += 1);
+    assert_eq!(ticks, 3);
+    assert_eq!(alpha, 0.5);
+
+    // Negative delta is ignored.
+    let alpha = timestep.advance(-1.0, |_| ticks += 1);
+    assert_eq!(ticks, 3);
+    assert_eq!(alpha, 0.5);
+}
+
+#[itest]
+fn fixed_timestep_non_finite_delta() {
+    let mut timestep = FixedTimestep::new(0.25).with_max_steps_per_frame(3);
+    let mut ticks = 0;
+
+    timestep.advance(0.125, |_| ticks += 1);
+    for delta in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let alpha = timestep.advance(delta, |_| ticks += 1);
+        assert_eq!(ticks, 0);
+        assert_eq!(alpha, 0.5);
+    }
+
+    // Still ticks afterwards.
+    let alpha = timestep.advance(0.125, |_| ticks += 1);
+    assert_eq!(ticks, 1);
+    assert_eq!(alpha, 0.0);
+}
+
+#[itest]
+fn fixed_timestep_invalid_step() {
+    expect_panic("zero step", || {
+        FixedTimestep::new(0.0);
+    });
+}
@@ -0,0 +1,159 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Vector2, Vector3};
+use godot::engine::geometry;
+use godot::engine::geometry_2d::PolyJoinType;
+
+use crate::framework::itest;
+
+fn square(origin: Vector2, size: f32) -> [Vector2; 4] {
+    [
+        origin,
+        origin + Vector2::new(size as _, 0.0),
+        origin + Vector2::new(size as _, size as _),
+        origin + Vector2::new(0.0, size as _),
+    ]
+}
+
+fn polygon_area(polygon: &[Vector2]) -> f32 {
+    let twice_area: f32 = (0..polygon.len())
+        .map(|i| {
+            let a = polygon[i];
+            let b = polygon[(i + 1) % polygon.len()];
+            (a.x * b.y - b.x * a.y) as f32
+        })
+        .sum();
+
+    twice_area.abs() / 2.0
+}
+
+#[itest]
+fn geometry_polygon_booleans() {
+    let a = square(Vector2::ZERO, 2.0);
+    let b = square(Vector2::new(1.0, 1.0), 2.0);
+
+    let intersection = geometry::intersect_polygons(&a, &b);
+    assert_eq!(intersection.len(), 1);
+    assert_eq_approx!(polygon_area(&intersection[0]), 1.0);
+
+    let merged = geometry::merge_polygons(&a, &b);
+    assert_eq!(merged.len(), 1);
+    assert_eq_approx!(polygon_area(&merged[0]), 7.0);
+
+    let clipped = geometry::clip_polygons(&a, &b);
+    assert_eq!(clipped.len(), 1);
+    assert_eq_approx!(polygon_area(&clipped[0]), 3.0);
+
+    let far = square(Vector2::new(10.0, 10.0), 1.0);
+    assert!(geometry::intersect_polygons(&a, &far).is_empty());
+    assert_eq!(geometry::exclude_polygons(&a, &far).len(), 2);
+}
+
+#[itest]
+fn geometry_offset_polygon() {
+    let a = square(Vector2::ZERO, 2.0);
+
+    let grown = geometry::offset_polygon(&a, 1.0, PolyJoinType::MITER);
+    assert_eq!(grown.len(), 1);
+    assert_eq_approx!(polygon_area(&grown[0]), 16.0);
+
+    assert!(geometry::offset_polygon(&a, -2.0, PolyJoinType::MITER).is_empty());
+}
+
+#[itest]
+fn geometry_polyline() {
+    let polygon = square(Vector2::ZERO, 2.0);
+    let line = [Vector2::new(-1.0, 1.0), Vector2::new(3.0, 1.0)];
+
+    let inside = geometry::intersect_polyline_with_polygon(&line, &polygon);
+    assert_eq!(inside.len(), 1);
+
+    let outside = geometry::clip_polyline_with_polygon(&line, &polygon);
+    assert_eq!(outside.len(), 2);
+}
+
+#[itest]
+fn geometry_points_and_hull() {
+    let a = square(Vector2::ZERO, 2.0);
+    assert!(geometry::is_point_in_polygon(Vector2::new(1.0, 1.0), &a));
+    assert!(!geometry::is_point_in_polygon(Vector2::new(3.0, 1.0), &a));
+
+    let mut reversed = a;
+    reversed.reverse();
+    assert_ne!(
+        geometry::is_polygon_clockwise(&a),
+        geometry::is_polygon_clockwise(&reversed)
+    );
+
+    let mut points = a.to_vec();
+    points.push(Vector2::new(1.0, 1.0));
+    let hull = geometry::convex_hull(&points);
+    assert_eq!(hull.len(), 5);
+    assert_eq!(hull.first(), hull.last());
+    assert!(!hull.contains(&Vector2::new(1.0, 1.0)));
+}
+
+#[itest]
+fn geometry_triangulation() {
+    let a = square(Vector2::ZERO, 2.0);
+
+    let triangles = geometry::triangulate_polygon(&a).expect("square is triangulable");
+    assert_eq!(triangles.len(), 2);
+    assert!(triangles.iter().flatten().all(|&i| i < a.len()));
+
+    assert_eq!(geometry::triangulate_polygon(&a[..2]), None);
+
+    let mut points = a.to_vec();
+    points.push(Vector2::new(1.0, 1.0));
+    assert_eq!(geometry::triangulate_delaunay(&points).len(), 4);
+}
+
+#[itest]
+fn geometry_intersections() {
+    let hit = geometry::segment_intersection(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(2.0, 2.0),
+        Vector2::new(0.0, 2.0),
+        Vector2::new(2.0, 0.0),
+    );
+    assert_eq_approx!(hit.unwrap(), Vector2::new(1.0, 1.0));
+
+    let miss = geometry::segment_intersection(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+        Vector2::new(1.0, 1.0),
+    );
+    assert_eq!(miss, None);
+    assert_eq!(
+        geometry::line_intersection(Vector2::ZERO, Vector2::RIGHT, Vector2::UP, Vector2::RIGHT),
+        None
+    );
+
+    let triangle = [
+        Vector3::new(-1.0, 0.0, -1.0),
+        Vector3::new(1.0, 0.0, -1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let hit = geometry::ray_triangle_intersection(Vector3::UP, Vector3::DOWN, triangle);
+    assert_eq_approx!(hit.unwrap(), Vector3::ZERO);
+    assert_eq!(
+        geometry::segment_triangle_intersection(Vector3::UP, Vector3::UP * 2.0, triangle),
+        None
+    );
+
+    let (on_p, on_q) = geometry::closest_points_between_segments(
+        Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, -1.0),
+        Vector3::new(0.0, 1.0, 1.0),
+    );
+    assert_eq_approx!(on_p, Vector3::ZERO);
+    assert_eq_approx!(on_q, Vector3::UP);
+}
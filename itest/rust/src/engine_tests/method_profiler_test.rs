@@ -0,0 +1,92 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{EngineDebugger, EngineProfiler, IEngineProfiler};
+use godot::prelude::*;
+use godot::tools::method_profiler;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ProfiledObject {}
+
+#[godot_api]
+impl ProfiledObject {
+    #[func]
+    fn work(&self, n: i64) -> i64 {
+        (0..n).sum()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=EngineProfiler)]
+struct ItestMethodProfiler {
+    base: Base<EngineProfiler>,
+}
+
+#[godot_api]
+impl IEngineProfiler for ItestMethodProfiler {
+    fn toggle(&mut self, enable: bool, _options: VariantArray) {
+        method_profiler::set_enabled(enable);
+    }
+
+    fn tick(
+        &mut self,
+        _frame_time: f64,
+        _process_time: f64,
+        _physics_time: f64,
+        _physics_frame_time: f64,
+    ) {
+        method_profiler::frame_end();
+    }
+}
+
+#[itest]
+fn method_profiler_register_rust_engine_profiler() {
+    let mut debugger = EngineDebugger::singleton();
+    let name = StringName::from(method_profiler::PROFILER_NAME);
+
+    let profiler = ItestMethodProfiler::new_gd();
+    debugger.register_profiler(name.clone(), profiler.upcast());
+    assert!(debugger.has_profiler(name.clone()));
+
+    debugger.unregister_profiler(name.clone());
+    assert!(!debugger.has_profiler(name));
+}
+
+#[itest]
+fn method_profiler_records_funcs() {
+    let mut obj = ProfiledObject::new_gd();
+
+    // Calls while disabled are not recorded.
+    obj.call("work".into(), &[10.to_variant()]);
+    method_profiler::frame_end();
+    assert!(method_profiler::last_frame().is_empty());
+
+    method_profiler::set_enabled(true);
+    obj.call("work".into(), &[10.to_variant()]);
+    obj.call("work".into(), &[1000.to_variant()]);
+    method_profiler::frame_end();
+
+    let frame = method_profiler::last_frame();
+    let work = frame
+        .iter()
+        .find(|time| time.name == "ProfiledObject::work")
+        .expect("work() recorded");
+    assert_eq!(work.calls, 2);
+    assert!(work.max <= work.total);
+
+    // Next frame starts empty.
+    method_profiler::frame_end();
+    assert!(method_profiler::last_frame()
+        .iter()
+        .all(|time| time.name != "ProfiledObject::work"));
+
+    method_profiler::set_enabled(false);
+    assert!(method_profiler::last_frame().is_empty());
+}
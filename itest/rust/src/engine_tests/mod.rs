@@ -5,11 +5,32 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod astar_test;
+mod canvas_handles_test;
 mod codegen_enums_test;
 mod codegen_test;
+mod curves_test;
+mod fixed_timestep_test;
+mod geometry_test;
 mod gfile_test;
+mod method_profiler_test;
+#[cfg(since_api = "4.2")]
+mod monitor_test;
 mod native_structures_test;
 mod node_test;
+mod noise_sampling_test;
+mod object_meta_test;
+mod object_properties_test;
+mod optional_api_test;
+mod plugin_config_test;
+mod profiling_test;
+mod raw_interface_test;
+mod regex_test;
 mod save_load_test;
+mod scene_diff_test;
+mod script_port_test;
+mod stable_ref_test;
+mod syntax_highlighting_test;
 mod translate_test;
 mod utilities_test;
+mod variant_bytes_test;
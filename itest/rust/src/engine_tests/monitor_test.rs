@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use godot::builtin::meta::ToGodot;
+use godot::engine::Performance;
+use godot::tools;
+
+use crate::framework::itest;
+
+#[itest]
+fn monitor_atomic_and_closure() {
+    static COUNT: AtomicI64 = AtomicI64::new(7);
+
+    let mut performance = Performance::singleton();
+    tools::monitor("itest/count", &COUNT);
+    tools::monitor("itest/ratio", || 0.5);
+
+    assert!(performance.has_custom_monitor("itest/count".into()));
+    assert_eq!(
+        performance.get_custom_monitor("itest/count".into()),
+        7.to_variant()
+    );
+
+    COUNT.store(12, Ordering::Relaxed);
+    assert_eq!(
+        performance.get_custom_monitor("itest/count".into()),
+        12.to_variant()
+    );
+    assert_eq!(
+        performance.get_custom_monitor("itest/ratio".into()),
+        0.5.to_variant()
+    );
+
+    assert!(tools::remove_monitor("itest/count"));
+    assert!(tools::remove_monitor("itest/ratio"));
+    assert!(!performance.has_custom_monitor("itest/count".into()));
+    assert!(!tools::remove_monitor("itest/count"));
+}
+
+#[itest]
+fn monitor_replace() {
+    let mut performance = Performance::singleton();
+    tools::monitor("itest/replaced", || 1);
+    tools::monitor("itest/replaced", || 2);
+
+    assert_eq!(
+        performance.get_custom_monitor("itest/replaced".into()),
+        2.to_variant()
+    );
+
+    assert!(tools::remove_monitor("itest/replaced"));
+}
@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{FastNoiseLite, NoiseExt as _};
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::{expect_panic, itest};
+
+fn noise() -> Gd<FastNoiseLite> {
+    let mut noise = FastNoiseLite::new_gd();
+    noise.set_seed(7);
+    noise.set_frequency(0.1);
+    noise
+}
+
+#[itest]
+fn noise_fill_matches_single_samples() {
+    let noise = noise();
+
+    let mut line = [0.0; 5];
+    noise.fill_1d(2.0, 0.5, &mut line);
+    assert_eq!(line[3], noise.get_noise_1d(3.5));
+
+    let mut grid = vec![0.0; 4 * 3];
+    noise.fill_2d([10.0, 20.0], 2.0, 4, &mut grid);
+    assert_eq!(grid[2 * 4 + 1], noise.get_noise_2d(12.0, 24.0));
+
+    let mut volume = vec![0.0; 2 * 3 * 4];
+    noise.fill_3d([0.0, 0.0, 0.0], 1.0, [2, 3], &mut volume);
+    assert_eq!(volume[3 * 6 + 2 * 2 + 1], noise.get_noise_3d(1.0, 2.0, 3.0));
+
+    expect_panic("slice not a multiple of the row width", || {
+        noise.fill_2d([0.0, 0.0], 1.0, 4, &mut [0.0; 6]);
+    });
+}
+
+#[itest]
+fn noise_image_2d() {
+    let noise = noise();
+
+    let image = noise.image_2d(8, 4).expect("FastNoiseLite produces images");
+    assert_eq!((image.width(), image.height()), (8, 4));
+    assert_eq!(image.as_bytes().len(), 8 * 4);
+    assert_eq!(image.to_f32_vec().len(), 8 * 4);
+
+    // Quantized to 8 bits, so compare with a tolerance of one step.
+    let expected = noise.get_noise_2d(5.0, 3.0);
+    assert!(
+        (image.get(5, 3) - expected).abs() <= 1.0 / 127.5,
+        "{expected}"
+    );
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, StringName, Vector2};
+use godot::engine::{Node, ObjectMetaExt};
+use godot::obj::NewAlloc;
+
+use crate::framework::itest;
+
+#[itest]
+fn object_meta_typed_roundtrip() {
+    let mut node = Node::new_alloc();
+    let pos = Vector2::new(3.0, -4.0);
+
+    node.set_meta_typed("spawn_pos", &pos);
+    node.set_meta_typed("label", &GString::from("boss"));
+
+    let got: Vector2 = node.get_meta_typed("spawn_pos").expect("entry exists");
+    assert_eq!(got, pos);
+
+    let label: GString = node.get_meta_typed("label").expect("entry exists");
+    assert_eq!(label, GString::from("boss"));
+
+    node.free();
+}
+
+#[itest]
+fn object_meta_typed_errors() {
+    let mut node = Node::new_alloc();
+    node.set_meta_typed("count", &7_i64);
+
+    let err = node
+        .get_meta_typed::<i64>("absent")
+        .expect_err("entry does not exist");
+    assert!(err.is_not_found());
+    assert_eq!(err.name(), &StringName::from("absent"));
+
+    let err = node
+        .get_meta_typed::<GString>("count")
+        .expect_err("entry has wrong type");
+    assert!(!err.is_not_found());
+
+    node.free();
+}
+
+#[itest]
+fn object_meta_entries() {
+    let mut node = Node::new_alloc();
+    node.set_meta_typed("a", &1_i64);
+    node.set_meta_typed("b", &2_i64);
+
+    let mut entries: Vec<(String, i64)> = node
+        .meta_entries()
+        .map(|(name, value)| (name.to_string(), value.to::<i64>()))
+        .collect();
+    entries.sort();
+
+    assert_eq!(entries, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+
+    node.free();
+}
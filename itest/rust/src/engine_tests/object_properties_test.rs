@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{StringName, Variant, Vector2};
+use godot::engine::{Node2D, ObjectPropertiesExt};
+use godot::obj::NewAlloc;
+use godot::register::PropertyView;
+
+use crate::framework::itest;
+
+#[derive(PropertyView, PartialEq, Debug)]
+struct Motion {
+    position: Vector2,
+    rotation: f32,
+    #[property(rename = "visible")]
+    shown: bool,
+}
+
+#[itest]
+fn object_properties_get_set_many() {
+    let mut node = Node2D::new_alloc();
+    let names = [
+        StringName::from("position"),
+        StringName::from("visible"),
+        StringName::from("does_not_exist"),
+    ];
+
+    node.set_many(&[
+        (names[0].clone(), Vector2::new(1.0, 2.0).to_variant()),
+        (names[1].clone(), false.to_variant()),
+    ]);
+
+    let values = node.get_many(&names);
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0], Vector2::new(1.0, 2.0).to_variant());
+    assert_eq!(values[1], false.to_variant());
+    assert_eq!(values[2], Variant::nil());
+
+    node.free();
+}
+
+#[itest]
+fn object_properties_view_roundtrip() {
+    let mut node = Node2D::new_alloc();
+    let motion = Motion {
+        position: Vector2::new(-3.0, 4.0),
+        rotation: 0.5,
+        shown: false,
+    };
+
+    node.write_properties(&motion);
+    assert_eq!(node.get_position(), Vector2::new(-3.0, 4.0));
+    assert!(!node.is_visible());
+
+    let read: Motion = node.read_properties().expect("properties convert");
+    assert_eq!(read, motion);
+
+    node.free();
+}
+
+#[derive(PropertyView)]
+struct WrongType {
+    #[allow(dead_code)]
+    position: bool,
+}
+
+#[itest]
+fn object_properties_view_bad_type() {
+    let node = Node2D::new_alloc();
+
+    let result = node.read_properties::<WrongType>();
+    assert!(result.is_err());
+
+    node.free();
+}
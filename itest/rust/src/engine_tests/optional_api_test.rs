@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{ApiVersion, Engine, EngineExt, Node, OptionalApi};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn engine_has_class() {
+    let engine = Engine::singleton();
+
+    assert!(engine.has_class("Node"));
+    assert!(engine.has_class("Engine"));
+    assert!(!engine.has_class("NoSuchClass"));
+}
+
+#[itest]
+fn engine_has_feature() {
+    let engine = Engine::singleton();
+
+    assert!(engine.has_feature("debug") || engine.has_feature("release"));
+    assert!(!engine.has_feature("no_such_feature"));
+}
+
+#[itest]
+fn engine_api_version() {
+    let version = Engine::api_version();
+    let info = Engine::singleton().get_version_info();
+
+    assert_eq!(info.get("major"), Some(version.major.to_variant()));
+    assert_eq!(info.get("minor"), Some(version.minor.to_variant()));
+    assert!(version.is_at_least(4, 0));
+    assert!(!version.is_at_least(5, 0));
+
+    // Without GDEXT_MIN_GODOT_API, gdext refuses to load in older engines.
+    assert!(version >= ApiVersion::compiled() || godot::sys::is_runtime_older_than_api());
+}
+
+#[itest]
+fn optional_api_instantiate() {
+    let api = OptionalApi::<Node>::detect();
+    assert!(api.is_available());
+
+    let node = api.instantiate().expect("Node is instantiable");
+    node.free();
+}
+
+#[itest]
+fn optional_api_singleton() {
+    let engine = OptionalApi::<Engine>::detect().singleton();
+    assert!(engine.is_some());
+
+    // Node is available, but not a singleton.
+    let node = OptionalApi::<Node>::detect().singleton();
+    assert!(node.is_none());
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::engine::PluginConfig;
+
+use crate::framework::itest;
+
+fn remove_config_file(plugin_name: &str) {
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    let file_path = godot_path.join(format!(".godot/plugins/{plugin_name}.cfg"));
+    std::fs::remove_file(&file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove config file: {}", file_path.display()));
+}
+
+#[itest]
+fn plugin_config_roundtrip() {
+    const NAME: &str = "itest_plugin_config";
+
+    let mut config = PluginConfig::open(NAME).expect("open new config");
+    assert_eq!(config.get::<i64>("grid", "size"), None);
+    assert_eq!(config.get_or("grid", "size", 16_i64), 16);
+
+    config.set("grid", "size", &32_i64);
+    config.set("grid", "label", &GString::from("cells"));
+    config.save().expect("save config");
+
+    let config = PluginConfig::open(NAME).expect("open saved config");
+    assert_eq!(config.get::<i64>("grid", "size"), Some(32));
+    assert_eq!(config.get::<GString>("grid", "label"), Some("cells".into()));
+
+    // Type mismatch is treated as absent.
+    assert_eq!(config.get::<GString>("grid", "size"), None);
+
+    remove_config_file(NAME);
+}
+
+#[itest]
+fn plugin_config_migrate() {
+    fn rename_size(config: &mut PluginConfig) {
+        if let Some(size) = config.get::<i64>("grid", "size") {
+            config.set("grid", "cell_size", &size);
+            config.erase("grid", "size");
+        }
+    }
+
+    fn double_size(config: &mut PluginConfig) {
+        let size = config.get_or("grid", "cell_size", 0_i64);
+        config.set("grid", "cell_size", &(size * 2));
+    }
+
+    let mut config = PluginConfig::open_path("user://itest_plugin_migrate.cfg").unwrap();
+    config.set("grid", "size", &8_i64);
+    assert_eq!(config.version(), 0);
+
+    assert!(config.migrate(&[rename_size]));
+    assert_eq!(config.version(), 1);
+    assert_eq!(config.get::<i64>("grid", "size"), None);
+    assert_eq!(config.get::<i64>("grid", "cell_size"), Some(8));
+
+    // Already applied migrations are skipped.
+    assert!(config.migrate(&[rename_size, double_size]));
+    assert_eq!(config.version(), 2);
+    assert_eq!(config.get::<i64>("grid", "cell_size"), Some(16));
+
+    assert!(!config.migrate(&[rename_size, double_size]));
+    assert_eq!(config.get::<i64>("grid", "cell_size"), Some(16));
+}
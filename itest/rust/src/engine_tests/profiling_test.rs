@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::time::Duration;
+
+use godot::engine::profiling;
+
+use crate::framework::itest;
+
+#[itest]
+fn profiling_scopes_per_frame() {
+    profiling::frame_begin();
+    {
+        let _outer = profiling::scope("itest_outer");
+        let _inner = profiling::scope("itest_inner");
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    // Not published before the frame ends.
+    assert_eq!(profiling::last_frame_time("itest_outer"), None);
+    profiling::frame_end();
+
+    let outer = profiling::last_frame_time("itest_outer").expect("scope recorded");
+    let inner = profiling::last_frame_time("itest_inner").expect("scope recorded");
+    let frame = profiling::last_frame_time(profiling::FRAME_SCOPE).expect("frame recorded");
+    assert!(outer >= Duration::from_millis(1));
+    assert!(outer >= inner);
+    assert!(frame >= outer);
+
+    // Known scopes that did not run are reported as zero.
+    profiling::frame_begin();
+    profiling::frame_end();
+    assert_eq!(
+        profiling::last_frame_time("itest_outer"),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(profiling::last_frame_time("itest_unknown"), None);
+}
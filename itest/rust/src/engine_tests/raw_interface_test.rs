@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ClassName;
+use godot::engine::raw_interface::{self, GDExtensionConstObjectPtr};
+use godot::engine::Node;
+use godot::obj::{GodotClass, NewAlloc};
+
+use crate::framework::itest;
+
+type ObjectGetInstanceId = Option<unsafe extern "C" fn(p_object: GDExtensionConstObjectPtr) -> u64>;
+
+#[itest]
+fn raw_interface_get_fn() {
+    let get_instance_id: ObjectGetInstanceId =
+        unsafe { raw_interface::get_interface_fn("object_get_instance_id") };
+    let get_instance_id = get_instance_id.expect("object_get_instance_id exists");
+
+    let node = Node::new_alloc();
+    let id = unsafe { get_instance_id(raw_interface::object_ptr(&node)) };
+    assert_eq!(id as i64, node.instance_id().to_i64());
+    node.free();
+}
+
+#[itest]
+fn raw_interface_get_fn_missing() {
+    let missing: ObjectGetInstanceId =
+        unsafe { raw_interface::get_interface_fn("no_such_interface_function") };
+
+    assert!(missing.is_none());
+}
+
+#[itest]
+fn raw_interface_class_tag() {
+    assert!(!raw_interface::class_tag(Node::class_name()).is_null());
+    assert!(raw_interface::class_tag(ClassName::from_ascii_cstr(b"NoSuchClass\0")).is_null());
+}
@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::engine::regex::Regex;
+
+use crate::framework::itest;
+
+#[itest]
+fn regex_find() {
+    let re = Regex::new(r"\d+").unwrap();
+    assert!(re.is_match("level 12"));
+    assert!(!re.is_match("no digits"));
+
+    let found = re.find("level 12").unwrap();
+    assert_eq!(found.as_str(), "12");
+    assert_eq!(found.range(), 6..8);
+
+    let all: Vec<&str> = re.find_iter("1, 22, 333").map(|m| m.as_str()).collect();
+    assert_eq!(all, vec!["1", "22", "333"]);
+}
+
+#[itest]
+fn regex_captures() {
+    let re = Regex::new(r"(?<key>\w+)=(?<value>\d+)(%)?").unwrap();
+    assert_eq!(re.captures_len(), 4);
+    assert_eq!(re.capture_names().len(), 2);
+
+    let caps = re.captures("hp=10").unwrap();
+    assert_eq!(&caps[0], "hp=10");
+    assert_eq!(&caps["key"], "hp");
+    assert_eq!(caps.name("value").unwrap().as_str(), "10");
+    assert_eq!(caps.get(3), None);
+
+    let keys: Vec<&str> = re
+        .captures_iter("hp=10 mp=25%")
+        .map(|caps| caps.name("key").unwrap().as_str())
+        .collect();
+    assert_eq!(keys, vec!["hp", "mp"]);
+}
+
+#[itest]
+fn regex_multibyte_offsets() {
+    let re = Regex::new("é+").unwrap();
+    let text = "café éé";
+
+    let matches: Vec<_> = re.find_iter(text).map(|m| m.range()).collect();
+    assert_eq!(matches, vec![3..5, 6..10]);
+    assert_eq!(&text[matches[1].clone()], "éé");
+}
+
+#[itest]
+fn regex_replace() {
+    let re = Regex::new(r"(\w+)@(\w+)").unwrap();
+    assert_eq!(re.replace("a@b c@d", "$2@$1"), GString::from("b@a c@d"));
+    assert_eq!(re.replace_all("a@b c@d", "$2@$1"), GString::from("b@a d@c"));
+}
+
+#[itest]
+fn regex_invalid() {
+    let err = Regex::new("(unclosed").unwrap_err();
+    assert_eq!(err.pattern(), "(unclosed");
+}
@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{NodePath, Vector2};
+use godot::engine::{Node, Node2D, Node3D, NodeExt, SceneChange, SceneDiff};
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::itest;
+
+fn make_tree(child_pos: Vector2) -> Gd<Node> {
+    let mut root = Node::new_alloc();
+    root.set_name("Root".into());
+
+    let mut child = Node2D::new_alloc();
+    child.set_name("Child".into());
+    child.set_position(child_pos);
+    root.add_child(child.upcast());
+
+    root
+}
+
+#[itest]
+fn scene_diff_equal_trees() {
+    let a = make_tree(Vector2::new(1.0, 2.0));
+    let b = make_tree(Vector2::new(1.0, 2.0));
+
+    let diff = SceneDiff::compute(&a, &b);
+    assert!(diff.is_empty(), "unexpected changes: {:?}", diff.changes());
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn scene_diff_property_change() {
+    let a = make_tree(Vector2::new(1.0, 2.0));
+    let b = make_tree(Vector2::new(5.0, 6.0));
+
+    let diff = SceneDiff::compute(&a, &b);
+    assert_eq!(diff.changes().len(), 1);
+
+    match &diff.changes()[0] {
+        SceneChange::SetProperty { path, property, .. } => {
+            assert_eq!(path, &NodePath::from("Child"));
+            assert_eq!(property.to_string(), "position");
+        }
+        other => panic!("unexpected change: {other}"),
+    }
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn scene_diff_apply_patch() {
+    let mut a = make_tree(Vector2::new(1.0, 2.0));
+    let mut b = make_tree(Vector2::new(5.0, 6.0));
+
+    // Add a subtree to `b`.
+    let mut extra = Node3D::new_alloc();
+    extra.set_name("Extra".into());
+    let mut nested = Node::new_alloc();
+    nested.set_name("Nested".into());
+    extra.add_child(nested);
+    b.add_child(extra.upcast());
+
+    let diff = SceneDiff::compute(&a, &b);
+    diff.apply(&mut a).expect("patch succeeds");
+
+    let child = a.get_node_as::<Node2D>("Child");
+    assert_eq!(child.get_position(), Vector2::new(5.0, 6.0));
+    assert!(a.has_node(NodePath::from("Extra/Nested")));
+
+    let remaining = SceneDiff::compute(&a, &b);
+    assert!(
+        remaining.is_empty(),
+        "unexpected changes: {:?}",
+        remaining.changes()
+    );
+
+    // Removal: patch `b` back towards a tree without `Extra`.
+    let c = make_tree(Vector2::new(5.0, 6.0));
+    SceneDiff::compute(&b, &c)
+        .apply(&mut b)
+        .expect("patch succeeds");
+    assert!(!b.has_node(NodePath::from("Extra")));
+
+    a.free();
+    b.free();
+    c.free();
+}
+
+#[itest]
+fn scene_diff_removed_property() {
+    let mut a = make_tree(Vector2::new(1.0, 2.0));
+    let b = make_tree(Vector2::new(1.0, 2.0));
+
+    let mut child = a.get_node_as::<Node>("Child");
+    child.set_meta("note".into(), 5.to_variant());
+
+    let diff = SceneDiff::compute(&a, &b);
+    assert_eq!(diff.changes().len(), 1);
+
+    match &diff.changes()[0] {
+        SceneChange::RemoveProperty {
+            path,
+            property,
+            old_value,
+        } => {
+            assert_eq!(path, &NodePath::from("Child"));
+            assert_eq!(property.to_string(), "metadata/note");
+            assert_eq!(old_value, &5.to_variant());
+        }
+        other => panic!("unexpected change: {other}"),
+    }
+
+    diff.apply(&mut a).expect("patch succeeds");
+    assert!(!child.has_meta("note".into()));
+    assert!(SceneDiff::compute(&a, &b).is_empty());
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn scene_diff_display_paths() {
+    let mut a = make_tree(Vector2::new(1.0, 2.0));
+    let mut b = make_tree(Vector2::new(5.0, 6.0));
+
+    a.set_meta("old".into(), 1.to_variant());
+
+    let mut extra = Node::new_alloc();
+    extra.set_name("Extra".into());
+    b.add_child(extra);
+
+    let lines: Vec<String> = SceneDiff::compute(&a, &b)
+        .changes()
+        .iter()
+        .map(|change| change.to_string())
+        .collect();
+
+    assert_eq!(
+        lines,
+        [
+            "- :metadata/old: 1",
+            "~ Child:position: (1, 2) -> (5, 6)",
+            "+ Extra (Node)",
+        ]
+    );
+
+    a.free();
+    b.free();
+}
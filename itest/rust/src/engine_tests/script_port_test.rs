@@ -0,0 +1,47 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{load, RustSkeleton, Script};
+
+use crate::framework::itest;
+
+#[itest]
+fn rust_skeleton_from_gdscript() {
+    let script = load::<Script>("res://ScriptPortFixture.gd");
+    let skeleton = RustSkeleton::from_script(&script);
+
+    assert_eq!(skeleton.struct_name(), "ScriptPortFixture");
+    assert_eq!(skeleton.base_class(), "Node2D");
+
+    let code = skeleton.to_string();
+    let expected_snippets = [
+        "#[class(init, base=Node2D)]",
+        "pub struct ScriptPortFixture {",
+        "    #[export]\n    #[init(default = 200.0)]\n    speed: f64,",
+        "    #[export]\n    target: Option<Gd<Node>>,",
+        "    #[var]\n    #[init(default = 3)]\n    health: i64,",
+        "    base: Base<Node2D>,",
+        "impl INode2D for ScriptPortFixture {\n    fn ready(&mut self) {",
+        "    #[signal]\n    fn died(cause: GString);",
+        "    #[func]\n    fn take_damage(&mut self, amount: i64) -> bool {",
+    ];
+
+    for snippet in expected_snippets {
+        assert!(
+            code.contains(snippet),
+            "generated code lacks `{snippet}`:\n{code}"
+        );
+    }
+}
+
+#[itest]
+fn rust_skeleton_struct_name() {
+    let script = load::<Script>("res://ScriptPortFixture.gd");
+    let skeleton = RustSkeleton::from_script(&script).with_struct_name("Player");
+
+    assert!(skeleton.to_string().contains("impl INode2D for Player {"));
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::{FromGodot, ToGodot};
+use godot::builtin::GString;
+use godot::engine::{Node, Node2D, StableId, StableRef};
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::itest;
+
+/// Builds `root -> level -> door`, where the door has the given stable ID.
+fn make_scene(door_id: &StableId) -> (Gd<Node>, Gd<Node2D>) {
+    let mut root = Node::new_alloc();
+    let mut level = Node::new_alloc();
+    let door = Node2D::new_alloc();
+
+    door_id.assign(&door);
+    level.add_child(door.clone().upcast());
+    root.add_child(level);
+
+    (root, door)
+}
+
+#[itest]
+fn stable_id_assign_ensure() {
+    let mut node = Node::new_alloc();
+    assert_eq!(StableId::of(&node), None);
+
+    let generated = StableId::ensure(&node);
+    assert_eq!(generated.as_gstring().len(), 32);
+    assert_eq!(StableId::of(&node), Some(generated.clone()));
+    assert_eq!(StableId::ensure(&node), generated);
+    assert_ne!(StableId::generate(), StableId::generate());
+
+    let id = StableId::new("boss_door");
+    id.assign(&node);
+    assert_eq!(StableId::of(&node), Some(id));
+
+    node.free();
+}
+
+#[itest]
+fn stable_ref_resolve_across_reload() {
+    let id = StableId::new("door");
+    let mut stable_ref = StableRef::<Node2D>::new(id.clone());
+
+    let (root, door) = make_scene(&id);
+    assert_eq!(stable_ref.resolve(&root), Some(door.clone()));
+    assert_eq!(stable_ref.resolve(&root), Some(door));
+    root.free();
+
+    // Reloaded scene: new objects, same IDs.
+    let (root, door) = make_scene(&id);
+    assert_eq!(stable_ref.resolve(&root), Some(door.clone()));
+
+    let mut door_as_node = StableRef::<Node>::new(id);
+    assert_eq!(door_as_node.resolve(&root), Some(door.upcast()));
+
+    let mut wrong_class = StableRef::<Node2D>::new(StableId::new("missing"));
+    assert_eq!(wrong_class.resolve(&root), None);
+    root.free();
+}
+
+#[itest]
+fn stable_ref_outside_subtree() {
+    let id = StableId::new("door");
+    let (root, door) = make_scene(&id);
+    let other_root = Node::new_alloc();
+
+    let mut stable_ref = StableRef::to_node(&door);
+    assert_eq!(stable_ref.resolve(&other_root), None);
+    assert_eq!(stable_ref.resolve(&root), Some(door));
+
+    root.free();
+    other_root.free();
+}
+
+#[itest]
+fn stable_ref_conversion() {
+    let stable_ref = StableRef::<Node>::new(StableId::new("player"));
+    assert_eq!(stable_ref.to_godot(), GString::from("player"));
+    assert_eq!(StableRef::<Node>::from_godot("player".into()), stable_ref);
+
+    let unset = StableRef::<Node>::from_godot(GString::new());
+    assert!(!unset.is_set());
+    assert_eq!(unset.to_godot(), GString::new());
+}
@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{dict, Color, Dictionary};
+use godot::engine::LineHighlighting;
+
+use crate::framework::itest;
+
+#[itest]
+fn line_highlighting_to_dictionary() {
+    let mut highlighting = LineHighlighting::new();
+    assert!(highlighting.is_empty());
+
+    highlighting
+        .color_from(8, Color::WHITE)
+        .color_from(0, Color::YELLOW)
+        .color_from(8, Color::RED);
+
+    let dict = highlighting.to_dictionary();
+    let columns: Vec<i64> = dict.keys_array().iter_shared().map(|k| k.to()).collect();
+    assert_eq!(columns, vec![0, 8]);
+
+    let first: Dictionary = dict.get(0).unwrap().to();
+    let second: Dictionary = dict.get(8).unwrap().to();
+    assert_eq!(first, dict! { "color": Color::YELLOW });
+    assert_eq!(second, dict! { "color": Color::RED });
+}
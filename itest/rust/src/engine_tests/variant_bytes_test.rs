@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{dict, Dictionary, GString, Variant, Vector2i};
+use godot::engine::utilities;
+use godot::engine::variant_bytes::{self, VariantReader, VariantWriter};
+
+use crate::framework::itest;
+
+#[itest]
+fn variant_bytes_roundtrip() {
+    let value = dict! { "level": 3, "spawn": Vector2i::new(4, 5) };
+
+    let bytes = variant_bytes::encode(&value);
+    assert_eq!(bytes, utilities::var_to_bytes(value.to_variant()));
+
+    let back: Dictionary = variant_bytes::decode(bytes.as_slice()).unwrap();
+    assert_eq!(back, value);
+
+    let nil = variant_bytes::encode(&Variant::nil());
+    assert_eq!(
+        variant_bytes::decode::<Variant>(nil.as_slice()).unwrap(),
+        Variant::nil()
+    );
+}
+
+#[itest]
+fn variant_bytes_errors() {
+    let bytes = variant_bytes::encode(&42_i64);
+
+    let err = variant_bytes::decode::<GString>(bytes.as_slice()).unwrap_err();
+    assert!(err.is_type_mismatch());
+
+    let truncated = &bytes.as_slice()[..2];
+    assert!(variant_bytes::decode::<i64>(truncated).is_err());
+
+    let mut trailing = bytes.as_slice().to_vec();
+    trailing.push(0);
+    let err = variant_bytes::decode::<i64>(&trailing).unwrap_err();
+    assert!(!err.is_type_mismatch());
+}
+
+#[itest]
+fn variant_bytes_stream() {
+    let mut writer = VariantWriter::new(Vec::new());
+    writer.write(&7_i64).unwrap();
+    writer.write(&GString::from("seven")).unwrap();
+    let buffer = writer.into_inner();
+
+    let mut reader = VariantReader::new(buffer.as_slice());
+    assert_eq!(reader.read::<i64>().unwrap(), Some(7));
+    assert_eq!(reader.read::<GString>().unwrap(), Some("seven".into()));
+    assert_eq!(reader.read::<i64>().unwrap(), None);
+}
+
+#[itest]
+fn variant_bytes_stream_max_len() {
+    // Length prefix of 4 GiB, without payload.
+    let huge = u32::MAX.to_le_bytes();
+    let mut reader = VariantReader::new(huge.as_slice());
+    let err = reader.read::<i64>().unwrap_err();
+    assert!(!err.is_type_mismatch());
+
+    let mut writer = VariantWriter::new(Vec::new());
+    writer.write(&GString::from("a longer string")).unwrap();
+    let buffer = writer.into_inner();
+
+    let mut reader = VariantReader::new(buffer.as_slice()).with_max_len(8);
+    assert!(reader.read::<GString>().is_err());
+
+    // Stream ends before the announced length.
+    let truncated = &buffer[..buffer.len() - 1];
+    let mut reader = VariantReader::new(truncated);
+    assert!(reader.read::<GString>().is_err());
+}
@@ -5,7 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::init::{gdextension, ExtensionLibrary};
+use godot::engine::{Node, Node3D};
+use godot::init::{gdextension, preload_methods, ExtensionLibrary, InitLevel};
 
 mod benchmarks;
 mod builtin_tests;
@@ -19,4 +20,12 @@ mod register_tests;
 // Entry point
 
 #[gdextension(entry_point=itest_init)]
-unsafe impl ExtensionLibrary for framework::IntegrationTests {}
+unsafe impl ExtensionLibrary for framework::IntegrationTests {
+    // Exercises preloading with lazy-function-tables; no-op otherwise.
+    fn on_level_init(level: InitLevel) {
+        if level == InitLevel::Scene {
+            preload_methods::<Node>();
+            preload_methods::<Node3D>();
+        }
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Argument checks only run in debug builds.
+#![cfg(debug_assertions)]
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{real, Basis, Transform3D, Vector3};
+use godot::engine::global::Side;
+use godot::engine::{Control, Node, Node3D};
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn debug_validation_freed_object_arg() {
+    let mut parent = Node::new_alloc();
+    let child = Node::new_alloc();
+    child.clone().free();
+
+    expect_panic("freed object passed to add_child()", || {
+        parent.add_child(child);
+    });
+
+    parent.free();
+}
+
+#[itest]
+fn debug_validation_nan_transform() {
+    let mut node = Node3D::new_alloc();
+    let transform = Transform3D::new(Basis::IDENTITY, Vector3::new(real::NAN, 0.0, 0.0));
+
+    expect_panic("NaN transform passed to set_transform()", || {
+        node.set_transform(transform);
+    });
+
+    node.set_transform(Transform3D::IDENTITY);
+    node.free();
+}
+
+#[itest]
+fn debug_validation_arg_checks() {
+    let valid = Transform3D::IDENTITY;
+    assert!(valid.validate_engine_arg().is_ok());
+
+    let infinite = Transform3D::new(Basis::IDENTITY, Vector3::new(0.0, real::INFINITY, 0.0));
+    assert!(infinite.validate_engine_arg().is_err());
+
+    assert!(Side::LEFT.validate_engine_arg().is_ok());
+
+    let control = Control::new_alloc();
+    assert!(Some(control.clone()).validate_engine_arg().is_ok());
+    control.clone().free();
+    assert!(Some(control).validate_engine_arg().is_err());
+    assert!(None::<Gd<Control>>.validate_engine_arg().is_ok());
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, Vector3};
+use godot::engine::dynamic_call::call;
+use godot::engine::Node3D;
+use godot::obj::NewAlloc;
+
+use crate::framework::itest;
+
+#[itest]
+fn dynamic_call_typed() {
+    let node = Node3D::new_alloc();
+    let pos = Vector3::new(1.0, 2.0, 3.0);
+
+    call!(node, set_position(pos) -> ()).expect("set_position() exists");
+    let result: Vector3 = call!(node, get_position()).expect("get_position() exists");
+    assert_eq!(result, pos);
+
+    node.free();
+}
+
+#[itest]
+fn dynamic_call_method_not_found() {
+    let node = Node3D::new_alloc();
+
+    let err = call!(node, no_such_method(1, 2) -> ()).expect_err("method does not exist");
+    assert_eq!(err.method_name(), "no_such_method");
+
+    node.free();
+}
+
+#[itest]
+fn dynamic_call_bad_return_type() {
+    let node = Node3D::new_alloc();
+
+    let err = call!(node, get_position() -> GString).expect_err("Vector3 is not a GString");
+    assert!(std::error::Error::source(&err).is_some());
+
+    node.free();
+}
+
+#[itest]
+fn dynamic_call_freed_object() {
+    let node = Node3D::new_alloc();
+    node.clone().free();
+
+    call!(node, get_position() -> Vector3).expect_err("object is dead");
+}
+
+// Generated `try_*` engine methods share the error type with `call!`.
+#[cfg(feature = "try-methods")]
+mod try_methods {
+    use godot::builtin::meta::ToGodot;
+    use godot::builtin::{GString, Vector3};
+    use godot::engine::Node3D;
+    use godot::obj::NewAlloc;
+
+    use crate::framework::itest;
+
+    #[itest]
+    fn try_method_ptrcall_ok() {
+        let mut node = Node3D::new_alloc();
+        let pos = Vector3::new(1.0, 2.0, 3.0);
+
+        node.try_set_position(pos).expect("set_position() succeeds");
+        let result = node.try_get_position().expect("get_position() succeeds");
+        assert_eq!(result, pos);
+
+        node.free();
+    }
+
+    #[itest]
+    fn try_method_varcall_rejected() {
+        let mut node = Node3D::new_alloc();
+
+        let err = node
+            .try_call("set_position".into(), &[GString::from("up").to_variant()])
+            .expect_err("GString is not a Vector3");
+        assert_eq!(err.method_name(), "call");
+        assert!(!err.is_object_freed());
+
+        node.try_call("set_position".into(), &[])
+            .expect_err("too few arguments");
+
+        node.free();
+    }
+}
@@ -7,11 +7,16 @@
 
 mod base_test;
 mod class_rename_test;
+#[cfg(feature = "debug-validation")]
+mod debug_validation_test;
+mod dynamic_call_test;
 mod object_swap_test;
 mod object_test;
 mod onready_test;
+mod process_priority_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
 mod singleton_test;
+mod smoothed_test;
 mod virtual_methods_test;
@@ -154,6 +154,27 @@ fn object_instance_id_when_freed() {
     });
 }
 
+#[itest]
+fn object_if_alive() {
+    let mut node: Gd<Node3D> = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    let pos = Vector3::new(1.0, 2.0, 3.0);
+    node.if_alive_mut(|node| node.set_position(pos))
+        .expect("object is alive");
+    assert_eq!(node.if_alive(|node| node.get_position()).ok(), Some(pos));
+
+    node.clone().free();
+
+    let err = node
+        .if_alive(|node| node.get_position())
+        .expect_err("if_alive() on dead object");
+    assert_eq!(err.instance_id(), id);
+
+    node.if_alive_mut(|node| node.set_position(pos))
+        .expect_err("if_alive_mut() on dead object");
+}
+
 #[itest]
 fn object_from_invalid_instance_id() {
     let id = InstanceId::try_from_i64(0xDEADBEEF).unwrap();
@@ -623,6 +644,22 @@ fn object_engine_bad_downcast() {
     object2.free();
 }
 
+#[itest]
+fn object_engine_bad_downcast_error() {
+    let object: Gd<Object> = Object::new_alloc();
+    let id = object.instance_id();
+
+    let err = object.clone().try_cast_err::<Node3D>().unwrap_err();
+    assert_eq!(err.from_class(), "Object");
+    assert_eq!(err.to_class().to_string(), "Node3D");
+    assert_eq!(err.instance_id(), id);
+
+    let err = godot::error::Error::from(err);
+    assert_eq!(err.kind(), godot::error::ErrorKind::Cast);
+
+    object.free();
+}
+
 #[itest]
 fn object_engine_accept_polymorphic() {
     let mut node = Camera3D::new_alloc();
@@ -1048,6 +1085,59 @@ fn double_use_reference() {
 #[class(no_init, base = EditorPlugin, editor_plugin, tool)]
 struct CustomEditorPlugin;
 
+// Resource preview generators run only in the editor; declare one to ensure the virtual methods are available.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base = EditorResourcePreviewGenerator, tool)]
+struct CustomPreviewGenerator;
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl godot::engine::IEditorResourcePreviewGenerator for CustomPreviewGenerator {
+    fn handles(&self, type_: GString) -> bool {
+        type_ == GString::from("Resource")
+    }
+
+    fn generate(
+        &self,
+        _resource: Gd<godot::engine::Resource>,
+        size: godot::builtin::Vector2i,
+        _metadata: godot::builtin::Dictionary,
+    ) -> Option<Gd<godot::engine::Texture2D>> {
+        let image = godot::engine::Image::create(
+            size.x,
+            size.y,
+            false,
+            godot::engine::image::Format::RGBA8,
+        )?;
+        let texture = godot::engine::ImageTexture::create_from_image(image)?;
+
+        Some(texture.upcast())
+    }
+}
+
+// Syntax highlighters for the script editor; declare one to ensure the virtual methods are available.
+#[derive(GodotClass)]
+#[class(init, base = EditorSyntaxHighlighter, tool)]
+struct CustomSyntaxHighlighter;
+
+#[godot_api]
+impl godot::engine::IEditorSyntaxHighlighter for CustomSyntaxHighlighter {
+    fn get_name(&self) -> GString {
+        GString::from("Custom")
+    }
+
+    fn get_supported_languages(&self) -> godot::builtin::PackedStringArray {
+        godot::builtin::PackedStringArray::from(&[GString::from("Custom")])
+    }
+
+    fn get_line_syntax_highlighting(&self, _line: i32) -> godot::builtin::Dictionary {
+        godot::engine::LineHighlighting::new()
+            .color_from(0, godot::builtin::Color::RED)
+            .to_dictionary()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[itest]
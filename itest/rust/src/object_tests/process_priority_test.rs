@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::notify::NodeNotification;
+use godot::engine::{INode, Node};
+use godot::obj::{Base, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, process_priority = -10)]
+struct EarlyNode {
+    base: Base<Node>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, process_priority = i32::MAX - 1)]
+struct LateNodeWithReady {
+    ready_called: bool,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for LateNodeWithReady {
+    fn ready(&mut self) {
+        self.ready_called = true;
+    }
+}
+
+#[itest]
+fn process_priority_applied_before_ready() {
+    let mut early = EarlyNode::new_alloc();
+    assert_eq!(early.get_process_priority(), 0);
+
+    early.notify(NodeNotification::Ready);
+    assert_eq!(early.get_process_priority(), -10);
+    early.free();
+
+    let mut late = LateNodeWithReady::new_alloc();
+    late.notify(NodeNotification::Ready);
+    assert_eq!(late.get_process_priority(), i32::MAX - 1);
+    assert!(late.bind().ready_called);
+    late.free();
+}
+
+#[cfg(since_api = "4.1")]
+#[derive(GodotClass)]
+#[class(init, base=Node, physics_process_priority = 5)]
+struct PhysicsPriorityNode {
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn physics_process_priority_applied_before_ready() {
+    let mut node = PhysicsPriorityNode::new_alloc();
+    node.notify(NodeNotification::Ready);
+
+    assert_eq!(node.get_physics_process_priority(), 5);
+    assert_eq!(node.get_process_priority(), 0);
+    node.free();
+}
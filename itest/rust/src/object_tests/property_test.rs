@@ -362,6 +362,36 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[derive(GodotConvert, Var, Export, Default, Copy, Clone, PartialEq, Debug)]
+#[godot(transparent)]
+struct Meters(f32);
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DeriveExportTransparent {
+    #[export]
+    distance: Meters,
+}
+
+#[itest]
+fn derive_export_transparent() {
+    let mut class = DeriveExportTransparent::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "distance".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::Float as i32);
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+
+    class.set("distance".into(), 2.5.to_variant());
+    assert_eq!(class.bind().distance, Meters(2.5));
+    assert_eq!(class.get("distance".into()), 2.5.to_variant());
+
+    class.free();
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -79,3 +79,29 @@ fn reentrant_emit_succeeds() {
 
     class.free()
 }
+
+#[itest]
+fn reentrant_try_bind_fails_while_bound() {
+    let mut class = ReentrantClass::new_alloc();
+    let mut copy = class.clone();
+
+    {
+        let _guard = class.bind_mut();
+        assert!(copy.try_bind().is_err());
+        assert!(copy.try_bind_mut().is_err());
+    }
+
+    {
+        let _guard = class.bind();
+        assert!(copy.try_bind().is_ok());
+        assert!(copy.try_bind_mut().is_err());
+    }
+
+    let mut guard = copy.try_bind_mut().expect("no other binds active");
+    guard.second_called = true;
+    drop(guard);
+
+    assert!(class.bind().second_called);
+
+    class.free()
+}
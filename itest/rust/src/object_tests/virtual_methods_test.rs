@@ -282,11 +282,43 @@ impl IRefCounted for SetTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init)]
+struct ReloadStateTest {
+    counter: i64,
+}
+
+#[godot_api]
+impl IRefCounted for ReloadStateTest {
+    fn save_reload_state(&self) -> Variant {
+        self.counter.to_variant()
+    }
+
+    fn restore_reload_state(&mut self, state: Variant) {
+        self.counter = state.to();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[itest]
 fn test_to_string() {
     let _obj = VirtualMethodTest::new_gd();
 }
 
+// Hot reloading itself can only be triggered from the editor; this checks that state round-trips through the hooks.
+#[itest]
+fn test_reload_state() {
+    let mut old = ReloadStateTest::new_gd();
+    old.bind_mut().counter = 42;
+
+    let state = old.bind().save_reload_state();
+
+    let mut recreated = ReloadStateTest::new_gd();
+    recreated.bind_mut().restore_reload_state(state);
+    assert_eq!(recreated.bind().counter, 42);
+}
+
 #[itest]
 fn test_ready(test_context: &TestContext) {
     let obj = VirtualReadyTest::new_alloc();
@@ -8,7 +8,7 @@
 use std::fmt::Debug;
 
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Variant};
+use godot::builtin::{dict, varray, Variant, Vector2};
 use godot::register::{FromGodot, GodotConvert, ToGodot};
 
 use crate::common::roundtrip;
@@ -128,6 +128,195 @@ fn enum_named() {
     });
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Transparent newtypes
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[godot(transparent)]
+struct TransparentTuple(i64);
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[godot(transparent)]
+struct TransparentNamed {
+    name: String,
+}
+
+#[itest]
+fn transparent_newtype() {
+    roundtrip(TransparentTuple(42));
+    roundtrip(TransparentNamed {
+        name: String::from("inner"),
+    });
+
+    assert_eq!(TransparentTuple(42).to_variant(), 42.to_variant());
+    assert_eq!(
+        TransparentNamed::from_variant(&"inner".to_variant()),
+        TransparentNamed {
+            name: String::from("inner")
+        }
+    );
+    assert!(TransparentTuple::try_from_variant(&"text".to_variant()).is_err());
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[godot(lenient)]
+struct LenientNamed {
+    ratio: f32,
+    count: i64,
+}
+
+#[itest]
+fn lenient_struct() {
+    let dict = dict! {
+        "LenientNamed": dict! { "ratio": 2, "count": 3 }
+    };
+
+    assert!(StructNamed::try_from_variant(
+        &dict! {
+            "StructNamed": dict! { "field1": "text", "field2": 2.0 }
+        }
+        .to_variant()
+    )
+    .is_err());
+    assert_eq!(
+        LenientNamed::from_variant(&dict.to_variant()),
+        LenientNamed {
+            ratio: 2.0,
+            count: 3
+        }
+    );
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[repr(i32)]
+enum IntEnum {
+    Low = -1,
+    Mid = 2,
+    High = 5,
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+#[repr(i32)]
+#[godot(lenient)]
+enum LenientIntEnum {
+    Low = -1,
+    High = 5,
+}
+
+#[itest]
+fn int_enum_strict() {
+    assert_eq!(IntEnum::Mid.to_variant(), 2.to_variant());
+    assert_eq!(IntEnum::from_variant(&(-1).to_variant()), IntEnum::Low);
+
+    assert!(IntEnum::try_from_variant(&0.to_variant()).is_err());
+    assert!(IntEnum::try_from_variant(&9.to_variant()).is_err());
+    assert!(IntEnum::try_from_variant(&5.0.to_variant()).is_err());
+}
+
+#[itest]
+fn int_enum_lenient_clamps() {
+    // Below and above the range of discriminants.
+    assert_eq!(
+        IntEnum::try_from_variant_lenient(&(-100).to_variant()).unwrap(),
+        IntEnum::Low
+    );
+    assert_eq!(
+        IntEnum::try_from_variant_lenient(&i64::MAX.to_variant()).unwrap(),
+        IntEnum::High
+    );
+    assert_eq!(
+        IntEnum::try_from_variant_lenient(&9.5.to_variant()).unwrap(),
+        IntEnum::High
+    );
+
+    // Gaps between discriminants have no nearest value.
+    assert!(IntEnum::try_from_variant_lenient(&0.to_variant()).is_err());
+
+    // With #[godot(lenient)], regular conversions clamp as well.
+    assert_eq!(
+        LenientIntEnum::from_variant(&(-7).to_variant()),
+        LenientIntEnum::Low
+    );
+    assert_eq!(
+        LenientIntEnum::from_variant(&6.to_variant()),
+        LenientIntEnum::High
+    );
+    assert_eq!(
+        LenientIntEnum::from_variant(&5.to_variant()),
+        LenientIntEnum::High
+    );
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tagged enum representations
+
+#[derive(FromGodot, ToGodot, GodotConvert, Clone, PartialEq, Debug)]
+#[variant(tag = "type")]
+enum InternallyTagged {
+    Idle,
+    Attack { target: i64, strength: f32 },
+}
+
+#[derive(FromGodot, ToGodot, GodotConvert, Clone, PartialEq, Debug)]
+#[variant(tag = "type", content = "data")]
+enum AdjacentlyTagged {
+    Idle,
+    Move(Vector2),
+    Tuple(String, i32),
+    Attack { target: i64 },
+}
+
+#[itest]
+fn enum_internally_tagged() {
+    roundtrip(InternallyTagged::Idle);
+    roundtrip(InternallyTagged::Attack {
+        target: 7,
+        strength: 0.5,
+    });
+
+    assert_eq!(
+        InternallyTagged::Attack {
+            target: 7,
+            strength: 0.5
+        }
+        .to_variant(),
+        dict! { "type": "Attack", "target": 7, "strength": 0.5 }.to_variant()
+    );
+    assert_eq!(
+        InternallyTagged::Idle.to_variant(),
+        dict! { "type": "Idle" }.to_variant()
+    );
+}
+
+#[itest]
+fn enum_adjacently_tagged() {
+    roundtrip(AdjacentlyTagged::Idle);
+    roundtrip(AdjacentlyTagged::Move(Vector2::new(1.0, 2.0)));
+    roundtrip(AdjacentlyTagged::Tuple(String::from("four"), 5));
+    roundtrip(AdjacentlyTagged::Attack { target: 3 });
+
+    assert_eq!(
+        AdjacentlyTagged::Move(Vector2::new(1.0, 2.0)).to_variant(),
+        dict! { "type": "Move", "data": Vector2::new(1.0, 2.0) }.to_variant()
+    );
+    assert_eq!(
+        AdjacentlyTagged::Tuple(String::from("four"), 5).to_variant(),
+        dict! { "type": "Tuple", "data": varray!["four", 5] }.to_variant()
+    );
+}
+
+#[itest]
+fn enum_tagged_invalid() {
+    let unknown = dict! { "type": "Jump" }.to_variant();
+    assert!(AdjacentlyTagged::try_from_variant(&unknown).is_err());
+
+    let missing_tag = dict! { "target": 3 }.to_variant();
+    assert!(InternallyTagged::try_from_variant(&missing_tag).is_err());
+
+    let missing_field = dict! { "type": "Attack", "target": 3 }.to_variant();
+    assert!(InternallyTagged::try_from_variant(&missing_field).is_err());
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Skipping of enums
 
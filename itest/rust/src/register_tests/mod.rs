@@ -10,6 +10,7 @@ mod derive_variant_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod option_ffi_test;
+mod panic_test;
 mod var_test;
 
 pub use gdscript_ffi_test::gen_ffi;
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use godot::init::{FuncPanicBehavior, PanicReporting};
+use godot::prelude::*;
+use godot::private::{handle_panic, set_panic_config};
+
+use crate::framework::{expect_panic, itest, suppress_godot_print};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct PanicFuncs;
+
+#[godot_api]
+impl PanicFuncs {
+    #[func]
+    fn panics(&self) -> i64 {
+        panic!("expected panic in #[func]")
+    }
+}
+
+// The itest library uses the default configuration, which is restored at the end of each test.
+
+#[itest]
+fn panic_reporting_detailed() {
+    static OUTER_HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_panic_info| {
+        OUTER_HOOK_CALLS.fetch_add(1, Ordering::Relaxed);
+    }));
+
+    set_panic_config(PanicReporting::Detailed, FuncPanicBehavior::FailCall);
+
+    // Caught by godot-rust: reported by the detailed hook, not forwarded.
+    suppress_godot_print(|| {
+        let result = handle_panic(|| "panic_reporting_detailed", || panic!("expected panic"));
+        assert!(result.is_none());
+    });
+    assert_eq!(OUTER_HOOK_CALLS.load(Ordering::Relaxed), 0);
+
+    // Outside of godot-rust's handling: forwarded to the previous hook.
+    let _ = panic::catch_unwind(|| panic!("expected panic"));
+    assert_eq!(OUTER_HOOK_CALLS.load(Ordering::Relaxed), 1);
+
+    // Terse reporting removes the detailed hook and reinstalls the previous one.
+    set_panic_config(PanicReporting::Terse, FuncPanicBehavior::FailCall);
+    let _ = panic::catch_unwind(|| panic!("expected panic"));
+    assert_eq!(OUTER_HOOK_CALLS.load(Ordering::Relaxed), 2);
+
+    panic::set_hook(prev_hook);
+}
+
+#[itest]
+fn func_panic_return_nil() {
+    let obj = PanicFuncs::new_gd().to_variant();
+
+    suppress_godot_print(|| {
+        set_panic_config(PanicReporting::Terse, FuncPanicBehavior::ReturnNil);
+        let result = obj.call("panics", &[]);
+        set_panic_config(PanicReporting::Terse, FuncPanicBehavior::FailCall);
+
+        assert_eq!(result, Variant::nil());
+
+        // Default: the call fails, which Variant::call() turns into a panic.
+        expect_panic("failed call", || {
+            obj.call("panics", &[]);
+        });
+    });
+}